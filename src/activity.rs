@@ -0,0 +1,161 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+
+use crate::config::Config;
+use crate::model::Repo;
+use crate::quiet::notice;
+use crate::state;
+
+/// Something the user did with a repository
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Action {
+    Shown,
+    Star,
+    Unstar,
+    Clone,
+    Open,
+    Save,
+    Block,
+    Unblock,
+}
+
+/// One line of the activity log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Event {
+    pub timestamp: DateTime<Utc>,
+    pub action: Action,
+    /// Repository name (`owner/repo`) or URL, as given
+    pub repo: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl Event {
+    pub fn new(action: Action, repo: &str) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            action,
+            repo: repo.to_string(),
+            provider: None,
+            url: None,
+        }
+    }
+
+    pub fn for_repo(action: Action, repo: &Repo) -> Self {
+        Self {
+            provider: Some(repo.provider.clone()),
+            url: Some(repo.url.clone()),
+            ..Self::new(action, &repo.name)
+        }
+    }
+}
+
+/// Append-only JSONL log of shown repositories and actions taken on them
+pub struct ActivityLog {
+    file: PathBuf,
+}
+
+impl ActivityLog {
+    /// Create a new activity log in the data directory
+    pub fn new() -> Result<Self> {
+        let data_dir = state::data_dir()?;
+
+        Ok(Self::with_dir(&data_dir))
+    }
+
+    fn with_dir(data_dir: &Path) -> Self {
+        Self {
+            file: data_dir.join("activity.jsonl"),
+        }
+    }
+
+    /// Append events, one JSON object per line
+    pub async fn append(&self, events: &[Event]) -> Result<()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let mut lines = String::new();
+        for event in events {
+            lines.push_str(
+                &serde_json::to_string(event).context("Failed to serialize activity event")?,
+            );
+            lines.push('\n');
+        }
+
+        if let Some(parent) = self.file.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.file)
+            .await
+            .with_context(|| format!("Failed to open activity log: {}", self.file.display()))?;
+        file.write_all(lines.as_bytes())
+            .await
+            .with_context(|| format!("Failed to write activity log: {}", self.file.display()))?;
+        // tokio finishes writes in the background; flush so they land before returning
+        file.flush()
+            .await
+            .with_context(|| format!("Failed to write activity log: {}", self.file.display()))
+    }
+}
+
+/// Append events when the activity log is enabled; failures only print a warning
+pub async fn record(config: &Config, events: &[Event]) {
+    if !config.general.activity_log {
+        return;
+    }
+    let result = match ActivityLog::new() {
+        Ok(log) => log.append(events).await,
+        Err(e) => Err(e),
+    };
+    if let Err(e) = result {
+        notice!("⚠ {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_append_jsonl() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-activity-test-{}",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let log = ActivityLog::with_dir(&temp_dir);
+
+        log.append(&[Event::new(Action::Star, "owner/repo")])
+            .await
+            .unwrap();
+        log.append(&[
+            Event::new(Action::Clone, "owner/repo"),
+            Event::new(Action::Open, "owner/other"),
+        ])
+        .await
+        .unwrap();
+
+        let content = std::fs::read_to_string(&log.file).unwrap();
+        let events: Vec<Event> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].action, Action::Star);
+        assert_eq!(events[2].repo, "owner/other");
+        assert!(content.contains(r#""action":"clone""#));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
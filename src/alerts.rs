@@ -0,0 +1,119 @@
+use colored::Colorize;
+use std::fmt::Write;
+
+use crate::model::Repo;
+use crate::render::OutputFormat;
+
+/// Why a trending repo concerns the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Reason {
+    Owned,
+    Starred,
+}
+
+/// Repos owned by one of `accounts` (case-insensitive) or starred by the user
+fn matches<'a>(repos: &'a [Repo], accounts: &[String]) -> Vec<(&'a Repo, Reason)> {
+    repos
+        .iter()
+        .filter_map(|repo| {
+            let owner = repo.name.split('/').next().unwrap_or_default();
+            if accounts.iter().any(|a| a.eq_ignore_ascii_case(owner)) {
+                Some((repo, Reason::Owned))
+            } else if repo.is_starred {
+                Some((repo, Reason::Starred))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// "Your repos trending" section printed above the list, empty when nothing matches
+///
+/// Only MOTD and accessible output get the section; structured formats already
+/// carry `is_starred` and the repo name. A `title` replaces the built-in heading.
+pub fn section(
+    repos: &[Repo],
+    accounts: &[String],
+    format: OutputFormat,
+    title: Option<&str>,
+) -> String {
+    let found = matches(repos, accounts);
+    if found.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::new();
+    match format {
+        OutputFormat::Motd => {
+            let title = title.unwrap_or("🎉 Your repos are trending today:");
+            let _ = writeln!(out, "{}", title.bold());
+            for (repo, reason) in found {
+                let label = match reason {
+                    Reason::Owned => "yours",
+                    Reason::Starred => "starred",
+                };
+                let _ = writeln!(
+                    out,
+                    "   {} {} ({label})",
+                    repo.icon,
+                    repo.name.bright_cyan().bold()
+                );
+            }
+            out.push('\n');
+        }
+        OutputFormat::Accessible => {
+            if let Some(title) = title {
+                let _ = writeln!(out, "{title}");
+            }
+            for (repo, reason) in found {
+                let label = match reason {
+                    Reason::Owned => "Your repository",
+                    Reason::Starred => "A repository you starred",
+                };
+                let _ = writeln!(out, "{label} is trending today: {}", repo.name);
+            }
+            out.push('\n');
+        }
+        _ => {}
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_repo(name: &str, is_starred: bool) -> Repo {
+        Repo {
+            is_starred,
+            ..Repo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_section() {
+        colored::control::set_override(false);
+        let repos = vec![
+            create_test_repo("Me/project", false),
+            create_test_repo("other/liked", true),
+            create_test_repo("other/unrelated", false),
+        ];
+        let accounts = vec!["me".to_string()];
+
+        let motd = section(&repos, &accounts, OutputFormat::Motd, None);
+        assert!(motd.contains("Your repos are trending"));
+        assert!(motd.contains("Me/project (yours)"));
+        assert!(motd.contains("other/liked (starred)"));
+        assert!(!motd.contains("unrelated"));
+
+        let accessible = section(&repos, &accounts, OutputFormat::Accessible, None);
+        assert!(accessible.contains("Your repository is trending today: Me/project"));
+
+        assert!(section(&repos, &accounts, OutputFormat::Json, None).is_empty());
+        assert!(section(&repos[2..], &accounts, OutputFormat::Motd, None).is_empty());
+
+        let titled = section(&repos, &accounts, OutputFormat::Motd, Some("Mine:"));
+        assert!(titled.starts_with("Mine:\n"));
+    }
+}
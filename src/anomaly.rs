@@ -0,0 +1,95 @@
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+
+use crate::model::Repo;
+use crate::providers::GitHub;
+
+/// Stars in a day below which a spike isn't worth a lookup
+pub const SPIKE_STARS: u64 = 200;
+
+/// Repos created within this many days count as freshly created
+const YOUNG_DAYS: i64 = 30;
+
+/// At most this many commits counts as little history
+const FEW_COMMITS: u64 = 10;
+
+/// Signals a star spike is judged against; `None` means the signal is unknown
+#[derive(Debug, Clone, Default)]
+pub struct StarSignals {
+    pub created_at: Option<DateTime<Utc>>,
+    /// Commits on the default branch
+    pub commits: Option<u64>,
+    /// Contributor count, capped at the number requested from the API
+    pub contributors: Option<usize>,
+}
+
+/// Whether a day's stars look farmed: a spike of `SPIKE_STARS`+ on a repo created in
+/// the last `YOUNG_DAYS` days with few commits or a single contributor
+///
+/// Unknown signals never flag a repo.
+pub fn is_suspicious(stars_today: Option<u64>, signals: &StarSignals, now: DateTime<Utc>) -> bool {
+    let spike = stars_today.is_some_and(|stars| stars >= SPIKE_STARS);
+    let young = signals
+        .created_at
+        .is_some_and(|created| (now - created).num_days() <= YOUNG_DAYS);
+    let thin = signals
+        .commits
+        .is_some_and(|commits| commits <= FEW_COMMITS)
+        || signals.contributors.is_some_and(|count| count <= 1);
+    spike && young && thin
+}
+
+/// Flag GitHub repos with suspicious star spikes
+///
+/// Only repos gaining `SPIKE_STARS` or more today are looked up, two requests each;
+/// failed lookups stay unflagged.
+pub async fn enrich(repos: &mut [Repo], github: &GitHub, token: Option<&str>) {
+    let now = Utc::now();
+    let lookups = repos.iter().map(|repo| async move {
+        if repo.provider != "github" || repo.stars_today.unwrap_or(0) < SPIKE_STARS {
+            return None;
+        }
+        let (owner, name) = repo.name.split_once('/')?;
+        github.star_signals(owner, name, token).await.ok()
+    });
+    let signals = join_all(lookups).await;
+
+    for (repo, signals) in repos.iter_mut().zip(signals) {
+        if let Some(signals) = signals {
+            repo.is_suspicious = is_suspicious(repo.stars_today, &signals, now);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_is_suspicious() {
+        let now = Utc::now();
+        let farmed = StarSignals {
+            created_at: Some(now - Duration::days(3)),
+            commits: Some(2),
+            contributors: Some(1),
+        };
+        assert!(is_suspicious(Some(1500), &farmed, now));
+        assert!(!is_suspicious(Some(50), &farmed, now), "no spike");
+        assert!(!is_suspicious(None, &farmed, now));
+
+        let established = StarSignals {
+            created_at: Some(now - Duration::days(400)),
+            ..farmed.clone()
+        };
+        assert!(!is_suspicious(Some(1500), &established, now));
+
+        let busy = StarSignals {
+            commits: Some(120),
+            contributors: Some(6),
+            ..farmed
+        };
+        assert!(!is_suspicious(Some(1500), &busy, now));
+        assert!(!is_suspicious(Some(1500), &StarSignals::default(), now));
+    }
+}
@@ -0,0 +1,241 @@
+use anyhow::{Context, Result};
+use flate2::read::GzDecoder;
+use serde::Deserialize;
+use std::path::{Component, Path, PathBuf};
+
+use crate::clone;
+use crate::config::Config;
+use crate::http::HttpClient;
+
+/// Timeout for archive downloads, which are larger than API responses
+const DOWNLOAD_TIMEOUT_SECS: u64 = 120;
+
+/// API flavour serving a repository's archives
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Forge {
+    GitHub,
+    GitLab,
+    Bitbucket,
+    /// Gitea and Forgejo, including Codeberg
+    Gitea,
+}
+
+impl Forge {
+    /// Forge of a repository, from its provider id when known or else its host
+    pub fn detect(provider: Option<&str>, host: &str) -> Self {
+        match provider {
+            Some("github") => Self::GitHub,
+            Some("gitlab") => Self::GitLab,
+            Some("bitbucket") => Self::Bitbucket,
+            Some("gitea" | "codeberg") => Self::Gitea,
+            _ if host.eq_ignore_ascii_case("github.com") => Self::GitHub,
+            _ if host.to_ascii_lowercase().contains("gitlab") => Self::GitLab,
+            _ if host.eq_ignore_ascii_case("bitbucket.org") => Self::Bitbucket,
+            _ => Self::Gitea,
+        }
+    }
+
+    /// Configured token for API requests to `host`
+    ///
+    /// Tokens only go to the hosts they were configured for; archives from any other
+    /// server are fetched anonymously.
+    pub fn token<'a>(self, config: &'a Config, host: &str) -> Option<&'a str> {
+        match self {
+            // Archives come from api.github.com whatever the host
+            Self::GitHub => config.auth.github_token.as_deref(),
+            Self::GitLab if host.eq_ignore_ascii_case(base_host(&config.gitlab.base_url)) => {
+                config.auth.gitlab_token.as_deref()
+            }
+            Self::Gitea if host.eq_ignore_ascii_case(base_host(&config.gitea.base_url)) => {
+                config.auth.gitea_token.as_deref()
+            }
+            Self::Gitea if host.eq_ignore_ascii_case(base_host(&config.codeberg.base_url)) => {
+                config.auth.codeberg_token.as_deref()
+            }
+            // App passwords need Basic auth, so only public Bitbucket repos are reachable
+            Self::GitLab | Self::Bitbucket | Self::Gitea => None,
+        }
+    }
+}
+
+/// Host (with port) of a configured base URL such as `https://gitea.example.com/`
+fn base_host(base_url: &str) -> &str {
+    let rest = base_url
+        .split_once("://")
+        .map_or(base_url, |(_, rest)| rest);
+    rest.split('/').next().unwrap_or_default()
+}
+
+#[derive(Deserialize)]
+struct GiteaRepo {
+    default_branch: String,
+}
+
+/// Tarball URL of the default branch of `path` (`owner/repo`) on `host`
+///
+/// Gitea needs the branch name in the URL, so only it takes `branch`.
+pub fn tarball_url(forge: Forge, host: &str, path: &str, branch: &str) -> String {
+    match forge {
+        Forge::GitHub => format!("https://api.github.com/repos/{path}/tarball"),
+        Forge::GitLab => format!(
+            "https://{host}/api/v4/projects/{}/repository/archive.tar.gz",
+            path.replace('/', "%2F")
+        ),
+        Forge::Bitbucket => format!("https://bitbucket.org/{path}/get/HEAD.tar.gz"),
+        Forge::Gitea => format!("https://{host}/api/v1/repos/{path}/archive/{branch}.tar.gz"),
+    }
+}
+
+/// Download the default branch of the repository at `url` and unpack it into `target`
+///
+/// Returns the number of files written.
+pub async fn fetch(
+    config: &Config,
+    url: &str,
+    provider: Option<&str>,
+    target: &Path,
+) -> Result<usize> {
+    let (host, path) = clone::split_url(url)?;
+    let forge = Forge::detect(provider, &host);
+    let token = forge.token(config, &host);
+    let http = HttpClient::new(DOWNLOAD_TIMEOUT_SECS)?;
+
+    let branch = if forge == Forge::Gitea {
+        let repo: GiteaRepo = http
+            .get_json(&format!("https://{host}/api/v1/repos/{path}"), token)
+            .await
+            .with_context(|| format!("Failed to look up the default branch of {path}"))?;
+        repo.default_branch
+    } else {
+        String::new()
+    };
+
+    let tarball = http
+        .get_bytes(&tarball_url(forge, &host, &path, &branch), token)
+        .await?;
+    unpack(&tarball, target)
+}
+
+/// Unpack a gzipped tarball into `target`, dropping the single top-level directory
+/// forges wrap archives in
+///
+/// Links and entries escaping `target` are skipped. Returns the number of files written.
+pub fn unpack(tarball: &[u8], target: &Path) -> Result<usize> {
+    let mut archive = tar::Archive::new(GzDecoder::new(tarball));
+    let mut files = 0;
+
+    for entry in archive.entries().context("Failed to read archive")? {
+        let mut entry = entry.context("Failed to read archive entry")?;
+        let kind = entry.header().entry_type();
+        if !(kind.is_file() || kind.is_dir()) {
+            continue;
+        }
+
+        let relative: PathBuf = entry.path()?.components().skip(1).collect();
+        let contained = relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+        if relative.as_os_str().is_empty() || !contained {
+            continue;
+        }
+
+        let dest = target.join(&relative);
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+        entry
+            .unpack(&dest)
+            .with_context(|| format!("Failed to write {}", dest.display()))?;
+        if kind.is_file() {
+            files += 1;
+        }
+    }
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    #[test]
+    fn test_token_only_for_configured_hosts() {
+        let mut config = Config::default();
+        config.auth.github_token = Some("gh".to_string());
+        config.auth.gitlab_token = Some("gl".to_string());
+        config.auth.gitea_token = Some("gt".to_string());
+        config.auth.codeberg_token = Some("cb".to_string());
+        config.gitea.base_url = "https://git.example.com/".to_string();
+
+        assert_eq!(Forge::GitHub.token(&config, "github.com"), Some("gh"));
+        assert_eq!(Forge::GitLab.token(&config, "gitlab.com"), Some("gl"));
+        assert_eq!(Forge::GitLab.token(&config, "gitlab.evil.test"), None);
+        assert_eq!(Forge::Gitea.token(&config, "git.example.com"), Some("gt"));
+        assert_eq!(Forge::Gitea.token(&config, "codeberg.org"), Some("cb"));
+        assert_eq!(Forge::Gitea.token(&config, "example.org"), None);
+        assert_eq!(Forge::Bitbucket.token(&config, "bitbucket.org"), None);
+    }
+
+    #[test]
+    fn test_tarball_url() {
+        assert_eq!(Forge::detect(None, "github.com"), Forge::GitHub);
+        assert_eq!(Forge::detect(None, "gitlab.example.org"), Forge::GitLab);
+        assert_eq!(
+            Forge::detect(Some("codeberg"), "codeberg.org"),
+            Forge::Gitea
+        );
+
+        assert_eq!(
+            tarball_url(Forge::GitHub, "github.com", "owner/repo", ""),
+            "https://api.github.com/repos/owner/repo/tarball"
+        );
+        assert_eq!(
+            tarball_url(Forge::GitLab, "gitlab.com", "group/sub/repo", ""),
+            "https://gitlab.com/api/v4/projects/group%2Fsub%2Frepo/repository/archive.tar.gz"
+        );
+        assert_eq!(
+            tarball_url(Forge::Bitbucket, "bitbucket.org", "team/repo", ""),
+            "https://bitbucket.org/team/repo/get/HEAD.tar.gz"
+        );
+        assert_eq!(
+            tarball_url(Forge::Gitea, "codeberg.org", "owner/repo", "main"),
+            "https://codeberg.org/api/v1/repos/owner/repo/archive/main.tar.gz"
+        );
+    }
+
+    #[test]
+    fn test_unpack_strips_top_level_directory() {
+        let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::fast()));
+        for (path, body) in [
+            ("owner-repo-abc123/README.md", "# repo\n"),
+            ("owner-repo-abc123/src/main.rs", "fn main() {}\n"),
+        ] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(body.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, path, body.as_bytes())
+                .unwrap();
+        }
+        let tarball = builder.into_inner().unwrap().finish().unwrap();
+
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let target = std::env::temp_dir().join(format!("trotd-archive-test-{nanos}"));
+
+        assert_eq!(unpack(&tarball, &target).unwrap(), 2);
+        assert_eq!(
+            std::fs::read_to_string(target.join("src/main.rs")).unwrap(),
+            "fn main() {}\n"
+        );
+        assert!(target.join("README.md").exists());
+
+        std::fs::remove_dir_all(&target).unwrap();
+    }
+}
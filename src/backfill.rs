@@ -0,0 +1,42 @@
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::config::Config;
+use crate::model::{LanguageFilter, Repo};
+use crate::providers;
+use crate::render::OutputFormat;
+use crate::seen::SeenTracker;
+
+/// Label above backfilled output, so it isn't mistaken for today's list
+pub fn label(format: OutputFormat) -> String {
+    if matches!(format, OutputFormat::Accessible) {
+        "Nothing new is trending today. Showing this week's trending repositories instead.\n"
+            .to_string()
+    } else {
+        format!(
+            "{}\n",
+            "📅 Nothing new trending today, showing this week's repos instead:".bold()
+        )
+    }
+}
+
+/// GitHub's weekly trending repos that haven't been shown today
+///
+/// Only GitHub has a weekly list; the GitLab and Gitea queries already cover the
+/// last week and have nothing more to give.
+pub async fn weekly(
+    config: &Config,
+    langs: &LanguageFilter,
+    tracker: &SeenTracker,
+) -> Result<Vec<Repo>> {
+    let github = providers::build("github", config)?;
+    // With topic filters GitHub is queried through the Search API, whose list
+    // already spans the last week, so the weekly page only changes the scraped list
+    let mut cfg = config.provider_cfg("github");
+    cfg.weekly = true;
+
+    let repos = github
+        .top_today(&cfg, 0, config.get_max_entries("github"), langs)
+        .await?;
+    tracker.filter_unseen(&repos).await
+}
@@ -0,0 +1,221 @@
+use anyhow::{Context, Result};
+use futures::future::join_all;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::config::Config;
+use crate::filter;
+use crate::model::{LanguageFilter, Repo};
+use crate::providers;
+use crate::quiet::notice;
+use crate::render::{render_to_string, OutputFormat, RenderOptions};
+use crate::spam;
+
+/// Options for a benchmark run
+pub struct BenchOptions {
+    pub iterations: usize,
+    pub fixtures: Option<PathBuf>,
+    pub record: Option<PathBuf>,
+    pub format: OutputFormat,
+}
+
+/// Latency samples collected per stage, keyed by stage name
+#[derive(Default)]
+struct Samples {
+    stages: BTreeMap<String, Vec<Duration>>,
+}
+
+impl Samples {
+    fn record(&mut self, stage: &str, elapsed: Duration) {
+        self.stages
+            .entry(stage.to_string())
+            .or_default()
+            .push(elapsed);
+    }
+}
+
+/// Run repeated fetch/filter/render cycles and print latency percentiles per stage
+pub async fn run(config: &Config, provider_ids: &[String], opts: BenchOptions) -> Result<()> {
+    let lang_filter = LanguageFilter::new(config.general.language_filter.clone())
+        .with_unknown(config.general.include_unknown_lang)
+        .with_secondary(config.general.secondary_language_percent);
+    let mut samples = Samples::default();
+
+    let source = opts
+        .fixtures
+        .as_ref()
+        .map_or_else(|| "network".to_string(), |dir| dir.display().to_string());
+    notice!(
+        "⏱ Benchmarking {} cycles over {provider_ids:?} ({source})",
+        opts.iterations
+    );
+
+    for iteration in 0..opts.iterations {
+        let cycle_start = Instant::now();
+
+        let fetches = provider_ids.iter().map(|id| {
+            let lang_filter = &lang_filter;
+            let fixtures = opts.fixtures.as_deref();
+            async move {
+                let start = Instant::now();
+                let result = match fixtures {
+                    Some(dir) => load_fixture(dir, id, config.get_max_entries(id)).await,
+                    None => fetch_provider(config, id, lang_filter).await,
+                };
+                (id, result, start.elapsed())
+            }
+        });
+        let results = join_all(fetches).await;
+        samples.record("fetch (all)", cycle_start.elapsed());
+
+        let mut all_repos = Vec::new();
+        for (id, result, elapsed) in results {
+            samples.record(&format!("fetch {id}"), elapsed);
+            match result {
+                Ok(repos) => {
+                    if iteration == 0 {
+                        if let Some(ref dir) = opts.record {
+                            save_fixture(dir, id, &repos).await?;
+                        }
+                    }
+                    all_repos.extend(repos);
+                }
+                Err(e) => notice!("✗ Cycle {}: {id}: {e}", iteration + 1),
+            }
+        }
+
+        let start = Instant::now();
+        if config.general.ascii_only {
+            filter::ascii_only(&mut all_repos);
+        }
+        if let Some(min_stars) = config.general.min_stars {
+            filter::min_stars(&mut all_repos, min_stars);
+        }
+        if let Some(max_stars) = config.general.max_stars {
+            filter::max_stars(&mut all_repos, max_stars);
+        }
+        spam::apply(&mut all_repos, &config.spam, &HashSet::new());
+        samples.record("filter", start.elapsed());
+
+        let start = Instant::now();
+        let output = render_to_string(&all_repos, opts.format, &RenderOptions::from_config(config));
+        samples.record("render", start.elapsed());
+        std::hint::black_box(output);
+
+        samples.record("total", cycle_start.elapsed());
+    }
+
+    if let Some(ref dir) = opts.record {
+        notice!("💾 Recorded fixtures to {}", dir.display());
+    }
+
+    print!("{}", report(&samples));
+    Ok(())
+}
+
+/// Fetch a single provider from the network without touching cache or seen state
+async fn fetch_provider(config: &Config, id: &str, langs: &LanguageFilter) -> Result<Vec<Repo>> {
+    let provider = providers::build(id, config)?;
+    provider
+        .top_today(
+            &config.provider_cfg(id),
+            0,
+            config.get_max_entries(id),
+            langs,
+        )
+        .await
+}
+
+/// Load a recorded provider result (`<dir>/<provider>.json`, a JSON array of repos)
+async fn load_fixture(dir: &Path, id: &str, limit: usize) -> Result<Vec<Repo>> {
+    let path = dir.join(format!("{id}.json"));
+    let content = tokio::fs::read_to_string(&path)
+        .await
+        .with_context(|| format!("Failed to read fixture: {}", path.display()))?;
+    let repos: Vec<Repo> = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse fixture: {}", path.display()))?;
+    Ok(repos.into_iter().take(limit).collect())
+}
+
+/// Save a provider result as a fixture for later offline runs
+async fn save_fixture(dir: &Path, id: &str, repos: &[Repo]) -> Result<()> {
+    tokio::fs::create_dir_all(dir)
+        .await
+        .with_context(|| format!("Failed to create fixture directory: {}", dir.display()))?;
+
+    let path = dir.join(format!("{id}.json"));
+    let content = serde_json::to_string_pretty(repos).context("Failed to serialize fixture")?;
+    tokio::fs::write(&path, content)
+        .await
+        .with_context(|| format!("Failed to write fixture: {}", path.display()))
+}
+
+/// Nearest-rank percentile of a sorted sample set
+fn percentile(sorted: &[Duration], pct: usize) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    sorted[rank.min(sorted.len()) - 1]
+}
+
+/// Format the percentile table
+fn report(samples: &Samples) -> String {
+    let mut out = format!(
+        "{:<16} {:>10} {:>10} {:>10} {:>10}\n",
+        "stage", "p50", "p90", "p99", "max"
+    );
+
+    for (stage, durations) in &samples.stages {
+        let mut sorted = durations.clone();
+        sorted.sort();
+        let ms = |d: Duration| format!("{:.1}ms", d.as_secs_f64() * 1000.0);
+        let _ = writeln!(
+            out,
+            "{stage:<16} {:>10} {:>10} {:>10} {:>10}",
+            ms(percentile(&sorted, 50)),
+            ms(percentile(&sorted, 90)),
+            ms(percentile(&sorted, 99)),
+            ms(*sorted.last().unwrap_or(&Duration::ZERO)),
+        );
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentile() {
+        let sorted: Vec<Duration> = (1..=10).map(Duration::from_millis).collect();
+        assert_eq!(percentile(&sorted, 50), Duration::from_millis(5));
+        assert_eq!(percentile(&sorted, 90), Duration::from_millis(9));
+        assert_eq!(percentile(&sorted, 99), Duration::from_millis(10));
+        assert_eq!(percentile(&[], 50), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_fixture_roundtrip() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-bench-test-{}",
+            chrono::Utc::now().timestamp()
+        ));
+        let repos: Vec<Repo> = (0..3)
+            .map(|i| Repo {
+                stars_today: Some(i),
+                ..Repo::test(&format!("owner/repo{i}"))
+            })
+            .collect();
+
+        save_fixture(&temp_dir, "github", &repos).await.unwrap();
+        let loaded = load_fixture(&temp_dir, "github", 2).await.unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[1].name, "owner/repo1");
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
@@ -0,0 +1,54 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::config::Config;
+use crate::lookup::{self, RepoCheck};
+use crate::model::Repo;
+use crate::providers::{GitHub, GitLab, Gitea};
+
+/// Whether the latest release has uploaded assets
+struct ReleaseAssets;
+
+#[async_trait]
+impl RepoCheck for ReleaseAssets {
+    type Answer = bool;
+
+    async fn github(&self, github: &GitHub, name: &str, token: Option<&str>) -> Result<bool> {
+        github.has_release_assets(name, token).await
+    }
+
+    async fn gitlab(
+        &self,
+        gitlab: &GitLab,
+        base_url: &str,
+        path: &str,
+        token: Option<&str>,
+    ) -> Result<bool> {
+        gitlab.has_release_assets(base_url, path, token).await
+    }
+
+    async fn gitea(
+        &self,
+        gitea: &Gitea,
+        base_url: &str,
+        name: &str,
+        token: Option<&str>,
+    ) -> Result<bool> {
+        gitea.has_release_assets(base_url, name, token).await
+    }
+}
+
+/// Mark repos whose latest release ships prebuilt binaries (uploaded release assets)
+///
+/// One request per repo; repos without releases, and failed lookups, stay unmarked.
+/// With `skip_github`, GitHub repos already covered by [`crate::enrich::github_batch`]
+/// are left as they are.
+pub async fn enrich(repos: &mut [Repo], config: &Config, skip_github: bool) {
+    let has_binaries = lookup::check_each(repos, config, skip_github, &ReleaseAssets).await;
+
+    for (repo, has_binaries) in repos.iter_mut().zip(has_binaries) {
+        if let Some(has_binaries) = has_binaries {
+            repo.has_binaries = has_binaries;
+        }
+    }
+}
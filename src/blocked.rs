@@ -0,0 +1,94 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::state;
+
+/// Blocklist entries (`owner/repo` or `owner/*`) added with `block`, kept in the data
+/// directory and applied on top of `general.blocklist`
+pub struct BlockedStore {
+    blocked_file: PathBuf,
+}
+
+impl BlockedStore {
+    /// Create a new blocked-repos store
+    pub fn new() -> Result<Self> {
+        let data_dir = state::data_dir()?;
+
+        Ok(Self::with_dir(&data_dir))
+    }
+
+    fn with_dir(data_dir: &Path) -> Self {
+        Self {
+            blocked_file: data_dir.join("blocked.json"),
+        }
+    }
+
+    /// Entries in the order they were blocked
+    pub async fn list(&self) -> Result<Vec<String>> {
+        Ok(state::read_json(&self.blocked_file)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Block an entry; returns false when it was already blocked
+    pub async fn add(&self, entry: &str) -> Result<bool> {
+        let mut blocked = self.list().await?;
+        if blocked.iter().any(|b| b.eq_ignore_ascii_case(entry)) {
+            return Ok(false);
+        }
+        blocked.push(entry.to_string());
+        self.save(&blocked).await?;
+        Ok(true)
+    }
+
+    /// Unblock an entry; returns false when it wasn't blocked
+    pub async fn remove(&self, entry: &str) -> Result<bool> {
+        let mut blocked = self.list().await?;
+        let before_count = blocked.len();
+        blocked.retain(|b| !b.eq_ignore_ascii_case(entry));
+        if blocked.len() == before_count {
+            return Ok(false);
+        }
+        self.save(&blocked).await?;
+        Ok(true)
+    }
+
+    async fn save(&self, blocked: &[String]) -> Result<()> {
+        let content =
+            serde_json::to_string_pretty(blocked).context("Failed to serialize blocked repos")?;
+        state::write_atomic(&self.blocked_file, &content)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to write blocked repos file: {}",
+                    self.blocked_file.display()
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_block_and_unblock() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-blocked-test-{}",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let store = BlockedStore::with_dir(&temp_dir);
+
+        assert!(store.list().await.unwrap().is_empty());
+        assert!(store.add("spam/repo").await.unwrap());
+        assert!(store.add("bots/*").await.unwrap());
+        assert!(!store.add("Spam/Repo").await.unwrap());
+        assert_eq!(store.list().await.unwrap(), ["spam/repo", "bots/*"]);
+
+        assert!(store.remove("SPAM/repo").await.unwrap());
+        assert!(!store.remove("spam/repo").await.unwrap());
+        assert_eq!(store.list().await.unwrap(), ["bots/*"]);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
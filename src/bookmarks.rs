@@ -0,0 +1,195 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::model::Repo;
+use crate::readlater::Link;
+use crate::state;
+
+/// A repository bookmarked with `bookmark add`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub name: String,
+    pub url: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub topics: Vec<String>,
+    /// Total stars when bookmarked
+    #[serde(default)]
+    pub stars: Option<u64>,
+    pub added: DateTime<Utc>,
+    /// Already sent to the bookmark manager by `bookmark push`
+    #[serde(default)]
+    pub pushed: bool,
+}
+
+impl Bookmark {
+    pub fn from_repo(repo: &Repo) -> Self {
+        Self {
+            name: repo.name.clone(),
+            url: repo.url.clone(),
+            description: repo.description.clone(),
+            language: repo.language.clone(),
+            topics: repo.topics.clone(),
+            stars: repo.stars_total,
+            added: Utc::now(),
+            pushed: false,
+        }
+    }
+
+    /// Bookmark without metadata, for a repo that wasn't in the last output
+    pub fn from_url(name: &str, url: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            url: url.to_string(),
+            description: None,
+            language: None,
+            topics: Vec::new(),
+            stars: None,
+            added: Utc::now(),
+            pushed: false,
+        }
+    }
+
+    /// Tags for the bookmark manager: the language followed by the topics
+    ///
+    /// Lowercased with spaces replaced by hyphens, since tags can't contain spaces.
+    pub fn tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = Vec::new();
+        for tag in self.language.iter().chain(&self.topics) {
+            let tag = tag.trim().to_lowercase().replace(char::is_whitespace, "-");
+            if !tag.is_empty() && !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        tags
+    }
+
+    /// The bookmark as `bookmark push` sends it to the read-later service
+    pub fn link(&self) -> Link<'_> {
+        Link {
+            url: &self.url,
+            title: Some(&self.name),
+            description: self.description.as_deref(),
+            tags: self.tags(),
+        }
+    }
+}
+
+/// Bookmarked repositories, kept in the data directory
+pub struct BookmarkStore {
+    bookmarks_file: PathBuf,
+}
+
+impl BookmarkStore {
+    /// Create a new bookmark store
+    pub fn new() -> Result<Self> {
+        let data_dir = state::data_dir()?;
+
+        Ok(Self::with_dir(&data_dir))
+    }
+
+    fn with_dir(data_dir: &Path) -> Self {
+        Self {
+            bookmarks_file: data_dir.join("bookmarks.json"),
+        }
+    }
+
+    /// Bookmarks in the order they were added
+    pub async fn list(&self) -> Result<Vec<Bookmark>> {
+        Ok(state::read_json(&self.bookmarks_file)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Add a bookmark; returns false when the repository was already bookmarked
+    pub async fn add(&self, bookmark: Bookmark) -> Result<bool> {
+        let mut bookmarks = self.list().await?;
+        if bookmarks.iter().any(|b| b.url == bookmark.url) {
+            return Ok(false);
+        }
+        bookmarks.push(bookmark);
+        self.save(&bookmarks).await?;
+        Ok(true)
+    }
+
+    /// Remove a bookmark by name; returns false when it wasn't bookmarked
+    pub async fn remove(&self, name: &str) -> Result<bool> {
+        let mut bookmarks = self.list().await?;
+        let before_count = bookmarks.len();
+        bookmarks.retain(|b| !b.name.eq_ignore_ascii_case(name));
+        if bookmarks.len() == before_count {
+            return Ok(false);
+        }
+        self.save(&bookmarks).await?;
+        Ok(true)
+    }
+
+    /// Replace the stored bookmarks
+    pub async fn save(&self, bookmarks: &[Bookmark]) -> Result<()> {
+        let content =
+            serde_json::to_string_pretty(bookmarks).context("Failed to serialize bookmarks")?;
+        state::write_atomic(&self.bookmarks_file, &content)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to write bookmarks file: {}",
+                    self.bookmarks_file.display()
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_bookmark(name: &str) -> Bookmark {
+        Bookmark {
+            language: Some("Rust".to_string()),
+            topics: vec![
+                "cli".to_string(),
+                "Command Line".to_string(),
+                "rust".to_string(),
+            ],
+            ..Bookmark::from_url(name, &format!("https://github.com/{name}"))
+        }
+    }
+
+    #[test]
+    fn test_tags_and_link() {
+        let bookmark = create_test_bookmark("owner/tool");
+        assert_eq!(bookmark.tags(), ["rust", "cli", "command-line"]);
+
+        let link = bookmark.link();
+        assert_eq!(link.url, "https://github.com/owner/tool");
+        assert_eq!(link.title, Some("owner/tool"));
+        assert_eq!(link.tags[2], "command-line");
+    }
+
+    #[tokio::test]
+    async fn test_store_add_remove() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-bookmarks-test-{}",
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let store = BookmarkStore::with_dir(&temp_dir);
+
+        assert!(store.list().await.unwrap().is_empty());
+        assert!(store.add(create_test_bookmark("a/one")).await.unwrap());
+        assert!(store.add(create_test_bookmark("b/two")).await.unwrap());
+        assert!(!store.add(create_test_bookmark("a/one")).await.unwrap());
+
+        assert!(store.remove("A/One").await.unwrap());
+        assert!(!store.remove("a/one").await.unwrap());
+        let bookmarks = store.list().await.unwrap();
+        assert_eq!(bookmarks.len(), 1);
+        assert_eq!(bookmarks[0].name, "b/two");
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
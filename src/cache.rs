@@ -1,9 +1,10 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::model::Repo;
+use crate::state;
 
 /// Cache entry with timestamp
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,37 +13,80 @@ struct CacheEntry {
     repos: Vec<Repo>,
 }
 
+/// Permissions of a shared cache directory, so every user on the machine can read it
+#[cfg(unix)]
+const SHARED_DIR_MODE: u32 = 0o755;
+
+/// Permissions of the files in a shared cache directory
+#[cfg(unix)]
+const SHARED_FILE_MODE: u32 = 0o644;
+
 /// Filesystem-based cache with TTL support
 pub struct Cache {
-    cache_dir: PathBuf,
+    dir: PathBuf,
     ttl_secs: u64,
+    /// A `--system-cache` directory shared by every user on the machine
+    shared: bool,
+    /// The shared directory isn't writable for this user: entries are read but never stored
+    read_only: bool,
 }
 
 impl Cache {
     /// Create a new cache instance
     pub fn new(ttl_mins: u64) -> Result<Self> {
-        let cache_dir = dirs::cache_dir()
-            .context("Failed to determine cache directory")?
-            .join("trotd");
+        let cache_dir = state::cache_dir()?;
 
         Ok(Self {
-            cache_dir,
-            ttl_secs: ttl_mins * 60,
+            dir: cache_dir,
+            ttl_secs: ttl_mins.saturating_mul(60),
+            shared: false,
+            read_only: false,
         })
     }
 
+    /// Create a cache in a system-wide directory such as `/var/cache/trotd`
+    ///
+    /// The user that can write the directory (typically a daemon on a timer) fetches
+    /// and stores results readable by everyone. Every other user gets a read-only
+    /// cache that serves the stored results regardless of age.
+    pub fn system(cache_dir: PathBuf, ttl_mins: u64) -> Self {
+        let read_only = !prepare_shared_dir(&cache_dir);
+        Self {
+            dir: cache_dir,
+            ttl_secs: if read_only {
+                u64::MAX
+            } else {
+                ttl_mins.saturating_mul(60)
+            },
+            shared: true,
+            read_only,
+        }
+    }
+
     /// Create a cache instance with a custom directory (for testing)
     #[cfg(test)]
     fn with_dir(cache_dir: PathBuf, ttl_mins: u64) -> Self {
         Self {
-            cache_dir,
+            dir: cache_dir,
             ttl_secs: ttl_mins * 60,
+            shared: false,
+            read_only: false,
         }
     }
 
+    /// The `--system-cache` directory, `None` for the per-user cache
+    pub fn system_dir(&self) -> Option<&Path> {
+        self.shared.then_some(self.dir.as_path())
+    }
+
+    /// Whether results are only read, from a shared directory this user can't write
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
     /// Get cache file path for a provider
     fn cache_file(&self, provider: &str) -> PathBuf {
-        self.cache_dir.join(format!("{provider}.json"))
+        self.dir.join(format!("{provider}.json"))
     }
 
     /// Get current timestamp in seconds
@@ -75,15 +119,14 @@ impl Cache {
 
     /// Save repositories to cache for a provider
     pub async fn set(&self, provider: &str, repos: Vec<Repo>) -> Result<()> {
+        if self.read_only {
+            return Ok(());
+        }
+
         // Ensure cache directory exists
-        tokio::fs::create_dir_all(&self.cache_dir)
+        tokio::fs::create_dir_all(&self.dir)
             .await
-            .with_context(|| {
-                format!(
-                    "Failed to create cache directory: {}",
-                    self.cache_dir.display()
-                )
-            })?;
+            .with_context(|| format!("Failed to create cache directory: {}", self.dir.display()))?;
 
         let entry = CacheEntry {
             timestamp: Self::now(),
@@ -93,8 +136,21 @@ impl Cache {
         let content =
             serde_json::to_string_pretty(&entry).context("Failed to serialize cache entry")?;
 
+        // Write aside and rename, so readers never see a half-written entry
         let cache_file = self.cache_file(provider);
-        tokio::fs::write(&cache_file, content)
+        let partial = self
+            .dir
+            .join(format!(".{provider}.json.{}", std::process::id()));
+        tokio::fs::write(&partial, content)
+            .await
+            .with_context(|| format!("Failed to write cache file: {}", partial.display()))?;
+        #[cfg(unix)]
+        if self.shared {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::Permissions::from_mode(SHARED_FILE_MODE);
+            let _ = tokio::fs::set_permissions(&partial, mode).await;
+        }
+        tokio::fs::rename(&partial, &cache_file)
             .await
             .with_context(|| format!("Failed to write cache file: {}", cache_file.display()))?;
 
@@ -116,20 +172,42 @@ impl Cache {
     /// Clear all cached data
     #[allow(dead_code)]
     pub async fn clear_all(&self) -> Result<()> {
-        if self.cache_dir.exists() {
-            tokio::fs::remove_dir_all(&self.cache_dir)
+        if self.dir.exists() {
+            tokio::fs::remove_dir_all(&self.dir)
                 .await
                 .with_context(|| {
-                    format!(
-                        "Failed to remove cache directory: {}",
-                        self.cache_dir.display()
-                    )
+                    format!("Failed to remove cache directory: {}", self.dir.display())
                 })?;
         }
         Ok(())
     }
 }
 
+/// Create a shared cache directory readable by everyone, and report whether this
+/// user can write to it
+fn prepare_shared_dir(dir: &Path) -> bool {
+    if !dir.exists() && std::fs::create_dir_all(dir).is_ok() {
+        // A restrictive umask would otherwise lock other users out
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::Permissions::from_mode(SHARED_DIR_MODE);
+            let _ = std::fs::set_permissions(dir, mode);
+        }
+    }
+
+    let probe = dir.join(format!(".write-test-{}", std::process::id()));
+    let writable = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+        .is_ok();
+    if writable {
+        let _ = std::fs::remove_file(&probe);
+    }
+    writable
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,17 +219,13 @@ mod tests {
         let cache = Cache::with_dir(temp_dir.clone(), 60);
 
         let test_repos = vec![Repo {
-            provider: "github".to_string(),
-            icon: "[GH]".to_string(),
-            name: "test/repo".to_string(),
             language: Some("Rust".to_string()),
             description: Some("Test repository".to_string()),
-            url: "https://github.com/test/repo".to_string(),
             stars_today: Some(10),
             stars_total: Some(100),
             last_activity: Some(chrono::Utc::now()),
             topics: vec!["rust".to_string(), "cli".to_string()],
-            is_starred: false,
+            ..Repo::test("test/repo")
         }];
 
         // Clear any existing cache
@@ -184,17 +258,10 @@ mod tests {
         let cache = Cache::with_dir(temp_dir.clone(), 0);
 
         let test_repos = vec![Repo {
-            provider: "github".to_string(),
-            icon: "[GH]".to_string(),
-            name: "test/repo".to_string(),
             language: Some("Rust".to_string()),
-            description: None,
-            url: "https://github.com/test/repo".to_string(),
-            stars_today: None,
             stars_total: Some(50),
             last_activity: Some(chrono::Utc::now()),
-            topics: vec![],
-            is_starred: false,
+            ..Repo::test("test/repo")
         }];
 
         // Clear any existing cache
@@ -212,4 +279,31 @@ mod tests {
         let _ = cache.clear("test-expiry").await;
         let _ = std::fs::remove_dir_all(&temp_dir);
     }
+
+    #[tokio::test]
+    async fn test_system_cache() {
+        let temp_dir = std::env::temp_dir().join(format!("trotd-system-test-{}", Cache::now()));
+        let cache = Cache::system(temp_dir.join("shared"), 60);
+        assert!(!cache.is_read_only());
+        assert_eq!(cache.system_dir(), Some(temp_dir.join("shared").as_path()));
+
+        cache.set("test-shared", Vec::new()).await.unwrap();
+        assert_eq!(cache.get("test-shared").await.map(|r| r.len()), Some(0));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = |path: &Path| std::fs::metadata(path).unwrap().permissions().mode() & 0o777;
+            assert_eq!(mode(&temp_dir.join("shared")), SHARED_DIR_MODE);
+            assert_eq!(mode(&cache.cache_file("test-shared")), SHARED_FILE_MODE);
+        }
+
+        // A directory that can't be created (its parent is a file) is read-only
+        std::fs::write(temp_dir.join("file"), "").unwrap();
+        let cache = Cache::system(temp_dir.join("file").join("shared"), 60);
+        assert!(cache.is_read_only());
+        cache.set("test-shared", Vec::new()).await.unwrap();
+        assert!(cache.get("test-shared").await.is_none());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
 }
@@ -0,0 +1,329 @@
+use anyhow::{Context, Result};
+use std::path::{Component, Path, PathBuf};
+
+use crate::config::{CloneConfig, CloneLayout};
+
+/// Highest `-N` suffix tried for a flat-layout directory name taken by other repos
+const MAX_DUPLICATES: usize = 99;
+
+/// Build the clone URL for `owner/repo` shorthand or pass through full URLs
+pub fn clone_url(repo: &str) -> String {
+    // Support both "owner/repo" format and full URLs
+    if repo.starts_with("http://") || repo.starts_with("https://") {
+        repo.to_string()
+    } else {
+        // Assume GitHub by default for owner/repo format
+        format!("https://github.com/{repo}.git")
+    }
+}
+
+/// Determine where a repository should be cloned according to the configured layout
+///
+/// `flat` clones into `<root>/<repo>`, `hosted` into `<root>/<host>/<owner>/<repo>`.
+/// When another repository's checkout already holds `<root>/<repo>`, the flat layout
+/// moves on to `<root>/<repo>-<owner>` (then `-2`, `-3`, ...).
+pub fn target_dir(url: &str, cfg: &CloneConfig) -> Result<PathBuf> {
+    let (host, path) = split_url(url)?;
+    let root = cfg.root_dir().unwrap_or_else(|| PathBuf::from("."));
+    let segments: Vec<_> = path.split('/').collect();
+    // URL segments such as `..` would otherwise reach outside the clone root
+    let contained = std::iter::once(host.as_str())
+        .chain(segments.iter().copied())
+        .all(|segment| {
+            let mut components = Path::new(segment).components();
+            matches!(components.next(), Some(Component::Normal(_))) && components.next().is_none()
+        });
+    if !contained {
+        anyhow::bail!("Refusing to clone outside the clone root: {url}");
+    }
+
+    Ok(match cfg.layout {
+        CloneLayout::Flat => {
+            let name = segments[segments.len() - 1];
+            let owner = segments[0];
+            let deduplicated = format!("{name}-{owner}");
+            std::iter::once(name.to_string())
+                .chain((segments.len() > 1).then(|| deduplicated.clone()))
+                .chain((2..=MAX_DUPLICATES).map(|n| format!("{deduplicated}-{n}")))
+                .map(|dir| root.join(dir))
+                .find(|dir| !already_cloned(dir) || is_checkout_of(dir, url))
+                .with_context(|| format!("No free directory for {name} in {}", root.display()))?
+        }
+        CloneLayout::Hosted => segments
+            .iter()
+            .fold(root.join(host), |dir, segment| dir.join(segment)),
+    })
+}
+
+/// Whether a previous clone already occupies the target directory
+pub fn already_cloned(target: &Path) -> bool {
+    target.join(".git").exists() || target.read_dir().is_ok_and(|mut d| d.next().is_some())
+}
+
+/// Whether `target` is a checkout whose `origin` remote points at `url`
+pub fn is_checkout_of(target: &Path, url: &str) -> bool {
+    let Ok(config) = std::fs::read_to_string(target.join(".git").join("config")) else {
+        return false;
+    };
+    origin_url(&config)
+        .and_then(remote_key)
+        .is_some_and(|origin| remote_key(url).is_some_and(|wanted| origin == wanted))
+}
+
+/// `url` of the `[remote "origin"]` section of a git config file
+fn origin_url(config: &str) -> Option<&str> {
+    let mut in_origin = false;
+    for line in config.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_origin = line == r#"[remote "origin"]"#;
+        } else if in_origin {
+            if let Some((key, value)) = line.split_once('=') {
+                if key.trim() == "url" {
+                    return Some(value.trim());
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Lowercased host and path of a remote URL, so HTTPS, SSH and scp-style remotes compare
+fn remote_key(url: &str) -> Option<(String, String)> {
+    let (host, path) = match url.split_once("://") {
+        Some((_, rest)) => rest.split_once('/')?,
+        // scp-style `git@host:owner/repo.git`
+        None => url.split_once(':')?,
+    };
+    let host = host.rsplit('@').next().unwrap_or(host);
+    let host = host.split(':').next().unwrap_or(host);
+    let path = path.trim_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    (!path.is_empty()).then(|| (host.to_ascii_lowercase(), path.to_ascii_lowercase()))
+}
+
+/// Split a clone URL into host and `owner/repo` path without the `.git` suffix
+pub fn split_url(url: &str) -> Result<(String, String)> {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+
+    let Some((host, path)) = rest.split_once('/') else {
+        anyhow::bail!("Cannot determine repository path from URL: {url}");
+    };
+
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    if path.is_empty() {
+        anyhow::bail!("Cannot determine repository path from URL: {url}");
+    }
+
+    Ok((host.to_string(), path.to_string()))
+}
+
+/// Clone `url` into `target` by running `git clone`
+#[cfg(not(feature = "gitoxide"))]
+pub fn clone_repo(url: &str, target: &Path) -> Result<()> {
+    let output = std::process::Command::new("git")
+        .arg("clone")
+        .arg(url)
+        .arg(target)
+        .output()
+        .context("Failed to execute git clone. Is git installed?")?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        let error = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("Git clone failed: {error}");
+    }
+}
+
+/// Clone `url` into `target` natively with gitoxide, no `git` binary needed
+///
+/// Fetch and checkout progress is drawn on one stderr line while the clone runs.
+#[cfg(feature = "gitoxide")]
+pub fn clone_repo(url: &str, target: &Path) -> Result<()> {
+    use gix::progress::tree::Root;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    let interrupt = AtomicBool::new(false);
+    let done = Arc::new(AtomicBool::new(false));
+    let root = Root::new();
+    let reporter = {
+        let (root, done) = (Arc::clone(&root), Arc::clone(&done));
+        std::thread::spawn(move || report_progress(&root, &done))
+    };
+
+    let result = (|| -> Result<()> {
+        let mut fetch = gix::prepare_clone(url, target)?;
+        let (mut checkout, _) = fetch.fetch_then_checkout(root.add_child("fetch"), &interrupt)?;
+        checkout.main_worktree(root.add_child("checkout"), &interrupt)?;
+        Ok(())
+    })();
+
+    done.store(true, Ordering::Relaxed);
+    let _ = reporter.join();
+    result.context("Git clone failed")
+}
+
+/// Redraw the most recent active task of `root` until `done` is set
+#[cfg(feature = "gitoxide")]
+fn report_progress(root: &gix::progress::tree::Root, done: &std::sync::atomic::AtomicBool) {
+    use std::io::{IsTerminal, Write};
+    use std::sync::atomic::Ordering;
+
+    let interactive = std::io::stderr().is_terminal() && !crate::quiet::enabled();
+    let mut tasks = Vec::new();
+    let mut drawn = false;
+    while !done.load(Ordering::Relaxed) {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        if !interactive {
+            continue;
+        }
+        root.sorted_snapshot(&mut tasks);
+        let line = tasks.iter().rev().find_map(|(_, task)| {
+            let value = task.progress.as_ref()?;
+            let step = value.step.load(Ordering::Relaxed);
+            (step > 0).then(|| progress_line(&task.name, step, value.done_at))
+        });
+        if let Some(line) = line {
+            eprint!("\r\x1b[2K  {line}");
+            let _ = std::io::stderr().flush();
+            drawn = true;
+        }
+    }
+    if drawn {
+        eprint!("\r\x1b[2K");
+    }
+}
+
+/// One progress line: `name step/total (pct%)`, or `name step` when the total is unknown
+#[cfg_attr(not(feature = "gitoxide"), allow(dead_code))]
+fn progress_line(name: &str, step: usize, total: Option<usize>) -> String {
+    match total {
+        Some(total) if total > 0 => {
+            let percent = step.min(total) * 100 / total;
+            format!("{name} {step}/{total} ({percent}%)")
+        }
+        _ => format!("{name} {step}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clone_url() {
+        assert_eq!(clone_url("owner/repo"), "https://github.com/owner/repo.git");
+        assert_eq!(
+            clone_url("https://gitlab.com/group/repo"),
+            "https://gitlab.com/group/repo"
+        );
+    }
+
+    #[test]
+    fn test_target_dir_layouts() {
+        let hosted = CloneConfig {
+            layout: CloneLayout::Hosted,
+            root: Some("/src".to_string()),
+            ..CloneConfig::default()
+        };
+        assert_eq!(
+            target_dir("https://github.com/owner/repo.git", &hosted).unwrap(),
+            PathBuf::from("/src/github.com/owner/repo")
+        );
+        assert_eq!(
+            target_dir("https://gitlab.com/group/sub/repo", &hosted).unwrap(),
+            PathBuf::from("/src/gitlab.com/group/sub/repo")
+        );
+
+        let flat = CloneConfig::default();
+        assert_eq!(
+            target_dir("https://github.com/owner/repo.git", &flat).unwrap(),
+            PathBuf::from("./repo")
+        );
+    }
+
+    #[test]
+    fn test_flat_layout_deduplicates_by_origin() {
+        let root = std::env::temp_dir().join(format!(
+            "trotd-clone-test-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let checkout = |dir: &str, origin: &str| {
+            let git = root.join(dir).join(".git");
+            std::fs::create_dir_all(&git).unwrap();
+            std::fs::write(
+                git.join("config"),
+                format!("[core]\n\tbare = false\n[remote \"origin\"]\n\turl = {origin}\n"),
+            )
+            .unwrap();
+        };
+        let flat = CloneConfig {
+            root: Some(root.display().to_string()),
+            ..CloneConfig::default()
+        };
+
+        assert_eq!(
+            target_dir("https://github.com/bob/tool", &flat).unwrap(),
+            root.join("tool")
+        );
+        checkout("tool", "git@github.com:alice/tool.git");
+        assert_eq!(
+            target_dir("https://github.com/Alice/tool.git", &flat).unwrap(),
+            root.join("tool")
+        );
+        assert_eq!(
+            target_dir("https://github.com/bob/tool", &flat).unwrap(),
+            root.join("tool-bob")
+        );
+        checkout("tool-bob", "https://gitlab.com/bob/tool");
+        assert_eq!(
+            target_dir("https://github.com/bob/tool", &flat).unwrap(),
+            root.join("tool-bob-2")
+        );
+        assert!(is_checkout_of(
+            &root.join("tool-bob"),
+            "https://gitlab.com/bob/tool.git"
+        ));
+        assert!(!is_checkout_of(
+            &root.join("tool"),
+            "https://github.com/bob/tool"
+        ));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_progress_line() {
+        assert_eq!(
+            progress_line("receiving pack", 120, Some(480)),
+            "receiving pack 120/480 (25%)"
+        );
+        assert_eq!(
+            progress_line("counting objects", 42, None),
+            "counting objects 42"
+        );
+        assert_eq!(progress_line("checkout", 3, Some(0)), "checkout 3");
+    }
+
+    #[test]
+    fn test_invalid_url() {
+        assert!(target_dir("https://github.com", &CloneConfig::default()).is_err());
+
+        let hosted = CloneConfig {
+            layout: CloneLayout::Hosted,
+            root: Some("/src".to_string()),
+            ..CloneConfig::default()
+        };
+        assert!(target_dir("https://github.com/../../etc", &hosted).is_err());
+        assert!(target_dir("https://../owner/repo", &hosted).is_err());
+        assert!(target_dir("https://example.com/owner/./repo", &hosted).is_err());
+    }
+}
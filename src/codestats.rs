@@ -0,0 +1,216 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::archive::{self, Forge};
+use crate::clone;
+use crate::config::Config;
+use crate::http::HttpClient;
+
+/// Languages below this share are folded into "Other"
+const MIN_SHARE_PERCENT: f64 = 1.0;
+
+/// Lines counted by tokei for one language
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineCount {
+    pub language: String,
+    pub code: u64,
+    pub comments: u64,
+    pub blanks: u64,
+}
+
+#[derive(Deserialize)]
+struct TokeiLanguage {
+    #[serde(default)]
+    code: u64,
+    #[serde(default)]
+    comments: u64,
+    #[serde(default)]
+    blanks: u64,
+}
+
+/// Languages API URL for `path` on `host`; Bitbucket has none
+fn languages_url(forge: Forge, host: &str, path: &str) -> Option<String> {
+    match forge {
+        Forge::GitHub => Some(format!("https://api.github.com/repos/{path}/languages")),
+        Forge::GitLab => Some(format!(
+            "https://{host}/api/v4/projects/{}/languages",
+            path.replace('/', "%2F")
+        )),
+        Forge::Gitea => Some(format!("https://{host}/api/v1/repos/{path}/languages")),
+        Forge::Bitbucket => None,
+    }
+}
+
+/// Language shares in percent, largest first
+///
+/// GitHub and Gitea report bytes, GitLab percentages; both normalize the same way.
+pub fn shares(languages: HashMap<String, f64>) -> Vec<(String, f64)> {
+    let total: f64 = languages.values().sum();
+    if total <= 0.0 {
+        return Vec::new();
+    }
+    let mut shares: Vec<_> = languages
+        .into_iter()
+        .map(|(language, amount)| (language, amount / total * 100.0))
+        .collect();
+    shares.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    shares
+}
+
+/// Language breakdown of the repository at `url` from its provider's languages API
+pub async fn languages(
+    config: &Config,
+    url: &str,
+    provider: Option<&str>,
+) -> Result<Vec<(String, f64)>> {
+    let (host, path) = clone::split_url(url)?;
+    let forge = Forge::detect(provider, &host);
+    let Some(api) = languages_url(forge, &host, &path) else {
+        anyhow::bail!("{host} has no languages API");
+    };
+    let http = HttpClient::new(config.get_timeout_secs(provider.unwrap_or_default()))?;
+    let languages: HashMap<String, f64> = http
+        .get_json(&api, forge.token(config, &host))
+        .await
+        .with_context(|| format!("Failed to fetch languages of {path}"))?;
+    Ok(shares(languages))
+}
+
+/// Download the repository at `url` into a temporary directory and count lines with tokei
+pub async fn count_lines(
+    config: &Config,
+    url: &str,
+    provider: Option<&str>,
+) -> Result<Vec<LineCount>> {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let dir = std::env::temp_dir().join(format!("trotd-show-{nanos}"));
+
+    let counted = match archive::fetch(config, url, provider, &dir).await {
+        Ok(_) => run_tokei(&dir),
+        Err(e) => Err(e),
+    };
+    let _ = std::fs::remove_dir_all(&dir);
+    counted
+}
+
+/// Run `tokei --output json` over `dir`
+fn run_tokei(dir: &Path) -> Result<Vec<LineCount>> {
+    let output = std::process::Command::new("tokei")
+        .args(["--output", "json"])
+        .arg(dir)
+        .output()
+        .context("Failed to run tokei. Is it installed? (cargo install tokei)")?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("tokei failed: {error}");
+    }
+    parse_tokei(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Per-language counts from tokei's JSON report, most code first, without the total
+fn parse_tokei(json: &str) -> Result<Vec<LineCount>> {
+    let report: HashMap<String, TokeiLanguage> =
+        serde_json::from_str(json).context("Failed to parse tokei output")?;
+    let mut counts: Vec<_> = report
+        .into_iter()
+        .filter(|(language, stats)| language != "Total" && stats.code > 0)
+        .map(|(language, stats)| LineCount {
+            language,
+            code: stats.code,
+            comments: stats.comments,
+            blanks: stats.blanks,
+        })
+        .collect();
+    counts.sort_by(|a, b| {
+        b.code
+            .cmp(&a.code)
+            .then_with(|| a.language.cmp(&b.language))
+    });
+    Ok(counts)
+}
+
+/// Text block with the language shares and, when counted, lines of code
+pub fn render(languages: &[(String, f64)], lines: Option<&[LineCount]>) -> String {
+    let mut out = String::new();
+    if !languages.is_empty() {
+        out.push_str("Languages:\n");
+        let mut other = 0.0;
+        for (language, percent) in languages {
+            if *percent < MIN_SHARE_PERCENT {
+                other += percent;
+                continue;
+            }
+            let _ = writeln!(out, "  {language:<16} {percent:>5.1}%");
+        }
+        if other > 0.0 {
+            let _ = writeln!(out, "  {:<16} {other:>5.1}%", "Other");
+        }
+    }
+
+    if let Some(lines) = lines {
+        out.push_str("Lines of code:\n");
+        for count in lines {
+            let _ = writeln!(
+                out,
+                "  {:<16} {:>9} code {:>8} comments {:>8} blank",
+                count.language, count.code, count.comments, count.blanks
+            );
+        }
+        let total: u64 = lines.iter().map(|count| count.code).sum();
+        let _ = writeln!(out, "  {:<16} {total:>9} code", "Total");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shares_and_render() {
+        let languages = HashMap::from([
+            ("Rust".to_string(), 900.0),
+            ("Shell".to_string(), 95.0),
+            ("Nix".to_string(), 5.0),
+        ]);
+        let breakdown = shares(languages);
+        assert_eq!(breakdown[0], ("Rust".to_string(), 90.0));
+        assert!(shares(HashMap::new()).is_empty());
+
+        let lines = parse_tokei(
+            r#"{"Rust": {"blanks": 10, "code": 400, "comments": 20, "reports": [], "children": {}},
+                "TOML": {"blanks": 1, "code": 30, "comments": 2, "reports": [], "children": {}},
+                "Markdown": {"blanks": 0, "code": 0, "comments": 50, "reports": [], "children": {}},
+                "Total": {"blanks": 11, "code": 430, "comments": 72, "reports": [], "children": {}}}"#,
+        )
+        .unwrap();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].language, "Rust");
+
+        let text = render(&breakdown, Some(&lines));
+        assert!(text.starts_with("Languages:\n  Rust              90.0%\n"));
+        assert!(text.contains("  Other              0.5%\n"));
+        assert!(!text.contains("Nix"));
+        assert!(text.contains("Lines of code:\n  Rust                   400 code"));
+        assert!(text.ends_with("  Total                  430 code\n"));
+    }
+
+    #[test]
+    fn test_languages_url() {
+        assert_eq!(
+            languages_url(Forge::GitLab, "gitlab.com", "group/repo").as_deref(),
+            Some("https://gitlab.com/api/v4/projects/group%2Frepo/languages")
+        );
+        assert_eq!(
+            languages_url(Forge::Bitbucket, "bitbucket.org", "t/r"),
+            None
+        );
+    }
+}
@@ -1,7 +1,31 @@
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
+use crate::filter::TopicFilter;
+use crate::model::{GiteaRanking, HubKind, ProviderCfg};
+use crate::quiet::notice;
+use crate::schema;
+use crate::terminal;
+use crate::theme::Paint;
+
+/// Built-in provider ids with the short names `--provider` accepts for them
+pub const BUILTIN_PROVIDERS: [(&str, &str); 10] = [
+    ("github", "gh"),
+    ("gitlab", "gl"),
+    ("gitea", "ge"),
+    ("codeberg", "cb"),
+    ("bitbucket", "bb"),
+    ("gitee", "gt"),
+    ("huggingface", "hf"),
+    ("crates", "cr"),
+    ("hackernews", "hn"),
+    ("reddit", "rd"),
+];
+
 /// Main configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Config {
@@ -15,9 +39,154 @@ pub struct Config {
     pub gitea: GiteaConfig,
     #[serde(default)]
     pub github: GitHubConfig,
+    #[serde(default)]
+    pub gitlab: GitLabConfig,
+    #[serde(default)]
+    pub codeberg: CodebergConfig,
+    #[serde(default)]
+    pub gitee: GiteeConfig,
+    #[serde(default)]
+    pub huggingface: HuggingFaceConfig,
+    #[serde(default)]
+    pub reddit: RedditConfig,
+    #[serde(default)]
+    pub hooks: HooksConfig,
+    #[serde(default)]
+    pub clone: CloneConfig,
+    #[serde(default)]
+    pub description: DescriptionConfig,
+    #[serde(default)]
+    pub display: DisplayConfig,
+    #[serde(default)]
+    pub theme: ThemeConfig,
+    #[serde(default)]
+    pub icons: IconsConfig,
+    #[serde(default)]
+    pub statusbar: StatusbarConfig,
+    #[serde(default)]
+    pub readlater: ReadLaterConfig,
+    #[serde(default)]
+    pub todo: TodoConfig,
+    #[serde(default)]
+    pub spam: SpamConfig,
+    /// Custom `--provider` short names, e.g. `cb = "gitea@https://codeberg.org"`
+    #[serde(default)]
+    pub aliases: BTreeMap<String, String>,
+    /// Named filter bundles for `--preset`, e.g. `[preset.security]`
+    #[serde(default)]
+    pub preset: BTreeMap<String, PresetConfig>,
+    /// Extra sections shown beneath the trending list
+    #[serde(default)]
+    pub section: SectionConfig,
+    /// Providers declared in config, one `[[custom]]` table each
+    #[serde(default)]
+    pub custom: Vec<CustomProviderConfig>,
 }
 
+/// A provider for any JSON API, declared under `[[custom]]`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomProviderConfig {
+    /// Provider id for `--provider`, the JSON output and `[theme]`; must not be a built-in
+    /// id, short name or alias
+    pub name: String,
+    /// Tag shown before each repo; `[XX]` from the name by default
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// Endpoint URL; `{offset}` and `{limit}` are filled in, otherwise paging happens locally
+    pub url: String,
+    /// Header carrying `token`, e.g. `PRIVATE-TOKEN`
+    #[serde(default = "default_custom_auth_header")]
+    pub auth_header: String,
+    /// Complete header value, e.g. `Bearer <token>`
+    #[serde(default)]
+    pub token: Option<String>,
+    /// Path to the list of repositories in the response, e.g. `$.data.items`
+    #[serde(default = "default_custom_items")]
+    pub items: String,
+    /// Paths to each `Repo` field within one list entry
+    #[serde(default)]
+    pub fields: CustomFields,
+    /// Set to false to keep the definition without querying it
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub max_entries: Option<usize>,
+    #[serde(default = "default_custom_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+impl CustomProviderConfig {
+    /// The configured icon, or the name's first two letters as `[XX]`
+    pub fn icon(&self) -> String {
+        self.icon.clone().unwrap_or_else(|| {
+            let tag: String = self.name.chars().take(2).collect();
+            format!("[{}]", tag.to_uppercase())
+        })
+    }
+}
+
+/// Paths (like `$.owner.login` or `topics`) mapping a list entry to `Repo` fields
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomFields {
+    pub name: String,
+    pub url: String,
+    pub description: String,
+    pub language: String,
+    pub stars_today: String,
+    pub stars_total: String,
+    /// RFC 3339 date or Unix timestamp
+    pub last_activity: String,
+    /// A list of strings, or one comma-separated string
+    pub topics: String,
+    pub homepage: String,
+}
+
+impl Default for CustomFields {
+    fn default() -> Self {
+        Self {
+            name: "name".to_string(),
+            url: "url".to_string(),
+            description: "description".to_string(),
+            language: "language".to_string(),
+            stars_today: "stars_today".to_string(),
+            stars_total: "stars".to_string(),
+            last_activity: "updated_at".to_string(),
+            topics: "topics".to_string(),
+            homepage: "homepage".to_string(),
+        }
+    }
+}
+
+/// A named bundle of filters applied with `--preset NAME`
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PresetConfig {
+    /// Providers to query (ids, short names or aliases); unset keeps the configured ones
+    #[serde(default)]
+    pub providers: Option<Vec<String>>,
+    /// Added to every provider's include list
+    #[serde(default)]
+    pub include_topics: Vec<String>,
+    /// Added to every provider's exclude list
+    #[serde(default)]
+    pub exclude_topics: Vec<String>,
+    /// Replaces the language filter when set
+    #[serde(default)]
+    pub languages: Vec<String>,
+    #[serde(default)]
+    pub min_stars: Option<u32>,
+    #[serde(default)]
+    pub max_stars: Option<u32>,
+    /// Replaces the license filter when set
+    #[serde(default)]
+    pub licenses: Vec<String>,
+    /// Turns on the container indicator
+    #[serde(default)]
+    pub show_containers: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct GeneralConfig {
     #[serde(default = "default_max_per_provider")]
     pub max_per_provider: usize,
@@ -27,29 +196,108 @@ pub struct GeneralConfig {
     pub gitlab_max_entries: Option<usize>,
     #[serde(default)]
     pub gitea_max_entries: Option<usize>,
-    #[serde(default = "default_timeout_secs")]
+    #[serde(default)]
+    pub codeberg_max_entries: Option<usize>,
+    #[serde(default)]
+    pub bitbucket_max_entries: Option<usize>,
+    #[serde(default)]
+    pub gitee_max_entries: Option<usize>,
+    #[serde(default)]
+    pub huggingface_max_entries: Option<usize>,
+    #[serde(default)]
+    pub crates_max_entries: Option<usize>,
+    #[serde(default)]
+    pub hackernews_max_entries: Option<usize>,
+    #[serde(default)]
+    pub reddit_max_entries: Option<usize>,
+    /// Deprecated in favour of the per-provider timeouts
+    #[serde(default = "default_timeout_secs", skip_serializing)]
     pub timeout_secs: u64,
     #[serde(default = "default_cache_ttl_mins")]
     pub cache_ttl_mins: u64,
+    /// Cache behavior by local time of day, e.g. cache-only in the evening
+    #[serde(default)]
+    pub refresh_schedule: Vec<RefreshWindow>,
     #[serde(default)]
     pub language_filter: Vec<String>,
+    /// Keep only repos under one of these licenses (SPDX ids, e.g. `MIT`); repos of
+    /// providers without a license lookup are kept
+    #[serde(default)]
+    pub license_filter: Vec<String>,
+    /// Keep repos whose language is unknown when a language filter is set
+    #[serde(default)]
+    pub include_unknown_lang: bool,
+    /// Share (percent) at which a secondary language matches the language filter (GitHub)
+    #[serde(default)]
+    pub secondary_language_percent: Option<f64>,
     #[serde(default = "default_github_timeout_secs")]
     pub github_timeout_secs: u64,
     #[serde(default = "default_gitlab_timeout_secs")]
     pub gitlab_timeout_secs: u64,
     #[serde(default = "default_gitea_timeout_secs")]
     pub gitea_timeout_secs: u64,
+    #[serde(default = "default_gitea_timeout_secs")]
+    pub codeberg_timeout_secs: u64,
+    #[serde(default = "default_bitbucket_timeout_secs")]
+    pub bitbucket_timeout_secs: u64,
+    #[serde(default = "default_gitee_timeout_secs")]
+    pub gitee_timeout_secs: u64,
+    #[serde(default = "default_huggingface_timeout_secs")]
+    pub huggingface_timeout_secs: u64,
+    #[serde(default = "default_crates_timeout_secs")]
+    pub crates_timeout_secs: u64,
+    #[serde(default = "default_hackernews_timeout_secs")]
+    pub hackernews_timeout_secs: u64,
+    #[serde(default = "default_reddit_timeout_secs")]
+    pub reddit_timeout_secs: u64,
     #[serde(default)]
     pub ascii_only: bool,
     #[serde(default)]
     pub min_stars: Option<u32>,
+    #[serde(default)]
+    pub max_stars: Option<u32>,
     #[serde(default = "default_fast_network_timeout_secs")]
     pub fast_network_timeout_secs: u64,
     #[serde(default = "default_true")]
     pub show_starred_status: bool,
+    /// Append shown repos and star/clone/open actions to `activity.jsonl` in the data dir
+    #[serde(default)]
+    pub activity_log: bool,
+    /// Interleave providers into one ranking instead of listing them one after another
+    #[serde(default)]
+    pub merge_providers: bool,
+    /// Ranking weight per provider for the merged ordering (unlisted = 1.0)
+    #[serde(default)]
+    pub provider_weights: BTreeMap<String, f64>,
+    /// Accounts and orgs whose repos are yours, called out when they trend
+    #[serde(default)]
+    pub my_accounts: Vec<String>,
+    /// Call out your own and starred repos above the list when they are anywhere in
+    /// today's trending lists
+    #[serde(default)]
+    pub trending_alert: bool,
+    /// Repositories never shown: `owner/repo`, or `owner/*` for a whole account
+    #[serde(default)]
+    pub blocklist: Vec<String>,
+    /// Shared rules (blocklist, topics, presets) merged into this config, from a URL or path
+    #[serde(default)]
+    pub remote_rules_url: Option<String>,
+    /// minijinja template file that replaces the MOTD output
+    #[serde(default)]
+    pub template: Option<String>,
+    /// Pager for output taller than the terminal; unset uses `$PAGER`, then `less`
+    #[serde(default)]
+    pub pager: Option<String>,
+    /// Fallback once the day's trending list is used up: "weekly" or "none"
+    #[serde(default)]
+    pub backfill: Backfill,
+    /// MOTD sections in display order, each a name or `{ name, title }`
+    #[serde(default = "default_sections")]
+    pub sections: Vec<SectionSpec>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct ProvidersConfig {
     #[serde(default = "default_true")]
     pub github: bool,
@@ -57,6 +305,27 @@ pub struct ProvidersConfig {
     pub gitlab: bool,
     #[serde(default = "default_true")]
     pub gitea: bool,
+    /// Off by default; `-p cb` picks it for a single run
+    #[serde(default)]
+    pub codeberg: bool,
+    /// Off by default; `-p bb` picks it for a single run
+    #[serde(default)]
+    pub bitbucket: bool,
+    /// Off by default; `-p gt` picks it for a single run
+    #[serde(default)]
+    pub gitee: bool,
+    /// Off by default; `-p hf` picks it for a single run
+    #[serde(default)]
+    pub huggingface: bool,
+    /// Off by default; `-p cr` picks it for a single run
+    #[serde(default)]
+    pub crates: bool,
+    /// Off by default; `-p hn` picks it for a single run
+    #[serde(default)]
+    pub hackernews: bool,
+    /// Off by default; `-p rd` picks it for a single run
+    #[serde(default)]
+    pub reddit: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -65,20 +334,610 @@ pub struct AuthConfig {
     pub github_token: Option<String>,
     pub gitlab_token: Option<String>,
     pub gitea_token: Option<String>,
+    pub codeberg_token: Option<String>,
+    /// Bitbucket access token, or an app password / API token with `bitbucket_username`
+    pub bitbucket_token: Option<String>,
+    /// Atlassian username for Basic auth with `bitbucket_token`
+    pub bitbucket_username: Option<String>,
+    pub gitee_token: Option<String>,
+    pub huggingface_token: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GiteaConfig {
     #[serde(default = "default_gitea_url")]
     pub base_url: String,
+    /// Ranking strategy for instances without an entry in `instance_ranking`
+    #[serde(default)]
+    pub ranking: GiteaRanking,
+    /// Per-instance ranking strategy keyed by base URL, for gitea and codeberg instances
+    #[serde(default)]
+    pub instance_ranking: BTreeMap<String, GiteaRanking>,
+    #[serde(default)]
+    pub include_topics: Vec<String>,
+    #[serde(default)]
+    pub exclude_topics: Vec<String>,
+}
+
+impl GiteaConfig {
+    /// Ranking strategy `instance_ranking` sets for an instance
+    pub fn instance_ranking_for(&self, base_url: &str) -> Option<GiteaRanking> {
+        self.instance_ranking
+            .iter()
+            .find(|(url, _)| same_instance(url, base_url))
+            .map(|(_, ranking)| *ranking)
+    }
+
+    /// Ranking strategy for a Gitea instance, falling back to `ranking`
+    pub fn ranking_for(&self, base_url: &str) -> GiteaRanking {
+        self.instance_ranking_for(base_url).unwrap_or(self.ranking)
+    }
+}
+
+/// Gitee, searched through its open API v5
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GiteeConfig {
+    /// Search query; the API has no trending list, so recent pushes matching it are ranked
+    #[serde(default = "default_gitee_query")]
+    pub query: String,
+}
+
+/// Hugging Face Hub, listed by its trending score
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HuggingFaceConfig {
+    /// Repository kinds to list: models, datasets and/or spaces
+    #[serde(default = "default_huggingface_kinds")]
+    pub kinds: Vec<HubKind>,
+}
+
+/// Reddit, listed by the top posts of the day in a set of subreddits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedditConfig {
+    /// Subreddits whose posts linking to a repository are listed, e.g. `["rust"]`
+    #[serde(default = "default_reddit_subs")]
+    pub subs: Vec<String>,
+}
+
+/// Codeberg, a Forgejo instance fetched through the Gitea API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodebergConfig {
+    #[serde(default = "default_codeberg_url")]
+    pub base_url: String,
+    /// Stars from the last 24h by default; a quiet day falls back to `starred-updated-today`
+    #[serde(default = "default_codeberg_ranking")]
+    pub ranking: GiteaRanking,
+    #[serde(default)]
+    pub include_topics: Vec<String>,
+    #[serde(default)]
+    pub exclude_topics: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct GitHubConfig {
+    #[serde(default)]
+    pub include_topics: Vec<String>,
+    #[serde(default)]
+    pub exclude_topics: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabConfig {
+    /// GitLab instance, e.g. a self-hosted `https://gitlab.example.com`
+    #[serde(default = "default_gitlab_url")]
+    pub base_url: String,
+    #[serde(default)]
+    pub include_topics: Vec<String>,
     #[serde(default)]
     pub exclude_topics: Vec<String>,
 }
 
+/// External commands run at lifecycle points, receiving repo JSON on stdin
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct HooksConfig {
+    #[serde(default)]
+    pub post_fetch: Option<String>,
+    #[serde(default)]
+    pub pre_render: Option<String>,
+    #[serde(default)]
+    pub on_new_repo: Option<String>,
+}
+
+/// How repository descriptions are cleaned up for display
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DescriptionConfig {
+    /// Maximum characters shown in MOTD output when the terminal width is unknown,
+    /// e.g. piped (0 = no limit)
+    #[serde(default = "default_description_max_length")]
+    pub max_length: usize,
+    /// Strip markdown and HTML fragments
+    #[serde(default = "default_true")]
+    pub strip_markup: bool,
+    /// Keep at most this many emoji, collapsing runs of emoji into one (unset = keep all)
+    #[serde(default)]
+    pub max_emoji: Option<usize>,
+    /// Wrap long descriptions onto further lines instead of cutting them to the terminal width
+    #[serde(default)]
+    pub wrap: bool,
+}
+
+impl Default for DescriptionConfig {
+    fn default() -> Self {
+        Self {
+            max_length: default_description_max_length(),
+            strip_markup: true,
+            max_emoji: None,
+            wrap: false,
+        }
+    }
+}
+
+/// Extra repository details shown in MOTD and accessible output
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct DisplayConfig {
+    /// Number of topics shown as tags per repo (0 = hidden)
+    #[serde(default)]
+    pub max_topics: usize,
+    /// Color topic tags by category (language, AI, web, infra, tooling)
+    #[serde(default = "default_true")]
+    pub color_topics: bool,
+    /// Show the project homepage after the description when it differs from the repo URL
+    #[serde(default)]
+    pub show_homepage: bool,
+    /// Mark repos whose owner has GitHub Sponsors enabled with ♥ (needs a GitHub token)
+    #[serde(default)]
+    pub show_sponsors: bool,
+    /// Mark repos whose latest release ships prebuilt binaries with 📦
+    #[serde(default)]
+    pub show_binaries: bool,
+    /// Mark repos that ship a Dockerfile or compose file with 🐳
+    #[serde(default)]
+    pub show_containers: bool,
+    /// Mark GitHub repos whose star spike looks farmed with ⚠ (young repo, little history)
+    #[serde(default)]
+    pub show_suspicious: bool,
+    /// Use ASCII markers instead of emoji (unset = only on legacy Windows consoles)
+    #[serde(default)]
+    pub ascii_symbols: Option<bool>,
+    /// Make repo names clickable with OSC 8 hyperlinks (unset = auto-detect the terminal)
+    #[serde(default)]
+    pub hyperlinks: Option<bool>,
+    /// Show a sparkline of the last week's star totals once two days are recorded
+    #[serde(default = "default_true")]
+    pub sparklines: bool,
+    /// Columns MOTD lines fit into (unset = the terminal's width, or `max_length` when
+    /// piped; 0 = always `max_length`)
+    #[serde(default)]
+    pub width: Option<usize>,
+    /// When to color output: on a terminal unless `NO_COLOR` is set, always or never
+    #[serde(default)]
+    pub color: ColorChoice,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self {
+            max_topics: 0,
+            color_topics: true,
+            show_homepage: false,
+            show_sponsors: false,
+            show_binaries: false,
+            show_containers: false,
+            show_suspicious: false,
+            ascii_symbols: None,
+            hyperlinks: None,
+            width: None,
+            color: ColorChoice::Auto,
+            sparklines: true,
+        }
+    }
+}
+
+impl DisplayConfig {
+    /// Whether ASCII markers replace emoji, auto-detecting legacy Windows consoles
+    pub fn ascii(&self) -> bool {
+        self.ascii_symbols.unwrap_or_else(terminal::legacy_console)
+    }
+}
+
+/// What happens to repositories the spam heuristic flags
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpamAction {
+    /// Remove them from the output
+    #[default]
+    Drop,
+    /// Move them to the end of the list
+    Demote,
+}
+
+/// A `general.refresh_schedule` window, in effect from `from` until the next one starts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefreshWindow {
+    /// Local time the window starts
+    pub from: TimeOfDay,
+    /// Cache TTL within the window; unset keeps `cache_ttl_mins`
+    #[serde(default)]
+    pub ttl_mins: Option<u64>,
+    /// Serve cached results of any age; only providers with nothing cached are fetched
+    #[serde(default)]
+    pub cache_only: bool,
+}
+
+/// Local time of day, written as `HH:MM`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TimeOfDay(chrono::NaiveTime);
+
+impl TryFrom<String> for TimeOfDay {
+    type Error = String;
+
+    fn try_from(spec: String) -> Result<Self, Self::Error> {
+        chrono::NaiveTime::parse_from_str(spec.trim(), "%H:%M")
+            .map(Self)
+            .map_err(|_| format!("invalid time \"{spec}\", expected HH:MM"))
+    }
+}
+
+impl From<TimeOfDay> for String {
+    fn from(time: TimeOfDay) -> Self {
+        time.0.format("%H:%M").to_string()
+    }
+}
+
+/// Cache behavior for one run, from `cache_ttl_mins` and `refresh_schedule`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RefreshPolicy {
+    pub ttl_mins: u64,
+    /// Prefer cached results of any age over the network
+    pub cache_only: bool,
+}
+
+impl RefreshPolicy {
+    /// Serve cached results of any age
+    pub const CACHE_ONLY: Self = Self {
+        ttl_mins: u64::MAX,
+        cache_only: true,
+    };
+}
+
+/// What fills the list once the seen filter has used up the day's trending repos
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Backfill {
+    /// GitHub's weekly trending repos not shown yet, labeled as such
+    #[default]
+    Weekly,
+    /// Nothing; say that everything was shown already
+    None,
+}
+
+/// When output is colored (`display.color`, `--color`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorChoice {
+    /// Only on a terminal, unless `NO_COLOR` is set (`CLICOLOR_FORCE=1` forces it)
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// Bundled color presets for `[theme]`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemePreset {
+    /// Bright colors for dark backgrounds
+    #[default]
+    Dark,
+    /// Darker colors that stay readable on light backgrounds
+    Light,
+    /// No colors on the themed fields
+    Monochrome,
+    /// Solarized accent colors (24-bit)
+    Solarized,
+}
+
+/// A color in `[theme]`: a name, `#rrggbb`, `provider` or `none`, checked on load
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ThemeColor(String);
+
+impl ThemeColor {
+    pub fn paint(&self) -> Paint {
+        // Validated in try_from, so parsing cannot fail here
+        Paint::parse(&self.0).unwrap_or(Paint::Plain)
+    }
+}
+
+impl TryFrom<String> for ThemeColor {
+    type Error = String;
+
+    fn try_from(spec: String) -> Result<Self, Self::Error> {
+        Paint::parse(&spec)?;
+        Ok(Self(spec))
+    }
+}
+
+impl From<ThemeColor> for String {
+    fn from(color: ThemeColor) -> Self {
+        color.0
+    }
+}
+
+/// MOTD colors: a preset plus optional per-field overrides
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ThemeConfig {
+    #[serde(default)]
+    pub preset: ThemePreset,
+    #[serde(default)]
+    pub icon: Option<ThemeColor>,
+    #[serde(default)]
+    pub name: Option<ThemeColor>,
+    #[serde(default)]
+    pub stars: Option<ThemeColor>,
+    #[serde(default)]
+    pub language: Option<ThemeColor>,
+    #[serde(default)]
+    pub description: Option<ThemeColor>,
+}
+
+/// Single line printed by `--format statusbar` for polybar/i3blocks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusbarConfig {
+    /// Placeholders: {name}, {repo}, {stars}, {language}, {provider}, {count}
+    #[serde(default = "default_statusbar_template")]
+    pub template: String,
+    /// Command run on left click, emitted as a polybar action tag
+    #[serde(default)]
+    pub on_click: Option<String>,
+    /// Maximum visible characters for `--format statusbar` and `--format tmux` (unset = no limit)
+    #[serde(default)]
+    pub max_width: Option<usize>,
+}
+
+impl Default for StatusbarConfig {
+    fn default() -> Self {
+        Self {
+            template: default_statusbar_template(),
+            on_click: None,
+            max_width: None,
+        }
+    }
+}
+
+fn default_statusbar_template() -> String {
+    "{name} ★{stars}".to_string()
+}
+
+/// Read-later service `save` and `bookmark push` send repository URLs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReadLaterService {
+    Wallabag,
+    Omnivore,
+    Linkding,
+}
+
+/// Connection settings for `save` and `bookmark push` (`[readlater]`)
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ReadLaterConfig {
+    #[serde(default)]
+    pub service: Option<ReadLaterService>,
+    /// Base URL of the instance (Omnivore: GraphQL endpoint, defaults to the hosted one)
+    #[serde(default)]
+    pub url: Option<String>,
+    /// API token (Linkding) or API key (Omnivore)
+    #[serde(default)]
+    pub token: Option<String>,
+    /// Tags (Wallabag, Linkding) or labels (Omnivore) added to every saved repo
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Wallabag API client credentials and login
+    #[serde(default)]
+    pub client_id: Option<String>,
+    #[serde(default)]
+    pub client_secret: Option<String>,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+/// How `todo` creates tasks (`[todo]`)
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TodoConfig {
+    /// Shell command with `{title}`, `{name}`, `{url}`, `{description}` (unset = Taskwarrior)
+    #[serde(default)]
+    pub command: Option<String>,
+}
+
+/// Provider icons in front of each repo
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct IconsConfig {
+    /// Built-in icon set; unset keeps the plain tags like `[GH]`
+    #[serde(default)]
+    pub style: Option<IconStyle>,
+    /// Own icon per provider id (built-in or custom), overriding the style
+    #[serde(flatten)]
+    pub custom: BTreeMap<String, String>,
+}
+
+/// Built-in provider icon sets for `[icons]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IconStyle {
+    /// Emoji like 🐙 for GitHub
+    Emoji,
+    /// Nerd Font glyphs (needs a patched font)
+    Nerd,
+}
+
+/// Heuristic filter for growth-hacked and marketing repositories
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SpamConfig {
+    /// Score (0-100) at or above which a repo counts as spam; unset disables the check
+    #[serde(default)]
+    pub threshold: Option<u8>,
+    #[serde(default)]
+    pub action: SpamAction,
+    /// Extra marketing phrases on top of the built-in list
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// Fetch the READMEs of borderline repos and count link-only ones (GitHub, GitLab, Gitea)
+    #[serde(default)]
+    pub check_readmes: bool,
+}
+
+/// A part of the MOTD output that `general.sections` enables and orders
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputSection {
+    /// "Your repos are trending" callout
+    Alerts,
+    /// The trending list itself
+    Trending,
+    /// Daily picks of followed topics
+    Topics,
+    /// Reminders due today
+    Reminders,
+    /// Stats dashboard of `[section.myrepos]`
+    Myrepos,
+    /// Languages gaining or losing trending slots against the last week (not shown by default)
+    Languages,
+}
+
+/// One entry of `general.sections`: a section name, optionally with its own title
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SectionSpec {
+    Name(OutputSection),
+    Titled {
+        name: OutputSection,
+        #[serde(default)]
+        title: Option<String>,
+    },
+}
+
+impl SectionSpec {
+    pub fn section(&self) -> OutputSection {
+        match self {
+            Self::Name(section) | Self::Titled { name: section, .. } => *section,
+        }
+    }
+
+    /// Custom title replacing the section's built-in heading
+    pub fn title(&self) -> Option<&str> {
+        match self {
+            Self::Name(_) => None,
+            Self::Titled { title, .. } => title.as_deref().filter(|t| !t.trim().is_empty()),
+        }
+    }
+}
+
+fn default_sections() -> Vec<SectionSpec> {
+    [
+        OutputSection::Alerts,
+        OutputSection::Trending,
+        OutputSection::Topics,
+        OutputSection::Reminders,
+        OutputSection::Myrepos,
+    ]
+    .map(SectionSpec::Name)
+    .to_vec()
+}
+
+/// Extra sections shown beneath the trending list
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SectionConfig {
+    #[serde(default)]
+    pub myrepos: MyReposConfig,
+}
+
+/// Daily stats dashboard for your own repositories (`[section.myrepos]`)
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MyReposConfig {
+    /// GitHub repositories (`owner/repo`) to show stars, open issues and views for
+    #[serde(default)]
+    pub repos: Vec<String>,
+}
+
+/// Directory layout used by the clone subcommand
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CloneLayout {
+    /// `<root>/<repo>`
+    #[default]
+    Flat,
+    /// `<root>/<host>/<owner>/<repo>`
+    Hosted,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CloneConfig {
+    #[serde(default)]
+    pub layout: CloneLayout,
+    /// Root directory for clones (defaults to the current directory)
+    #[serde(default)]
+    pub root: Option<String>,
+    /// Check account age, README install scripts and `known_bad_url` before cloning
+    #[serde(default)]
+    pub safety_check: bool,
+    /// Known-bad repositories (`owner/repo` or `owner/*` per line), from a URL or path
+    #[serde(default)]
+    pub known_bad_url: Option<String>,
+}
+
+impl CloneConfig {
+    /// Root directory with a leading `~` expanded to the home directory
+    pub fn root_dir(&self) -> Option<PathBuf> {
+        expand_home(self.root.as_deref()?)
+    }
+}
+
+impl GeneralConfig {
+    /// Template file with a leading `~` expanded to the home directory
+    pub fn template_path(&self) -> Option<PathBuf> {
+        expand_home(self.template.as_deref()?)
+    }
+
+    /// Cache behavior at local time `now`
+    ///
+    /// Before the day's first window starts, the last window of the schedule still applies.
+    pub fn refresh_policy(&self, now: chrono::NaiveTime) -> RefreshPolicy {
+        let window = self
+            .refresh_schedule
+            .iter()
+            .filter(|window| window.from.0 <= now)
+            .max_by_key(|window| window.from)
+            .or_else(|| {
+                self.refresh_schedule
+                    .iter()
+                    .max_by_key(|window| window.from)
+            });
+
+        match window {
+            Some(window) if window.cache_only => RefreshPolicy::CACHE_ONLY,
+            window => RefreshPolicy {
+                ttl_mins: window
+                    .and_then(|window| window.ttl_mins)
+                    .unwrap_or(self.cache_ttl_mins),
+                cache_only: false,
+            },
+        }
+    }
+}
+
+/// Expand a leading `~` to the home directory; blank paths are unset
+fn expand_home(path: &str) -> Option<PathBuf> {
+    let path = Some(path).filter(|p| !p.trim().is_empty())?;
+    match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().map(|home| home.join(rest)),
+        None if path == "~" => dirs::home_dir(),
+        None => Some(PathBuf::from(path)),
+    }
+}
+
 impl Default for GeneralConfig {
     fn default() -> Self {
         Self {
@@ -86,16 +945,46 @@ impl Default for GeneralConfig {
             github_max_entries: None,
             gitlab_max_entries: None,
             gitea_max_entries: None,
+            codeberg_max_entries: None,
+            bitbucket_max_entries: None,
+            gitee_max_entries: None,
+            huggingface_max_entries: None,
+            crates_max_entries: None,
+            hackernews_max_entries: None,
+            reddit_max_entries: None,
             timeout_secs: default_timeout_secs(),
             cache_ttl_mins: default_cache_ttl_mins(),
+            refresh_schedule: Vec::new(),
             language_filter: vec![],
+            license_filter: vec![],
+            include_unknown_lang: false,
+            secondary_language_percent: None,
             github_timeout_secs: default_github_timeout_secs(),
             gitlab_timeout_secs: default_gitlab_timeout_secs(),
             gitea_timeout_secs: default_gitea_timeout_secs(),
+            codeberg_timeout_secs: default_gitea_timeout_secs(),
+            bitbucket_timeout_secs: default_bitbucket_timeout_secs(),
+            gitee_timeout_secs: default_gitee_timeout_secs(),
+            huggingface_timeout_secs: default_huggingface_timeout_secs(),
+            crates_timeout_secs: default_crates_timeout_secs(),
+            hackernews_timeout_secs: default_hackernews_timeout_secs(),
+            reddit_timeout_secs: default_reddit_timeout_secs(),
             ascii_only: false,
             min_stars: None,
+            max_stars: None,
             fast_network_timeout_secs: default_fast_network_timeout_secs(),
             show_starred_status: true,
+            activity_log: false,
+            merge_providers: false,
+            provider_weights: BTreeMap::new(),
+            my_accounts: vec![],
+            trending_alert: false,
+            blocklist: vec![],
+            remote_rules_url: None,
+            template: None,
+            pager: None,
+            backfill: Backfill::default(),
+            sections: default_sections(),
         }
     }
 }
@@ -106,6 +995,23 @@ impl Default for ProvidersConfig {
             github: true,
             gitlab: true,
             gitea: true,
+            codeberg: false,
+            bitbucket: false,
+            gitee: false,
+            huggingface: false,
+            crates: false,
+            hackernews: false,
+            reddit: false,
+        }
+    }
+}
+
+impl Default for GitLabConfig {
+    fn default() -> Self {
+        Self {
+            base_url: default_gitlab_url(),
+            include_topics: Vec::new(),
+            exclude_topics: Vec::new(),
         }
     }
 }
@@ -114,6 +1020,45 @@ impl Default for GiteaConfig {
     fn default() -> Self {
         Self {
             base_url: default_gitea_url(),
+            ranking: GiteaRanking::default(),
+            instance_ranking: BTreeMap::new(),
+            include_topics: Vec::new(),
+            exclude_topics: Vec::new(),
+        }
+    }
+}
+
+impl Default for GiteeConfig {
+    fn default() -> Self {
+        Self {
+            query: default_gitee_query(),
+        }
+    }
+}
+
+impl Default for HuggingFaceConfig {
+    fn default() -> Self {
+        Self {
+            kinds: default_huggingface_kinds(),
+        }
+    }
+}
+
+impl Default for RedditConfig {
+    fn default() -> Self {
+        Self {
+            subs: default_reddit_subs(),
+        }
+    }
+}
+
+impl Default for CodebergConfig {
+    fn default() -> Self {
+        Self {
+            base_url: default_codeberg_url(),
+            ranking: default_codeberg_ranking(),
+            include_topics: Vec::new(),
+            exclude_topics: Vec::new(),
         }
     }
 }
@@ -142,18 +1087,154 @@ fn default_cache_ttl_mins() -> u64 {
     60
 }
 
+/// Whether two base URLs name the same instance, ignoring case and trailing slashes
+pub fn same_instance(a: &str, b: &str) -> bool {
+    a.trim_end_matches('/')
+        .eq_ignore_ascii_case(b.trim_end_matches('/'))
+}
+
+fn default_gitlab_url() -> String {
+    "https://gitlab.com".to_string()
+}
+
 fn default_gitea_url() -> String {
     "https://gitea.com".to_string()
 }
 
+fn default_bitbucket_timeout_secs() -> u64 {
+    10
+}
+
+fn default_gitee_timeout_secs() -> u64 {
+    10
+}
+
+fn default_gitee_query() -> String {
+    "开源".to_string()
+}
+
+fn default_huggingface_timeout_secs() -> u64 {
+    10
+}
+
+fn default_crates_timeout_secs() -> u64 {
+    10
+}
+
+fn default_hackernews_timeout_secs() -> u64 {
+    10
+}
+
+fn default_reddit_timeout_secs() -> u64 {
+    10
+}
+
+fn default_reddit_subs() -> Vec<String> {
+    vec!["opensource".to_string(), "selfhosted".to_string()]
+}
+
+fn default_huggingface_kinds() -> Vec<HubKind> {
+    vec![HubKind::Models]
+}
+
+fn default_codeberg_url() -> String {
+    "https://codeberg.org".to_string()
+}
+
+fn default_codeberg_ranking() -> GiteaRanking {
+    GiteaRanking::RecentStars
+}
+
 fn default_fast_network_timeout_secs() -> u64 {
     3
 }
 
+/// Presets available without any config
+const BUILTIN_PRESETS: &[&str] = &["security", "selfhosted"];
+
+fn builtin_preset(name: &str) -> Option<PresetConfig> {
+    match name {
+        "security" => Some(PresetConfig {
+            include_topics: [
+                "security",
+                "cybersecurity",
+                "infosec",
+                "pentesting",
+                "penetration-testing",
+                "red-team",
+                "blue-team",
+                "osint",
+                "ctf",
+                "fuzzing",
+                "reverse-engineering",
+                "malware-analysis",
+                "vulnerability-scanner",
+            ]
+            .map(String::from)
+            .to_vec(),
+            min_stars: Some(20),
+            ..PresetConfig::default()
+        }),
+        // Self-hostable services under an open source license, marked when containerized
+        "selfhosted" => Some(PresetConfig {
+            include_topics: ["selfhosted", "self-hosted", "docker", "homelab"]
+                .map(String::from)
+                .to_vec(),
+            licenses: [
+                "MIT",
+                "Apache-2.0",
+                "GPL-2.0",
+                "GPL-3.0",
+                "AGPL-3.0",
+                "LGPL-2.1",
+                "LGPL-3.0",
+                "MPL-2.0",
+                "BSD-2-Clause",
+                "BSD-3-Clause",
+                "ISC",
+                "Unlicense",
+            ]
+            .map(String::from)
+            .to_vec(),
+            show_containers: true,
+            ..PresetConfig::default()
+        }),
+        _ => None,
+    }
+}
+
+/// Providers with topic include/exclude lists
+pub const TOPIC_PROVIDERS: [&str; 4] = ["github", "gitlab", "gitea", "codeberg"];
+
+/// Append the values not already present (case-insensitive)
+pub(crate) fn extend_unique(list: &mut Vec<String>, values: &[String]) {
+    for value in values {
+        if !list.iter().any(|v| v.eq_ignore_ascii_case(value)) {
+            list.push(value.clone());
+        }
+    }
+}
+
+fn default_description_max_length() -> usize {
+    45
+}
+
 fn default_true() -> bool {
     true
 }
 
+fn default_custom_auth_header() -> String {
+    "Authorization".to_string()
+}
+
+fn default_custom_items() -> String {
+    "$".to_string()
+}
+
+fn default_custom_timeout_secs() -> u64 {
+    10
+}
+
 impl Config {
     /// Load configuration from file, with XDG config directory support
     pub fn load() -> Result<Self> {
@@ -168,9 +1249,14 @@ impl Config {
                 let content = std::fs::read_to_string(path)
                     .with_context(|| format!("Failed to read config file: {}", path.display()))?;
 
-                let mut config: Config = toml::from_str(&content)
+                let (mut config, warnings) = schema::parse(&content)
+                    .map_err(|d| anyhow!("{}", d.located(path)))
                     .with_context(|| format!("Failed to parse config file: {}", path.display()))?;
 
+                for warning in &warnings {
+                    notice!("⚠ {}", warning.located(path));
+                }
+
                 // Convert empty token strings to None
                 config.normalize_tokens();
 
@@ -212,11 +1298,11 @@ impl Config {
                     format!("Failed to write default config: {}", config_path.display())
                 })?;
 
-                eprintln!(
+                notice!(
                     "ℹ No config file found. Created default configuration at: {}",
                     config_path.display()
                 );
-                eprintln!("  Edit this file to customize trotd settings.");
+                notice!("  Edit this file to customize trotd settings.");
             }
         }
 
@@ -237,7 +1323,32 @@ impl Config {
         }
         if let Some(ref token) = self.auth.gitea_token {
             if token.trim().is_empty() {
-                self.auth.gitea_token = None;
+                self.auth.gitea_token = None;
+            }
+        }
+        if let Some(ref token) = self.auth.codeberg_token {
+            if token.trim().is_empty() {
+                self.auth.codeberg_token = None;
+            }
+        }
+        if let Some(ref token) = self.auth.bitbucket_token {
+            if token.trim().is_empty() {
+                self.auth.bitbucket_token = None;
+            }
+        }
+        if let Some(ref username) = self.auth.bitbucket_username {
+            if username.trim().is_empty() {
+                self.auth.bitbucket_username = None;
+            }
+        }
+        if let Some(ref token) = self.auth.gitee_token {
+            if token.trim().is_empty() {
+                self.auth.gitee_token = None;
+            }
+        }
+        if let Some(ref token) = self.auth.huggingface_token {
+            if token.trim().is_empty() {
+                self.auth.huggingface_token = None;
             }
         }
     }
@@ -288,12 +1399,38 @@ impl Config {
             self.auth.gitea_token = Some(val);
         }
 
+        if let Ok(val) = std::env::var("TROTD_CODEBERG_TOKEN") {
+            self.auth.codeberg_token = Some(val);
+        }
+
+        if let Ok(val) = std::env::var("TROTD_BITBUCKET_TOKEN") {
+            self.auth.bitbucket_token = Some(val);
+        }
+
+        if let Ok(val) = std::env::var("TROTD_BITBUCKET_USERNAME") {
+            self.auth.bitbucket_username = Some(val);
+        }
+
+        if let Ok(val) = std::env::var("TROTD_GITEE_TOKEN") {
+            self.auth.gitee_token = Some(val);
+        }
+
+        if let Ok(val) = std::env::var("TROTD_HUGGINGFACE_TOKEN") {
+            self.auth.huggingface_token = Some(val);
+        }
+
         if let Ok(val) = std::env::var("TROTD_MIN_STARS") {
             if let Ok(min) = val.parse() {
                 self.general.min_stars = Some(min);
             }
         }
 
+        if let Ok(val) = std::env::var("TROTD_MAX_STARS") {
+            if let Ok(max) = val.parse() {
+                self.general.max_stars = Some(max);
+            }
+        }
+
         if let Ok(val) = std::env::var("TROTD_GITHUB_EXCLUDE_TOPICS") {
             self.github.exclude_topics = val.split(',').map(|s| s.trim().to_string()).collect();
         }
@@ -311,11 +1448,64 @@ impl Config {
         if self.providers.gitea {
             providers.push("gitea");
         }
+        if self.providers.codeberg {
+            providers.push("codeberg");
+        }
+        if self.providers.bitbucket {
+            providers.push("bitbucket");
+        }
+        if self.providers.gitee {
+            providers.push("gitee");
+        }
+        if self.providers.huggingface {
+            providers.push("huggingface");
+        }
+        if self.providers.crates {
+            providers.push("crates");
+        }
+        if self.providers.hackernews {
+            providers.push("hackernews");
+        }
+        if self.providers.reddit {
+            providers.push("reddit");
+        }
+        for custom in self.custom.iter().filter(|c| c.enabled) {
+            if !providers.contains(&custom.name.as_str()) {
+                providers.push(&custom.name);
+            }
+        }
         providers
     }
 
-    /// Get the maximum number of entries for a specific provider
+    /// The `[[custom]]` provider named `name`
+    pub fn custom_provider(&self, name: &str) -> Option<&CustomProviderConfig> {
+        self.custom.iter().find(|c| c.name == name)
+    }
+
+    /// Resolve a `--provider` name through user aliases and the built-in short names
+    ///
+    /// Returns the provider id and an optional base URL from `provider@url` targets.
+    pub fn resolve_provider(&self, name: &str) -> (String, Option<String>) {
+        let target = self.aliases.get(name).map_or_else(
+            || {
+                BUILTIN_PROVIDERS
+                    .iter()
+                    .find(|(_, short)| *short == name)
+                    .map_or(name, |(id, _)| id)
+            },
+            String::as_str,
+        );
+
+        match target.split_once('@') {
+            Some((provider, base_url)) => (provider.to_string(), Some(base_url.to_string())),
+            None => (target.to_string(), None),
+        }
+    }
+
+    /// Get the maximum number of entries for a specific provider or alias
     pub fn get_max_entries(&self, provider: &str) -> usize {
+        let (id, _) = self.resolve_provider(provider);
+        let provider = id.as_str();
         match provider {
             "github" => self
                 .general
@@ -329,7 +1519,243 @@ impl Config {
                 .general
                 .gitea_max_entries
                 .unwrap_or(self.general.max_per_provider),
-            _ => self.general.max_per_provider,
+            "codeberg" => self
+                .general
+                .codeberg_max_entries
+                .unwrap_or(self.general.max_per_provider),
+            "bitbucket" => self
+                .general
+                .bitbucket_max_entries
+                .unwrap_or(self.general.max_per_provider),
+            "gitee" => self
+                .general
+                .gitee_max_entries
+                .unwrap_or(self.general.max_per_provider),
+            "huggingface" => self
+                .general
+                .huggingface_max_entries
+                .unwrap_or(self.general.max_per_provider),
+            "crates" => self
+                .general
+                .crates_max_entries
+                .unwrap_or(self.general.max_per_provider),
+            "hackernews" => self
+                .general
+                .hackernews_max_entries
+                .unwrap_or(self.general.max_per_provider),
+            "reddit" => self
+                .general
+                .reddit_max_entries
+                .unwrap_or(self.general.max_per_provider),
+            _ => self
+                .custom_provider(provider)
+                .and_then(|c| c.max_entries)
+                .unwrap_or(self.general.max_per_provider),
+        }
+    }
+
+    /// Get the HTTP timeout in seconds for a specific provider or alias
+    pub fn get_timeout_secs(&self, provider: &str) -> u64 {
+        let (id, _) = self.resolve_provider(provider);
+        let provider = id.as_str();
+        match provider {
+            "github" => self.general.github_timeout_secs,
+            "gitlab" => self.general.gitlab_timeout_secs,
+            "gitea" => self.general.gitea_timeout_secs,
+            "codeberg" => self.general.codeberg_timeout_secs,
+            "bitbucket" => self.general.bitbucket_timeout_secs,
+            "gitee" => self.general.gitee_timeout_secs,
+            "huggingface" => self.general.huggingface_timeout_secs,
+            "crates" => self.general.crates_timeout_secs,
+            "hackernews" => self.general.hackernews_timeout_secs,
+            "reddit" => self.general.reddit_timeout_secs,
+            _ => self
+                .custom_provider(provider)
+                .map_or(self.general.timeout_secs, |c| c.timeout_secs),
+        }
+    }
+
+    /// Include/exclude topics configured for a provider
+    pub fn topic_filter(&self, provider: &str) -> TopicFilter {
+        let (include, exclude) = match provider {
+            "github" => (&self.github.include_topics, &self.github.exclude_topics),
+            "gitlab" => (&self.gitlab.include_topics, &self.gitlab.exclude_topics),
+            "gitea" => (&self.gitea.include_topics, &self.gitea.exclude_topics),
+            "codeberg" => (&self.codeberg.include_topics, &self.codeberg.exclude_topics),
+            _ => return TopicFilter::default(),
+        };
+        TopicFilter {
+            include: include.clone(),
+            exclude: exclude.clone(),
+        }
+    }
+
+    /// Mutable include and exclude topic lists of a provider in [`TOPIC_PROVIDERS`]
+    pub fn topic_lists_mut(
+        &mut self,
+        provider: &str,
+    ) -> Option<(&mut Vec<String>, &mut Vec<String>)> {
+        let lists = match provider {
+            "github" => (
+                &mut self.github.include_topics,
+                &mut self.github.exclude_topics,
+            ),
+            "gitlab" => (
+                &mut self.gitlab.include_topics,
+                &mut self.gitlab.exclude_topics,
+            ),
+            "gitea" => (
+                &mut self.gitea.include_topics,
+                &mut self.gitea.exclude_topics,
+            ),
+            "codeberg" => (
+                &mut self.codeberg.include_topics,
+                &mut self.codeberg.exclude_topics,
+            ),
+            _ => return None,
+        };
+        Some(lists)
+    }
+
+    /// Add topics to every provider's include and exclude lists
+    pub fn extend_topics(&mut self, include: &[String], exclude: &[String]) {
+        for provider in TOPIC_PROVIDERS {
+            if let Some((include_topics, exclude_topics)) = self.topic_lists_mut(provider) {
+                extend_unique(include_topics, include);
+                extend_unique(exclude_topics, exclude);
+            }
+        }
+    }
+
+    /// Look up a preset; config entries take precedence over the built-in ones
+    pub fn preset(&self, name: &str) -> Option<PresetConfig> {
+        self.preset
+            .get(name)
+            .cloned()
+            .or_else(|| builtin_preset(name))
+    }
+
+    /// Apply a named preset, returning the providers it selects (if any)
+    pub fn apply_preset(&mut self, name: &str) -> Result<Option<Vec<String>>> {
+        let Some(preset) = self.preset(name) else {
+            let mut names: Vec<&str> = self.preset.keys().map(String::as_str).collect();
+            names.extend(
+                BUILTIN_PRESETS
+                    .iter()
+                    .filter(|p| !self.preset.contains_key(**p)),
+            );
+            names.sort_unstable();
+            anyhow::bail!("Unknown preset `{name}` (available: {})", names.join(", "));
+        };
+
+        self.extend_topics(&preset.include_topics, &preset.exclude_topics);
+        if !preset.languages.is_empty() {
+            self.general.language_filter = preset.languages;
+        }
+        if preset.min_stars.is_some() {
+            self.general.min_stars = preset.min_stars;
+        }
+        if preset.max_stars.is_some() {
+            self.general.max_stars = preset.max_stars;
+        }
+        if !preset.licenses.is_empty() {
+            self.general.license_filter = preset.licenses;
+        }
+        if preset.show_containers {
+            self.display.show_containers = true;
+        }
+
+        Ok(preset.providers)
+    }
+
+    /// Non-empty topic filters keyed by provider id
+    pub fn topic_filters(&self) -> BTreeMap<String, TopicFilter> {
+        TOPIC_PROVIDERS
+            .into_iter()
+            .map(|provider| (provider.to_string(), self.topic_filter(provider)))
+            .filter(|(_, filter)| !filter.is_empty())
+            .collect()
+    }
+
+    /// `Authorization` value for Bitbucket: Basic when `bitbucket_username` is set (app
+    /// passwords and API tokens), Bearer otherwise (workspace and repository access tokens)
+    pub fn bitbucket_authorization(&self) -> Option<String> {
+        let token = self.auth.bitbucket_token.as_deref()?;
+        Some(match self.auth.bitbucket_username.as_deref() {
+            Some(username) => format!("Basic {}", STANDARD.encode(format!("{username}:{token}"))),
+            None => format!("Bearer {token}"),
+        })
+    }
+
+    /// Base URL configured for a provider that supports other instances
+    fn configured_base_url(&self, provider: &str) -> Option<&str> {
+        match provider {
+            "gitlab" => Some(&self.gitlab.base_url),
+            "gitea" => Some(&self.gitea.base_url),
+            "codeberg" => Some(&self.codeberg.base_url),
+            _ => None,
+        }
+    }
+
+    /// Build the per-request provider configuration for a provider or an alias
+    ///
+    /// An alias with a base URL (`work = "gitea@https://git.example.com"`) gets its own
+    /// instance and that instance's ranking; tokens only go to the configured instance.
+    pub fn provider_cfg(&self, provider: &str) -> ProviderCfg {
+        let (id, instance_url) = self.resolve_provider(provider);
+        let provider = id.as_str();
+        let configured_url = self.configured_base_url(provider);
+        let base_url =
+            configured_url.map(|configured| instance_url.unwrap_or_else(|| configured.to_string()));
+        let other_instance = base_url
+            .as_deref()
+            .zip(configured_url)
+            .is_some_and(|(url, configured)| !same_instance(url, configured));
+        let gitea_ranking = match (provider, base_url.as_deref()) {
+            ("codeberg", Some(url)) => self
+                .gitea
+                .instance_ranking_for(url)
+                .unwrap_or(self.codeberg.ranking),
+            (_, url) => self.gitea.ranking_for(url.unwrap_or(&self.gitea.base_url)),
+        };
+
+        ProviderCfg {
+            timeout_secs: self.general.timeout_secs,
+            token: match provider {
+                _ if other_instance => None,
+                // Repos linked from Show HN and Reddit posts are resolved through the GitHub API
+                "github" | "hackernews" | "reddit" => self.auth.github_token.clone(),
+                "gitlab" => self.auth.gitlab_token.clone(),
+                "gitea" => self.auth.gitea_token.clone(),
+                "codeberg" => self.auth.codeberg_token.clone(),
+                // A complete `Authorization` value, since Bitbucket may need Basic auth
+                "bitbucket" => self.bitbucket_authorization(),
+                "gitee" => self.auth.gitee_token.clone(),
+                "huggingface" => self.auth.huggingface_token.clone(),
+                _ => self.custom_provider(provider).and_then(|c| c.token.clone()),
+            },
+            base_url,
+            topics: self.topic_filter(provider),
+            gitea_ranking,
+            weekly: false,
+            query: match provider {
+                "gitee" => Some(self.gitee.query.clone()),
+                // Reddit lists several subreddits at once as `r/rust+selfhosted`
+                "reddit" => Some(
+                    self.reddit
+                        .subs
+                        .iter()
+                        .map(|sub| sub.trim().trim_start_matches("r/"))
+                        .collect::<Vec<_>>()
+                        .join("+"),
+                ),
+                _ => None,
+            },
+            hub_kinds: if provider == "huggingface" {
+                self.huggingface.kinds.clone()
+            } else {
+                Vec::new()
+            },
         }
     }
 }
@@ -423,4 +1849,515 @@ mod tests {
         assert_eq!(config.get_max_entries("gitlab"), 1);
         assert_eq!(config.get_max_entries("gitea"), 1);
     }
+
+    #[test]
+    fn test_resolve_provider_aliases() {
+        let toml_str = r#"
+            [aliases]
+            cb = "gitea@https://codeberg.org"
+            gh = "gitlab"
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config.resolve_provider("cb"),
+            (
+                "gitea".to_string(),
+                Some("https://codeberg.org".to_string())
+            )
+        );
+        // User aliases take precedence over built-in short names
+        assert_eq!(config.resolve_provider("gh"), ("gitlab".to_string(), None));
+        assert_eq!(config.resolve_provider("ge"), ("gitea".to_string(), None));
+        assert_eq!(
+            config.resolve_provider("github"),
+            ("github".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn test_provider_cfg_per_instance() {
+        let toml_str = r#"
+            [auth]
+            gitea_token = "secret"
+
+            [aliases]
+            cb = "gitea@https://codeberg.org"
+            home = "gitea@https://gitea.com/"
+
+            [gitea.instance_ranking]
+            "https://codeberg.org" = "newest-with-stars"
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let cb = config.provider_cfg("cb");
+        assert_eq!(cb.base_url.as_deref(), Some("https://codeberg.org"));
+        assert_eq!(cb.gitea_ranking, GiteaRanking::NewestWithStars);
+        // The token belongs to `[gitea] base_url`, not to other instances
+        assert_eq!(cb.token, None);
+        assert_eq!(config.provider_cfg("home").token.as_deref(), Some("secret"));
+
+        let gitea = config.provider_cfg("gitea");
+        assert_eq!(gitea.base_url.as_deref(), Some("https://gitea.com"));
+        assert_eq!(gitea.gitea_ranking, GiteaRanking::Updated);
+        assert_eq!(
+            config.get_timeout_secs("cb"),
+            config.general.gitea_timeout_secs
+        );
+    }
+
+    #[test]
+    fn test_config_parsing_clone_layout() {
+        let toml_str = r#"
+            [clone]
+            layout = "hosted"
+            root = "~/src"
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.clone.layout, CloneLayout::Hosted);
+        assert_eq!(
+            config.clone.root_dir(),
+            dirs::home_dir().map(|h| h.join("src"))
+        );
+        assert_eq!(Config::default().clone.layout, CloneLayout::Flat);
+    }
+
+    #[test]
+    fn test_config_parsing_with_hooks() {
+        let toml_str = r#"
+            [hooks]
+            post_fetch = "cat >> ~/trending.log"
+            on_new_repo = "notify-send trotd"
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config.hooks.post_fetch.as_deref(),
+            Some("cat >> ~/trending.log")
+        );
+        assert!(config.hooks.pre_render.is_none());
+        assert_eq!(
+            config.hooks.on_new_repo.as_deref(),
+            Some("notify-send trotd")
+        );
+    }
+
+    #[test]
+    fn test_gitea_ranking_per_instance() {
+        let toml_str = r#"
+            [gitea]
+            base_url = "https://codeberg.org"
+            ranking = "starred-updated-today"
+
+            [gitea.instance_ranking]
+            "https://codeberg.org/" = "recent-stars"
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config.gitea.ranking_for("https://codeberg.org"),
+            GiteaRanking::RecentStars
+        );
+        assert_eq!(
+            config.gitea.ranking_for("https://gitea.com"),
+            GiteaRanking::StarredUpdatedToday
+        );
+        assert_eq!(
+            config.provider_cfg("gitea").gitea_ranking,
+            GiteaRanking::RecentStars
+        );
+        // Codeberg's own instance picks up its entry too
+        assert_eq!(
+            config.provider_cfg("codeberg").gitea_ranking,
+            GiteaRanking::RecentStars
+        );
+    }
+
+    #[test]
+    fn test_gitlab_base_url() {
+        let config = Config::default();
+        assert_eq!(
+            config.provider_cfg("gitlab").base_url.as_deref(),
+            Some("https://gitlab.com")
+        );
+
+        let config: Config = toml::from_str(
+            r#"
+            [gitlab]
+            base_url = "https://gitlab.example.com"
+        "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.provider_cfg("gitlab").base_url.as_deref(),
+            Some("https://gitlab.example.com")
+        );
+    }
+
+    #[test]
+    fn test_codeberg_provider() {
+        let config = Config::default();
+        assert!(!config.enabled_providers().contains(&"codeberg"));
+        assert_eq!(
+            config.resolve_provider("cb"),
+            ("codeberg".to_string(), None)
+        );
+        let cfg = config.provider_cfg("codeberg");
+        assert_eq!(cfg.base_url.as_deref(), Some("https://codeberg.org"));
+        assert_eq!(cfg.gitea_ranking, GiteaRanking::RecentStars);
+
+        let toml_str = r#"
+            [providers]
+            codeberg = true
+
+            [codeberg]
+            ranking = "newest-with-stars"
+            exclude_topics = ["mirror"]
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.enabled_providers().contains(&"codeberg"));
+        assert_eq!(
+            config.provider_cfg("codeberg").gitea_ranking,
+            GiteaRanking::NewestWithStars
+        );
+        assert!(config.topic_filters().contains_key("codeberg"));
+    }
+
+    #[test]
+    fn test_bitbucket_provider() {
+        let mut config = Config::default();
+        assert!(!config.enabled_providers().contains(&"bitbucket"));
+        assert_eq!(
+            config.resolve_provider("bb"),
+            ("bitbucket".to_string(), None)
+        );
+        assert_eq!(config.provider_cfg("bitbucket").token, None);
+
+        config.auth.bitbucket_token = Some("secret".to_string());
+        assert_eq!(
+            config.provider_cfg("bitbucket").token.as_deref(),
+            Some("Bearer secret")
+        );
+        config.auth.bitbucket_username = Some("user".to_string());
+        assert_eq!(
+            config.bitbucket_authorization().as_deref(),
+            Some("Basic dXNlcjpzZWNyZXQ=")
+        );
+    }
+
+    #[test]
+    fn test_gitee_provider() {
+        let config = Config::default();
+        assert!(!config.enabled_providers().contains(&"gitee"));
+        assert_eq!(config.resolve_provider("gt"), ("gitee".to_string(), None));
+        assert_eq!(config.provider_cfg("gitee").query.as_deref(), Some("开源"));
+        assert_eq!(config.provider_cfg("github").query, None);
+
+        let toml_str = r#"
+            [providers]
+            gitee = true
+
+            [gitee]
+            query = "rust"
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.enabled_providers().contains(&"gitee"));
+        assert_eq!(config.provider_cfg("gitee").query.as_deref(), Some("rust"));
+    }
+
+    #[test]
+    fn test_huggingface_provider() {
+        let config = Config::default();
+        assert!(!config.enabled_providers().contains(&"huggingface"));
+        assert_eq!(
+            config.resolve_provider("hf"),
+            ("huggingface".to_string(), None)
+        );
+        assert_eq!(
+            config.provider_cfg("huggingface").hub_kinds,
+            [HubKind::Models]
+        );
+        assert!(config.provider_cfg("github").hub_kinds.is_empty());
+
+        let toml_str = r#"
+            [providers]
+            huggingface = true
+
+            [huggingface]
+            kinds = ["datasets", "spaces"]
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.enabled_providers().contains(&"huggingface"));
+        assert_eq!(
+            config.provider_cfg("huggingface").hub_kinds,
+            [HubKind::Datasets, HubKind::Spaces]
+        );
+    }
+
+    #[test]
+    fn test_refresh_policy() {
+        let at = |time: &str| chrono::NaiveTime::parse_from_str(time, "%H:%M").unwrap();
+        let config = Config::default();
+        assert_eq!(
+            config.general.refresh_policy(at("20:00")),
+            RefreshPolicy {
+                ttl_mins: 60,
+                cache_only: false
+            }
+        );
+
+        let toml_str = r#"
+            [general]
+            cache_ttl_mins = 90
+            refresh_schedule = [
+                { from = "18:00", cache_only = true },
+                { from = "06:00", ttl_mins = 15 },
+                { from = "12:00" },
+            ]
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let policy = |time| config.general.refresh_policy(at(time));
+        assert_eq!(policy("07:30").ttl_mins, 15);
+        assert_eq!(policy("12:00").ttl_mins, 90);
+        assert!(policy("18:00").cache_only);
+        // Past midnight the evening window is still in effect
+        assert!(policy("02:00").cache_only);
+
+        let invalid = "[general]\nrefresh_schedule = [{ from = \"6pm\" }]";
+        assert!(toml::from_str::<Config>(invalid).is_err());
+    }
+
+    #[test]
+    fn test_crates_provider() {
+        let config = Config::default();
+        assert!(!config.enabled_providers().contains(&"crates"));
+        assert_eq!(config.resolve_provider("cr"), ("crates".to_string(), None));
+
+        let toml_str = r"
+            [providers]
+            crates = true
+
+            [general]
+            crates_max_entries = 4
+            crates_timeout_secs = 20
+        ";
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.enabled_providers().contains(&"crates"));
+        assert_eq!(config.get_max_entries("crates"), 4);
+        assert_eq!(config.get_timeout_secs("crates"), 20);
+    }
+
+    #[test]
+    fn test_hackernews_provider() {
+        let mut config = Config::default();
+        assert!(!config.enabled_providers().contains(&"hackernews"));
+        assert_eq!(
+            config.resolve_provider("hn"),
+            ("hackernews".to_string(), None)
+        );
+
+        config.providers.hackernews = true;
+        config.auth.github_token = Some("ghp_test".to_string());
+        assert!(config.enabled_providers().contains(&"hackernews"));
+        assert_eq!(
+            config.provider_cfg("hackernews").token.as_deref(),
+            Some("ghp_test")
+        );
+    }
+
+    #[test]
+    fn test_reddit_provider() {
+        let config = Config::default();
+        assert!(!config.enabled_providers().contains(&"reddit"));
+        assert_eq!(config.resolve_provider("rd"), ("reddit".to_string(), None));
+
+        let toml_str = r#"
+            [providers]
+            reddit = true
+
+            [reddit]
+            subs = ["rust", "r/selfhosted"]
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.enabled_providers().contains(&"reddit"));
+        assert_eq!(
+            config.provider_cfg("reddit").query.as_deref(),
+            Some("rust+selfhosted")
+        );
+    }
+
+    #[test]
+    fn test_custom_provider() {
+        let toml_str = r#"
+            [[custom]]
+            name = "internal"
+            url = "https://git.example.com/api/trending?limit={limit}"
+            token = "Bearer secret"
+            max_entries = 5
+
+            [custom.fields]
+            name = "$.full_name"
+
+            [[custom]]
+            name = "paused"
+            url = "https://example.com"
+            enabled = false
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let providers = config.enabled_providers();
+        assert!(providers.contains(&"internal"));
+        assert!(!providers.contains(&"paused"));
+
+        let custom = config.custom_provider("internal").unwrap();
+        assert_eq!(custom.icon(), "[IN]");
+        assert_eq!(custom.auth_header, "Authorization");
+        assert_eq!(custom.fields.name, "$.full_name");
+        assert_eq!(custom.fields.url, "url");
+        assert_eq!(config.get_max_entries("internal"), 5);
+        assert_eq!(config.get_timeout_secs("internal"), 10);
+        assert_eq!(
+            config.provider_cfg("internal").token.as_deref(),
+            Some("Bearer secret")
+        );
+    }
+
+    #[test]
+    fn test_config_parsing_topic_filters() {
+        let toml_str = r#"
+            [github]
+            exclude_topics = ["awesome"]
+
+            [gitlab]
+            include_topics = ["rust", "cli"]
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let filters = config.topic_filters();
+        assert_eq!(filters.len(), 2);
+        assert_eq!(filters["github"].exclude, vec!["awesome".to_string()]);
+        assert_eq!(filters["gitlab"].include.len(), 2);
+        assert!(!filters.contains_key("gitea"));
+
+        assert!(!config.provider_cfg("github").topics.is_empty());
+        assert!(!config.provider_cfg("gitlab").topics.is_empty());
+        assert!(config.provider_cfg("gitea").topics.is_empty());
+    }
+
+    #[test]
+    fn test_selfhosted_preset() {
+        let mut config = Config::default();
+        config.github.include_topics = vec!["Docker".to_string()];
+        assert!(config.apply_preset("selfhosted").unwrap().is_none());
+
+        assert_eq!(config.github.include_topics.len(), 4);
+        assert!(config
+            .gitlab
+            .include_topics
+            .contains(&"homelab".to_string()));
+        assert!(config.display.show_containers);
+        assert!(config
+            .general
+            .license_filter
+            .contains(&"AGPL-3.0".to_string()));
+        assert!(!config.provider_cfg("github").topics.is_empty());
+    }
+
+    #[test]
+    fn test_presets() {
+        let toml_str = r#"
+            [preset.rusty]
+            providers = ["gh"]
+            include_topics = ["cli"]
+            languages = ["rust"]
+            min_stars = 100
+        "#;
+        let mut config: Config = toml::from_str(toml_str).unwrap();
+
+        let providers = config.apply_preset("rusty").unwrap();
+        assert_eq!(providers, Some(vec!["gh".to_string()]));
+        assert_eq!(config.gitlab.include_topics, vec!["cli".to_string()]);
+        assert_eq!(config.general.language_filter, vec!["rust".to_string()]);
+        assert_eq!(config.general.min_stars, Some(100));
+
+        assert_eq!(config.apply_preset("security").unwrap(), None);
+        assert!(config.github.include_topics.contains(&"osint".to_string()));
+
+        let error = config.apply_preset("missing").unwrap_err().to_string();
+        assert!(error.contains("rusty, security"));
+    }
+
+    #[test]
+    fn test_config_parsing_spam() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.spam.threshold, None);
+        assert_eq!(config.spam.action, SpamAction::Drop);
+
+        let toml_str = r#"
+            [spam]
+            threshold = 40
+            action = "demote"
+            keywords = ["ai wrapper"]
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.spam.threshold, Some(40));
+        assert_eq!(config.spam.action, SpamAction::Demote);
+        assert_eq!(config.spam.keywords.len(), 1);
+    }
+
+    #[test]
+    fn test_config_parsing_backfill() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.general.backfill, Backfill::Weekly);
+
+        let config: Config = toml::from_str("[general]\nbackfill = \"none\"\n").unwrap();
+        assert_eq!(config.general.backfill, Backfill::None);
+        assert!(toml::from_str::<Config>("[general]\nbackfill = \"monthly\"\n").is_err());
+    }
+
+    #[test]
+    fn test_config_parsing_description() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.description.max_length, 45);
+        assert!(config.description.strip_markup);
+        assert_eq!(config.description.max_emoji, None);
+
+        let toml_str = r"
+            [description]
+            max_length = 80
+            strip_markup = false
+            max_emoji = 1
+        ";
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.description.max_length, 80);
+        assert!(!config.description.strip_markup);
+        assert_eq!(config.description.max_emoji, Some(1));
+    }
+
+    #[test]
+    fn test_config_parsing_display() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.display.max_topics, 0);
+        assert!(config.display.color_topics);
+
+        let config: Config = toml::from_str("[display]\nmax_topics = 3\n").unwrap();
+        assert_eq!(config.display.max_topics, 3);
+    }
+    #[test]
+    fn test_config_parsing_sections() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.general.sections.len(), 5);
+        assert_eq!(config.general.sections[0].section(), OutputSection::Alerts);
+
+        let config: Config = toml::from_str(
+            "[general]\nsections = [\"trending\", { name = \"reminders\", title = \"Due\" }]\n",
+        )
+        .unwrap();
+        let sections = &config.general.sections;
+        assert_eq!(sections[0], SectionSpec::Name(OutputSection::Trending));
+        assert_eq!(sections[1].section(), OutputSection::Reminders);
+        assert_eq!(sections[1].title(), Some("Due"));
+
+        assert!(toml::from_str::<Config>("[general]\nsections = [\"footer\"]\n").is_err());
+    }
 }
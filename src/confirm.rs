@@ -0,0 +1,52 @@
+use anyhow::{Context, Result};
+use std::io::{BufRead, IsTerminal, Write};
+
+/// Ask the user to confirm a mutating action on stderr
+///
+/// Returns `Ok(true)` immediately when `assume_yes` is set. Without a terminal on
+/// stdin there is nobody to ask, so the action is refused with a hint to pass `--yes`.
+pub fn confirm(prompt: &str, assume_yes: bool) -> Result<bool> {
+    if assume_yes {
+        return Ok(true);
+    }
+
+    if !std::io::stdin().is_terminal() {
+        anyhow::bail!("Refusing to continue without confirmation; pass --yes to skip the prompt");
+    }
+
+    let mut stderr = std::io::stderr();
+    write!(stderr, "{prompt} [y/N] ").context("Failed to write prompt")?;
+    stderr.flush().context("Failed to write prompt")?;
+
+    let mut answer = String::new();
+    std::io::stdin()
+        .lock()
+        .read_line(&mut answer)
+        .context("Failed to read confirmation")?;
+
+    Ok(is_yes(&answer))
+}
+
+/// Whether an answer to a `[y/N]` prompt means yes
+fn is_yes(answer: &str) -> bool {
+    matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_yes() {
+        assert!(is_yes("y\n"));
+        assert!(is_yes(" YES "));
+        assert!(!is_yes("\n"));
+        assert!(!is_yes("no"));
+        assert!(!is_yes("yep"));
+    }
+
+    #[test]
+    fn test_assume_yes_skips_prompt() {
+        assert!(confirm("Star owner/repo?", true).unwrap());
+    }
+}
@@ -0,0 +1,109 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::future::join_all;
+
+use crate::config::Config;
+use crate::lookup::{self, RepoCheck};
+use crate::model::Repo;
+use crate::providers::{GitHub, GitLab, Gitea};
+
+/// Root files that mean a project can be run as a container
+const CONTAINER_FILES: &[&str] = &[
+    "dockerfile",
+    "containerfile",
+    "docker-compose.yml",
+    "docker-compose.yaml",
+    "compose.yml",
+    "compose.yaml",
+];
+
+/// Whether a container file sits at the repository root
+struct ContainerFiles;
+
+#[async_trait]
+impl RepoCheck for ContainerFiles {
+    type Answer = bool;
+
+    async fn github(&self, github: &GitHub, name: &str, token: Option<&str>) -> Result<bool> {
+        Ok(any_container_file(&github.root_files(name, token).await?))
+    }
+
+    async fn gitlab(
+        &self,
+        gitlab: &GitLab,
+        base_url: &str,
+        path: &str,
+        token: Option<&str>,
+    ) -> Result<bool> {
+        Ok(any_container_file(
+            &gitlab.root_files(base_url, path, token).await?,
+        ))
+    }
+
+    async fn gitea(
+        &self,
+        gitea: &Gitea,
+        base_url: &str,
+        name: &str,
+        token: Option<&str>,
+    ) -> Result<bool> {
+        Ok(any_container_file(
+            &gitea.root_files(base_url, name, token).await?,
+        ))
+    }
+}
+
+/// Mark repos that publish a ghcr.io image or ship a Dockerfile, Containerfile or
+/// compose file at their root
+///
+/// One request per repo for the root files, plus the ghcr.io lookup for GitHub repos
+/// without container files; failed lookups stay unmarked. With `skip_github`, the
+/// root files of GitHub repos already came from [`crate::enrich::github_batch`].
+pub async fn enrich(repos: &mut [Repo], config: &Config, skip_github: bool) {
+    let has_container = lookup::check_each(repos, config, skip_github, &ContainerFiles).await;
+    for (repo, has_container) in repos.iter_mut().zip(has_container) {
+        if let Some(has_container) = has_container {
+            repo.has_container = has_container;
+        }
+    }
+
+    let Ok(github) = GitHub::new(config.general.github_timeout_secs) else {
+        return;
+    };
+    let images = repos.iter().map(|repo| {
+        let github = &github;
+        async move {
+            repo.provider == "github"
+                && !repo.has_container
+                && github.has_ghcr_image(&repo.name).await.unwrap_or(false)
+        }
+    });
+    let published = join_all(images).await;
+    for (repo, published) in repos.iter_mut().zip(published) {
+        repo.has_container |= published;
+    }
+}
+
+fn any_container_file(files: &[String]) -> bool {
+    files.iter().any(|f| is_container_file(f))
+}
+
+/// Whether a root file name marks a containerized project
+pub fn is_container_file(name: &str) -> bool {
+    let name = name.to_lowercase();
+    CONTAINER_FILES.contains(&name.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_container_file() {
+        assert!(is_container_file("Dockerfile"));
+        assert!(is_container_file("compose.yaml"));
+        assert!(is_container_file("docker-compose.yml"));
+        assert!(!is_container_file("Dockerfile.md"));
+        assert!(!is_container_file("README.md"));
+    }
+}
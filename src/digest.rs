@@ -0,0 +1,186 @@
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::model::Repo;
+use crate::render::{render_to_string, xml_escape, OutputFormat, RenderOptions};
+
+/// Write today's digest into `dir` and regenerate the index of all digests
+///
+/// Markdown digests and their `_index.md` section page carry TOML front matter
+/// (`+++`), which Zola and Hugo read. With `html` the digest and `index.html` are
+/// standalone HTML pages instead. Returns the digest path.
+pub async fn publish(
+    dir: &Path,
+    date: NaiveDate,
+    repos: &[Repo],
+    opts: &RenderOptions,
+    html: bool,
+) -> Result<PathBuf> {
+    tokio::fs::create_dir_all(dir)
+        .await
+        .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+
+    let extension = if html { "html" } else { "md" };
+    let digest_path = dir.join(format!("{date}.{extension}"));
+    let content = if html {
+        digest_html(date, repos)
+    } else {
+        digest_markdown(date, repos, opts)
+    };
+    tokio::fs::write(&digest_path, content)
+        .await
+        .with_context(|| format!("Failed to write digest: {}", digest_path.display()))?;
+
+    let dates = digest_dates(dir, extension).await?;
+    let index = if html {
+        index_html(&dates)
+    } else {
+        index_markdown(&dates)
+    };
+    // Zola and Hugo take `_index.md` as the page of the section the digests are in
+    let index_path = if html {
+        dir.join("index.html")
+    } else {
+        dir.join("_index.md")
+    };
+    tokio::fs::write(&index_path, index)
+        .await
+        .with_context(|| format!("Failed to write index: {}", index_path.display()))?;
+
+    Ok(digest_path)
+}
+
+fn digest_markdown(date: NaiveDate, repos: &[Repo], opts: &RenderOptions) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "+++");
+    let _ = writeln!(out, "title = \"Trending repositories {date}\"");
+    let _ = writeln!(out, "date = {date}");
+    let _ = writeln!(out, "+++\n");
+    let _ = writeln!(out, "# Trending repositories {date}\n");
+    out.push_str(&render_to_string(repos, OutputFormat::Markdown, opts));
+    out
+}
+
+fn digest_html(date: NaiveDate, repos: &[Repo]) -> String {
+    let mut rows = String::new();
+    for repo in repos {
+        let stars = match (repo.stars_today, repo.stars_total) {
+            (Some(today), _) => format!("+{today} today"),
+            (None, Some(total)) => total.to_string(),
+            (None, None) => String::new(),
+        };
+        let _ = writeln!(
+            rows,
+            "<tr><td><a href=\"{}\">{}</a></td><td>{}</td><td>{stars}</td><td>{}</td></tr>",
+            xml_escape(&repo.url),
+            xml_escape(&repo.name),
+            xml_escape(repo.language.as_deref().unwrap_or("")),
+            xml_escape(repo.description.as_deref().unwrap_or(""))
+        );
+    }
+    html_page(
+        &format!("Trending repositories {date}"),
+        &format!(
+            "<table>\n<tr><th>Repository</th><th>Language</th><th>Stars</th><th>Description</th></tr>\n{rows}</table>"
+        ),
+    )
+}
+
+fn index_markdown(dates: &[NaiveDate]) -> String {
+    let mut out = String::from("+++\ntitle = \"Trending archive\"\nsort_by = \"date\"\n+++\n\n");
+    out.push_str("# Trending archive\n\n");
+    for date in dates {
+        let _ = writeln!(out, "- [{date}]({date}.md)");
+    }
+    out
+}
+
+fn index_html(dates: &[NaiveDate]) -> String {
+    let mut items = String::new();
+    for date in dates {
+        let _ = writeln!(items, "<li><a href=\"{date}.html\">{date}</a></li>");
+    }
+    html_page("Trending archive", &format!("<ul>\n{items}</ul>"))
+}
+
+fn html_page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n</head>\n<body>\n<h1>{title}</h1>\n{body}\n</body>\n</html>\n"
+    )
+}
+
+/// Dates of the digests in `dir` with the given extension, newest first
+async fn digest_dates(dir: &Path, extension: &str) -> Result<Vec<NaiveDate>> {
+    let mut entries = tokio::fs::read_dir(dir)
+        .await
+        .with_context(|| format!("Failed to read directory: {}", dir.display()))?;
+
+    let mut dates = Vec::new();
+    while let Some(entry) = entries.next_entry().await? {
+        let name = entry.file_name();
+        let Some(stem) = name
+            .to_str()
+            .and_then(|n| n.strip_suffix(&format!(".{extension}")))
+        else {
+            continue;
+        };
+        if let Ok(date) = NaiveDate::parse_from_str(stem, "%Y-%m-%d") {
+            dates.push(date);
+        }
+    }
+    dates.sort_unstable_by(|a, b| b.cmp(a));
+    Ok(dates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_repo(name: &str) -> Repo {
+        Repo {
+            language: Some("Rust".to_string()),
+            description: Some("A <fast> tool".to_string()),
+            stars_today: Some(5),
+            ..Repo::test(name)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_publish_markdown_and_html() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-digest-test-{}",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let repos = vec![create_test_repo("owner/tool")];
+        let opts = RenderOptions::default();
+        let day1 = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let day2 = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
+
+        publish(&temp_dir, day1, &repos, &opts, false)
+            .await
+            .unwrap();
+        let path = publish(&temp_dir, day2, &repos, &opts, false)
+            .await
+            .unwrap();
+        assert_eq!(path, temp_dir.join("2024-05-02.md"));
+
+        let digest = std::fs::read_to_string(&path).unwrap();
+        assert!(digest.starts_with("+++\ntitle = \"Trending repositories 2024-05-02\""));
+        assert!(digest.contains("[owner/tool](https://github.com/owner/tool)"));
+
+        let index = std::fs::read_to_string(temp_dir.join("_index.md")).unwrap();
+        assert!(index.starts_with("+++\ntitle = \"Trending archive\""));
+        assert!(index.contains("- [2024-05-02](2024-05-02.md)\n- [2024-05-01](2024-05-01.md)"));
+
+        publish(&temp_dir, day2, &repos, &opts, true).await.unwrap();
+        let html = std::fs::read_to_string(temp_dir.join("2024-05-02.html")).unwrap();
+        assert!(html.contains("A &lt;fast&gt; tool"));
+        let index = std::fs::read_to_string(temp_dir.join("index.html")).unwrap();
+        assert!(index.contains("<a href=\"2024-05-02.html\">"));
+        assert!(!index.contains("2024-05-01"));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
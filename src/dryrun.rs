@@ -0,0 +1,261 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::config::{Config, RefreshPolicy};
+use crate::enrich;
+
+/// Where a provider's repos would come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// Replayed from everything fetched today (`--show-all`)
+    TodaySoFar(usize),
+    /// A fresh cache entry
+    Cache(usize),
+    /// The provider API, with a fresh cache entry as fallback when it's slow
+    Network { fallback: bool },
+}
+
+/// One provider's part of the fetch
+#[derive(Debug, Clone)]
+pub struct ProviderPlan {
+    pub id: String,
+    pub source: Source,
+}
+
+/// Everything a run would do, as worked out from config and flags alone
+pub struct FetchPlan<'a> {
+    pub config: &'a Config,
+    pub providers: Vec<ProviderPlan>,
+    pub offset: usize,
+    /// Whether repos shown earlier today are skipped
+    pub seen_filter: bool,
+    /// Cache behavior, `None` with `--no-cache` or `--no-state`
+    pub cache: Option<RefreshPolicy>,
+    /// The `--system-cache` directory and whether this user can only read it
+    pub system_cache: Option<(&'a Path, bool)>,
+    pub enrichments: enrich::Plan,
+}
+
+impl FetchPlan<'_> {
+    /// Describe the plan: providers with offsets and limits, filters, caches and enrichments
+    pub fn describe(&self) -> String {
+        let config = self.config;
+        let mut out = String::from("Dry run: nothing is fetched\n\nProviders:\n");
+        for provider in &self.providers {
+            let source = match provider.source {
+                Source::TodaySoFar(count) => format!("today so far ({count} repos)"),
+                Source::Cache(count) => format!("cache ({count} repos)"),
+                Source::Network { fallback: true } => "network, cache fallback if slow".to_string(),
+                Source::Network { fallback: false } => "network".to_string(),
+            };
+            let _ = writeln!(
+                out,
+                "  {:<7} offset {}, limit {}, timeout {}s, from {source}",
+                provider.id,
+                self.offset,
+                config.get_max_entries(&provider.id),
+                config.get_timeout_secs(&provider.id),
+            );
+        }
+
+        out.push_str("\nCache:\n");
+        match self.cache {
+            Some(policy) if policy.cache_only => {
+                out.push_str("  provider results of any age (cache-only)\n");
+            }
+            Some(policy) => {
+                let _ = writeln!(out, "  provider results, TTL {} mins", policy.ttl_mins);
+            }
+            None => out.push_str("  disabled (--no-cache or --no-state)\n"),
+        }
+        match self.system_cache {
+            Some((dir, true)) => {
+                let _ = writeln!(out, "  shared: {} (read-only)", dir.display());
+            }
+            Some((dir, false)) => {
+                let _ = writeln!(out, "  shared: {}", dir.display());
+            }
+            None => {}
+        }
+        if self.seen_filter {
+            out.push_str("  seen filter: skips repos shown earlier today\n");
+        }
+
+        out.push_str("\nFilters:\n");
+        let filters = self.filters();
+        if filters.is_empty() {
+            out.push_str("  none\n");
+        }
+        for filter in filters {
+            let _ = writeln!(out, "  {filter}");
+        }
+
+        out.push_str("\nEnrichments:\n");
+        let enrichments = self.enrichments();
+        if enrichments.is_empty() {
+            out.push_str("  none\n");
+        }
+        for enrichment in enrichments {
+            let _ = writeln!(out, "  {enrichment}");
+        }
+        out
+    }
+
+    fn filters(&self) -> Vec<String> {
+        let general = &self.config.general;
+        let mut filters = Vec::new();
+        if !general.language_filter.is_empty() {
+            let mut line = format!("language: {}", general.language_filter.join(", "));
+            if let Some(percent) = general.secondary_language_percent {
+                let _ = write!(line, " (or secondary at {percent}%+)");
+            }
+            if general.include_unknown_lang {
+                line.push_str(", keeping unknown");
+            }
+            filters.push(line);
+        }
+        if general.ascii_only {
+            filters.push("ascii only".to_string());
+        }
+        if let Some(min) = general.min_stars {
+            filters.push(format!("min stars: {min}"));
+        }
+        if let Some(max) = general.max_stars {
+            filters.push(format!("max stars: {max}"));
+        }
+        if !general.license_filter.is_empty() {
+            filters.push(format!("licenses: {}", general.license_filter.join(", ")));
+        }
+        if !general.blocklist.is_empty() {
+            filters.push(format!("blocklist: {}", general.blocklist.join(", ")));
+        }
+        for (provider, topics) in self.config.topic_filters() {
+            if !topics.include.is_empty() {
+                filters.push(format!(
+                    "{provider} topics: any of {}",
+                    topics.include.join(", ")
+                ));
+            }
+            if !topics.exclude.is_empty() {
+                filters.push(format!(
+                    "{provider} topics: none of {}",
+                    topics.exclude.join(", ")
+                ));
+            }
+        }
+        if let Some(threshold) = self.config.spam.threshold {
+            let readmes = if self.config.spam.check_readmes {
+                ", READMEs of borderline repos checked"
+            } else {
+                ""
+            };
+            filters.push(format!(
+                "spam score >= {threshold}: {:?}{readmes}",
+                self.config.spam.action
+            ));
+        }
+        if general.merge_providers {
+            filters.push("merged ranking across providers".to_string());
+        }
+        filters
+    }
+
+    fn enrichments(&self) -> Vec<String> {
+        let plan = self.enrichments;
+        let has_github = self.providers.iter().any(|p| p.id == "github");
+        let token = self.config.auth.github_token.is_some();
+        let mut enrichments = Vec::new();
+        if has_github && token && plan.wants_batch() {
+            enrichments.push("GitHub lookups batched into GraphQL queries".to_string());
+        }
+        if plan.sponsors {
+            enrichments.push(if token {
+                "sponsors (GitHub)".to_string()
+            } else {
+                "sponsors (skipped: needs a GitHub token)".to_string()
+            });
+        }
+        if plan.binaries {
+            enrichments.push("binaries: latest release assets".to_string());
+        }
+        if plan.containers {
+            enrichments.push("containers: root container files, ghcr.io images".to_string());
+        }
+        if plan.health {
+            enrichments.push("health signals (GitHub)".to_string());
+        }
+        if plan.suspicious {
+            enrichments.push(format!(
+                "star spikes: history of GitHub repos gaining {}+ stars today",
+                crate::anomaly::SPIKE_STARS
+            ));
+        }
+        if plan.homepage {
+            enrichments.push(if token {
+                "homepages of GitHub repos".to_string()
+            } else {
+                "homepages of GitHub repos (skipped: needs a GitHub token)".to_string()
+            });
+        }
+        if plan.license {
+            enrichments.push("licenses (GitHub, GitLab, Gitea)".to_string());
+        }
+        if plan.starred {
+            enrichments.push(if has_github && token {
+                "starred status (GitHub batched, others from star lists)".to_string()
+            } else {
+                "starred status for providers with a token".to_string()
+            });
+        }
+        enrichments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_lists_sources_filters_and_enrichments() {
+        let mut config = Config::default();
+        config.general.language_filter = vec!["rust".to_string()];
+        config.general.min_stars = Some(50);
+        config.general.show_starred_status = false;
+        let plan = FetchPlan {
+            config: &config,
+            providers: vec![
+                ProviderPlan {
+                    id: "github".to_string(),
+                    source: Source::Cache(12),
+                },
+                ProviderPlan {
+                    id: "gitlab".to_string(),
+                    source: Source::Network { fallback: false },
+                },
+            ],
+            offset: 10,
+            seen_filter: true,
+            cache: Some(RefreshPolicy {
+                ttl_mins: 60,
+                cache_only: false,
+            }),
+            system_cache: Some((Path::new("/var/cache/trotd"), true)),
+            enrichments: enrich::Plan {
+                binaries: true,
+                ..enrich::Plan::default()
+            },
+        };
+
+        let out = plan.describe();
+        let limit = config.general.max_per_provider;
+        assert!(out.contains(&format!("github  offset 10, limit {limit}")));
+        assert!(out.contains("from cache (12 repos)"));
+        assert!(out.contains("from network\n"));
+        assert!(out.contains("TTL 60 mins"));
+        assert!(out.contains("shared: /var/cache/trotd (read-only)"));
+        assert!(out.contains("language: rust\n"));
+        assert!(out.contains("min stars: 50"));
+        assert!(out.contains("binaries: latest release assets"));
+        assert!(!out.contains("GraphQL"), "no token, nothing to batch");
+    }
+}
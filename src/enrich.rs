@@ -0,0 +1,188 @@
+use anyhow::Result;
+use chrono::Utc;
+use futures::future::join_all;
+
+use crate::anomaly::{self, StarSignals};
+use crate::config::Config;
+use crate::containers;
+use crate::health::{Health, HealthSignals};
+use crate::model::{useful_homepage, Repo};
+use crate::providers::GitHub;
+
+/// GitHub repos per batched query, well within GraphQL's node limits
+const BATCH_SIZE: usize = 25;
+
+/// Enrichments requested for this run
+#[derive(Debug, Clone, Copy, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Plan {
+    /// GitHub Sponsors listing of the owner (♥)
+    pub sponsors: bool,
+    /// Prebuilt binaries in the latest release (📦)
+    pub binaries: bool,
+    /// Container files at the repository root (🐳)
+    pub containers: bool,
+    /// Health score signals
+    pub health: bool,
+    /// Whether the token's user starred the repo (⭐), instead of downloading their star list
+    pub starred: bool,
+    /// Star spike signals for the ⚠ marker
+    pub suspicious: bool,
+    /// Project homepages, which GitHub's trending page doesn't list
+    pub homepage: bool,
+    /// Licenses, for the license filter
+    pub license: bool,
+}
+
+impl Plan {
+    /// Whether batching saves requests: sponsors alone is already a single query
+    pub fn wants_batch(self) -> bool {
+        self.binaries
+            || self.containers
+            || self.health
+            || self.starred
+            || self.suspicious
+            || self.homepage
+            || self.license
+    }
+}
+
+/// What a batched query found for one repository; unplanned fields stay empty
+#[derive(Debug, Clone, Default)]
+pub struct Enrichment {
+    pub sponsorable: bool,
+    pub starred: bool,
+    pub star_signals: StarSignals,
+    pub has_release_assets: bool,
+    pub root_files: Vec<String>,
+    pub health: HealthSignals,
+    pub homepage: Option<String>,
+    pub license: Option<String>,
+}
+
+/// Look up every planned enrichment of the GitHub repos in batched GraphQL queries
+///
+/// Replaces one to three REST calls per repo and enrichment with one query per
+/// 25 repos. Returns whether the GitHub repos are covered; without a token there
+/// is nothing to batch and the per-repo lookups run as before.
+pub async fn github_batch(repos: &mut [Repo], plan: Plan, config: &Config) -> Result<bool> {
+    let Some(token) = config.auth.github_token.as_deref() else {
+        return Ok(false);
+    };
+    let names: Vec<String> = repos
+        .iter()
+        .filter(|r| r.provider == "github")
+        .map(|r| r.name.clone())
+        .collect();
+    if !plan.wants_batch() || names.is_empty() {
+        return Ok(false);
+    }
+
+    let github = GitHub::new(config.general.github_timeout_secs)?;
+    let batches = names
+        .chunks(BATCH_SIZE)
+        .map(|chunk| github.repo_batch(chunk, plan, token));
+    let mut found = Vec::with_capacity(names.len());
+    for batch in join_all(batches).await {
+        found.extend(batch?);
+    }
+
+    apply(repos, plan, found);
+    Ok(true)
+}
+
+/// Attach batched results, in the order of the GitHub repos, to the repos
+fn apply(repos: &mut [Repo], plan: Plan, found: Vec<Option<Enrichment>>) {
+    let now = Utc::now();
+    let github_repos = repos.iter_mut().filter(|r| r.provider == "github");
+    for (repo, enrichment) in github_repos.zip(found) {
+        // Repos the query couldn't resolve stay unmarked, like a failed REST lookup
+        let enrichment = enrichment.unwrap_or_default();
+        if plan.sponsors {
+            repo.is_sponsorable = enrichment.sponsorable;
+        }
+        if plan.starred {
+            repo.is_starred = enrichment.starred;
+        }
+        if plan.binaries {
+            repo.has_binaries = enrichment.has_release_assets;
+        }
+        if plan.containers {
+            repo.has_container = enrichment
+                .root_files
+                .iter()
+                .any(|f| containers::is_container_file(f));
+        }
+        if plan.health {
+            repo.health = Some(Health::from_signals(&enrichment.health, now));
+        }
+        if plan.suspicious {
+            repo.is_suspicious =
+                anomaly::is_suspicious(repo.stars_today, &enrichment.star_signals, now);
+        }
+        if plan.homepage && repo.homepage.is_none() {
+            repo.homepage = useful_homepage(enrichment.homepage, &repo.url);
+        }
+        if plan.license && repo.license.is_none() {
+            repo.license = enrichment.license;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repo(provider: &str, name: &str) -> Repo {
+        Repo {
+            provider: provider.to_string(),
+            icon: String::new(),
+            url: format!("https://example.org/{name}"),
+            ..Repo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_apply_matches_github_repos_in_order() {
+        let mut repos = vec![
+            repo("github", "a/one"),
+            repo("gitlab", "b/two"),
+            repo("github", "c/three"),
+        ];
+        repos[1].has_binaries = true;
+        let plan = Plan {
+            binaries: true,
+            containers: true,
+            starred: true,
+            homepage: true,
+            license: true,
+            ..Plan::default()
+        };
+        let found = vec![
+            None,
+            Some(Enrichment {
+                starred: true,
+                has_release_assets: true,
+                root_files: vec!["Dockerfile".to_string()],
+                homepage: Some("https://three.dev".to_string()),
+                license: Some("MIT".to_string()),
+                ..Enrichment::default()
+            }),
+        ];
+
+        apply(&mut repos, plan, found);
+        assert!(!repos[0].has_binaries && !repos[0].has_container);
+        assert!(repos[1].has_binaries, "other providers are left alone");
+        assert!(repos[2].has_binaries && repos[2].has_container);
+        assert!(repos[2].is_starred && !repos[0].is_starred);
+        assert!(repos[2].health.is_none());
+        assert_eq!(repos[2].homepage.as_deref(), Some("https://three.dev"));
+        assert!(repos[0].homepage.is_none());
+        assert_eq!(repos[2].license.as_deref(), Some("MIT"));
+        assert!(!Plan {
+            sponsors: true,
+            ..Plan::default()
+        }
+        .wants_batch());
+    }
+}
@@ -0,0 +1,323 @@
+use std::collections::HashMap;
+
+use crate::model::Repo;
+
+/// Topics a provider's repositories must have (any of) or must not have (none of)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TopicFilter {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+impl TopicFilter {
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Check a repository's topics against the filter (case-insensitive)
+    pub fn matches(&self, topics: &[String]) -> bool {
+        let has = |wanted: &String| topics.iter().any(|t| t.eq_ignore_ascii_case(wanted));
+        (self.include.is_empty() || self.include.iter().any(has)) && !self.exclude.iter().any(has)
+    }
+}
+
+/// Remove repositories that are mostly non-ASCII, returning how many were removed
+pub fn ascii_only(repos: &mut Vec<Repo>) -> usize {
+    let before_count = repos.len();
+    repos.retain(is_mostly_ascii);
+    before_count - repos.len()
+}
+
+/// Remove repositories below a total star threshold, returning how many were removed
+pub fn min_stars(repos: &mut Vec<Repo>, min_stars: u32) -> usize {
+    let before_count = repos.len();
+    repos.retain(|repo| repo.stars_total.unwrap_or(0) >= min_stars.into());
+    before_count - repos.len()
+}
+
+/// Remove repositories above a total star ceiling, returning how many were removed
+///
+/// Repositories with an unknown star count are kept.
+pub fn max_stars(repos: &mut Vec<Repo>, max_stars: u32) -> usize {
+    let before_count = repos.len();
+    repos.retain(|repo| {
+        repo.stars_total
+            .is_none_or(|stars| stars <= max_stars.into())
+    });
+    before_count - repos.len()
+}
+
+/// Providers whose licenses are looked up; repos of the others pass the license filter
+const LICENSED_PROVIDERS: &[&str] = &["github", "gitlab", "gitea", "codeberg"];
+
+/// Keep only repositories under one of the allowed licenses (SPDX ids, any case),
+/// returning how many were removed
+///
+/// `GPL-3.0-only` and `GPL-3.0-or-later` match `GPL-3.0`. Repos of providers without
+/// a license lookup are kept; the others need a recognized license.
+pub fn license(repos: &mut Vec<Repo>, allowed: &[String]) -> usize {
+    let base = |id: &str| {
+        let id = id.to_lowercase();
+        let stripped = id
+            .strip_suffix("-only")
+            .or_else(|| id.strip_suffix("-or-later"));
+        stripped.map_or_else(|| id.clone(), str::to_string)
+    };
+    let allowed: Vec<String> = allowed.iter().map(|id| base(id)).collect();
+
+    let before_count = repos.len();
+    repos.retain(|repo| {
+        if !LICENSED_PROVIDERS.contains(&repo.provider.as_str()) {
+            return true;
+        }
+        repo.license
+            .as_deref()
+            .is_some_and(|id| allowed.contains(&base(id)))
+    });
+    before_count - repos.len()
+}
+
+/// Keep only repositories whose latest release ships binaries, returning how many were removed
+pub fn has_binaries(repos: &mut Vec<Repo>) -> usize {
+    let before_count = repos.len();
+    repos.retain(|repo| repo.has_binaries);
+    before_count - repos.len()
+}
+
+/// Keep only repositories that publish a container image or ship container files,
+/// returning how many were removed
+pub fn has_container(repos: &mut Vec<Repo>) -> usize {
+    let before_count = repos.len();
+    repos.retain(|repo| repo.has_container);
+    before_count - repos.len()
+}
+
+/// Keep at most `limits[key]` repos per provider instance, in order, returning how many
+/// were removed
+///
+/// `key` names the instance a repo came from; instances without a limit keep all their repos.
+pub fn limit_per_provider(
+    repos: &mut Vec<Repo>,
+    limits: &HashMap<String, usize>,
+    key: impl Fn(&Repo) -> String,
+) -> usize {
+    let before_count = repos.len();
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    repos.retain(|repo| {
+        let key = key(repo);
+        let Some(&limit) = limits.get(&key) else {
+            return true;
+        };
+        let count = counts.entry(key).or_default();
+        *count += 1;
+        *count <= limit
+    });
+    before_count - repos.len()
+}
+
+/// Keep only repositories whose owner accepts GitHub Sponsors, returning how many were removed
+pub fn sponsorable(repos: &mut Vec<Repo>) -> usize {
+    let before_count = repos.len();
+    repos.retain(|repo| repo.is_sponsorable);
+    before_count - repos.len()
+}
+
+/// Remove blocklisted repositories (`owner/repo` or `owner/*`), returning how many were removed
+pub fn blocklist(repos: &mut Vec<Repo>, blocklist: &[String]) -> usize {
+    let before_count = repos.len();
+    repos.retain(|repo| !is_listed(&repo.name, blocklist));
+    before_count - repos.len()
+}
+
+/// Whether `name` (`owner/repo`) matches an `owner/repo` or `owner/*` entry of `list`
+pub fn is_listed(name: &str, list: &[String]) -> bool {
+    list.iter().any(|entry| match entry.strip_suffix("/*") {
+        Some(owner) => name
+            .split_once('/')
+            .is_some_and(|(repo_owner, _)| repo_owner.eq_ignore_ascii_case(owner)),
+        None => name.eq_ignore_ascii_case(entry),
+    })
+}
+
+/// Check if a repository is mostly ASCII (filters out CJK/non-Latin scripts)
+fn is_mostly_ascii(repo: &Repo) -> bool {
+    // Check name - should be primarily ASCII
+    let name_ascii_ratio = ascii_ratio(&repo.name);
+    if name_ascii_ratio < 0.8 {
+        return false;
+    }
+
+    // Check description if present
+    if let Some(ref desc) = repo.description {
+        let desc_ascii_ratio = ascii_ratio(desc);
+        if desc_ascii_ratio < 0.7 {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Calculate the ratio of ASCII characters in a string
+#[allow(clippy::cast_precision_loss)]
+fn ascii_ratio(s: &str) -> f64 {
+    if s.is_empty() {
+        return 1.0;
+    }
+    let total_chars = s.chars().count();
+    let ascii_chars = s.chars().filter(char::is_ascii).count();
+    ascii_chars as f64 / total_chars as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_repo(name: &str, description: &str, stars_total: u64) -> Repo {
+        Repo {
+            language: Some("Rust".to_string()),
+            description: Some(description.to_string()),
+            stars_total: Some(stars_total),
+            ..Repo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_limit_per_provider() {
+        let mut repos: Vec<Repo> = ["a/1", "a/2", "a/3"]
+            .iter()
+            .map(|name| create_test_repo(name, "", 10))
+            .collect();
+        repos.push(Repo {
+            provider: "gitlab".to_string(),
+            ..create_test_repo("b/1", "", 10)
+        });
+        let limits = HashMap::from([("github".to_string(), 2)]);
+
+        assert_eq!(
+            limit_per_provider(&mut repos, &limits, |r| r.provider.clone()),
+            1
+        );
+        let names: Vec<_> = repos.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["a/1", "a/2", "b/1"]);
+    }
+
+    #[test]
+    fn test_ascii_only() {
+        let mut repos = vec![
+            create_test_repo("owner/tool", "A fast tool", 10),
+            create_test_repo("owner/中文项目", "一个很好的项目", 10),
+            create_test_repo("owner/docs", "学习资料合集和教程", 10),
+        ];
+        assert_eq!(ascii_only(&mut repos), 2);
+        assert_eq!(repos.len(), 1);
+        assert_eq!(repos[0].name, "owner/tool");
+    }
+
+    #[test]
+    fn test_min_stars() {
+        let mut repos = vec![
+            create_test_repo("owner/small", "Small", 5),
+            create_test_repo("owner/big", "Big", 500),
+        ];
+        assert_eq!(min_stars(&mut repos, 100), 1);
+        assert_eq!(repos[0].name, "owner/big");
+    }
+
+    #[test]
+    fn test_max_stars() {
+        let mut unknown = create_test_repo("owner/unknown", "Unknown", 0);
+        unknown.stars_total = None;
+        let mut repos = vec![
+            create_test_repo("owner/small", "Small", 5),
+            create_test_repo("owner/mega", "Mega", 300_000),
+            unknown,
+        ];
+        assert_eq!(max_stars(&mut repos, 10_000), 1);
+        assert_eq!(repos.len(), 2);
+        assert!(repos.iter().all(|r| r.name != "owner/mega"));
+    }
+
+    #[test]
+    fn test_has_container() {
+        let mut packaged = create_test_repo("owner/packaged", "Packaged", 10);
+        packaged.has_container = true;
+        let mut repos = vec![packaged, create_test_repo("owner/plain", "Plain", 10)];
+        assert_eq!(has_container(&mut repos), 1);
+        assert_eq!(repos[0].name, "owner/packaged");
+    }
+
+    #[test]
+    fn test_license() {
+        let licensed = |name: &str, license: Option<&str>| Repo {
+            license: license.map(str::to_string),
+            ..create_test_repo(name, "", 10)
+        };
+        let mut repos = vec![
+            licensed("owner/mit", Some("mit")),
+            licensed("owner/gpl", Some("GPL-3.0-or-later")),
+            licensed("owner/bsl", Some("BUSL-1.1")),
+            licensed("owner/none", None),
+            Repo {
+                provider: "crates".to_string(),
+                ..licensed("owner/crate", None)
+            },
+        ];
+        let allowed = ["MIT".to_string(), "GPL-3.0".to_string()];
+        assert_eq!(license(&mut repos, &allowed), 2);
+
+        let names: Vec<&str> = repos.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["owner/mit", "owner/gpl", "owner/crate"]);
+    }
+
+    #[test]
+    fn test_has_binaries() {
+        let mut released = create_test_repo("owner/released", "Released", 10);
+        released.has_binaries = true;
+        let mut repos = vec![create_test_repo("owner/source", "Source", 10), released];
+        assert_eq!(has_binaries(&mut repos), 1);
+        assert_eq!(repos[0].name, "owner/released");
+    }
+
+    #[test]
+    fn test_sponsorable() {
+        let mut funded = create_test_repo("owner/funded", "Funded", 10);
+        funded.is_sponsorable = true;
+        let mut repos = vec![funded, create_test_repo("owner/other", "Other", 10)];
+        assert_eq!(sponsorable(&mut repos), 1);
+        assert_eq!(repos[0].name, "owner/funded");
+    }
+
+    #[test]
+    fn test_blocklist() {
+        let mut repos = vec![
+            create_test_repo("spam/one", "", 10),
+            create_test_repo("Spam/two", "", 10),
+            create_test_repo("owner/bad", "", 10),
+            create_test_repo("owner/good", "", 10),
+        ];
+        let list = vec!["spam/*".to_string(), "Owner/Bad".to_string()];
+        assert_eq!(blocklist(&mut repos, &list), 3);
+        assert_eq!(repos[0].name, "owner/good");
+    }
+
+    #[test]
+    fn test_topic_filter_matches() {
+        let exclude = TopicFilter {
+            include: vec![],
+            exclude: vec!["awesome".to_string()],
+        };
+        assert!(!exclude.matches(&["Awesome".to_string()]));
+        assert!(exclude.matches(&["cli".to_string()]));
+        assert!(exclude.matches(&[]));
+
+        let include = TopicFilter {
+            include: vec!["rust".to_string(), "cli".to_string()],
+            exclude: vec!["awesome".to_string()],
+        };
+        assert!(include.matches(&["CLI".to_string()]));
+        assert!(!include.matches(&[]));
+        assert!(!include.matches(&["rust".to_string(), "awesome".to_string()]));
+        assert!(TopicFilter::default().matches(&[]));
+    }
+}
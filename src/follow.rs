@@ -0,0 +1,283 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::model::Repo;
+use crate::providers::GitHub;
+use crate::render::OutputFormat;
+use crate::state;
+
+/// Candidates fetched per topic when picking the day's repo
+const CANDIDATES_PER_TOPIC: usize = 10;
+
+/// Description characters shown per pick
+const DESCRIPTION_WIDTH: usize = 60;
+
+/// Earlier picks remembered per topic so the same repo isn't shown on two days
+const HISTORY_PER_TOPIC: usize = 100;
+
+/// Normalize a topic as typed (`#Rust` -> `rust`), rejecting invalid ones
+///
+/// GitHub topics are lowercase letters, digits and hyphens.
+pub fn normalize_topic(topic: &str) -> Result<String> {
+    let topic = topic.trim().trim_start_matches('#').to_lowercase();
+    let valid = !topic.is_empty()
+        && topic
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !valid {
+        anyhow::bail!("Invalid topic `{topic}`: use lowercase letters, digits and hyphens");
+    }
+    Ok(topic)
+}
+
+/// Topics followed with `follow-topic add`, kept in the data directory
+pub struct FollowedTopics {
+    topics_file: PathBuf,
+}
+
+impl FollowedTopics {
+    /// Create a new followed-topics store
+    pub fn new() -> Result<Self> {
+        let data_dir = state::data_dir()?;
+
+        Ok(Self::with_dir(&data_dir))
+    }
+
+    fn with_dir(data_dir: &Path) -> Self {
+        Self {
+            topics_file: data_dir.join("followed-topics.json"),
+        }
+    }
+
+    /// Followed topics in the order they were added
+    pub async fn list(&self) -> Result<Vec<String>> {
+        Ok(state::read_json(&self.topics_file)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Follow a topic; returns false when it was already followed
+    pub async fn add(&self, topic: &str) -> Result<bool> {
+        let mut topics = self.list().await?;
+        if topics.iter().any(|t| t == topic) {
+            return Ok(false);
+        }
+        topics.push(topic.to_string());
+        self.save(&topics).await?;
+        Ok(true)
+    }
+
+    /// Stop following a topic; returns false when it wasn't followed
+    pub async fn remove(&self, topic: &str) -> Result<bool> {
+        let mut topics = self.list().await?;
+        let before_count = topics.len();
+        topics.retain(|t| t != topic);
+        if topics.len() == before_count {
+            return Ok(false);
+        }
+        self.save(&topics).await?;
+        Ok(true)
+    }
+
+    async fn save(&self, topics: &[String]) -> Result<()> {
+        let content = serde_json::to_string_pretty(topics).context("Failed to serialize topics")?;
+        state::write_atomic(&self.topics_file, &content)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to write topics file: {}",
+                    self.topics_file.display()
+                )
+            })
+    }
+}
+
+/// The day's pick per topic plus every repo picked before
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct PicksFile {
+    date: String, // Format: YYYY-MM-DD
+    picks: BTreeMap<String, Repo>,
+    /// Topics looked up on `date`, picked or not, so a failed or empty lookup
+    /// isn't repeated on every run that day
+    #[serde(default)]
+    tried: BTreeSet<String>,
+    history: BTreeMap<String, Vec<String>>,
+}
+
+impl PicksFile {
+    /// Pick the first candidate not picked on an earlier day and remember it
+    fn pick(&mut self, topic: &str, candidates: Vec<Repo>) {
+        let history = self.history.entry(topic.to_string()).or_default();
+        let Some(repo) = candidates.into_iter().find(|r| !history.contains(&r.name)) else {
+            return;
+        };
+
+        history.push(repo.name.clone());
+        if history.len() > HISTORY_PER_TOPIC {
+            history.remove(0);
+        }
+        self.picks.insert(topic.to_string(), repo);
+    }
+}
+
+/// Section with the top new repo of each followed topic, empty when none are followed
+///
+/// Picks are made once per day from GitHub repos created in the last week, skipping
+/// repos already picked for that topic on an earlier day. A topic whose lookup fails
+/// or finds nothing new is tried again the next day.
+pub async fn section(config: &Config, format: OutputFormat) -> Result<String> {
+    if !matches!(format, OutputFormat::Motd | OutputFormat::Accessible) {
+        return Ok(String::new());
+    }
+    let topics = FollowedTopics::new()?.list().await?;
+    if topics.is_empty() {
+        return Ok(String::new());
+    }
+
+    let picks_file = state::cache_dir()?.join("topic-picks.json");
+    let mut file: PicksFile = state::read_json(&picks_file).await?.unwrap_or_default();
+
+    let today = Utc::now().format("%Y-%m-%d").to_string();
+    if file.date != today {
+        file.date.clone_from(&today);
+        file.picks.clear();
+        file.tried.clear();
+    }
+
+    let missing: Vec<&String> = topics.iter().filter(|t| !file.tried.contains(*t)).collect();
+    if !missing.is_empty() {
+        let github = GitHub::new(config.general.github_timeout_secs)?;
+        let token = config.auth.github_token.as_deref();
+        for topic in missing {
+            if let Ok(candidates) = github
+                .new_in_topic(topic, token, CANDIDATES_PER_TOPIC)
+                .await
+            {
+                file.pick(topic, candidates);
+            }
+            file.tried.insert(topic.clone());
+        }
+        let content =
+            serde_json::to_string_pretty(&file).context("Failed to serialize topic picks")?;
+        state::write_atomic(&picks_file, &content).await?;
+    }
+
+    Ok(render(&topics, &file.picks, format))
+}
+
+fn render(topics: &[String], picks: &BTreeMap<String, Repo>, format: OutputFormat) -> String {
+    let width = topics
+        .iter()
+        .map(|t| t.chars().count() + 1)
+        .max()
+        .unwrap_or(0);
+
+    let mut out = String::new();
+    for topic in topics {
+        let Some(repo) = picks.get(topic) else {
+            continue;
+        };
+        let stars = repo.stars_total.unwrap_or_default();
+        if matches!(format, OutputFormat::Accessible) {
+            let _ = writeln!(
+                out,
+                "New in topic {topic}: {} with {stars} stars",
+                repo.name
+            );
+        } else {
+            let tag = format!("#{topic}");
+            let _ = write!(
+                out,
+                "  🏷  {} {} {}",
+                format!("{tag:<width$}").bright_yellow(),
+                repo.name.bright_cyan().bold(),
+                format!("★{stars}").bright_green()
+            );
+            if let Some(ref description) = repo.description {
+                let short: String = description.chars().take(DESCRIPTION_WIDTH).collect();
+                let ellipsis = if short.len() < description.len() {
+                    "..."
+                } else {
+                    ""
+                };
+                let _ = write!(out, " {}", format!("{short}{ellipsis}").white());
+            }
+            out.push('\n');
+        }
+    }
+
+    if out.is_empty() {
+        out
+    } else {
+        format!("\n{out}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_repo(name: &str) -> Repo {
+        Repo {
+            stars_total: Some(42),
+            ..Repo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_normalize_topic() {
+        assert_eq!(normalize_topic(" #Rust ").unwrap(), "rust");
+        assert_eq!(normalize_topic("self-hosted").unwrap(), "self-hosted");
+        assert!(normalize_topic("two words").is_err());
+        assert!(normalize_topic("#").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_follow_add_remove() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-follow-test-{}",
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let store = FollowedTopics::with_dir(&temp_dir);
+
+        assert!(store.list().await.unwrap().is_empty());
+        assert!(store.add("rust").await.unwrap());
+        assert!(store.add("nix").await.unwrap());
+        assert!(!store.add("rust").await.unwrap());
+        assert_eq!(store.list().await.unwrap(), ["rust", "nix"]);
+
+        assert!(store.remove("rust").await.unwrap());
+        assert!(!store.remove("rust").await.unwrap());
+        assert_eq!(store.list().await.unwrap(), ["nix"]);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_pick_skips_earlier_picks() {
+        let mut file = PicksFile::default();
+        file.pick(
+            "rust",
+            vec![create_test_repo("a/one"), create_test_repo("b/two")],
+        );
+        assert_eq!(file.picks["rust"].name, "a/one");
+
+        file.picks.clear();
+        file.pick(
+            "rust",
+            vec![create_test_repo("a/one"), create_test_repo("b/two")],
+        );
+        assert_eq!(file.picks["rust"].name, "b/two");
+
+        colored::control::set_override(false);
+        let topics = vec!["rust".to_string(), "nix".to_string()];
+        let out = render(&topics, &file.picks, OutputFormat::Accessible);
+        assert_eq!(out, "\nNew in topic rust: b/two with 42 stars\n");
+    }
+}
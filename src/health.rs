@@ -0,0 +1,185 @@
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+use crate::model::Repo;
+use crate::providers::GitHub;
+
+/// Raw signals a health score is computed from; `None` means the signal is unknown
+#[derive(Debug, Clone, Default)]
+pub struct HealthSignals {
+    pub last_push: Option<DateTime<Utc>>,
+    pub last_release: Option<DateTime<Utc>>,
+    pub open_issues: Option<u64>,
+    pub stars: Option<u64>,
+    /// Contributor count, capped at the number requested from the API
+    pub contributors: Option<usize>,
+}
+
+/// One scored part of the health indicator
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthComponent {
+    pub name: String,
+    pub points: u8,
+    pub max: u8,
+    pub detail: String,
+}
+
+/// Composite health indicator (0-100) with its breakdown
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Health {
+    pub score: u8,
+    pub components: Vec<HealthComponent>,
+}
+
+impl Health {
+    /// Score the signals against a fixed point budget per component
+    pub fn from_signals(signals: &HealthSignals, now: DateTime<Utc>) -> Self {
+        let days_since = |dt: Option<DateTime<Utc>>| dt.map(|d| (now - d).num_days());
+
+        let commits = match days_since(signals.last_push) {
+            Some(days) => (
+                tiered(days, &[(7, 30), (30, 20), (90, 10)]),
+                format!("pushed {days}d ago"),
+            ),
+            None => (0, "unknown".to_string()),
+        };
+
+        let releases = match days_since(signals.last_release) {
+            Some(days) => (
+                tiered(days, &[(30, 25), (180, 15), (365, 5)]),
+                format!("released {days}d ago"),
+            ),
+            None => (0, "no releases".to_string()),
+        };
+
+        // Open issues per 100 stars as a proxy for how well issues are kept up with
+        let issues = match (signals.open_issues, signals.stars) {
+            (Some(open), Some(stars)) => {
+                let per_hundred = open * 100 / stars.max(1);
+                let per_hundred = i64::try_from(per_hundred).unwrap_or(i64::MAX);
+                (
+                    tiered(per_hundred, &[(0, 25), (4, 15), (14, 5)]),
+                    format!("{open} open issues"),
+                )
+            }
+            _ => (0, "unknown".to_string()),
+        };
+
+        let bus_factor = match signals.contributors {
+            Some(count) => {
+                let points = match count {
+                    0 | 1 => 0,
+                    2 => 6,
+                    3 | 4 => 12,
+                    _ => 20,
+                };
+                (points, format!("{count} contributors"))
+            }
+            None => (0, "unknown".to_string()),
+        };
+
+        let components = vec![
+            component("recent commits", commits, 30),
+            component("release recency", releases, 25),
+            component("issue load", issues, 25),
+            component("bus factor", bus_factor, 20),
+        ];
+        let score = components.iter().map(|c| c.points).sum();
+
+        Self { score, components }
+    }
+}
+
+fn component(name: &str, (points, detail): (u8, String), max: u8) -> HealthComponent {
+    HealthComponent {
+        name: name.to_string(),
+        points,
+        max,
+        detail,
+    }
+}
+
+/// Points of the first tier whose upper bound `value` does not exceed
+fn tiered(value: i64, tiers: &[(i64, u8)]) -> u8 {
+    tiers
+        .iter()
+        .find(|(limit, _)| value <= *limit)
+        .map_or(0, |(_, points)| *points)
+}
+
+/// Fetch health signals for every GitHub repo and attach the score
+///
+/// Other providers are left without a score. Failures only lose that repo's score.
+pub async fn enrich(repos: &mut [Repo], github: &GitHub, token: Option<&str>) {
+    let now = Utc::now();
+    let lookups = repos.iter().map(|repo| async move {
+        if repo.provider != "github" {
+            return None;
+        }
+        let (owner, name) = repo.name.split_once('/')?;
+        github.repo_health(owner, name, token).await.ok()
+    });
+    let signals = join_all(lookups).await;
+
+    for (repo, signals) in repos.iter_mut().zip(signals) {
+        repo.health = signals.map(|s| Health::from_signals(&s, now));
+    }
+}
+
+/// Plain-text breakdown of every scored repository
+pub fn explain(repos: &[Repo]) -> String {
+    let mut out = String::new();
+    for repo in repos {
+        let Some(ref health) = repo.health else {
+            continue;
+        };
+        let _ = writeln!(out, "\n{} — health {}/100", repo.name, health.score);
+        for c in &health.components {
+            let _ = writeln!(
+                out,
+                "  {:<16} {:>2}/{:<2}  {}",
+                c.name, c.points, c.max, c.detail
+            );
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_healthy_repo_scores_full() {
+        let now = Utc::now();
+        let signals = HealthSignals {
+            last_push: Some(now - Duration::days(1)),
+            last_release: Some(now - Duration::days(10)),
+            open_issues: Some(0),
+            stars: Some(1000),
+            contributors: Some(10),
+        };
+        let health = Health::from_signals(&signals, now);
+        assert_eq!(health.score, 100);
+        assert_eq!(health.components.len(), 4);
+    }
+
+    #[test]
+    fn test_unknown_signals_score_zero() {
+        let health = Health::from_signals(&HealthSignals::default(), Utc::now());
+        assert_eq!(health.score, 0);
+        assert!(health.components.iter().all(|c| c.points == 0));
+        assert!(explain(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_tiered() {
+        let tiers = [(7, 30), (30, 20)];
+        assert_eq!(tiered(3, &tiers), 30);
+        assert_eq!(tiered(30, &tiers), 20);
+        assert_eq!(tiered(31, &tiers), 0);
+    }
+}
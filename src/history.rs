@@ -0,0 +1,437 @@
+use anyhow::{Context, Result};
+use chrono::{Days, NaiveDate, Utc};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::model::Repo;
+use crate::render::OutputFormat;
+use crate::state;
+
+/// Days of star counts kept per repository
+const HISTORY_DAYS: u64 = 7;
+
+/// Sparkline levels from lowest to highest
+const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const ASCII_BARS: [char; 8] = ['_', '.', ',', ':', '-', '=', '+', '#'];
+
+/// Languages shown in each direction of the language section
+const TREND_LIMIT: usize = 3;
+
+/// Repo URL -> total stars per day
+type HistoryFile = BTreeMap<String, BTreeMap<NaiveDate, u64>>;
+
+/// Day -> repo URL -> what it trended with
+type LanguageFile = BTreeMap<NaiveDate, BTreeMap<String, Slot>>;
+
+/// One trending slot of a day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Slot {
+    language: Option<String>,
+    #[serde(default)]
+    stars_today: u64,
+}
+
+/// Total star counts of the repositories fetched over the last week
+pub struct StarHistory {
+    history_file: PathBuf,
+}
+
+impl StarHistory {
+    /// Create a new star history instance
+    pub fn new() -> Result<Self> {
+        let cache_dir = state::cache_dir()?;
+
+        Ok(Self::with_dir(&cache_dir))
+    }
+
+    fn with_dir(cache_dir: &Path) -> Self {
+        Self {
+            history_file: cache_dir.join("star-history.json"),
+        }
+    }
+
+    /// Record today's star totals and return each repo's counts, oldest first
+    ///
+    /// Days older than a week are dropped. Repos without a known total are skipped.
+    pub async fn record(&self, repos: &[Repo]) -> Result<HashMap<String, Vec<u64>>> {
+        self.record_on(repos, Utc::now().date_naive()).await
+    }
+
+    async fn record_on(
+        &self,
+        repos: &[Repo],
+        today: NaiveDate,
+    ) -> Result<HashMap<String, Vec<u64>>> {
+        let mut file: HistoryFile = state::read_json(&self.history_file)
+            .await?
+            .unwrap_or_default();
+
+        for repo in repos {
+            if let Some(total) = repo.stars_total {
+                file.entry(repo.url.clone())
+                    .or_default()
+                    .insert(today, total);
+            }
+        }
+
+        let oldest = today
+            .checked_sub_days(Days::new(HISTORY_DAYS - 1))
+            .unwrap_or(today);
+        for days in file.values_mut() {
+            days.retain(|date, _| *date >= oldest);
+        }
+        file.retain(|_, days| !days.is_empty());
+
+        let content =
+            serde_json::to_string_pretty(&file).context("Failed to serialize star history")?;
+        state::write_atomic(&self.history_file, &content)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to write star history: {}",
+                    self.history_file.display()
+                )
+            })?;
+
+        Ok(repos
+            .iter()
+            .filter_map(|repo| {
+                let days = file.get(&repo.url)?;
+                Some((repo.url.clone(), days.values().copied().collect()))
+            })
+            .collect())
+    }
+}
+
+/// Trending slots per language over the last week, for the `languages` section
+pub struct LanguageHistory {
+    history_file: PathBuf,
+}
+
+/// A language's trending slots and stars today against its average of the days before
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageTrend {
+    pub language: String,
+    pub slots: f64,
+    pub stars: f64,
+}
+
+impl LanguageHistory {
+    /// Create a new language history instance
+    pub fn new() -> Result<Self> {
+        let cache_dir = state::cache_dir()?;
+
+        Ok(Self::with_dir(&cache_dir))
+    }
+
+    fn with_dir(cache_dir: &Path) -> Self {
+        Self {
+            history_file: cache_dir.join("language-history.json"),
+        }
+    }
+
+    /// Add the fetched repos to today's slots
+    ///
+    /// Slots are kept per repo, so later pages fetched the same day add up
+    /// instead of replacing the first one.
+    pub async fn record(&self, repos: &[Repo]) -> Result<()> {
+        self.record_on(repos, Utc::now().date_naive()).await
+    }
+
+    async fn record_on(&self, repos: &[Repo], today: NaiveDate) -> Result<()> {
+        let mut file: LanguageFile = state::read_json(&self.history_file)
+            .await?
+            .unwrap_or_default();
+
+        let day = file.entry(today).or_default();
+        for repo in repos {
+            day.insert(
+                repo.url.clone(),
+                Slot {
+                    language: repo.language.clone(),
+                    stars_today: repo.stars_today.unwrap_or_default(),
+                },
+            );
+        }
+
+        // Today plus the week it is compared against
+        let oldest = today
+            .checked_sub_days(Days::new(HISTORY_DAYS))
+            .unwrap_or(today);
+        file.retain(|date, _| *date >= oldest);
+
+        let content =
+            serde_json::to_string_pretty(&file).context("Failed to serialize language history")?;
+        state::write_atomic(&self.history_file, &content)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to write language history: {}",
+                    self.history_file.display()
+                )
+            })
+    }
+
+    /// Today's change per language against the daily average of the previous week,
+    /// biggest gain first; empty until there is a previous day to compare with
+    pub async fn trends(&self) -> Result<Vec<LanguageTrend>> {
+        let file: LanguageFile = state::read_json(&self.history_file)
+            .await?
+            .unwrap_or_default();
+        Ok(trends_on(&file, Utc::now().date_naive()))
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn trends_on(file: &LanguageFile, today: NaiveDate) -> Vec<LanguageTrend> {
+    let Some(current) = file.get(&today) else {
+        return Vec::new();
+    };
+    let previous: Vec<_> = file.range(..today).map(|(_, day)| day).collect();
+    if previous.is_empty() {
+        return Vec::new();
+    }
+
+    // Language -> (slots, stars) of one day
+    let totals = |day: &BTreeMap<String, Slot>| {
+        let mut totals: BTreeMap<String, (f64, f64)> = BTreeMap::new();
+        for slot in day.values() {
+            if let Some(ref language) = slot.language {
+                let entry = totals.entry(language.clone()).or_default();
+                entry.0 += 1.0;
+                entry.1 += slot.stars_today as f64;
+            }
+        }
+        totals
+    };
+
+    let days = previous.len() as f64;
+    let mut averages: BTreeMap<String, (f64, f64)> = BTreeMap::new();
+    for day in &previous {
+        for (language, (slots, stars)) in totals(day) {
+            let entry = averages.entry(language).or_default();
+            entry.0 += slots / days;
+            entry.1 += stars / days;
+        }
+    }
+
+    let mut changes = totals(current);
+    for language in averages.keys() {
+        changes.entry(language.clone()).or_default();
+    }
+    let mut trends: Vec<LanguageTrend> = changes
+        .into_iter()
+        .map(|(language, (slots, stars))| {
+            let (avg_slots, avg_stars) = averages.get(&language).copied().unwrap_or_default();
+            LanguageTrend {
+                language,
+                slots: slots - avg_slots,
+                stars: stars - avg_stars,
+            }
+        })
+        .collect();
+    trends.sort_by(|a, b| {
+        b.slots
+            .total_cmp(&a.slots)
+            .then_with(|| b.stars.total_cmp(&a.stars))
+    });
+    trends
+}
+
+/// Language momentum section, e.g. "Zig ▲, Rust ▲, Go ▼"; empty without history
+pub async fn language_section(
+    format: OutputFormat,
+    title: Option<&str>,
+    ascii: bool,
+) -> Result<String> {
+    if !matches!(format, OutputFormat::Motd | OutputFormat::Accessible) {
+        return Ok(String::new());
+    }
+    let trends = LanguageHistory::new()?.trends().await?;
+    Ok(render_languages(&trends, format, title, ascii))
+}
+
+fn render_languages(
+    trends: &[LanguageTrend],
+    format: OutputFormat,
+    title: Option<&str>,
+    ascii: bool,
+) -> String {
+    let gaining: Vec<&str> = trends
+        .iter()
+        .filter(|t| t.slots > 0.0 || (t.slots == 0.0 && t.stars > 0.0))
+        .take(TREND_LIMIT)
+        .map(|t| t.language.as_str())
+        .collect();
+    let losing: Vec<&str> = trends
+        .iter()
+        .rev()
+        .filter(|t| t.slots < 0.0 || (t.slots == 0.0 && t.stars < 0.0))
+        .take(TREND_LIMIT)
+        .map(|t| t.language.as_str())
+        .collect();
+    if gaining.is_empty() && losing.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::new();
+    if matches!(format, OutputFormat::Accessible) {
+        if let Some(title) = title {
+            let _ = writeln!(out, "{title}");
+        }
+        if !gaining.is_empty() {
+            let _ = writeln!(
+                out,
+                "Languages gaining on their weekly average: {}",
+                gaining.join(", ")
+            );
+        }
+        if !losing.is_empty() {
+            let _ = writeln!(
+                out,
+                "Languages losing on their weekly average: {}",
+                losing.join(", ")
+            );
+        }
+    } else {
+        let (up, down) = if ascii { ("^", "v") } else { ("▲", "▼") };
+        let title = title.unwrap_or("📈 Languages vs. last week:");
+        let entries: Vec<String> = gaining
+            .iter()
+            .map(|language| format!("{language} {}", up.green()))
+            .chain(
+                losing
+                    .iter()
+                    .map(|language| format!("{language} {}", down.red())),
+            )
+            .collect();
+        let _ = writeln!(out, "{} {}", title.bold(), entries.join(", "));
+    }
+    format!("\n{out}")
+}
+
+/// One bar per value, scaled between the lowest and highest value
+pub fn sparkline(values: &[u64], ascii: bool) -> String {
+    let bars = if ascii { &ASCII_BARS } else { &BARS };
+    let (Some(&min), Some(&max)) = (values.iter().min(), values.iter().max()) else {
+        return String::new();
+    };
+    let top = bars.len() as u64 - 1;
+    values
+        .iter()
+        .map(|&value| {
+            // A flat history has no range and stays at the lowest bar
+            let level = ((value - min) * top).checked_div(max - min).unwrap_or(0);
+            bars[usize::try_from(level).unwrap_or(0)]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repo(name: &str, stars_total: Option<u64>) -> Repo {
+        Repo {
+            stars_total,
+            ..Repo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(sparkline(&[0, 7, 14], false), "▁▄█");
+        assert_eq!(sparkline(&[5, 5], false), "▁▁");
+        assert_eq!(sparkline(&[1, 2], true), "_#");
+        assert_eq!(sparkline(&[], false), "");
+    }
+
+    #[tokio::test]
+    async fn test_record_keeps_a_week() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-history-test-{}",
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let history = StarHistory::with_dir(&temp_dir);
+        let day = |d| NaiveDate::from_ymd_opt(2024, 5, d).unwrap();
+
+        for (d, stars) in [(1, 10), (2, 20), (8, 80)] {
+            history
+                .record_on(&[repo("a/one", Some(stars))], day(d))
+                .await
+                .unwrap();
+        }
+        let counts = history
+            .record_on(&[repo("a/one", Some(90)), repo("b/none", None)], day(9))
+            .await
+            .unwrap();
+
+        assert_eq!(counts["https://github.com/a/one"], [80, 90]);
+        assert!(!counts.contains_key("https://github.com/b/none"));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_language_trends() {
+        colored::control::set_override(false);
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-languages-test-{}",
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let history = LanguageHistory::with_dir(&temp_dir);
+        let day = |d| NaiveDate::from_ymd_opt(2024, 5, d).unwrap();
+        let slot = |name: &str, language: &str| {
+            let mut repo = repo(name, None);
+            repo.language = Some(language.to_string());
+            repo.stars_today = Some(10);
+            repo
+        };
+
+        for d in [1, 2] {
+            let repos = [
+                slot("a/go1", "Go"),
+                slot("a/go2", "Go"),
+                slot("a/rs", "Rust"),
+            ];
+            history.record_on(&repos, day(d)).await.unwrap();
+        }
+        // Two pages fetched on the same day add up
+        history
+            .record_on(&[slot("b/zig1", "Zig"), slot("b/rs", "Rust")], day(3))
+            .await
+            .unwrap();
+        history
+            .record_on(&[slot("b/zig2", "Zig")], day(3))
+            .await
+            .unwrap();
+
+        let file: LanguageFile = state::read_json(&history.history_file)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(
+            trends_on(&file, day(1)).is_empty(),
+            "nothing to compare yet"
+        );
+        let trends = trends_on(&file, day(3));
+        let names: Vec<&str> = trends.iter().map(|t| t.language.as_str()).collect();
+        assert_eq!(names, ["Zig", "Rust", "Go"]);
+        assert!((trends[0].slots - 2.0).abs() < f64::EPSILON);
+        assert!((trends[2].slots + 2.0).abs() < f64::EPSILON);
+
+        assert_eq!(
+            render_languages(&trends, OutputFormat::Motd, None, true),
+            "\n📈 Languages vs. last week: Zig ^, Go v\n"
+        );
+        assert!(
+            render_languages(&trends, OutputFormat::Accessible, None, false)
+                .contains("Languages losing on their weekly average: Go")
+        );
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
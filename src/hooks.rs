@@ -0,0 +1,153 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use crate::config::HooksConfig;
+use crate::model::Repo;
+
+/// Lifecycle points at which user-configured hook commands run
+#[derive(Debug, Clone, Copy)]
+pub enum HookEvent {
+    /// After all providers have been fetched, before any filtering
+    PostFetch,
+    /// Right before the final repository list is rendered
+    PreRender,
+    /// Once for every repository shown for the first time today
+    OnNewRepo,
+}
+
+impl HookEvent {
+    /// Name used for the `TROTD_HOOK` environment variable
+    fn name(self) -> &'static str {
+        match self {
+            HookEvent::PostFetch => "post_fetch",
+            HookEvent::PreRender => "pre_render",
+            HookEvent::OnNewRepo => "on_new_repo",
+        }
+    }
+
+    /// Look up the configured command for this event
+    fn command(self, hooks: &HooksConfig) -> Option<&str> {
+        match self {
+            HookEvent::PostFetch => hooks.post_fetch.as_deref(),
+            HookEvent::PreRender => hooks.pre_render.as_deref(),
+            HookEvent::OnNewRepo => hooks.on_new_repo.as_deref(),
+        }
+        .filter(|cmd| !cmd.trim().is_empty())
+    }
+}
+
+/// Run the hook for a list-level event with the repositories as a JSON array on stdin
+pub fn run_for_repos(hooks: &HooksConfig, event: HookEvent, repos: &[Repo]) -> Result<()> {
+    match event.command(hooks) {
+        Some(command) => run(command, event, repos),
+        None => Ok(()),
+    }
+}
+
+/// Run the `on_new_repo` hook once per repository with a single JSON object on stdin
+pub fn run_for_each_repo(hooks: &HooksConfig, repos: &[Repo]) -> Result<()> {
+    let event = HookEvent::OnNewRepo;
+    if let Some(command) = event.command(hooks) {
+        for repo in repos {
+            run(command, event, repo)?;
+        }
+    }
+    Ok(())
+}
+
+/// Spawn a hook command through the shell and feed it the JSON payload
+fn run<T: Serialize + ?Sized>(command: &str, event: HookEvent, payload: &T) -> Result<()> {
+    let json = serde_json::to_vec(payload).context("Failed to serialize hook payload")?;
+
+    let mut child = shell_command(command)
+        .env("TROTD_HOOK", event.name())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to run {} hook: {command}", event.name()))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // A hook that ignores its input may close stdin early; that is not an error
+        let _ = stdin.write_all(&json);
+    }
+
+    let status = child
+        .wait()
+        .with_context(|| format!("Failed to wait for {} hook: {command}", event.name()))?;
+
+    if !status.success() {
+        anyhow::bail!("{} hook exited with {status}: {command}", event.name());
+    }
+
+    Ok(())
+}
+
+/// Command that runs `command` through the platform shell
+#[cfg(not(windows))]
+pub fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(command);
+    cmd
+}
+
+/// Command that runs `command` through the platform shell
+#[cfg(windows)]
+pub fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("cmd");
+    cmd.arg("/C").arg(command);
+    cmd
+}
+
+#[cfg(all(test, not(windows)))]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn create_test_repo(name: &str) -> Repo {
+        Repo {
+            language: Some("Rust".to_string()),
+            description: Some("Test repository".to_string()),
+            stars_today: Some(10),
+            stars_total: Some(100),
+            last_activity: Some(Utc::now()),
+            ..Repo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_hook_receives_repo_json() {
+        let temp_dir =
+            std::env::temp_dir().join(format!("trotd-hooks-test-{}", Utc::now().timestamp()));
+        std::fs::create_dir_all(&temp_dir).unwrap();
+        let out = temp_dir.join("payload.json");
+
+        let hooks = HooksConfig {
+            pre_render: Some(format!("cat > {}", out.display())),
+            ..HooksConfig::default()
+        };
+        let repos = vec![create_test_repo("owner/repo")];
+        run_for_repos(&hooks, HookEvent::PreRender, &repos).unwrap();
+
+        let payload: Vec<Repo> =
+            serde_json::from_str(&std::fs::read_to_string(&out).unwrap()).unwrap();
+        assert_eq!(payload.len(), 1);
+        assert_eq!(payload[0].name, "owner/repo");
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_unconfigured_and_failing_hooks() {
+        let repos = vec![create_test_repo("owner/repo")];
+        let hooks = HooksConfig::default();
+        assert!(run_for_repos(&hooks, HookEvent::PostFetch, &repos).is_ok());
+
+        let hooks = HooksConfig {
+            on_new_repo: Some("exit 3".to_string()),
+            ..HooksConfig::default()
+        };
+        assert!(run_for_each_repo(&hooks, &repos).is_err());
+    }
+}
@@ -1,9 +1,9 @@
 use anyhow::{Context, Result};
 use reqwest::{
-    header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, USER_AGENT},
+    header::{HeaderMap, HeaderName, HeaderValue, ACCEPT, AUTHORIZATION, USER_AGENT},
     Proxy,
 };
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Serialize};
 use std::{env, time::Duration};
 use tokio_retry::strategy::{jitter, ExponentialBackoff};
 use tokio_retry::Retry;
@@ -104,9 +104,29 @@ impl HttpClient {
 
     /// Fetch JSON data from URL with optional authentication token
     pub async fn get_json<T: DeserializeOwned>(&self, url: &str, token: Option<&str>) -> Result<T> {
+        let authorization = token.map(|token| format!("Bearer {token}"));
+        self.get_json_with_auth(url, authorization.as_deref()).await
+    }
+
+    /// Fetch JSON data with a raw `Authorization` header value, e.g. `Basic <credentials>`
+    pub async fn get_json_with_auth<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        authorization: Option<&str>,
+    ) -> Result<T> {
+        let auth = authorization.map(|value| (AUTHORIZATION.as_str(), value));
+        self.get_json_with_header(url, auth).await
+    }
+
+    /// Fetch JSON data with credentials in any header, e.g. `("PRIVATE-TOKEN", token)`
+    pub async fn get_json_with_header<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        auth: Option<(&str, &str)>,
+    ) -> Result<T> {
         if self.max_retries == 0 {
             // No retries, execute once
-            return self.get_json_once(url, token).await;
+            return self.get_json_once(url, auth).await;
         }
 
         let retry_strategy = ExponentialBackoff::from_millis(self.retry_base_ms)
@@ -114,7 +134,7 @@ impl HttpClient {
             .take(self.max_retries);
 
         Retry::spawn(retry_strategy, || async {
-            self.get_json_once(url, token).await
+            self.get_json_once(url, auth).await
         })
         .await
     }
@@ -123,16 +143,18 @@ impl HttpClient {
     async fn get_json_once<T: DeserializeOwned>(
         &self,
         url: &str,
-        token: Option<&str>,
+        auth: Option<(&str, &str)>,
     ) -> Result<T> {
         let mut headers = HeaderMap::new();
         headers.insert(USER_AGENT, HeaderValue::from_static("trotd/0.1.0"));
         headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
 
-        if let Some(token) = token {
-            let auth_value = HeaderValue::from_str(&format!("Bearer {token}"))
-                .context("Invalid authentication token")?;
-            headers.insert(AUTHORIZATION, auth_value);
+        if let Some((name, value)) = auth {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .with_context(|| format!("Invalid header name: {name}"))?;
+            let auth_value =
+                HeaderValue::from_str(value).context("Invalid authentication token")?;
+            headers.insert(name, auth_value);
         }
 
         let response = self
@@ -163,23 +185,31 @@ impl HttpClient {
 
     /// Fetch HTML content from URL (for web scraping)
     pub async fn get_html(&self, url: &str) -> Result<String> {
+        self.get_text(url, "text/html").await
+    }
+
+    /// Fetch a text document (e.g. a TOML file) with the given `Accept` type
+    pub async fn get_text(&self, url: &str, accept: &'static str) -> Result<String> {
         if self.max_retries == 0 {
             // No retries, execute once
-            return self.get_html_once(url).await;
+            return self.get_text_once(url, accept).await;
         }
 
         let retry_strategy = ExponentialBackoff::from_millis(self.retry_base_ms)
             .map(jitter)
             .take(self.max_retries);
 
-        Retry::spawn(retry_strategy, || async { self.get_html_once(url).await }).await
+        Retry::spawn(retry_strategy, || async {
+            self.get_text_once(url, accept).await
+        })
+        .await
     }
 
-    /// Internal method to fetch HTML once (used by retry logic)
-    async fn get_html_once(&self, url: &str) -> Result<String> {
+    /// Internal method to fetch text once (used by retry logic)
+    async fn get_text_once(&self, url: &str, accept: &'static str) -> Result<String> {
         let mut headers = HeaderMap::new();
         headers.insert(USER_AGENT, HeaderValue::from_static("trotd/0.1.0"));
-        headers.insert(ACCEPT, HeaderValue::from_static("text/html"));
+        headers.insert(ACCEPT, HeaderValue::from_static(accept));
 
         let response = self
             .client
@@ -204,7 +234,62 @@ impl HttpClient {
         response
             .text()
             .await
-            .with_context(|| format!("Failed to read HTML response from {url}"))
+            .with_context(|| format!("Failed to read response from {url}"))
+    }
+
+    /// Download a binary body such as an archive, authenticated when a token is given
+    pub async fn get_bytes(&self, url: &str, token: Option<&str>) -> Result<Vec<u8>> {
+        if self.max_retries == 0 {
+            // No retries, execute once
+            return self.get_bytes_once(url, token).await;
+        }
+
+        let retry_strategy = ExponentialBackoff::from_millis(self.retry_base_ms)
+            .map(jitter)
+            .take(self.max_retries);
+
+        Retry::spawn(retry_strategy, || async {
+            self.get_bytes_once(url, token).await
+        })
+        .await
+    }
+
+    /// Internal method to download once (used by retry logic)
+    async fn get_bytes_once(&self, url: &str, token: Option<&str>) -> Result<Vec<u8>> {
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static("trotd/0.1.0"));
+
+        if let Some(token) = token {
+            let auth_value = HeaderValue::from_str(&format!("Bearer {token}"))
+                .context("Invalid authentication token")?;
+            headers.insert(AUTHORIZATION, auth_value);
+        }
+
+        let response = self
+            .client
+            .get(url)
+            .headers(headers)
+            .timeout(self.timeout)
+            .send()
+            .await
+            .with_context(|| format!("Failed to fetch URL: {url}"))?;
+
+        let status = response.status();
+
+        // Don't retry on 4xx client errors
+        if status.is_client_error() {
+            anyhow::bail!("HTTP request failed with client error {status}: {url}");
+        }
+
+        if !status.is_success() {
+            anyhow::bail!("HTTP request failed with status {status}: {url}");
+        }
+
+        Ok(response
+            .bytes()
+            .await
+            .with_context(|| format!("Failed to read response from {url}"))?
+            .to_vec())
     }
 
     /// Send a PUT request (for starring repositories)
@@ -237,8 +322,60 @@ impl HttpClient {
         Ok(())
     }
 
+    /// Send a JSON POST request and parse the JSON response (for the GraphQL API)
+    pub async fn post_json<B: Serialize + ?Sized, T: DeserializeOwned>(
+        &self,
+        url: &str,
+        token: Option<&str>,
+        body: &B,
+    ) -> Result<T> {
+        let authorization = token.map(|token| format!("Bearer {token}"));
+        self.post_json_with_auth(url, authorization.as_deref(), body)
+            .await
+    }
+
+    /// Send a JSON POST request with a raw `Authorization` header value
+    ///
+    /// For APIs that don't take bearer tokens, e.g. `Token <key>` or a bare API key.
+    pub async fn post_json_with_auth<B: Serialize + ?Sized, T: DeserializeOwned>(
+        &self,
+        url: &str,
+        authorization: Option<&str>,
+        body: &B,
+    ) -> Result<T> {
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static("trotd/0.1.0"));
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+
+        if let Some(authorization) = authorization {
+            let auth_value =
+                HeaderValue::from_str(authorization).context("Invalid authentication token")?;
+            headers.insert(AUTHORIZATION, auth_value);
+        }
+
+        let response = self
+            .client
+            .post(url)
+            .headers(headers)
+            .json(body)
+            .timeout(self.timeout)
+            .send()
+            .await
+            .with_context(|| format!("Failed to POST to URL: {url}"))?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            anyhow::bail!("POST request failed with status {status}: {url}");
+        }
+
+        response
+            .json::<T>()
+            .await
+            .with_context(|| format!("Failed to parse JSON response from {url}"))
+    }
+
     /// Send a DELETE request (for unstarring repositories)
-    #[allow(dead_code)]
     pub async fn delete(&self, url: &str, token: Option<&str>) -> Result<()> {
         let mut headers = HeaderMap::new();
         headers.insert(USER_AGENT, HeaderValue::from_static("trotd/0.1.0"));
@@ -0,0 +1,104 @@
+use colored::Color;
+
+use crate::config::{Config, IconStyle};
+use crate::model::Repo;
+
+/// Built-in look of a provider: its icon per style and its color
+pub struct ProviderLook {
+    pub emoji: &'static str,
+    /// Nerd Font glyph
+    pub nerd: &'static str,
+    /// Icon color of the `provider` theme color
+    pub color: Color,
+}
+
+/// The built-in look of a provider id, `None` for custom providers
+pub fn look(provider: &str) -> Option<ProviderLook> {
+    let look = |emoji, nerd, color| Some(ProviderLook { emoji, nerd, color });
+    // nf-fa-github, nf-fa-gitlab, nf-fa-git, nf-fa-code_fork, nf-fa-bitbucket,
+    // nf-fa-git_square, nf-fa-smile_o, nf-dev-rust, nf-fa-hacker_news and nf-fa-reddit
+    match provider {
+        "github" => look("🐙", "\u{f09b}", Color::BrightMagenta),
+        "gitlab" => look("🦊", "\u{f296}", Color::BrightRed),
+        "gitea" => look("🍵", "\u{f1d3}", Color::BrightGreen),
+        "codeberg" => look("🗻", "\u{f126}", Color::BrightBlue),
+        "bitbucket" => look("🪣", "\u{f171}", Color::BrightCyan),
+        "gitee" => look("🏮", "\u{f1d2}", Color::Red),
+        "huggingface" => look("🤗", "\u{f118}", Color::BrightYellow),
+        "crates" => look("🦀", "\u{e7a8}", Color::Yellow),
+        "hackernews" => look(
+            "🟧",
+            "\u{f1d4}",
+            Color::TrueColor {
+                r: 255,
+                g: 102,
+                b: 0,
+            },
+        ),
+        "reddit" => look(
+            "👽",
+            "\u{f1a1}",
+            Color::TrueColor {
+                r: 255,
+                g: 69,
+                b: 0,
+            },
+        ),
+        _ => None,
+    }
+}
+
+/// The icon shown for `provider`, or `None` to keep the provider's own `[GH]` tag
+///
+/// ASCII symbols (`display.ascii_symbols`, `--no-emoji`) always keep the tags.
+pub fn for_provider(provider: &str, config: &Config) -> Option<String> {
+    if config.display.ascii() {
+        return None;
+    }
+    if let Some(icon) = config.icons.custom.get(provider) {
+        return Some(icon.clone());
+    }
+    let look = look(provider)?;
+    let icon = match config.icons.style? {
+        IconStyle::Emoji => look.emoji,
+        IconStyle::Nerd => look.nerd,
+    };
+    Some(icon.to_string())
+}
+
+/// Swap the provider icons of `repos` for the configured ones
+pub fn apply(repos: &mut [Repo], config: &Config) {
+    for repo in repos {
+        if let Some(icon) = for_provider(&repo.provider, config) {
+            repo.icon = icon;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_provider() {
+        let mut config = Config::default();
+        config.display.ascii_symbols = Some(false);
+        assert_eq!(for_provider("github", &config), None);
+
+        config.icons.style = Some(IconStyle::Emoji);
+        assert_eq!(for_provider("gitlab", &config).as_deref(), Some("🦊"));
+        assert_eq!(for_provider("sourcehut", &config), None);
+
+        config.icons.style = Some(IconStyle::Nerd);
+        config
+            .icons
+            .custom
+            .insert("gitea".to_string(), "[CB]".to_string());
+        assert_eq!(for_provider("github", &config).as_deref(), Some("\u{f09b}"));
+        assert_eq!(for_provider("gitea", &config).as_deref(), Some("[CB]"));
+
+        config.display.ascii_symbols = Some(true);
+        assert_eq!(for_provider("github", &config), None);
+        assert_eq!(for_provider("gitea", &config), None);
+    }
+}
@@ -0,0 +1,59 @@
+use clap::ValueEnum;
+
+/// Shells `init` can print a startup snippet for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum InitShell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+/// Command run at shell startup: at most once a day, printing the cached output
+/// right away while a background run refreshes it for next time
+const GREETING: &str = "git-trending --once-per day --instant";
+
+/// Startup snippet wiring trotd into a shell's interactive login
+///
+/// Only the outermost interactive shell runs it (not scripts, tmux or zellij panes),
+/// and errors never block the shell from starting.
+pub fn snippet(shell: InitShell) -> String {
+    match shell {
+        InitShell::Bash | InitShell::Zsh => format!(
+            r#"# trotd: trending repositories greeting
+if [[ $- == *i* ]] && [ -z "$TMUX" ] && [ -z "$ZELLIJ" ]; then
+    if command -v git-trending &> /dev/null; then
+        {GREETING} 2>/dev/null || true
+    fi
+fi
+"#
+        ),
+        InitShell::Fish => format!(
+            r"# trotd: trending repositories greeting
+if status is-interactive; and not set -q TMUX; and not set -q ZELLIJ
+    if command -v git-trending &> /dev/null
+        function __trotd_greeting --on-event fish_prompt
+            {GREETING} 2>/dev/null; or true
+            functions -e __trotd_greeting
+        end
+    end
+end
+"
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snippets_use_guards() {
+        for shell in [InitShell::Bash, InitShell::Zsh, InitShell::Fish] {
+            let snippet = snippet(shell);
+            assert!(snippet.contains("--once-per day --instant"));
+            assert!(snippet.contains("TMUX"));
+        }
+        assert!(snippet(InitShell::Fish).contains("status is-interactive"));
+        assert!(snippet(InitShell::Zsh).contains("[[ $- == *i* ]]"));
+    }
+}
@@ -0,0 +1,118 @@
+use anyhow::{Context, Result};
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use crate::render::OutputFormat;
+use crate::state;
+
+/// Last rendered output per format, printed as-is by `--instant`
+pub struct OutputCache {
+    cache_dir: PathBuf,
+}
+
+impl OutputCache {
+    /// Create a new output cache instance
+    pub fn new() -> Result<Self> {
+        let cache_dir = state::cache_dir()?;
+
+        Ok(Self { cache_dir })
+    }
+
+    /// Create an output cache with a custom directory (for testing)
+    #[cfg(test)]
+    fn with_dir(cache_dir: PathBuf) -> Self {
+        Self { cache_dir }
+    }
+
+    /// Get the output file path for a format
+    fn output_file(&self, format: OutputFormat) -> PathBuf {
+        let name = match format {
+            // A configured template replaces the MOTD output, so both share a slot
+            OutputFormat::Motd | OutputFormat::Template => "motd",
+            OutputFormat::Json => "json",
+            OutputFormat::Accessible => "accessible",
+            OutputFormat::Nuon => "nuon",
+            OutputFormat::JsonLines => "ndjson",
+            OutputFormat::Rss => "rss",
+            OutputFormat::Markdown => "markdown",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Tsv => "tsv",
+            OutputFormat::Waybar => "waybar",
+            OutputFormat::StatusLine => "statusbar",
+            OutputFormat::Tmux => "tmux",
+            OutputFormat::Table => "table",
+        };
+        self.cache_dir.join(format!("last-output-{name}.txt"))
+    }
+
+    /// Load the last rendered output for a format
+    pub async fn get(&self, format: OutputFormat) -> Option<String> {
+        tokio::fs::read_to_string(self.output_file(format))
+            .await
+            .ok()
+            .filter(|output| !output.is_empty())
+    }
+
+    /// Save the rendered output for a format
+    pub async fn save(&self, format: OutputFormat, output: &str) -> Result<()> {
+        let output_file = self.output_file(format);
+        state::write_atomic(&output_file, output)
+            .await
+            .with_context(|| format!("Failed to write output file: {}", output_file.display()))
+    }
+}
+
+/// Re-run the current command without `--instant` as a detached background process
+///
+/// The refresh writes its output to the cache for the next `--instant` run. Colors
+/// are forced when the foreground is a terminal so the cached output matches.
+pub fn spawn_refresh() -> Result<()> {
+    let exe = std::env::current_exe().context("Failed to locate trotd executable")?;
+    let args = std::env::args_os().skip(1).filter(|arg| arg != "--instant");
+
+    let mut cmd = Command::new(exe);
+    cmd.args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    if std::io::stdout().is_terminal() {
+        cmd.env("CLICOLOR_FORCE", "1");
+    }
+    if crate::terminal::supports_hyperlinks() {
+        cmd.env("FORCE_HYPERLINK", "1");
+    }
+
+    cmd.spawn().context("Failed to start background refresh")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_output_cache_per_format() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-instant-test-{}",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let cache = OutputCache::with_dir(temp_dir.clone());
+
+        assert!(cache.get(OutputFormat::Motd).await.is_none());
+
+        cache
+            .save(OutputFormat::Motd, "motd output\n")
+            .await
+            .unwrap();
+        cache.save(OutputFormat::Json, "[]\n").await.unwrap();
+
+        assert_eq!(
+            cache.get(OutputFormat::Motd).await.as_deref(),
+            Some("motd output\n")
+        );
+        assert_eq!(cache.get(OutputFormat::Json).await.as_deref(), Some("[]\n"));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
@@ -0,0 +1,87 @@
+use anyhow::{Context, Result};
+use std::io::{BufRead, Write};
+
+/// Help shown by `?` at the `repl` prompt
+pub const HELP: &str = "\
+Commands (N is a position in the list):
+  N, d N   show details
+  s N      star on GitHub
+  c N      clone
+  o N      open in the browser
+  h N      open the homepage
+  l        list again
+  q        quit
+";
+
+/// One action on a shown repo, typed at the `repl` prompt or picked in the `--interactive` browser
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Details(usize),
+    Star(usize),
+    Clone(usize),
+    Open(usize),
+    Homepage(usize),
+    List,
+    Help,
+    Quit,
+}
+
+/// Parse a prompt line into a command with a 0-based repo index
+///
+/// Positions are 1-based as shown in the list; out-of-range ones are rejected.
+pub fn parse(line: &str, repo_count: usize) -> Option<Command> {
+    let mut words = line.split_whitespace();
+    let first = words.next().unwrap_or("l");
+    let position = |word: Option<&str>| {
+        word.and_then(|w| w.parse::<usize>().ok())
+            .filter(|n| (1..=repo_count).contains(n))
+            .map(|n| n - 1)
+    };
+
+    let command = match first.to_ascii_lowercase().as_str() {
+        "q" | "quit" | "exit" => Command::Quit,
+        "?" | "help" => Command::Help,
+        "l" | "list" => Command::List,
+        "d" | "details" => Command::Details(position(words.next())?),
+        "s" | "star" => Command::Star(position(words.next())?),
+        "c" | "clone" => Command::Clone(position(words.next())?),
+        "o" | "open" => Command::Open(position(words.next())?),
+        "h" | "homepage" => Command::Homepage(position(words.next())?),
+        _ => Command::Details(position(Some(first))?),
+    };
+    words.next().is_none().then_some(command)
+}
+
+/// Print the prompt and read one line from stdin, `None` at end of input
+pub fn read_line() -> Result<Option<String>> {
+    let mut stderr = std::io::stderr();
+    write!(stderr, "trotd> ").context("Failed to write prompt")?;
+    stderr.flush().context("Failed to write prompt")?;
+
+    let mut line = String::new();
+    let read = std::io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .context("Failed to read command")?;
+    Ok((read > 0).then_some(line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("3", 5), Some(Command::Details(2)));
+        assert_eq!(parse("s 1", 5), Some(Command::Star(0)));
+        assert_eq!(parse(" clone 5 ", 5), Some(Command::Clone(4)));
+        assert_eq!(parse("o 2", 5), Some(Command::Open(1)));
+        assert_eq!(parse("", 5), Some(Command::List));
+        assert_eq!(parse("Q", 5), Some(Command::Quit));
+        assert_eq!(parse("s 6", 5), None);
+        assert_eq!(parse("s 0", 5), None);
+        assert_eq!(parse("s", 5), None);
+        assert_eq!(parse("s 1 2", 5), None);
+        assert_eq!(parse("x", 5), None);
+    }
+}
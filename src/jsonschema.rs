@@ -0,0 +1,140 @@
+use serde_json::{json, Value};
+
+/// Version of the `--json` output; bumped whenever a field is removed, renamed or
+/// changes type. New optional fields don't bump it.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// JSON Schema (draft 2020-12) of the `--json` output, printed by `schema`
+pub fn output_schema() -> Value {
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "git-trending --json output",
+        "type": "object",
+        "required": ["schema_version", "repos"],
+        "properties": {
+            "schema_version": { "const": SCHEMA_VERSION },
+            "repos": { "type": "array", "items": { "$ref": "#/$defs/repo" } }
+        },
+        "$defs": { "repo": repo_schema() }
+    })
+}
+
+fn repo_schema() -> Value {
+    let nullable = |kind: &str| json!({ "type": [kind, "null"] });
+    json!({
+        "type": "object",
+        "required": [
+            "provider", "icon", "name", "language", "description", "url",
+            "stars_today", "stars_total", "last_activity", "topics",
+            "is_starred", "is_cloned", "is_seen",
+            "is_sponsorable", "has_binaries", "has_container", "is_suspicious"
+        ],
+        "properties": {
+            "provider": { "type": "string" },
+            "icon": { "type": "string" },
+            "name": { "type": "string", "description": "owner/repo" },
+            "language": nullable("string"),
+            "description": nullable("string"),
+            "url": { "type": "string", "format": "uri" },
+            "stars_today": nullable("integer"),
+            "stars_total": nullable("integer"),
+            "last_activity": { "type": ["string", "null"], "format": "date-time" },
+            "topics": { "type": "array", "items": { "type": "string" } },
+            "is_starred": { "type": "boolean" },
+            "is_cloned": { "type": "boolean" },
+            "is_seen": { "type": "boolean" },
+            "movement": movement_schema(),
+            "health": health_schema(),
+            "homepage": { "type": "string" },
+            "license": { "type": "string", "description": "SPDX id" },
+            "is_sponsorable": { "type": "boolean" },
+            "has_binaries": { "type": "boolean" },
+            "has_container": { "type": "boolean" },
+            "is_suspicious": { "type": "boolean" }
+        }
+    })
+}
+
+/// `"same"`, `"new"`, `{"up": n}` or `{"down": n}`
+fn movement_schema() -> Value {
+    json!({
+        "description": "Rank change since yesterday's snapshot",
+        "oneOf": [
+            { "enum": ["same", "new"] },
+            {
+                "type": "object",
+                "properties": {
+                    "up": { "type": "integer" },
+                    "down": { "type": "integer" }
+                },
+                "minProperties": 1,
+                "maxProperties": 1,
+                "additionalProperties": false
+            }
+        ]
+    })
+}
+
+fn health_schema() -> Value {
+    let component = json!({
+        "type": "object",
+        "required": ["name", "points", "max", "detail"],
+        "properties": {
+            "name": { "type": "string" },
+            "points": { "type": "integer" },
+            "max": { "type": "integer" },
+            "detail": { "type": "string" }
+        }
+    });
+    json!({
+        "type": "object",
+        "required": ["score", "components"],
+        "properties": {
+            "score": { "type": "integer", "minimum": 0, "maximum": 100 },
+            "components": { "type": "array", "items": component }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::health::Health;
+    use crate::model::{Movement, Repo};
+    use chrono::Utc;
+
+    #[test]
+    fn test_schema_matches_repo_fields() {
+        let repo = Repo {
+            stars_today: Some(1),
+            last_activity: Some(Utc::now()),
+            movement: Some(Movement::Up(2)),
+            health: Some(Health {
+                score: 50,
+                components: vec![],
+            }),
+            homepage: Some("https://example.org".to_string()),
+            license: Some("MIT".to_string()),
+            ..Repo::test("test/repo")
+        };
+        let Value::Object(fields) = serde_json::to_value(&repo).unwrap() else {
+            panic!("repo serializes to an object");
+        };
+        let schema = output_schema();
+        let properties = schema["$defs"]["repo"]["properties"].as_object().unwrap();
+
+        let mut serialized: Vec<&String> = fields.keys().collect();
+        let mut described: Vec<&String> = properties.keys().collect();
+        serialized.sort();
+        described.sort();
+        assert_eq!(
+            serialized, described,
+            "update the schema with the Repo struct"
+        );
+
+        for required in schema["$defs"]["repo"]["required"].as_array().unwrap() {
+            assert!(fields.contains_key(required.as_str().unwrap()));
+        }
+        assert_eq!(fields["movement"], json!({ "up": 2 }));
+    }
+}
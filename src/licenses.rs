@@ -0,0 +1,63 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::config::Config;
+use crate::lookup::{self, RepoCheck};
+use crate::model::Repo;
+use crate::providers::{GitHub, GitLab, Gitea};
+
+/// The license the forge recognizes in a repository
+struct License;
+
+#[async_trait]
+impl RepoCheck for License {
+    type Answer = Option<String>;
+
+    async fn github(
+        &self,
+        github: &GitHub,
+        name: &str,
+        token: Option<&str>,
+    ) -> Result<Option<String>> {
+        github.repo_license(name, token).await
+    }
+
+    async fn gitlab(
+        &self,
+        gitlab: &GitLab,
+        base_url: &str,
+        path: &str,
+        token: Option<&str>,
+    ) -> Result<Option<String>> {
+        gitlab.license(base_url, path, token).await
+    }
+
+    async fn gitea(
+        &self,
+        gitea: &Gitea,
+        base_url: &str,
+        name: &str,
+        token: Option<&str>,
+    ) -> Result<Option<String>> {
+        Ok(gitea
+            .licenses(base_url, name, token)
+            .await?
+            .into_iter()
+            .next())
+    }
+}
+
+/// Look up the license of every repo that doesn't list one yet
+///
+/// One request per repo; failed lookups, and providers without a license API, leave
+/// the license unknown. With `skip_github`, GitHub repos already covered by
+/// [`crate::enrich::github_batch`] are left as they are.
+pub async fn enrich(repos: &mut [Repo], config: &Config, skip_github: bool) {
+    let licenses = lookup::check_each(repos, config, skip_github, &License).await;
+
+    for (repo, license) in repos.iter_mut().zip(licenses) {
+        if repo.license.is_none() {
+            repo.license = license.flatten();
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use futures::future::join_all;
+use serde::Deserialize;
+
+use crate::config::{self, Config};
+use crate::model::Repo;
+use crate::providers::{GitHub, GitLab, Gitea};
+
+/// A file or directory in a repository listing
+#[derive(Debug, Deserialize)]
+pub struct Entry {
+    pub name: String,
+}
+
+/// Names of the entries of a directory listing
+pub fn entry_names(entries: Vec<Entry>) -> Vec<String> {
+    entries.into_iter().map(|e| e.name).collect()
+}
+
+/// The README among the names of a directory listing, e.g. `README.md` or `readme`
+pub fn readme_name(names: &[String]) -> Option<&str> {
+    names.iter().map(String::as_str).find(|name| {
+        name.get(..6)
+            .is_some_and(|s| s.eq_ignore_ascii_case("readme"))
+    })
+}
+
+/// A file from a forge's contents API, base64-encoded
+#[derive(Debug, Deserialize)]
+pub struct FileContent {
+    content: String,
+}
+
+impl FileContent {
+    /// The file's text; GitHub wraps the base64 content in lines
+    pub fn text(&self) -> Result<String> {
+        let encoded: String = self.content.split_whitespace().collect();
+        let bytes = STANDARD
+            .decode(encoded)
+            .context("File content is not valid base64")?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
+
+/// A question about a repository, with one answer per forge API
+#[async_trait]
+pub trait RepoCheck: Sync {
+    /// What the lookup finds; its default is the answer when there is nothing to find
+    type Answer: Default + Send;
+
+    async fn github(
+        &self,
+        github: &GitHub,
+        name: &str,
+        token: Option<&str>,
+    ) -> Result<Self::Answer>;
+
+    async fn gitlab(
+        &self,
+        gitlab: &GitLab,
+        base_url: &str,
+        path: &str,
+        token: Option<&str>,
+    ) -> Result<Self::Answer>;
+
+    async fn gitea(
+        &self,
+        gitea: &Gitea,
+        base_url: &str,
+        name: &str,
+        token: Option<&str>,
+    ) -> Result<Self::Answer>;
+}
+
+/// The provider's token, but only for repos on the instance it was configured for
+///
+/// Repos fetched through an alias (`work = "gitea@https://git.example.com"`) keep the
+/// provider's id while living on another host, which must not see that token.
+fn token(config: &Config, repo: &Repo) -> Option<String> {
+    let provider_cfg = config.provider_cfg(&repo.provider);
+    match provider_cfg.base_url.as_deref() {
+        Some(configured)
+            if !repo
+                .instance_url()
+                .is_some_and(|url| config::same_instance(url, configured)) =>
+        {
+            None
+        }
+        _ => provider_cfg.token,
+    }
+}
+
+/// Answer `check` for every repo, one request per repo
+///
+/// Repos of providers without such an API, and failed lookups, get the default answer.
+/// With `skip_github`, GitHub repos already covered by [`crate::enrich::github_batch`]
+/// answer `None` and are left as they are.
+pub async fn check_each<C: RepoCheck>(
+    repos: &[Repo],
+    config: &Config,
+    skip_github: bool,
+    check: &C,
+) -> Vec<Option<C::Answer>> {
+    let github = GitHub::new(config.general.github_timeout_secs).ok();
+    let gitlab = GitLab::new(config.general.gitlab_timeout_secs).ok();
+    let gitea = Gitea::new(config.general.gitea_timeout_secs).ok();
+
+    let lookups = repos.iter().map(|repo| {
+        let (github, gitlab, gitea) = (github.as_ref(), gitlab.as_ref(), gitea.as_ref());
+        async move {
+            if skip_github && repo.provider == "github" {
+                return None;
+            }
+            let token = token(config, repo);
+            let token = token.as_deref();
+            // Aliases point at other instances than the configured `base_url`
+            let base_url = repo.instance_url().unwrap_or_default();
+            let result = match repo.provider.as_str() {
+                "github" => match github {
+                    Some(github) => check.github(github, &repo.name, token).await,
+                    None => return Some(C::Answer::default()),
+                },
+                "gitlab" => match gitlab {
+                    Some(gitlab) => check.gitlab(gitlab, base_url, &repo.name, token).await,
+                    None => return Some(C::Answer::default()),
+                },
+                "gitea" | "codeberg" => match gitea {
+                    Some(gitea) => check.gitea(gitea, base_url, &repo.name, token).await,
+                    None => return Some(C::Answer::default()),
+                },
+                _ => return Some(C::Answer::default()),
+            };
+            Some(result.unwrap_or_default())
+        }
+    });
+    join_all(lookups).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_only_for_configured_hosts() {
+        let mut config = Config::default();
+        config.auth.github_token = Some("gh".to_string());
+        config.auth.gitlab_token = Some("gl".to_string());
+        config.auth.gitea_token = Some("gt".to_string());
+        config.auth.codeberg_token = Some("cb".to_string());
+        config.gitea.base_url = "https://git.example.com/".to_string();
+
+        let repo = |provider: &str, url: &str| Repo {
+            provider: provider.to_string(),
+            url: format!("{url}/owner/repo"),
+            ..Repo::test("owner/repo")
+        };
+
+        assert_eq!(
+            token(&config, &repo("github", "https://github.com")).as_deref(),
+            Some("gh")
+        );
+        assert_eq!(
+            token(&config, &repo("gitlab", "https://gitlab.com")).as_deref(),
+            Some("gl")
+        );
+        assert_eq!(
+            token(&config, &repo("gitlab", "https://gitlab.evil.test")),
+            None
+        );
+        assert_eq!(
+            token(&config, &repo("gitea", "https://git.example.com")).as_deref(),
+            Some("gt")
+        );
+        assert_eq!(token(&config, &repo("gitea", "https://gitea.com")), None);
+        assert_eq!(
+            token(&config, &repo("codeberg", "https://codeberg.org")).as_deref(),
+            Some("cb")
+        );
+    }
+}
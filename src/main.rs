@@ -1,34 +1,106 @@
 use anyhow::{anyhow, Context, Result};
-use clap::{CommandFactory, Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use clap_complete::{generate, Shell};
 use futures::stream::{FuturesUnordered, StreamExt};
-use std::io;
+use std::collections::{HashMap, HashSet};
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 
+mod activity;
+mod alerts;
+mod anomaly;
+mod archive;
+mod backfill;
+mod bench;
+mod binaries;
+mod blocked;
+mod bookmarks;
 mod cache;
+mod clone;
+mod codestats;
 mod config;
+mod confirm;
+mod containers;
+mod digest;
+mod dryrun;
+mod enrich;
+mod filter;
+mod follow;
+mod health;
+mod history;
+mod hooks;
 mod http;
+mod icons;
+mod init;
+mod instant;
+mod interactive;
+mod jsonschema;
+mod licenses;
+mod lookup;
 mod model;
+mod myrepos;
+mod notes;
+mod nushell;
+mod once;
+mod open;
+mod pager;
+mod powershell;
+mod progress;
 mod providers;
+mod quiet;
+mod rank;
+mod readlater;
+mod reminders;
+mod remote;
 mod render;
+mod repl;
+mod resume;
+mod runlock;
+mod safety;
+mod sample;
+mod schema;
+mod sections;
 mod seen;
+mod snapshot;
+mod spam;
+mod sponsors;
 mod starred;
-
+mod state;
+mod statusbar;
+mod table;
+mod template;
+mod terminal;
+mod theme;
+mod todo;
+mod tui;
+
+use bench::BenchOptions;
 use cache::Cache;
-use config::Config;
-use model::{LanguageFilter, Provider, ProviderCfg};
+use config::{extend_unique, Backfill, ColorChoice, Config, RefreshPolicy, TOPIC_PROVIDERS};
+use history::{LanguageHistory, StarHistory};
+use hooks::HookEvent;
+use instant::OutputCache;
+use model::{LanguageFilter, Provider, Repo};
+use once::{OnceGuard, OncePer};
+use progress::Progress;
 use providers::{GitHub, GitLab, Gitea};
-use render::{render, OutputFormat};
+use quiet::notice;
+use render::{render_to_string, OutputFormat, RenderOptions};
+use resume::ResumeState;
+use runlock::RunLock;
 use seen::SeenTracker;
+use snapshot::SnapshotStore;
 use starred::StarredCache;
+use template::Template;
 
 const PROVIDER_SLOW_WARN_SECS: u64 = 10;
 const PROVIDER_FETCH_TIMEOUT_SECS: u64 = 30;
 
 /// Trending repositories of the day - minimal MOTD CLI
 #[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
+#[command(name = "git-trending", author, version, about, long_about = None)]
 #[allow(clippy::struct_excessive_bools)]
 struct Args {
     #[command(subcommand)]
@@ -38,7 +110,47 @@ struct Args {
     #[arg(short = 'n', long = "max", value_name = "N", global = true)]
     max_per_provider: Option<usize>,
 
-    /// Enable specific providers (comma-separated: gh,gl,ge)
+    /// Maximum GitHub repositories (overrides --max for GitHub)
+    #[arg(long = "max-github", value_name = "N", global = true)]
+    max_github: Option<usize>,
+
+    /// Maximum GitLab repositories (overrides --max for GitLab)
+    #[arg(long = "max-gitlab", value_name = "N", global = true)]
+    max_gitlab: Option<usize>,
+
+    /// Maximum Gitea repositories (overrides --max for Gitea)
+    #[arg(long = "max-gitea", value_name = "N", global = true)]
+    max_gitea: Option<usize>,
+
+    /// Maximum Codeberg repositories (overrides --max for Codeberg)
+    #[arg(long = "max-codeberg", value_name = "N", global = true)]
+    max_codeberg: Option<usize>,
+
+    /// Maximum Bitbucket repositories (overrides --max for Bitbucket)
+    #[arg(long = "max-bitbucket", value_name = "N", global = true)]
+    max_bitbucket: Option<usize>,
+
+    /// Maximum Gitee repositories (overrides --max for Gitee)
+    #[arg(long = "max-gitee", value_name = "N", global = true)]
+    max_gitee: Option<usize>,
+
+    /// Maximum Hugging Face repositories (overrides --max for Hugging Face)
+    #[arg(long = "max-huggingface", value_name = "N", global = true)]
+    max_huggingface: Option<usize>,
+
+    /// Maximum crates.io crates (overrides --max for crates.io)
+    #[arg(long = "max-crates", value_name = "N", global = true)]
+    max_crates: Option<usize>,
+
+    /// Maximum Show HN repositories (overrides --max for Hacker News)
+    #[arg(long = "max-hackernews", value_name = "N", global = true)]
+    max_hackernews: Option<usize>,
+
+    /// Maximum Reddit repositories (overrides --max for Reddit)
+    #[arg(long = "max-reddit", value_name = "N", global = true)]
+    max_reddit: Option<usize>,
+
+    /// Enable specific providers (comma-separated: gh,gl,ge,cb,bb,gt,hf,cr,hn,rd or aliases from config)
     #[arg(short, long, value_name = "LIST", value_delimiter = ',', global = true)]
     provider: Option<Vec<String>>,
 
@@ -46,23 +158,121 @@ struct Args {
     #[arg(short, long, value_name = "LIST", value_delimiter = ',', global = true)]
     lang: Option<Vec<String>>,
 
+    /// Keep repos with an unknown language when filtering by language
+    #[arg(long, global = true)]
+    include_unknown_lang: bool,
+
+    /// Interleave providers into one ranking (weighted by `provider_weights`)
+    #[arg(long, global = true)]
+    merge: bool,
+
+    /// Also match GitHub repos whose secondary language makes up at least PCT percent
+    #[arg(long, value_name = "PCT", global = true)]
+    secondary_lang_percent: Option<f64>,
+
+    /// Show up to N topics per repo as tags
+    #[arg(long, value_name = "N", global = true)]
+    topics: Option<usize>,
+
+    /// Apply a named filter bundle from `[preset.NAME]` (built in: security, selfhosted)
+    #[arg(long, value_name = "NAME", global = true)]
+    preset: Option<String>,
+
+    /// Shorthand for `--preset selfhosted`: open source self-hostable services, with the
+    /// container indicator
+    #[arg(long, global = true, conflicts_with = "preset")]
+    selfhosted: bool,
+
+    /// Only show repos whose latest release ships prebuilt binaries
+    #[arg(long, global = true)]
+    has_binaries: bool,
+
+    /// Only show repos that publish a ghcr.io image or ship a Dockerfile or compose file
+    #[arg(long, global = true)]
+    has_container: bool,
+
+    /// Only show GitHub repos whose owner accepts GitHub Sponsors (needs a GitHub token)
+    #[arg(long, global = true)]
+    sponsorable: bool,
+
     /// Disable cache
     #[arg(long, global = true)]
     no_cache: bool,
 
-    /// Output as JSON instead of MOTD
-    #[arg(long, global = true)]
+    /// Cache in a directory shared by all users: whoever can write it fetches, everyone else reads
+    #[arg(long, global = true, value_name = "DIR", conflicts_with = "no_cache")]
+    system_cache: Option<PathBuf>,
+
+    /// Keep caches and data (seen, starred, bookmarks, ...) under DIR (or `TROTD_STATE_DIR`)
+    #[arg(long, global = true, value_name = "DIR")]
+    state_dir: Option<PathBuf>,
+
+    /// Read and write no state at all, e.g. in read-only containers (or `TROTD_NO_STATE=1`)
+    #[arg(long, global = true, conflicts_with = "state_dir")]
+    no_state: bool,
+
+    /// Output format instead of the MOTD
+    #[arg(long, global = true, value_name = "FORMAT", value_enum)]
+    format: Option<OutputFormat>,
+
+    /// Output as JSON instead of MOTD (same as `--format json`)
+    #[arg(long, global = true, conflicts_with = "format")]
     json: bool,
 
+    /// Screen-reader-friendly output: no color or symbols, one labeled fact per line (same as `--format accessible`)
+    #[arg(long, global = true, conflicts_with_all = ["format", "json"])]
+    accessible: bool,
+
+    /// Render the repos through a minijinja template file instead of the MOTD output
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        conflicts_with_all = ["format", "json", "accessible"]
+    )]
+    template: Option<PathBuf>,
+
+    /// Fit MOTD lines into N columns instead of the terminal's width (0 = cut at the description max length)
+    #[arg(long, global = true, value_name = "N")]
+    width: Option<usize>,
+
+    /// Plain ASCII icons and markers instead of emoji, e.g. for server consoles
+    #[arg(long = "no-emoji", global = true)]
+    no_emoji: bool,
+
+    /// When to color output: auto (a terminal, unless `NO_COLOR` is set), always or never
+    #[arg(long, global = true, value_name = "WHEN")]
+    color: Option<ColorChoice>,
+
+    /// Maximum visible characters for `--format statusbar` and `--format tmux`
+    #[arg(long, global = true, value_name = "N")]
+    max_width: Option<usize>,
+
+    /// Never pipe long output through the pager
+    #[arg(long, global = true)]
+    no_pager: bool,
+
     /// Enable verbose output for debugging
     #[arg(short, long, global = true)]
     verbose: bool,
 
+    /// Suppress warnings and notices, printing only the rendered output
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
     /// Minimum star count threshold
     #[arg(long = "min-stars", value_name = "N", global = true)]
     min_stars: Option<u32>,
 
-    /// Exclude GitHub repositories with these topics (comma-separated)
+    /// Drop repos whose spam score (0-100) reaches N (see `[spam]` in the config)
+    #[arg(long, value_name = "N", global = true)]
+    spam_threshold: Option<u8>,
+
+    /// Maximum star count; hides megaprojects that trend perpetually
+    #[arg(long = "max-stars", value_name = "N", global = true)]
+    max_stars: Option<u32>,
+
+    /// Exclude repositories with these topics on every provider (comma-separated)
     #[arg(
         long = "exclude-topics",
         value_name = "LIST",
@@ -71,9 +281,57 @@ struct Args {
     )]
     exclude_topics: Option<Vec<String>>,
 
-    /// Show all repositories including those already seen today
+    /// Show N repos picked at random from a deeper fetch instead of the top of the list
+    #[arg(long, value_name = "N", global = true)]
+    sample: Option<usize>,
+
+    /// Seed for --sample; the same seed and pool give the same picks
+    #[arg(long, value_name = "S", global = true, requires = "sample")]
+    seed: Option<u64>,
+
+    /// Show all repositories fetched today so far, including those already seen
     #[arg(long = "show-all", global = true)]
     show_all: bool,
+
+    /// Fetch only the providers that failed in the last run, reusing the others' cached results
+    #[arg(long, global = true, conflicts_with = "no_cache")]
+    resume: bool,
+
+    /// Show a project health badge for GitHub repositories (extra API calls per repo)
+    #[arg(long)]
+    health: bool,
+
+    /// Show the health badge plus a per-repo breakdown of the score
+    #[arg(long = "explain-health")]
+    explain_health: bool,
+
+    /// Skip confirmation prompts for commands that change remote state
+    #[arg(short, long, global = true)]
+    yes: bool,
+
+    /// Print the last output immediately and refresh in the background for next time
+    #[arg(long, conflicts_with = "template")]
+    instant: bool,
+
+    /// Print which providers, caches, filters and enrichments a run would use, without fetching
+    #[arg(long = "dry-run", conflicts_with_all = ["instant", "once_per", "interactive"])]
+    dry_run: bool,
+
+    /// Run at most once per window, exiting immediately if already run
+    #[arg(long = "once-per", value_name = "WINDOW", value_enum)]
+    once_per: Option<OncePer>,
+
+    /// With --once-per, print the previous output instead of nothing when skipping
+    #[arg(long = "print-cached", requires = "once_per")]
+    print_cached: bool,
+
+    /// After showing the repos, browse them in a terminal UI to star, clone or open them
+    #[arg(
+        short,
+        long,
+        conflicts_with_all = ["format", "json", "accessible", "template", "instant", "once_per"]
+    )]
+    interactive: bool,
 }
 
 #[derive(Subcommand, Debug)]
@@ -82,44 +340,423 @@ enum Commands {
     Completions {
         /// Shell to generate completions for
         #[arg(value_enum)]
-        shell: Shell,
+        shell: CompletionShell,
+    },
+    /// Print a shell startup snippet showing trending repos once a day
+    Init {
+        /// Shell to print the snippet for
+        #[arg(value_enum)]
+        shell: init::InitShell,
     },
     /// Star a GitHub repository
     Star {
         /// Repository to star (format: owner/repo)
         repo: String,
+        /// Also add the repository to this GitHub Stars list (created if missing)
+        #[arg(long, value_name = "NAME")]
+        list: Option<String>,
+    },
+    /// Unstar a GitHub repository
+    Unstar {
+        /// Repository to unstar (format: owner/repo)
+        repo: String,
+    },
+    /// Open a repository from the last run, or any GitHub repo, in the browser
+    Open {
+        /// Position in the last output (1-based) or repository name (owner/repo)
+        repo: String,
+        /// Open the project homepage instead of the repository
+        #[arg(long)]
+        homepage: bool,
+    },
+    /// Send a repository to the read-later service configured under [readlater]
+    Save {
+        /// Position in the last output (1-based) or repository name (owner/repo)
+        repo: String,
+    },
+    /// Bookmark repositories and sync them into a self-hosted bookmark manager
+    Bookmark {
+        #[command(subcommand)]
+        action: BookmarkAction,
+    },
+    /// Write a Markdown note per bookmarked or starred repo, e.g. into an Obsidian vault
+    ExportNotes {
+        /// Directory to write the notes into
+        #[arg(long, value_name = "DIR")]
+        dir: PathBuf,
+    },
+    /// Create a "try out owner/repo" task in Taskwarrior or a configured todo command
+    Todo {
+        /// Position in the last output (1-based) or repository name (owner/repo)
+        repo: String,
+    },
+    /// Hide a repository, or a whole account, from future runs
+    Block {
+        /// Position in the last output (1-based), owner/repo or owner/*
+        repo: String,
+    },
+    /// Show a repository or account hidden with `block` again
+    Unblock {
+        /// Blocked entry (owner/repo or owner/*)
+        repo: String,
+    },
+    /// Show a repository again in a "Reminders" section after a delay
+    Remind {
+        /// Position in the last output (1-based) or repository name (owner/repo)
+        repo: String,
+        /// Delay such as 3d, 2w, 1m or 1y
+        #[arg(long = "in", value_name = "DELAY")]
+        delay: String,
     },
     /// Clone a trending repository
     Clone {
         /// Repository to clone (format: owner/repo or URL)
         repo: String,
     },
+    /// Show a repository's details with its language breakdown
+    Show {
+        /// Position in the last output (1-based), repository name (owner/repo) or URL
+        repo: String,
+        /// Also count lines of code with tokei over a downloaded archive
+        #[arg(long)]
+        loc: bool,
+    },
+    /// Download and unpack the default branch without a git clone
+    FetchArchive {
+        /// Position in the last output (1-based), repository name (owner/repo) or URL
+        repo: String,
+        /// Directory to unpack into (default: where `clone` would put it)
+        #[arg(long, value_name = "DIR")]
+        dir: Option<PathBuf>,
+    },
+    /// Run repeated fetch/render cycles and report latency percentiles per stage
+    Bench {
+        /// Number of cycles to run
+        #[arg(short, long, value_name = "N", default_value_t = 10)]
+        iterations: usize,
+
+        /// Read provider results from recorded fixtures instead of the network
+        #[arg(long, value_name = "DIR")]
+        fixtures: Option<PathBuf>,
+
+        /// Record the first cycle's provider results as fixtures
+        #[arg(long, value_name = "DIR", conflicts_with = "fixtures")]
+        record: Option<PathBuf>,
+    },
+    /// Write today's repos as a dated digest plus an index, e.g. for a static site
+    Digest {
+        /// Directory to write the digest and index into
+        #[arg(long, value_name = "DIR")]
+        publish: PathBuf,
+        /// Write HTML pages instead of Markdown with front matter
+        #[arg(long)]
+        html: bool,
+    },
+    /// Show the next batch of today's trending repos, continuing where the last run stopped
+    More {
+        /// Repositories per provider (default: `max_per_provider`)
+        count: Option<usize>,
+    },
+    /// Follow topics to see the top new repo of each one every day
+    FollowTopic {
+        #[command(subcommand)]
+        action: FollowTopicAction,
+    },
+    /// Manage the repos already shown today
+    Seen {
+        #[command(subcommand)]
+        action: SeenAction,
+    },
+    /// Print a short snippet of the hottest repo for shell prompts, from cache only
+    Prompt,
+    /// Fetch once, then open, star, page and filter the results at a prompt
+    Repl,
+    /// Print the JSON Schema of the `--json` output
+    Schema,
+    /// Print the repository names of the last run, one per line (used by completions)
+    #[command(name = "complete-repos", hide = true)]
+    CompleteRepos,
+}
+
+#[derive(Subcommand, Debug)]
+enum FollowTopicAction {
+    /// Follow a topic (e.g. `rust`, `self-hosted`)
+    Add { topic: String },
+    /// List followed topics
+    List,
+    /// Stop following a topic
+    Remove { topic: String },
+}
+
+#[derive(Subcommand, Debug)]
+enum SeenAction {
+    /// Show the last batch of repos again on the next run and roll back the fetch offset
+    Undo,
+}
+
+#[derive(Subcommand, Debug)]
+enum BookmarkAction {
+    /// Bookmark a repository by position in the last output or owner/repo
+    Add { repo: String },
+    /// List bookmarks
+    List,
+    /// Remove a bookmark (format: owner/repo)
+    Remove { repo: String },
+    /// Send bookmarks not yet pushed to the read-later service configured under [readlater]
+    Push,
+}
+
+/// Shells `completions` can generate scripts for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum CompletionShell {
+    Bash,
+    Elvish,
+    Fish,
+    #[value(name = "powershell")]
+    PowerShell,
+    Zsh,
+    Nushell,
+}
+
+impl CompletionShell {
+    /// The `clap_complete` generator, or `None` for Nushell which trotd generates itself
+    fn clap_shell(self) -> Option<Shell> {
+        match self {
+            Self::Bash => Some(Shell::Bash),
+            Self::Elvish => Some(Shell::Elvish),
+            Self::Fish => Some(Shell::Fish),
+            Self::PowerShell => Some(Shell::PowerShell),
+            Self::Zsh => Some(Shell::Zsh),
+            Self::Nushell => None,
+        }
+    }
 }
 
 #[tokio::main]
 #[allow(clippy::too_many_lines)]
 async fn main() -> Result<()> {
     let args = Args::parse();
+    quiet::set(args.quiet);
+    state::set_location(state::Location::resolve(
+        args.state_dir.clone(),
+        args.no_state,
+    ));
+    terminal::init(args.color.unwrap_or_default());
 
     // Handle subcommands
-    if let Some(command) = args.command {
+    if let Some(ref command) = args.command {
         match command {
             Commands::Completions { shell } => {
                 let mut cmd = Args::command();
                 let bin_name = cmd.get_name().to_string();
-                generate(shell, &mut cmd, bin_name, &mut io::stdout());
+                match shell {
+                    CompletionShell::Nushell => print!("{}", nushell::completions(&mut cmd)),
+                    CompletionShell::PowerShell => {
+                        let mut script = Vec::new();
+                        generate(Shell::PowerShell, &mut cmd, &bin_name, &mut script);
+                        let script = String::from_utf8_lossy(&script);
+                        print!("{}", powershell::with_repo_completion(&script, &bin_name));
+                    }
+                    _ => {
+                        let shell = shell.clap_shell().context("No generator for shell")?;
+                        generate(shell, &mut cmd, bin_name, &mut io::stdout());
+                    }
+                }
+                return Ok(());
+            }
+            // Run the normal fetch pipeline: digests publish instead of printing, and
+            // `more` continues from today's fetch offset
+            Commands::Digest { .. } | Commands::More { .. } => {}
+            Commands::FollowTopic { action } => {
+                return handle_follow_topic_command(action).await;
+            }
+            Commands::Seen { action } => {
+                return handle_seen_command(action).await;
+            }
+            Commands::Schema => {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&jsonschema::output_schema())?
+                );
+                return Ok(());
+            }
+            Commands::Prompt => {
+                // Prompts run on every keystroke-return: never touch the network or config,
+                // and print nothing rather than an error without state (--no-state)
+                if let Ok(last_shown) = open::LastShown::new() {
+                    let repos = last_shown.load().await.unwrap_or_default();
+                    let snippet = statusbar::render_prompt(&repos, args.max_width);
+                    if !snippet.is_empty() {
+                        println!("{snippet}");
+                    }
+                }
+                return Ok(());
+            }
+            Commands::CompleteRepos => {
+                // Completion must never fail loudly; no last run simply means no names
+                if let Ok(last_shown) = open::LastShown::new() {
+                    for repo in last_shown.load().await.unwrap_or_default() {
+                        println!("{}", repo.name);
+                    }
+                }
+                return Ok(());
+            }
+            Commands::Init { shell } => {
+                print!("{}", init::snippet(*shell));
                 return Ok(());
             }
-            Commands::Star { repo } => {
-                return handle_star_command(&repo).await;
+            Commands::Star { repo, list } => {
+                return handle_star_command(repo, list.as_deref(), args.yes).await;
+            }
+            Commands::Unstar { repo } => {
+                return handle_unstar_command(repo, args.yes).await;
+            }
+            Commands::Open { repo, homepage } => {
+                return handle_open_command(repo, *homepage).await;
+            }
+            Commands::Save { repo } => {
+                return handle_save_command(repo).await;
+            }
+            Commands::Bookmark { action } => {
+                return handle_bookmark_command(action).await;
+            }
+            Commands::ExportNotes { dir } => {
+                return handle_export_notes_command(dir).await;
+            }
+            Commands::Todo { repo } => {
+                return handle_todo_command(repo).await;
+            }
+            Commands::Block { repo } => {
+                return handle_block_command(repo).await;
+            }
+            Commands::Unblock { repo } => {
+                return handle_unblock_command(repo).await;
+            }
+            Commands::Remind { repo, delay } => {
+                return handle_remind_command(repo, delay).await;
             }
             Commands::Clone { repo } => {
-                return handle_clone_command(&repo);
+                return handle_clone_command(repo, args.yes).await;
+            }
+            Commands::Show { repo, loc } => {
+                return handle_show_command(repo, *loc).await;
+            }
+            Commands::FetchArchive { repo, dir } => {
+                return handle_fetch_archive_command(repo, dir.as_deref()).await;
+            }
+            Commands::Repl => {
+                return handle_repl_command(&args).await;
+            }
+            Commands::Bench {
+                iterations,
+                fixtures,
+                record,
+            } => {
+                let mut config = Config::load().context("Failed to load configuration")?;
+                let preset_providers = apply_preset(&args, &mut config)?;
+                apply_cli_overrides(&args, &mut config);
+                terminal::set_colors(config.display.color);
+                let provider_ids = resolve_providers(
+                    args.provider.as_deref().or(preset_providers.as_deref()),
+                    &config,
+                );
+                let opts = BenchOptions {
+                    iterations: *iterations,
+                    fixtures: fixtures.clone(),
+                    record: record.clone(),
+                    format: output_format(&args),
+                };
+                return bench::run(&config, &provider_ids, opts).await;
             }
         }
     }
 
     let verbose = args.verbose;
+    // An explicit request for the next batch never replays an earlier output
+    let more = matches!(args.command, Some(Commands::More { .. }));
+
+    // Skip all work if we already ran in the requested window
+    let once_guard = match args.once_per {
+        Some(per) if !more => {
+            let guard = OnceGuard::new()?;
+            if let Some(output) = guard.previous_output(per).await {
+                if verbose {
+                    eprintln!("🔁 Already ran this {per:?}, skipping");
+                }
+                if args.print_cached {
+                    print!("{output}");
+                }
+                return Ok(());
+            }
+            Some((guard, per))
+        }
+        _ => None,
+    };
+
+    // A later batch must not replace the output --instant prints
+    let output_cache = if more { None } else { OutputCache::new().ok() };
+
+    // Print the previous output right away and let a background run refresh it
+    if args.instant && !more {
+        let previous = match output_cache {
+            Some(ref cache) => cache.get(output_format(&args)).await,
+            None => None,
+        };
+        if let Some(output) = previous {
+            print!("{output}");
+            if let Err(e) = instant::spawn_refresh() {
+                if verbose {
+                    eprintln!("⚠ {e}");
+                }
+            }
+            return Ok(());
+        }
+        if verbose {
+            eprintln!("⚡ No previous output yet, running normally");
+        }
+    }
+
+    // Serialize with concurrent runs so they don't each advance the fetch offset
+    let invocation = runlock::invocation_args();
+    let run_lock = if args.no_cache || args.dry_run {
+        None
+    } else {
+        RunLock::new().ok()
+    };
+    let mut _run_guard = None;
+    if let Some(ref lock) = run_lock {
+        if let Some(guard) = lock.acquire().await {
+            // Waited for an identical run: print what it just printed
+            let reusable = match guard.waited_since() {
+                Some(since) if !more => lock.output_since(&invocation, since).await,
+                _ => None,
+            };
+            if let Some(output) = reusable {
+                if verbose {
+                    eprintln!("🔒 Reusing output of a concurrent run");
+                }
+                print!("{output}");
+                return Ok(());
+            }
+            _run_guard = Some(guard);
+        } else {
+            // Still running after a short wait: show the previous output like --instant
+            let previous = match output_cache {
+                Some(ref cache) => cache.get(output_format(&args)).await,
+                None => None,
+            };
+            if let Some(output) = previous {
+                if verbose {
+                    eprintln!("🔒 Another run is still in progress, showing the previous output");
+                }
+                print!("{output}");
+                return Ok(());
+            }
+            if verbose {
+                eprintln!("⚠ Another run is still in progress, fetching anyway");
+            }
+        }
+    }
 
     // Load configuration
     let mut config = Config::load().context("Failed to load configuration")?;
@@ -128,109 +765,114 @@ async fn main() -> Result<()> {
         eprintln!("📋 Config loaded successfully");
     }
 
-    // Apply CLI overrides
-    if let Some(max) = args.max_per_provider {
-        config.general.max_per_provider = max;
+    // Shared team rules are merged before presets, which they may define
+    if let Err(e) = remote::apply(&mut config, args.dry_run).await {
+        notice!("⚠ Failed to load remote rules: {e:#}");
     }
 
-    if let Some(langs) = args.lang {
-        config.general.language_filter = langs;
+    // Repos hidden with `block` join the configured blocklist
+    match blocked::BlockedStore::new() {
+        Ok(store) => match store.list().await {
+            Ok(blocked) => extend_unique(&mut config.general.blocklist, &blocked),
+            Err(e) => notice!("⚠ Failed to load blocked repos: {e:#}"),
+        },
+        Err(e) => notice!("⚠ Failed to load blocked repos: {e:#}"),
     }
 
-    if let Some(min) = args.min_stars {
-        config.general.min_stars = Some(min);
-    }
+    // Presets go first so explicit flags still override them
+    let preset_providers = apply_preset(&args, &mut config)?;
+    apply_cli_overrides(&args, &mut config);
+    terminal::set_colors(config.display.color);
 
-    if let Some(topics) = args.exclude_topics {
-        config.github.exclude_topics = topics;
+    // Without Sponsors data the filter would silently drop every repo
+    if args.sponsorable && config.auth.github_token.is_none() {
+        anyhow::bail!(
+            "--sponsorable needs a GitHub token: set github_token under [auth] or TROTD_GITHUB_TOKEN"
+        );
     }
 
-    // Determine output format
-    let format = if args.json {
-        OutputFormat::Json
+    // A template (from --template or the config) takes the place of the MOTD output
+    let template = match (config.general.template_path(), output_format(&args)) {
+        (Some(path), OutputFormat::Motd | OutputFormat::Template) => Some(Template::load(&path)?),
+        (None, OutputFormat::Template) => anyhow::bail!(
+            "--format template needs a template: pass --template PATH or set template under [general]"
+        ),
+        _ => None,
+    };
+    let format = if template.is_some() {
+        OutputFormat::Template
     } else {
-        OutputFormat::Motd
+        output_format(&args)
     };
 
-    // Initialize cache
-    let cache = if args.no_cache {
+    // Initialize cache, with the TTL the refresh schedule sets for this time of day
+    let mut refresh = config.general.refresh_policy(chrono::Local::now().time());
+    let cache = if args.no_cache || (state::disabled() && args.system_cache.is_none()) {
         if verbose {
             eprintln!("🚫 Cache disabled");
         }
         None
     } else {
-        let c = Cache::new(config.general.cache_ttl_mins).context("Failed to initialize cache")?;
-        if verbose {
+        let c = match args.system_cache {
+            Some(ref dir) => Cache::system(dir.clone(), refresh.ttl_mins),
+            None => Cache::new(refresh.ttl_mins).context("Failed to initialize cache")?,
+        };
+        // Users who can't write the shared cache read what its owner fetched
+        if c.is_read_only() {
+            refresh = RefreshPolicy::CACHE_ONLY;
+        }
+        if let (true, Some(dir)) = (verbose && c.is_read_only(), c.system_dir()) {
             eprintln!(
-                "💾 Cache initialized (TTL: {} mins)",
-                config.general.cache_ttl_mins
+                "💾 Shared cache {} is read-only, serving its results",
+                dir.display()
             );
+        } else if verbose && refresh.cache_only {
+            eprintln!("💾 Cache initialized (cache-only per refresh_schedule)");
+        } else if verbose {
+            eprintln!("💾 Cache initialized (TTL: {} mins)", refresh.ttl_mins);
         }
         Some(c)
     };
 
     // Determine enabled providers
-    let enabled_providers = if let Some(ref providers) = args.provider {
-        // Parse short names: gh -> github, gl -> gitlab, ge -> gitea
-        providers
-            .iter()
-            .map(|p| match p.as_str() {
-                "gh" => "github",
-                "gl" => "gitlab",
-                "ge" => "gitea",
-                _ => p.as_str(),
-            })
-            .collect::<Vec<_>>()
-    } else {
-        config.enabled_providers()
-    };
+    let enabled_providers = resolve_providers(
+        args.provider.as_deref().or(preset_providers.as_deref()),
+        &config,
+    );
 
     if verbose {
         eprintln!("🔌 Enabled providers: {enabled_providers:?}");
     }
 
+    // --has-binaries, --has-container and the license filter drop repos after fetching:
+    // fetch deeper, then cut back to the limits
+    let post_filter =
+        args.has_binaries || args.has_container || !config.general.license_filter.is_empty();
+    let limits: Option<HashMap<String, usize>> = post_filter.then(|| {
+        enabled_providers
+            .iter()
+            .map(|id| (id.clone(), config.get_max_entries(id)))
+            .collect()
+    });
+    if post_filter && args.sample.is_none() {
+        sample::deepen(&mut config, 0);
+    }
+
     // Build provider instances
     let mut provider_instances: Vec<(String, Box<dyn Provider>)> = Vec::new();
 
-    for provider_id in enabled_providers {
-        match provider_id {
-            "github" => match GitHub::new(config.general.github_timeout_secs) {
-                Ok(gh) => {
-                    if verbose {
-                        eprintln!(
-                            "  ✓ GitHub provider initialized (timeout: {}s)",
-                            config.general.github_timeout_secs
-                        );
-                    }
-                    provider_instances.push(("github".to_string(), Box::new(gh)));
-                }
-                Err(e) => eprintln!("✗ Failed to initialize GitHub provider: {e}"),
-            },
-            "gitlab" => match GitLab::new(config.general.gitlab_timeout_secs) {
-                Ok(gl) => {
-                    if verbose {
-                        eprintln!(
-                            "  ✓ GitLab provider initialized (timeout: {}s)",
-                            config.general.gitlab_timeout_secs
-                        );
-                    }
-                    provider_instances.push(("gitlab".to_string(), Box::new(gl)));
-                }
-                Err(e) => eprintln!("✗ Failed to initialize GitLab provider: {e}"),
-            },
-            "gitea" => match Gitea::new(config.general.gitea_timeout_secs) {
-                Ok(ge) => {
-                    if verbose {
-                        eprintln!(
-                            "  ✓ Gitea provider initialized (timeout: {}s)",
-                            config.general.gitea_timeout_secs
-                        );
-                    }
-                    provider_instances.push(("gitea".to_string(), Box::new(ge)));
+    for provider_id in &enabled_providers {
+        match providers::build(provider_id, &config) {
+            Ok(provider) => {
+                if verbose {
+                    eprintln!(
+                        "  ✓ {provider_id} provider initialized (timeout: {}s)",
+                        config.get_timeout_secs(provider_id)
+                    );
                 }
-                Err(e) => eprintln!("✗ Failed to initialize Gitea provider: {e}"),
-            },
-            _ => eprintln!("⚠ Unknown provider: {provider_id}"),
+                provider_instances.push((provider_id.clone(), provider));
+            }
+            Err(e) => notice!("✗ Failed to initialize {provider_id} provider: {e}"),
         }
     }
 
@@ -239,7 +881,9 @@ async fn main() -> Result<()> {
     }
 
     // Create language filter
-    let lang_filter = LanguageFilter::new(config.general.language_filter.clone());
+    let lang_filter = LanguageFilter::new(config.general.language_filter.clone())
+        .with_unknown(config.general.include_unknown_lang)
+        .with_secondary(config.general.secondary_language_percent);
 
     if config.general.language_filter.is_empty() {
         if verbose {
@@ -253,23 +897,50 @@ async fn main() -> Result<()> {
         eprintln!("🚀 Fetching repositories...");
     }
 
-    // Initialize seen tracker unless --show-all is set
-    let seen_tracker = if args.show_all {
-        None
-    } else {
-        match SeenTracker::new() {
-            Ok(tracker) => Some(tracker),
-            Err(e) => {
-                if verbose {
-                    eprintln!("⚠ Failed to initialize seen tracker: {e}");
-                }
-                None
+    // Initialize the daily tracker; seen filtering is skipped with --show-all
+    let day_tracker = match SeenTracker::new() {
+        Ok(tracker) => Some(tracker),
+        Err(e) => {
+            if verbose {
+                eprintln!("⚠ Failed to initialize seen tracker: {e}");
             }
+            None
         }
     };
+    // Digests list the whole day and must not advance the MOTD's seen state
+    let digest = match args.command {
+        Some(Commands::Digest { ref publish, html }) => Some((publish.clone(), html)),
+        _ => None,
+    };
+    // The owner of a --system-cache fetches the first page for everyone
+    let shared_writer = cache
+        .as_ref()
+        .is_some_and(|c| c.system_dir().is_some() && !c.is_read_only());
+    // Status bars poll all day; marking seen, paging on and firing hooks is left to the MOTD
+    let seen_tracker =
+        if args.show_all || digest.is_some() || shared_writer || format.is_status_bar() {
+            None
+        } else {
+            day_tracker.as_ref()
+        };
+
+    // With --show-all, replay everything fetched today instead of refetching
+    let fetch_key = seen::fetch_key(&config);
+    let mut fetched_today: HashMap<String, Vec<Repo>> = HashMap::new();
+    if args.show_all && !args.no_cache {
+        if let Some(tracker) = &day_tracker {
+            for repo in tracker.fetched_today(&fetch_key).await {
+                fetched_today
+                    .entry(instance_key(&config, &enabled_providers, &repo))
+                    .or_default()
+                    .push(repo);
+            }
+        }
+    }
+    let fetched_today = Arc::new(fetched_today);
 
     // Get fetch offset for pagination
-    let fetch_offset = if let Some(tracker) = &seen_tracker {
+    let fetch_offset = if let Some(tracker) = seen_tracker {
         tracker.get_fetch_offset().await
     } else {
         0
@@ -279,12 +950,88 @@ async fn main() -> Result<()> {
         eprintln!("📖 Starting from position {fetch_offset} in trending list");
     }
 
-    let prefer_cache_first = seen_tracker.is_none();
+    // Cache-only windows take cached pages even when the seen filter wants fresh ones
+    let prefer_cache_first = seen_tracker.is_none() || refresh.cache_only;
+
+    // With --resume, only the providers that failed last time go to the network
+    let resume_state = ResumeState::new().ok();
+    let resumed = match resume_state {
+        Some(ref state) if args.resume => state.pending().await,
+        _ => None,
+    };
+    if args.resume {
+        match resumed {
+            Some(ref run) if verbose => {
+                eprintln!("↻ Resuming: fetching {}", run.failed.join(", "));
+            }
+            Some(_) => {}
+            None => notice!("↻ Nothing to resume, fetching every provider"),
+        }
+    }
+
+    let plan = enrich::Plan {
+        sponsors: args.sponsorable || config.display.show_sponsors,
+        binaries: args.has_binaries || config.display.show_binaries,
+        containers: args.has_container || config.display.show_containers,
+        health: args.health || args.explain_health,
+        starred: config.general.show_starred_status,
+        suspicious: config.display.show_suspicious,
+        homepage: config.display.show_homepage,
+        license: !config.general.license_filter.is_empty(),
+    };
+
+    if args.dry_run {
+        let mut providers = Vec::new();
+        for (id, _) in &provider_instances {
+            let prefer_cached = resumed
+                .as_ref()
+                .map_or(prefer_cache_first, |run| !run.needs(id));
+            let cached = match cache {
+                Some(ref cache) => cache.get(id).await.map(|repos| repos.len()),
+                None => None,
+            };
+            let source = match (fetched_today.get(id), cached) {
+                (Some(repos), _) => dryrun::Source::TodaySoFar(repos.len()),
+                (None, Some(count)) if prefer_cached => dryrun::Source::Cache(count),
+                (None, cached) => dryrun::Source::Network {
+                    fallback: cached.is_some(),
+                },
+            };
+            providers.push(dryrun::ProviderPlan {
+                id: id.clone(),
+                source,
+            });
+        }
+        let fetch_plan = dryrun::FetchPlan {
+            config: &config,
+            providers,
+            offset: fetch_offset,
+            seen_filter: seen_tracker.is_some(),
+            cache: cache.as_ref().map(|_| refresh),
+            system_cache: cache
+                .as_ref()
+                .and_then(|c| Some((c.system_dir()?, c.is_read_only()))),
+            enrichments: plan,
+        };
+        print!("{}", fetch_plan.describe());
+        return Ok(());
+    }
 
     // Fetch repositories in parallel
     let cache_arc = Arc::new(cache);
     let mut futures = FuturesUnordered::new();
 
+    // Verbose output is line-based and would interleave with the spinner; screen
+    // readers would announce every spinner frame
+    let mut progress = Progress::new(
+        provider_instances
+            .iter()
+            .map(|(id, _)| id.clone())
+            .collect(),
+        !verbose && format != OutputFormat::Accessible && progress::available(),
+    );
+    let show_slow_notice = !progress.is_enabled();
+
     for (provider_id, provider) in provider_instances {
         let cache_prefetch = Arc::clone(&cache_arc);
         let cache_fetch = Arc::clone(&cache_arc);
@@ -295,9 +1042,20 @@ async fn main() -> Result<()> {
         let offset_clone = fetch_offset;
         let provider_name = provider_id.clone();
         let provider_key = provider_id.clone();
-        let prefer_cached = prefer_cache_first;
+        let prefer_cached = resumed
+            .as_ref()
+            .map_or(prefer_cache_first, |run| !run.needs(&provider_id));
+        let done_name = provider_id.clone();
+        let today_so_far = Arc::clone(&fetched_today);
 
         let future = async move {
+            if let Some(repos) = today_so_far.get(&provider_key) {
+                if verbose_clone {
+                    eprintln!("  📚 {provider_key} (today so far)");
+                }
+                return Ok((provider_key, repos.clone()));
+            }
+
             if prefer_cached {
                 if let Some(ref cache) = *cache_prefetch {
                     if let Some(cached_repos) = cache.get(&provider_key).await {
@@ -310,25 +1068,7 @@ async fn main() -> Result<()> {
             }
 
             let fetch_future = async move {
-                let provider_cfg = ProviderCfg {
-                    timeout_secs: config_clone.general.timeout_secs,
-                    token: match provider_id.as_str() {
-                        "github" => config_clone.auth.github_token.clone(),
-                        "gitlab" => config_clone.auth.gitlab_token.clone(),
-                        "gitea" => config_clone.auth.gitea_token.clone(),
-                        _ => None,
-                    },
-                    base_url: if provider_id == "gitea" {
-                        Some(config_clone.gitea.base_url.clone())
-                    } else {
-                        None
-                    },
-                    exclude_topics: if provider_id == "github" {
-                        config_clone.github.exclude_topics.clone()
-                    } else {
-                        vec![]
-                    },
-                };
+                let provider_cfg = config_clone.provider_cfg(&provider_id);
 
                 let repos = provider
                     .top_today(
@@ -357,10 +1097,12 @@ async fn main() -> Result<()> {
                         result = &mut fetch_future => break result,
                         () = &mut slow_notice, if !warned => {
                             warned = true;
-                            eprintln!("⏳ Still fetching {provider_name}...");
+                            if show_slow_notice {
+                                notice!("⏳ Still fetching {provider_name}...");
+                            }
                             if let Some(ref cache) = *cache_fallback {
                                 if let Some(cached_repos) = cache.get(&provider_key).await {
-                                    eprintln!("⚠ Using cached {provider_name} results while network call finishes...");
+                                    notice!("⚠ Using cached {provider_name} results while network call finishes...");
                                     return Ok((provider_key.clone(), cached_repos));
                                 }
                             }
@@ -379,15 +1121,33 @@ async fn main() -> Result<()> {
             }
         };
 
-        futures.push(future);
+        futures.push(async move { (done_name, future.await) });
     }
 
     // Collect results
     let mut all_repos = Vec::new();
     let mut errors = Vec::new();
+    let mut failed_providers = Vec::new();
     let mut no_new_repos = false;
 
-    while let Some(result) = futures.next().await {
+    let mut ticker = tokio::time::interval(progress::TICK);
+    loop {
+        let next = if progress.is_enabled() {
+            tokio::select! {
+                next = futures.next() => next,
+                _ = ticker.tick() => {
+                    progress.tick();
+                    continue;
+                }
+            }
+        } else {
+            futures.next().await
+        };
+        let Some((finished, result)) = next else {
+            break;
+        };
+        progress.finish(&finished);
+
         match result {
             Ok((provider_id, repos)) => {
                 if verbose {
@@ -395,8 +1155,8 @@ async fn main() -> Result<()> {
                 }
                 if !repos.is_empty() {
                     all_repos.extend(repos);
-                } else if format!("{format:?}") == "Motd" {
-                    eprintln!("⚠ No repositories found for {provider_id}");
+                } else if format == OutputFormat::Motd {
+                    notice!("⚠ No repositories found for {provider_id}");
                 }
             }
             Err(e) => {
@@ -404,14 +1164,17 @@ async fn main() -> Result<()> {
                     eprintln!("  ✗ Provider error: {e}");
                 }
                 errors.push(e);
+                failed_providers.push(finished);
             }
         }
     }
 
+    progress.clear();
+
     // Handle errors
     if !errors.is_empty() {
         for error in &errors {
-            eprintln!("✗ Error: {error}");
+            notice!("✗ Error: {error}");
         }
     }
 
@@ -419,106 +1182,440 @@ async fn main() -> Result<()> {
         anyhow::bail!("All providers failed");
     }
 
-    // Filter out previously seen repos when tracking is enabled
-    if let Some(tracker) = &seen_tracker {
-        let before_count = all_repos.len();
-        match tracker.filter_unseen(&all_repos).await {
-            Ok(filtered) => {
-                let removed = before_count.saturating_sub(filtered.len());
-                if verbose && removed > 0 {
-                    eprintln!("👀 Seen filter: skipped {removed} repos shown earlier today");
-                }
-                if filtered.is_empty() && before_count > 0 {
-                    no_new_repos = true;
-                }
-                all_repos = filtered;
-            }
-            Err(e) => {
-                if verbose {
-                    eprintln!("⚠ Failed to filter seen repos: {e}");
-                }
+    // Remember a partial failure so --resume can fetch just the missing providers
+    if let (Some(state), Some(_)) = (&resume_state, cache_arc.as_ref()) {
+        let result = if failed_providers.is_empty() {
+            state.clear().await
+        } else {
+            notice!(
+                "↻ Run with --resume to fetch only {}",
+                failed_providers.join(", ")
+            );
+            state.record(failed_providers).await
+        };
+        if let Err(e) = result {
+            if verbose {
+                eprintln!("⚠ Failed to update resume state: {e}");
+            }
+        }
+    }
+
+    // Keep the cumulative list of everything fetched today for --show-all
+    if let Some(tracker) = &day_tracker {
+        if let Err(e) = tracker.record_fetched(&fetch_key, &all_repos).await {
+            if verbose {
+                eprintln!("⚠ Failed to record fetched repos: {e}");
+            }
+        }
+    }
+
+    if let Err(e) = hooks::run_for_repos(&config.hooks, HookEvent::PostFetch, &all_repos) {
+        notice!("⚠ {e}");
+    }
+
+    // Compare provider ranks with yesterday's snapshot before anything is filtered out
+    match SnapshotStore::new() {
+        Ok(store) => {
+            if let Err(e) = store.annotate(&mut all_repos, fetch_offset).await {
+                if verbose {
+                    eprintln!("⚠ Failed to update rank snapshot: {e}");
+                }
+            }
+        }
+        Err(e) => {
+            if verbose {
+                eprintln!("⚠ Failed to initialize rank snapshot: {e}");
+            }
+        }
+    }
+
+    // Daily star totals for the sparklines
+    let star_history = match StarHistory::new() {
+        Ok(history) => history.record(&all_repos).await,
+        Err(e) => Err(e),
+    }
+    .unwrap_or_else(|e| {
+        if verbose {
+            eprintln!("⚠ Failed to update star history: {e}");
+        }
+        HashMap::new()
+    });
+
+    // Trending slots per language for the languages section
+    let recorded = match LanguageHistory::new() {
+        Ok(history) => history.record(&all_repos).await,
+        Err(e) => Err(e),
+    };
+    if let Err(e) = recorded {
+        if verbose {
+            eprintln!("⚠ Failed to update language history: {e}");
+        }
+    }
+
+    // Interleave providers into one weighted ranking instead of provider blocks
+    if config.general.merge_providers {
+        rank::merge(&mut all_repos, &config.general.provider_weights);
+    }
+
+    // With --show-all, keep repeats but mark them instead of hiding them
+    if args.show_all {
+        if let Some(tracker) = &day_tracker {
+            let seen = tracker.get_seen().await.unwrap_or_default();
+            for repo in &mut all_repos {
+                repo.is_seen = seen.contains(&repo.name);
+            }
+        }
+    }
+
+    // Filter out previously seen repos when tracking is enabled
+    if let Some(tracker) = seen_tracker {
+        let before_count = all_repos.len();
+        match tracker.filter_unseen(&all_repos).await {
+            Ok(filtered) => {
+                let removed = before_count.saturating_sub(filtered.len());
+                if verbose && removed > 0 {
+                    eprintln!("👀 Seen filter: skipped {removed} repos shown earlier today");
+                }
+                if filtered.is_empty() && before_count > 0 {
+                    no_new_repos = true;
+                }
+                all_repos = filtered;
+            }
+            Err(e) => {
+                if verbose {
+                    eprintln!("⚠ Failed to filter seen repos: {e}");
+                }
+            }
+        }
+    }
+
+    // Once the day's list is used up, fall back to this week's trending repos
+    let mut backfilled = false;
+    if let Some(tracker) = seen_tracker {
+        if all_repos.is_empty()
+            && config.general.backfill == Backfill::Weekly
+            && enabled_providers.iter().any(|p| p == "github")
+        {
+            match backfill::weekly(&config, &lang_filter, tracker).await {
+                Ok(weekly) if !weekly.is_empty() => {
+                    if verbose {
+                        eprintln!("📅 Backfilled {} repos from the weekly list", weekly.len());
+                    }
+                    all_repos = weekly;
+                    backfilled = true;
+                    no_new_repos = false;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    if verbose {
+                        eprintln!("⚠ Failed to backfill from the weekly list: {e}");
+                    }
+                }
             }
         }
     }
 
     // Apply ASCII-only filter if enabled
     if config.general.ascii_only {
-        let before_count = all_repos.len();
-        all_repos.retain(is_mostly_ascii);
+        let filtered_count = filter::ascii_only(&mut all_repos);
         if verbose {
-            let filtered_count = before_count - all_repos.len();
             eprintln!("🔤 ASCII filter: removed {filtered_count} non-ASCII repos");
         }
     }
 
     // Apply minimum star filter if configured
     if let Some(min_stars) = config.general.min_stars {
-        let before_count = all_repos.len();
-        all_repos.retain(|repo| repo.stars_total.unwrap_or(0) >= min_stars.into());
+        let filtered_count = filter::min_stars(&mut all_repos, min_stars);
         if verbose {
-            let filtered_count = before_count - all_repos.len();
             eprintln!("⭐ Star filter: removed {filtered_count} repos below {min_stars} stars");
         }
     }
 
+    // Apply maximum star filter if configured
+    if let Some(max_stars) = config.general.max_stars {
+        let filtered_count = filter::max_stars(&mut all_repos, max_stars);
+        if verbose {
+            eprintln!("⭐ Star filter: removed {filtered_count} repos above {max_stars} stars");
+        }
+    }
+
+    if !config.general.blocklist.is_empty() {
+        let filtered_count = filter::blocklist(&mut all_repos, &config.general.blocklist);
+        if verbose {
+            eprintln!("⛔ Blocklist: removed {filtered_count} repos");
+        }
+    }
+
+    // Drop or demote likely growth-hacked repos
+    let link_only_readmes = spam::link_only_readmes(&all_repos, &config).await;
+    let flagged = spam::apply(&mut all_repos, &config.spam, &link_only_readmes);
+    if verbose && flagged > 0 {
+        eprintln!("🚫 Spam filter: {:?} {flagged} repos", config.spam.action);
+    }
+
+    // Pick the sample before enriching, so only the picks cost lookups
+    if let Some(count) = args.sample {
+        let seed = args.seed.unwrap_or_else(sample::random_seed);
+        let pool = all_repos.len();
+        sample::sample(&mut all_repos, count, seed);
+        if verbose {
+            eprintln!(
+                "🎲 Sampled {} of {pool} repos (--seed {seed})",
+                all_repos.len()
+            );
+        }
+    }
+
+    // Batch the GitHub lookups of all requested enrichments into a few GraphQL queries
+    let batched = match enrich::github_batch(&mut all_repos, plan, &config).await {
+        Ok(batched) => {
+            if batched && verbose {
+                eprintln!("🧩 Looked up GitHub enrichments in batched GraphQL queries");
+            }
+            batched
+        }
+        Err(e) => {
+            if verbose {
+                eprintln!("⚠ Batched GitHub lookup failed, using per-repo requests: {e}");
+            }
+            false
+        }
+    };
+
+    // Look up GitHub Sponsors listings for the badge and the --sponsorable filter
+    if plan.sponsors && !batched {
+        match config.auth.github_token.as_deref() {
+            Some(token) => {
+                let github = GitHub::new(config.general.github_timeout_secs)?;
+                if let Err(e) = sponsors::enrich(&mut all_repos, &github, token).await {
+                    notice!("⚠ Failed to look up GitHub Sponsors: {e}");
+                }
+            }
+            None => notice!("⚠ GitHub Sponsors lookups need a GitHub token"),
+        }
+    }
+
+    if args.sponsorable {
+        let filtered_count = filter::sponsorable(&mut all_repos);
+        if verbose {
+            eprintln!("♥ Sponsorable filter: removed {filtered_count} repos");
+        }
+    }
+
+    // Check latest releases for the 📦 marker and the --has-binaries filter
+    if plan.binaries {
+        if verbose {
+            eprintln!("📦 Checking latest releases for binaries...");
+        }
+        binaries::enrich(&mut all_repos, &config, batched).await;
+    }
+
+    if args.has_binaries {
+        let filtered_count = filter::has_binaries(&mut all_repos);
+        if verbose {
+            eprintln!("📦 Binaries filter: removed {filtered_count} repos");
+        }
+    }
+
+    // Look for Dockerfiles and ghcr.io images for the 🐳 marker and the --has-container filter
+    if plan.containers {
+        if verbose {
+            eprintln!("🐳 Checking for container files and images...");
+        }
+        containers::enrich(&mut all_repos, &config, batched).await;
+    }
+
+    if args.has_container {
+        let filtered_count = filter::has_container(&mut all_repos);
+        if verbose {
+            eprintln!("🐳 Container filter: removed {filtered_count} repos");
+        }
+    }
+
+    // Look up licenses for the license filter
+    if plan.license {
+        if verbose {
+            eprintln!("⚖ Looking up licenses...");
+        }
+        licenses::enrich(&mut all_repos, &config, batched).await;
+        let filtered_count = filter::license(&mut all_repos, &config.general.license_filter);
+        if verbose {
+            eprintln!("⚖ License filter: removed {filtered_count} repos");
+        }
+    }
+
+    if let Some(ref limits) = limits {
+        filter::limit_per_provider(&mut all_repos, limits, |repo| {
+            instance_key(&config, &enabled_providers, repo)
+        });
+    }
+
     if verbose {
         eprintln!("📊 Total repositories: {}", all_repos.len());
     }
 
     if no_new_repos {
-        eprintln!(
+        notice!(
             "👀 All fetched repositories were already shown today. Use --show-all to repeat them."
         );
     }
 
-    // Apply starred status if enabled and GitHub token available
-    if config.general.show_starred_status && config.auth.github_token.is_some() {
-        if let Ok(starred_cache) = StarredCache::new() {
-            // Try to use cached starred list
-            let starred_set = if let Some(cached) = starred_cache.get_starred().await {
-                if verbose {
-                    eprintln!("⭐ Using cached starred status ({} repos)", cached.len());
-                }
-                cached
-            } else if let Some(ref token) = config.auth.github_token {
-                // Fetch fresh starred list if not cached
-                if verbose {
-                    eprintln!("⭐ Fetching starred repositories...");
-                }
-                if let Ok(github) = GitHub::new(config.general.github_timeout_secs) {
-                    if let Ok(starred_repos) = github.get_user_stars(token).await {
-                        let starred_set: std::collections::HashSet<String> =
-                            starred_repos.into_iter().collect();
-                        if verbose {
-                            eprintln!("⭐ Found {} starred repos", starred_set.len());
-                        }
-                        let _ = starred_cache.save_starred(starred_set.clone()).await;
-                        starred_set
-                    } else {
-                        std::collections::HashSet::new()
-                    }
-                } else {
-                    std::collections::HashSet::new()
-                }
-            } else {
-                std::collections::HashSet::new()
+    // Apply starred status for every provider with a configured token
+    if config.general.show_starred_status {
+        mark_starred(&config, &mut all_repos, batched, verbose).await;
+    }
+
+    // Mark repos whose checkout (by its origin remote) is under the configured clone root
+    if config.clone.root_dir().is_some() {
+        for repo in &mut all_repos {
+            repo.is_cloned = clone::target_dir(&repo.url, &config.clone)
+                .is_ok_and(|target| clone::is_checkout_of(&target, &repo.url));
+        }
+    }
+
+    icons::apply(&mut all_repos, &config);
+
+    // Alerts cover every repo fetched today, including ones past the limit or shown earlier
+    let mut trending_today = Vec::new();
+    if config.general.trending_alert
+        && matches!(format, OutputFormat::Motd | OutputFormat::Accessible)
+    {
+        if let Some(tracker) = &day_tracker {
+            trending_today = tracker.fetched_today(&fetch_key).await;
+        }
+        for repo in &all_repos {
+            if !trending_today.iter().any(|r| r.url == repo.url) {
+                trending_today.push(repo.clone());
+            }
+        }
+        if config.general.show_starred_status {
+            // Ask viewerHasStarred for these too rather than downloading the star list
+            let plan = enrich::Plan {
+                starred: true,
+                ..enrich::Plan::default()
             };
+            let batched = enrich::github_batch(&mut trending_today, plan, &config)
+                .await
+                .unwrap_or(false);
+            mark_starred(&config, &mut trending_today, batched, verbose).await;
+        }
+        icons::apply(&mut trending_today, &config);
+    }
 
-            // Mark starred repos
-            for repo in &mut all_repos {
-                if repo.provider == "github" {
-                    repo.is_starred = starred_set.contains(&repo.name);
+    // Enrich the final list with health scores only when asked; it costs API calls
+    if plan.health && !batched {
+        if verbose {
+            eprintln!("🩺 Fetching health signals...");
+        }
+        let github = GitHub::new(config.general.github_timeout_secs)?;
+        health::enrich(&mut all_repos, &github, config.auth.github_token.as_deref()).await;
+    }
+
+    // Star spikes only cost lookups for the few repos that have one
+    if plan.suspicious && !batched {
+        if verbose {
+            eprintln!("⚠ Checking star spikes...");
+        }
+        let github = GitHub::new(config.general.github_timeout_secs)?;
+        anomaly::enrich(&mut all_repos, &github, config.auth.github_token.as_deref()).await;
+    }
+
+    if let Err(e) = hooks::run_for_repos(&config.hooks, HookEvent::PreRender, &all_repos) {
+        notice!("⚠ {e}");
+    }
+
+    if let Some((dir, html)) = digest {
+        let opts = RenderOptions::from_config(&config);
+        let today = chrono::Utc::now().date_naive();
+        let path = digest::publish(&dir, today, &all_repos, &opts, html).await?;
+        println!("✓ Wrote {}", path.display());
+        return Ok(());
+    }
+
+    // Render output
+    let opts = RenderOptions {
+        star_history,
+        ..RenderOptions::from_config(&config)
+    };
+    let mut output = sections::render(
+        &all_repos,
+        &trending_today,
+        &config,
+        format,
+        &opts,
+        template.as_ref(),
+        verbose,
+    )
+    .await?;
+    if backfilled && matches!(format, OutputFormat::Motd | OutputFormat::Accessible) {
+        output = format!("{}{output}", backfill::label(format));
+    }
+    if args.explain_health && format.is_for_terminal() {
+        output.push_str(&health::explain(&all_repos));
+    }
+    // The interactive browser follows the list, which a pager would hold back until it quits
+    if args.no_pager || args.interactive {
+        print!("{output}");
+    } else {
+        pager::print(&output, config.general.pager.as_deref());
+    }
+
+    if let Some(ref cache) = output_cache {
+        if let Err(e) = cache.save(format, &output).await {
+            if verbose {
+                eprintln!("⚠ Failed to save output for --instant: {e}");
+            }
+        }
+    }
+
+    // Status bar polls would log every repo on every refresh
+    if !format.is_status_bar() {
+        let shown: Vec<activity::Event> = all_repos
+            .iter()
+            .map(|repo| activity::Event::for_repo(activity::Action::Shown, repo))
+            .collect();
+        activity::record(&config, &shown).await;
+    }
+
+    match open::LastShown::new() {
+        Ok(last_shown) => {
+            if let Err(e) = last_shown.save(&all_repos).await {
+                if verbose {
+                    eprintln!("⚠ Failed to save shown repos: {e}");
                 }
             }
         }
+        Err(e) => {
+            if verbose {
+                eprintln!("⚠ {e}");
+            }
+        }
     }
 
-    // Render output
-    render(&all_repos, format);
+    if let Some(ref lock) = run_lock {
+        if let Err(e) = lock.record(&invocation, &output).await {
+            if verbose {
+                eprintln!("⚠ Failed to record run output: {e}");
+            }
+        }
+    }
+
+    if let Some((guard, per)) = &once_guard {
+        if let Err(e) = guard.record(*per, &output).await {
+            if verbose {
+                eprintln!("⚠ Failed to record run marker: {e}");
+            }
+        }
+    }
 
     // Record seen repos and increment offset for next run when tracking is enabled
-    if let Some(tracker) = &seen_tracker {
+    if let Some(tracker) = seen_tracker {
         if !all_repos.is_empty() {
+            // Everything that survived the seen filter is new today
+            if let Err(e) = hooks::run_for_each_repo(&config.hooks, &all_repos) {
+                notice!("⚠ {e}");
+            }
+
             if let Err(e) = tracker.mark_seen(&all_repos).await {
                 if verbose {
                     eprintln!("⚠ Failed to record seen repos: {e}");
@@ -543,93 +1640,1092 @@ async fn main() -> Result<()> {
         }
     }
 
+    if args.interactive {
+        run_interactive(&all_repos, &RenderOptions::from_config(&config), args.yes).await?;
+    }
+
     Ok(())
 }
 
-/// Check if a repository is mostly ASCII (filters out CJK/non-Latin scripts)
-fn is_mostly_ascii(repo: &model::Repo) -> bool {
-    // Check name - should be primarily ASCII
-    let name_ascii_ratio = ascii_ratio(&repo.name);
-    if name_ascii_ratio < 0.8 {
-        return false;
+/// Terminal UI for `--interactive`: browse the shown repos and act on them until the user quits
+async fn run_interactive(repos: &[Repo], opts: &RenderOptions, assume_yes: bool) -> Result<()> {
+    if repos.is_empty() {
+        return Ok(());
+    }
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        notice!("ℹ --interactive needs a terminal, skipping");
+        return Ok(());
     }
 
-    // Check description if present
-    if let Some(ref desc) = repo.description {
-        let desc_ascii_ratio = ascii_ratio(desc);
-        if desc_ascii_ratio < 0.7 {
-            return false;
+    let mut browser = tui::Browser::new(repos, opts);
+    while let Some(command) = browser.next_command()? {
+        let (action, i, prompts) = match command {
+            interactive::Command::Star(i) => ("Star", i, true),
+            interactive::Command::Clone(i) => ("Clone", i, true),
+            interactive::Command::Open(i) => ("Open", i, false),
+            interactive::Command::Homepage(i) => ("Open homepage of", i, false),
+            _ => continue,
+        };
+        let result = run_action(command, repos, opts, assume_yes).await;
+        // Keep clone progress, safety warnings and confirmations on screen until read
+        if prompts && !assume_yes {
+            eprint!("Press Enter to return to the list");
+            let _ = io::stdin().read_line(&mut String::new());
         }
+        browser.set_status(match result {
+            Ok(()) => format!("✓ {action} {}: done", repos[i].name),
+            Err(e) => format!("✗ {action} {}: {e}", repos[i].name),
+        });
     }
+    Ok(())
+}
 
-    true
+/// Run a command shared by the `--interactive` browser and `repl` on one of `repos`
+async fn run_action(
+    command: interactive::Command,
+    repos: &[Repo],
+    opts: &RenderOptions,
+    assume_yes: bool,
+) -> Result<()> {
+    match command {
+        // The prompt loops handle these themselves
+        interactive::Command::Quit | interactive::Command::Help => Ok(()),
+        interactive::Command::List => {
+            print_numbered(repos, 0);
+            Ok(())
+        }
+        interactive::Command::Details(i) => {
+            let details = std::slice::from_ref(&repos[i]);
+            print!(
+                "{}",
+                render_to_string(details, OutputFormat::Accessible, opts)
+            );
+            Ok(())
+        }
+        interactive::Command::Star(i) if repos[i].provider != "github" => Err(anyhow::anyhow!(
+            "Starring is only supported for GitHub repositories"
+        )),
+        interactive::Command::Star(i) => {
+            handle_star_command(&repos[i].name, None, assume_yes).await
+        }
+        interactive::Command::Clone(i) => handle_clone_command(&repos[i].url, assume_yes).await,
+        interactive::Command::Open(i) => open::open_url(&repos[i].url),
+        interactive::Command::Homepage(i) => match repos[i].homepage {
+            Some(ref homepage) => open::open_url(homepage),
+            None => Err(anyhow::anyhow!("{} has no homepage", repos[i].name)),
+        },
+    }
 }
 
-/// Calculate the ratio of ASCII characters in a string
-#[allow(clippy::cast_precision_loss)]
-fn ascii_ratio(s: &str) -> f64 {
-    if s.is_empty() {
-        return 1.0;
+/// Print the repos from position `first` on with their 1-based positions for the prompt
+fn print_numbered(repos: &[Repo], first: usize) {
+    for (i, repo) in repos.iter().enumerate().skip(first) {
+        println!("{:>3}. {} {}", i + 1, repo.icon, repo.name);
     }
-    let total_chars = s.chars().count();
-    let ascii_chars = s.chars().filter(char::is_ascii).count();
-    ascii_chars as f64 / total_chars as f64
 }
 
-/// Handle the star subcommand
-async fn handle_star_command(repo: &str) -> Result<()> {
-    let config = Config::load().context("Failed to load configuration")?;
+/// `repl`: fetch once, then act on the results in memory until the user quits
+async fn handle_repl_command(args: &Args) -> Result<()> {
+    if !io::stdin().is_terminal() {
+        anyhow::bail!("repl needs a terminal on stdin");
+    }
 
-    let token = config.auth.github_token.as_ref().context(
-        "GitHub token not configured. Set TROTD_GITHUB_TOKEN or add github_token to config file.",
-    )?;
+    let mut config = Config::load().context("Failed to load configuration")?;
+    let preset_providers = apply_preset(args, &mut config)?;
+    apply_cli_overrides(args, &mut config);
+    terminal::set_colors(config.display.color);
+    let provider_ids = resolve_providers(
+        args.provider.as_deref().or(preset_providers.as_deref()),
+        &config,
+    );
 
-    // Parse repo name (format: owner/repo)
-    let parts: Vec<&str> = repo.split('/').collect();
-    if parts.len() != 2 {
-        anyhow::bail!("Invalid repository format. Expected: owner/repo");
+    let mut provider_instances: Vec<(String, Box<dyn Provider>)> = Vec::new();
+    for provider_id in provider_ids {
+        match providers::build(&provider_id, &config) {
+            Ok(provider) => provider_instances.push((provider_id, provider)),
+            Err(e) => notice!("✗ Failed to initialize {provider_id} provider: {e}"),
+        }
+    }
+    if provider_instances.is_empty() {
+        anyhow::bail!("No providers enabled or available");
     }
-    let (owner, repo_name) = (parts[0], parts[1]);
 
-    eprintln!("⭐ Starring {owner}/{repo_name} on GitHub...");
+    let lang_filter = LanguageFilter::new(config.general.language_filter.clone())
+        .with_unknown(config.general.include_unknown_lang);
+    let opts = RenderOptions::from_config(&config);
+    let mut offsets: HashMap<String, usize> = HashMap::new();
+    let mut session = repl::Session::default();
+
+    let page = fetch_repl_page(&config, &provider_instances, &mut offsets, &lang_filter).await;
+    session.extend(page);
+    let mut view = session.view();
+    print_numbered(&view, 0);
+    eprintln!("Type ? for help");
+
+    while let Some(line) = interactive::read_line()? {
+        let command = match repl::parse(&line, view.len()) {
+            Ok(command) => command,
+            Err(e) => {
+                eprintln!("{e}");
+                continue;
+            }
+        };
 
-    let github = GitHub::new(config.general.github_timeout_secs)?;
-    github.star_repo(owner, repo_name, token).await?;
+        let result = match command {
+            repl::Command::Action(interactive::Command::Quit) => break,
+            repl::Command::Action(interactive::Command::Help) => {
+                eprint!("{}{}", interactive::HELP, repl::HELP);
+                Ok(())
+            }
+            repl::Command::Action(action) => run_action(action, &view, &opts, args.yes).await,
+            repl::Command::Next => {
+                let page =
+                    fetch_repl_page(&config, &provider_instances, &mut offsets, &lang_filter).await;
+                session.extend(page);
+                let shown = view.len();
+                view = session.view();
+                if view.len() == shown {
+                    eprintln!("No more repositories");
+                }
+                print_numbered(&view, shown);
+                Ok(())
+            }
+            repl::Command::Filter(filters) => {
+                // Short names and aliases work as they do for --provider
+                let filters = filters
+                    .into_iter()
+                    .map(|filter| match filter {
+                        repl::Filter::Provider(name) => {
+                            repl::Filter::Provider(config.resolve_provider(&name).0)
+                        }
+                        filter => filter,
+                    })
+                    .collect();
+                session.filter(filters);
+                view = session.view();
+                if view.is_empty() {
+                    eprintln!("No repositories match, `filter clear` shows them all again");
+                }
+                print_numbered(&view, 0);
+                Ok(())
+            }
+            repl::Command::ShowFilters => {
+                let active: Vec<_> = session.filters().iter().map(ToString::to_string).collect();
+                if active.is_empty() {
+                    eprintln!("No filters");
+                } else {
+                    eprintln!("Filters: {}", active.join(" "));
+                }
+                Ok(())
+            }
+            repl::Command::ClearFilters => {
+                session.clear_filters();
+                view = session.view();
+                print_numbered(&view, 0);
+                Ok(())
+            }
+        };
+        if let Err(e) = result {
+            eprintln!("✗ {e}");
+        }
+    }
+    Ok(())
+}
 
-    println!("✓ Successfully starred {owner}/{repo_name}");
+/// Fetch the next page from every provider for `repl`, advancing its offsets
+async fn fetch_repl_page(
+    config: &Config,
+    providers: &[(String, Box<dyn Provider>)],
+    offsets: &mut HashMap<String, usize>,
+    langs: &LanguageFilter,
+) -> Vec<Repo> {
+    let fetches = providers.iter().map(|(id, provider)| {
+        let offset = offsets.get(id).copied().unwrap_or(0);
+        let cfg = config.provider_cfg(id);
+        async move {
+            let result = provider
+                .top_today(&cfg, offset, config.get_max_entries(id), langs)
+                .await;
+            (id, result)
+        }
+    });
 
-    // Invalidate starred cache
-    if let Ok(starred_cache) = StarredCache::new() {
-        let _ = starred_cache.clear().await;
+    let mut page = Vec::new();
+    for (id, result) in futures::future::join_all(fetches).await {
+        match result {
+            Ok(repos) => {
+                *offsets.entry(id.clone()).or_default() += repos.len();
+                page.extend(repos);
+            }
+            Err(e) => notice!("✗ {id}: {e}"),
+        }
     }
+    icons::apply(&mut page, config);
+    page
+}
 
-    Ok(())
+/// Apply the `--preset` filter bundle, returning the providers it selects (if any)
+fn apply_preset(args: &Args, config: &mut Config) -> Result<Option<Vec<String>>> {
+    let name = args
+        .preset
+        .as_deref()
+        .or(args.selfhosted.then_some("selfhosted"));
+    match name {
+        Some(name) => config.apply_preset(name),
+        None => Ok(None),
+    }
 }
 
-/// Handle the clone subcommand
-fn handle_clone_command(repo: &str) -> Result<()> {
-    // Support both "owner/repo" format and full URLs
-    let clone_url = if repo.starts_with("http://") || repo.starts_with("https://") {
-        repo.to_string()
-    } else {
-        // Assume GitHub by default for owner/repo format
-        format!("https://github.com/{repo}.git")
-    };
+/// Apply CLI flag overrides on top of the loaded configuration
+fn apply_cli_overrides(args: &Args, config: &mut Config) {
+    if let Some(max) = args.max_per_provider {
+        config.general.max_per_provider = max;
+    }
 
-    eprintln!("📦 Cloning {clone_url}...");
+    if let Some(Commands::More { count: Some(count) }) = args.command {
+        config.general.max_per_provider = count;
+    }
 
-    // Use git clone command
-    let output = std::process::Command::new("git")
-        .arg("clone")
-        .arg(&clone_url)
-        .output()
-        .context("Failed to execute git clone. Is git installed?")?;
+    if let Some(max) = args.max_github {
+        config.general.github_max_entries = Some(max);
+    }
 
-    if output.status.success() {
-        println!("✓ Successfully cloned {repo}");
-        Ok(())
-    } else {
-        let error = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("Git clone failed: {error}");
+    if let Some(max) = args.max_gitlab {
+        config.general.gitlab_max_entries = Some(max);
+    }
+
+    if let Some(max) = args.max_gitea {
+        config.general.gitea_max_entries = Some(max);
+    }
+
+    if let Some(max) = args.max_codeberg {
+        config.general.codeberg_max_entries = Some(max);
+    }
+
+    if let Some(max) = args.max_bitbucket {
+        config.general.bitbucket_max_entries = Some(max);
+    }
+
+    if let Some(max) = args.max_gitee {
+        config.general.gitee_max_entries = Some(max);
+    }
+
+    if let Some(max) = args.max_huggingface {
+        config.general.huggingface_max_entries = Some(max);
+    }
+
+    if let Some(max) = args.max_crates {
+        config.general.crates_max_entries = Some(max);
+    }
+
+    if let Some(max) = args.max_hackernews {
+        config.general.hackernews_max_entries = Some(max);
+    }
+
+    if let Some(max) = args.max_reddit {
+        config.general.reddit_max_entries = Some(max);
+    }
+
+    if let Some(count) = args.sample {
+        sample::deepen(config, count);
+    }
+
+    if let Some(ref langs) = args.lang {
+        config.general.language_filter.clone_from(langs);
+    }
+
+    if args.include_unknown_lang {
+        config.general.include_unknown_lang = true;
+    }
+
+    if args.merge {
+        config.general.merge_providers = true;
+    }
+
+    if let Some(ref path) = args.template {
+        config.general.template = Some(path.to_string_lossy().into_owned());
+    }
+
+    if args.no_emoji {
+        config.display.ascii_symbols = Some(true);
+    }
+
+    if let Some(color) = args.color {
+        config.display.color = color;
+    }
+
+    if let Some(width) = args.width {
+        config.display.width = Some(width);
+    }
+
+    if let Some(max) = args.max_width {
+        config.statusbar.max_width = Some(max);
+    }
+
+    if let Some(percent) = args.secondary_lang_percent {
+        config.general.secondary_language_percent = Some(percent);
+    }
+
+    if let Some(topics) = args.topics {
+        config.display.max_topics = topics;
+    }
+
+    if let Some(min) = args.min_stars {
+        config.general.min_stars = Some(min);
+    }
+
+    if let Some(max) = args.max_stars {
+        config.general.max_stars = Some(max);
+    }
+
+    if let Some(threshold) = args.spam_threshold {
+        config.spam.threshold = Some(threshold);
+    }
+
+    if let Some(ref topics) = args.exclude_topics {
+        for provider in TOPIC_PROVIDERS {
+            if let Some((_, exclude)) = config.topic_lists_mut(provider) {
+                exclude.clone_from(topics);
+            }
+        }
+    }
+}
+
+/// Determine output format from `--format` and its `--json` and `--accessible` shorthands
+fn output_format(args: &Args) -> OutputFormat {
+    if args.json {
+        OutputFormat::Json
+    } else if args.accessible {
+        OutputFormat::Accessible
+    } else {
+        args.format.unwrap_or(OutputFormat::Motd)
+    }
+}
+
+/// Resolve the providers to query from `--provider` or the config
+///
+/// Aliases with a base URL (`gitea@https://codeberg.org`) stay keyed by the alias, so
+/// each instance is fetched with its own base URL.
+fn resolve_providers(requested: Option<&[String]>, config: &Config) -> Vec<String> {
+    let Some(names) = requested else {
+        return config
+            .enabled_providers()
+            .into_iter()
+            .map(String::from)
+            .collect();
+    };
+
+    let mut ids = Vec::new();
+    for name in names {
+        let (id, base_url) = config.resolve_provider(name);
+        let key = match base_url {
+            Some(_) if matches!(id.as_str(), "gitlab" | "gitea" | "codeberg") => name.clone(),
+            Some(_) => {
+                notice!(
+                    "⚠ Ignoring base URL in alias {name}: only gitlab, gitea and codeberg support a base URL"
+                );
+                id
+            }
+            None => id,
+        };
+        if !ids.contains(&key) {
+            ids.push(key);
+        }
+    }
+    ids
+}
+
+/// The entry of `keys` (from [`resolve_providers`]) a repo was fetched through: the
+/// alias pointing at its instance, or else its provider id
+fn instance_key(config: &Config, keys: &[String], repo: &Repo) -> String {
+    keys.iter()
+        .find(|key| {
+            let (id, base_url) = config.resolve_provider(key);
+            id == repo.provider
+                && base_url
+                    .zip(repo.instance_url())
+                    .is_some_and(|(url, instance)| config::same_instance(&url, instance))
+        })
+        .map_or_else(|| repo.provider.clone(), Clone::clone)
+}
+
+/// Mark repositories the user has starred on their provider
+///
+/// Each provider's starred list is cached separately, so a missing token or a
+/// failed fetch for one provider leaves the others unaffected. GitHub repos already
+/// marked by the `batched` GraphQL lookup skip the (possibly huge) star list.
+async fn mark_starred(config: &Config, repos: &mut [Repo], batched: bool, verbose: bool) {
+    for provider in ["github", "gitlab", "gitea", "codeberg"] {
+        if !repos.iter().any(|r| r.provider == provider) || (batched && provider == "github") {
+            continue;
+        }
+        let provider_cfg = config.provider_cfg(provider);
+        let Some(token) = provider_cfg.token.as_deref() else {
+            continue;
+        };
+        let Ok(starred_cache) = StarredCache::new(provider) else {
+            continue;
+        };
+
+        // Try to use cached starred list
+        let starred_set = if let Some(cached) = starred_cache.get_starred().await {
+            if verbose {
+                eprintln!(
+                    "⭐ Using cached {provider} starred status ({} repos)",
+                    cached.len()
+                );
+            }
+            cached
+        } else {
+            // Fetch fresh starred list if not cached
+            if verbose {
+                eprintln!("⭐ Fetching {provider} starred repositories...");
+            }
+            match fetch_user_stars(config, provider, token).await {
+                Ok(starred_repos) => {
+                    let starred_set: HashSet<String> = starred_repos.into_iter().collect();
+                    if verbose {
+                        eprintln!("⭐ Found {} starred {provider} repos", starred_set.len());
+                    }
+                    let _ = starred_cache.save_starred(starred_set.clone()).await;
+                    starred_set
+                }
+                Err(e) => {
+                    if verbose {
+                        eprintln!("⚠ Failed to fetch {provider} starred repos: {e}");
+                    }
+                    continue;
+                }
+            }
+        };
+
+        // Mark starred repos
+        for repo in repos.iter_mut().filter(|r| r.provider == provider) {
+            repo.is_starred = starred_set.contains(&repo.name);
+        }
+    }
+}
+
+/// Fetch the full starred list of the authenticated user from one provider
+async fn fetch_user_stars(config: &Config, provider: &str, token: &str) -> Result<Vec<String>> {
+    let timeout_secs = config.get_timeout_secs(provider);
+    match provider {
+        "github" => GitHub::new(timeout_secs)?.get_user_stars(token).await,
+        "gitlab" => {
+            GitLab::new(timeout_secs)?
+                .get_user_stars(&config.gitlab.base_url, token)
+                .await
+        }
+        "gitea" | "codeberg" => {
+            let base_url = config.provider_cfg(provider).base_url.unwrap_or_default();
+            Gitea::new(timeout_secs)?
+                .get_user_stars(&base_url, token)
+                .await
+        }
+        _ => anyhow::bail!("Starred status is not supported for {provider}"),
+    }
+}
+
+/// Handle the star subcommand
+async fn handle_star_command(repo: &str, list: Option<&str>, assume_yes: bool) -> Result<()> {
+    let config = Config::load().context("Failed to load configuration")?;
+    let token = github_token(&config)?;
+    let (owner, repo_name) = parse_owner_repo(repo)?;
+    let github = GitHub::new(config.general.github_timeout_secs)?;
+
+    let prompt = match list {
+        Some(name) => format!("Star {owner}/{repo_name} into list \"{name}\"?"),
+        None => format!("Star {owner}/{repo_name}?"),
+    };
+    if !confirm_repo_action(&github, &prompt, owner, repo_name, token, assume_yes).await? {
+        println!("Aborted");
+        return Ok(());
+    }
+
+    notice!("⭐ Starring {owner}/{repo_name} on GitHub...");
+    github.star_repo(owner, repo_name, token).await?;
+    println!("✓ Successfully starred {owner}/{repo_name}");
+
+    if let Some(name) = list {
+        if github.add_to_list(owner, repo_name, name, token).await? {
+            notice!("📋 Created GitHub Stars list \"{name}\"");
+        }
+        println!("✓ Added {owner}/{repo_name} to list \"{name}\"");
+    }
+
+    activity::record(
+        &config,
+        &[activity::Event::new(activity::Action::Star, repo)],
+    )
+    .await;
+
+    // Invalidate starred cache
+    if let Ok(starred_cache) = StarredCache::new("github") {
+        let _ = starred_cache.clear().await;
+    }
+
+    Ok(())
+}
+
+/// Handle the unstar subcommand
+async fn handle_unstar_command(repo: &str, assume_yes: bool) -> Result<()> {
+    let config = Config::load().context("Failed to load configuration")?;
+    let token = github_token(&config)?;
+    let (owner, repo_name) = parse_owner_repo(repo)?;
+    let github = GitHub::new(config.general.github_timeout_secs)?;
+
+    let prompt = format!("Unstar {owner}/{repo_name}?");
+    if !confirm_repo_action(&github, &prompt, owner, repo_name, token, assume_yes).await? {
+        println!("Aborted");
+        return Ok(());
+    }
+
+    notice!("Unstarring {owner}/{repo_name} on GitHub...");
+    github.unstar_repo(owner, repo_name, token).await?;
+    println!("✓ Successfully unstarred {owner}/{repo_name}");
+    activity::record(
+        &config,
+        &[activity::Event::new(activity::Action::Unstar, repo)],
+    )
+    .await;
+
+    // Invalidate starred cache
+    if let Ok(starred_cache) = StarredCache::new("github") {
+        let _ = starred_cache.clear().await;
+    }
+
+    Ok(())
+}
+
+/// GitHub token required by the star commands
+fn github_token(config: &Config) -> Result<&str> {
+    config.auth.github_token.as_deref().context(
+        "GitHub token not configured. Set TROTD_GITHUB_TOKEN or add github_token to config file.",
+    )
+}
+
+/// Parse repo name (format: owner/repo)
+fn parse_owner_repo(repo: &str) -> Result<(&str, &str)> {
+    match repo.split_once('/') {
+        Some((owner, name)) if !owner.is_empty() && !name.is_empty() && !name.contains('/') => {
+            Ok((owner, name))
+        }
+        _ => anyhow::bail!("Invalid repository format. Expected: owner/repo"),
+    }
+}
+
+/// A repository named on the command line
+enum Selected<'a> {
+    /// Shown in the last output, with everything known about it
+    Shown(&'a Repo),
+    /// Any GitHub `owner/repo`, known by name only
+    GitHub(&'a str),
+}
+
+impl Selected<'_> {
+    fn name(&self) -> &str {
+        match self {
+            Self::Shown(repo) => &repo.name,
+            Self::GitHub(name) => name,
+        }
+    }
+
+    fn url(&self) -> String {
+        match self {
+            Self::Shown(repo) => repo.url.clone(),
+            Self::GitHub(name) => format!("https://github.com/{name}"),
+        }
+    }
+
+    fn description(&self) -> Option<String> {
+        match self {
+            Self::Shown(repo) => repo.description.clone(),
+            Self::GitHub(_) => None,
+        }
+    }
+
+    fn event(&self, action: activity::Action) -> activity::Event {
+        match self {
+            Self::Shown(repo) => activity::Event::for_repo(action, repo),
+            Self::GitHub(name) => activity::Event {
+                provider: Some("github".to_string()),
+                url: Some(self.url()),
+                ..activity::Event::new(action, name)
+            },
+        }
+    }
+}
+
+/// Find a repository of the last output by position or name, or take the selector as
+/// a GitHub `owner/repo`
+fn resolve_selector<'a>(repos: &'a [Repo], selector: &'a str) -> Result<Selected<'a>> {
+    if let Some(repo) = open::resolve(repos, selector) {
+        return Ok(Selected::Shown(repo));
+    }
+    parse_owner_repo(selector).with_context(|| {
+        format!("No repository `{selector}` in the last output (use its position or owner/repo)")
+    })?;
+    Ok(Selected::GitHub(selector))
+}
+
+/// URL and provider of a repository of the last output, or of any URL or `owner/repo`
+fn selector_source(repos: &[Repo], selector: &str) -> (String, Option<String>) {
+    match open::resolve(repos, selector) {
+        Some(repo) => (repo.url.clone(), Some(repo.provider.clone())),
+        None => (clone::clone_url(selector), None),
+    }
+}
+
+/// Show the repository description and ask before changing anything on GitHub
+async fn confirm_repo_action(
+    github: &GitHub,
+    prompt: &str,
+    owner: &str,
+    repo_name: &str,
+    token: &str,
+    assume_yes: bool,
+) -> Result<bool> {
+    if assume_yes {
+        return Ok(true);
+    }
+
+    // The description helps catch a mistyped name; failing to fetch it is not fatal
+    if let Ok(Some(description)) = github.repo_description(owner, repo_name, token).await {
+        eprintln!("{owner}/{repo_name}: {description}");
+    }
+
+    confirm::confirm(prompt, false)
+}
+
+/// Open a repository of the last run or any GitHub `owner/repo`, or its homepage, in the browser
+async fn handle_open_command(selector: &str, homepage: bool) -> Result<()> {
+    let config = Config::load().context("Failed to load configuration")?;
+    let repos = open::LastShown::new()?.load().await?;
+    let selected = resolve_selector(&repos, selector)?;
+    let (name, repo_url) = (selected.name(), selected.url());
+
+    // GitHub's trending page doesn't list homepages, so look them up when asked
+    let mut found = match selected {
+        Selected::Shown(repo) => repo.homepage.clone(),
+        Selected::GitHub(_) => None,
+    };
+    let on_github = match selected {
+        Selected::Shown(repo) => repo.provider == "github",
+        Selected::GitHub(_) => true,
+    };
+    if homepage && found.is_none() && on_github {
+        if let Ok((owner, repo_name)) = parse_owner_repo(name) {
+            let github = GitHub::new(config.general.github_timeout_secs)?;
+            found = github
+                .repo_homepage(owner, repo_name, config.auth.github_token.as_deref())
+                .await
+                .unwrap_or_else(|e| {
+                    notice!("⚠ Failed to look up the homepage of {name}: {e}");
+                    None
+                });
+        }
+    }
+    let url = match (homepage, &found) {
+        (true, Some(homepage)) => homepage,
+        (true, None) => {
+            notice!("ℹ {name} has no homepage, opening the repository");
+            &repo_url
+        }
+        (false, _) => &repo_url,
+    };
+
+    open::open_url(url)?;
+    notice!("🌐 Opened {url}");
+
+    activity::record(&config, &[selected.event(activity::Action::Open)]).await;
+    Ok(())
+}
+
+/// Send a repository of the last run, or any GitHub `owner/repo`, to the read-later service
+async fn handle_save_command(selector: &str) -> Result<()> {
+    let config = Config::load().context("Failed to load configuration")?;
+    let repos = open::LastShown::new()?.load().await?;
+    let selected = resolve_selector(&repos, selector)?;
+    let url = selected.url();
+
+    readlater::save(
+        &config.readlater,
+        &readlater::Link::new(&url),
+        config.general.timeout_secs,
+    )
+    .await?;
+    println!("✓ Saved {url} for later");
+
+    activity::record(&config, &[selected.event(activity::Action::Save)]).await;
+    Ok(())
+}
+
+async fn handle_bookmark_command(action: &BookmarkAction) -> Result<()> {
+    let store = bookmarks::BookmarkStore::new()?;
+    match action {
+        BookmarkAction::Add { repo: selector } => {
+            let repos = open::LastShown::new()?.load().await?;
+            let bookmark = match resolve_selector(&repos, selector)? {
+                Selected::Shown(repo) => bookmarks::Bookmark::from_repo(repo),
+                selected @ Selected::GitHub(_) => {
+                    bookmarks::Bookmark::from_url(selected.name(), &selected.url())
+                }
+            };
+            let name = bookmark.name.clone();
+            if store.add(bookmark).await? {
+                println!("✓ Bookmarked {name}");
+            } else {
+                println!("Already bookmarked {name}");
+            }
+        }
+        BookmarkAction::List => {
+            for bookmark in store.list().await? {
+                let marker = if bookmark.pushed { "✓" } else { " " };
+                println!("{marker} {}  {}", bookmark.name, bookmark.url);
+            }
+        }
+        BookmarkAction::Remove { repo } => {
+            if store.remove(repo).await? {
+                println!("✓ Removed bookmark {repo}");
+            } else {
+                println!("No bookmark {repo}");
+            }
+        }
+        BookmarkAction::Push => {
+            let config = Config::load().context("Failed to load configuration")?;
+            let service = config.readlater.service.context(
+                "No read-later service configured. Set service, url and token under [readlater].",
+            )?;
+
+            let mut all = store.list().await?;
+            let mut pushed = 0;
+            for idx in 0..all.len() {
+                if all[idx].pushed {
+                    continue;
+                }
+                let link = all[idx].link();
+                readlater::save(&config.readlater, &link, config.general.timeout_secs)
+                    .await
+                    .with_context(|| format!("Failed to push {}", all[idx].name))?;
+                all[idx].pushed = true;
+                pushed += 1;
+                // Save after each push so a failure midway doesn't resend earlier ones
+                store.save(&all).await?;
+            }
+            println!("✓ Pushed {pushed} bookmark(s) to {service:?}");
+        }
+    }
+    Ok(())
+}
+
+/// Export notes for bookmarks and for starred repos of the last run
+async fn handle_export_notes_command(dir: &std::path::Path) -> Result<()> {
+    let mut entries = bookmarks::BookmarkStore::new()?.list().await?;
+    let starred = open::LastShown::new()?.load().await?;
+    for repo in starred.iter().filter(|r| r.is_starred) {
+        if !entries.iter().any(|e| e.url == repo.url) {
+            entries.push(bookmarks::Bookmark::from_repo(repo));
+        }
+    }
+
+    let summary = notes::export(dir, &entries).await?;
+    println!(
+        "✓ Wrote {} note(s) to {} ({} already there)",
+        summary.written,
+        dir.display(),
+        summary.skipped
+    );
+    Ok(())
+}
+
+/// Create a task for a repository of the last run, or any GitHub `owner/repo`
+async fn handle_todo_command(selector: &str) -> Result<()> {
+    let config = Config::load().context("Failed to load configuration")?;
+    let repos = open::LastShown::new()?.load().await?;
+    let selected = resolve_selector(&repos, selector)?;
+    let item = todo::TodoItem {
+        name: selected.name().to_string(),
+        url: selected.url(),
+        description: selected.description(),
+    };
+
+    todo::create(&config.todo, &item)?;
+    println!("✓ Added task \"{}\"", item.title());
+    Ok(())
+}
+
+/// Block a repository of the last run, or any `owner/repo` or `owner/*`
+async fn handle_block_command(selector: &str) -> Result<()> {
+    let config = Config::load().context("Failed to load configuration")?;
+    let repos = open::LastShown::new()?.load().await?;
+    // Blocklist entries aren't tied to a provider, so only shown repos carry one
+    let event = if let Some(repo) = open::resolve(&repos, selector) {
+        activity::Event::for_repo(activity::Action::Block, repo)
+    } else {
+        parse_owner_repo(selector).with_context(|| {
+            format!(
+                "No repository `{selector}` in the last output (use its position, owner/repo or owner/*)"
+            )
+        })?;
+        activity::Event::new(activity::Action::Block, selector)
+    };
+
+    if blocked::BlockedStore::new()?.add(&event.repo).await? {
+        println!("✓ Blocked {}", event.repo);
+        activity::record(&config, &[event]).await;
+    } else {
+        println!("Already blocked {}", event.repo);
+    }
+    Ok(())
+}
+
+/// Unblock an entry added with `block`
+async fn handle_unblock_command(entry: &str) -> Result<()> {
+    let config = Config::load().context("Failed to load configuration")?;
+    if blocked::BlockedStore::new()?.remove(entry).await? {
+        println!("✓ Unblocked {entry}");
+        activity::record(
+            &config,
+            &[activity::Event::new(activity::Action::Unblock, entry)],
+        )
+        .await;
+    } else if filter::is_listed(entry, &config.general.blocklist) {
+        println!("{entry} is blocked by blocklist in the config file");
+    } else {
+        println!("{entry} isn't blocked");
+    }
+    Ok(())
+}
+
+/// Store a reminder for a repository of the last run, or any GitHub `owner/repo`
+async fn handle_remind_command(selector: &str, delay: &str) -> Result<()> {
+    let due = reminders::due_date(chrono::Utc::now().date_naive(), delay)?;
+    let repos = open::LastShown::new()?.load().await?;
+    let selected = resolve_selector(&repos, selector)?;
+    let reminder = reminders::Reminder {
+        name: selected.name().to_string(),
+        url: selected.url(),
+        description: selected.description(),
+        due,
+    };
+
+    let name = reminder.name.clone();
+    reminders::ReminderStore::new()?.add(reminder).await?;
+    println!("✓ Will remind you of {name} on {due}");
+    Ok(())
+}
+
+async fn handle_seen_command(action: &SeenAction) -> Result<()> {
+    let tracker = SeenTracker::new()?;
+    match action {
+        SeenAction::Undo => match tracker.undo_last_batch().await? {
+            Some(repos) => {
+                println!("✓ The next run shows these again:");
+                for repo in repos {
+                    println!("  {repo}");
+                }
+            }
+            None => println!("Nothing shown today to undo"),
+        },
+    }
+    Ok(())
+}
+
+async fn handle_follow_topic_command(action: &FollowTopicAction) -> Result<()> {
+    let store = follow::FollowedTopics::new()?;
+    match action {
+        FollowTopicAction::Add { topic } => {
+            let topic = follow::normalize_topic(topic)?;
+            if store.add(&topic).await? {
+                println!("✓ Following #{topic}");
+            } else {
+                println!("Already following #{topic}");
+            }
+        }
+        FollowTopicAction::List => {
+            for topic in store.list().await? {
+                println!("#{topic}");
+            }
+        }
+        FollowTopicAction::Remove { topic } => {
+            let topic = follow::normalize_topic(topic)?;
+            if store.remove(&topic).await? {
+                println!("✓ No longer following #{topic}");
+            } else {
+                println!("Not following #{topic}");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Handle the clone subcommand
+async fn handle_clone_command(repo: &str, assume_yes: bool) -> Result<()> {
+    let config = Config::load().context("Failed to load configuration")?;
+    let clone_url = clone::clone_url(repo);
+    let target = clone::target_dir(&clone_url, &config.clone)?;
+
+    if clone::already_cloned(&target) {
+        println!("✓ Already cloned at {}", target.display());
+        return Ok(());
+    }
+
+    let flags = safety::check(&config, &clone_url).await?;
+    if !flags.is_empty() {
+        eprintln!("⚠ {repo} looks suspicious:");
+        for flag in &flags {
+            eprintln!("  - {flag}");
+        }
+        if !confirm::confirm("Clone anyway?", assume_yes)? {
+            println!("Aborted");
+            return Ok(());
+        }
+    }
+
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+
+    notice!("📦 Cloning {clone_url} into {}...", target.display());
+
+    clone::clone_repo(&clone_url, &target)?;
+    println!("✓ Successfully cloned {repo}");
+    activity::record(
+        &config,
+        &[activity::Event::new(activity::Action::Clone, repo)],
+    )
+    .await;
+    Ok(())
+}
+
+/// Print a repository's details, language shares and, with `loc`, tokei line counts
+async fn handle_show_command(selector: &str, loc: bool) -> Result<()> {
+    let config = Config::load().context("Failed to load configuration")?;
+    let repos = open::LastShown::new()?.load().await.unwrap_or_default();
+    if let Some(repo) = open::resolve(&repos, selector) {
+        let opts = RenderOptions::from_config(&config);
+        let details = std::slice::from_ref(repo);
+        print!(
+            "{}",
+            render_to_string(details, OutputFormat::Accessible, &opts)
+        );
+    } else {
+        println!("Repository: {selector}");
+    }
+    let (url, provider) = selector_source(&repos, selector);
+
+    let languages = codestats::languages(&config, &url, provider.as_deref())
+        .await
+        .unwrap_or_else(|e| {
+            notice!("⚠ {e:#}");
+            Vec::new()
+        });
+    let lines = if loc {
+        notice!("📦 Downloading {url} to count lines...");
+        Some(codestats::count_lines(&config, &url, provider.as_deref()).await?)
+    } else {
+        None
+    };
+
+    print!("{}", codestats::render(&languages, lines.as_deref()));
+    Ok(())
+}
+
+/// Download a repository's default branch as an archive and unpack it
+async fn handle_fetch_archive_command(selector: &str, dir: Option<&Path>) -> Result<()> {
+    let config = Config::load().context("Failed to load configuration")?;
+    let repos = open::LastShown::new()?.load().await.unwrap_or_default();
+    let (url, provider) = selector_source(&repos, selector);
+    let target = match dir {
+        Some(dir) => dir.to_path_buf(),
+        None => clone::target_dir(&url, &config.clone)?,
+    };
+
+    if clone::already_cloned(&target) {
+        anyhow::bail!("{} already exists and is not empty", target.display());
+    }
+
+    notice!("📦 Downloading {url} into {}...", target.display());
+    let files = archive::fetch(&config, &url, provider.as_deref(), &target).await?;
+    println!("✓ Unpacked {files} files into {}", target.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_definition() {
+        // Catches conflicts with arguments that subcommands don't inherit
+        Args::command().debug_assert();
+    }
+
+    #[test]
+    fn test_interactive_conflicts_with_other_formats() {
+        for format in [
+            &["--accessible"][..],
+            &["--format", "table"],
+            &["--template", "motd.j2"],
+        ] {
+            let args = [&["git-trending", "--interactive"][..], format].concat();
+            assert!(Args::try_parse_from(args).is_err(), "{format:?}");
+        }
+        assert!(Args::try_parse_from(["git-trending", "--interactive"]).is_ok());
+    }
+
+    #[test]
+    fn test_resolve_providers_keeps_instances() {
+        let config: Config = toml::from_str(
+            r#"
+            [aliases]
+            cb = "gitea@https://codeberg.org"
+            work = "gitea@https://git.example.com"
+            "#,
+        )
+        .unwrap();
+        let requested = ["cb", "work", "gitea", "cb"].map(String::from);
+        let keys = resolve_providers(Some(&requested), &config);
+        assert_eq!(keys, ["cb", "work", "gitea"]);
+
+        let repo = |url: &str| Repo {
+            provider: "gitea".to_string(),
+            url: format!("{url}/owner/tool"),
+            ..Repo::test("owner/tool")
+        };
+        assert_eq!(
+            instance_key(&config, &keys, &repo("https://git.example.com")),
+            "work"
+        );
+        assert_eq!(
+            instance_key(&config, &keys, &repo("https://codeberg.org")),
+            "cb"
+        );
+        assert_eq!(
+            instance_key(&config, &keys, &repo("https://gitea.com")),
+            "gitea"
+        );
+    }
+
+    #[test]
+    fn test_resolve_selector() {
+        let repos = vec![Repo {
+            provider: "gitlab".to_string(),
+            url: "https://gitlab.com/group/tool".to_string(),
+            ..Repo::test("group/tool")
+        }];
+
+        let shown = resolve_selector(&repos, "1").unwrap();
+        assert_eq!(shown.url(), "https://gitlab.com/group/tool");
+        assert_eq!(shown.name(), "group/tool");
+
+        let other = resolve_selector(&repos, "owner/other").unwrap();
+        assert_eq!(other.url(), "https://github.com/owner/other");
+        let event = other.event(activity::Action::Save);
+        assert_eq!(event.provider.as_deref(), Some("github"));
+
+        let err = resolve_selector(&repos, "2").err().unwrap();
+        assert!(err.to_string().contains("No repository `2`"));
     }
 }
@@ -1,9 +1,14 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::filter::TopicFilter;
+use crate::health::Health;
 
 /// Normalized repository structure across all providers
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Repo {
     pub provider: String,
     pub icon: String,
@@ -18,6 +23,84 @@ pub struct Repo {
     pub topics: Vec<String>,
     #[serde(default)]
     pub is_starred: bool,
+    #[serde(default)]
+    pub is_cloned: bool,
+    #[serde(default)]
+    pub is_seen: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub movement: Option<Movement>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<Health>,
+    /// Project homepage, when it points somewhere other than the repository itself
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    /// SPDX id of the license, as far as the forge recognizes it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    /// Owner has a GitHub Sponsors listing
+    #[serde(default)]
+    pub is_sponsorable: bool,
+    /// Latest release ships prebuilt binaries (release assets)
+    #[serde(default)]
+    pub has_binaries: bool,
+    /// Repository ships a Dockerfile or compose file, i.e. can be run as a container
+    #[serde(default)]
+    pub has_container: bool,
+    /// Star spike on a young repo with little history, a sign of bought stars
+    #[serde(default)]
+    pub is_suspicious: bool,
+}
+
+impl Repo {
+    /// Base URL of the instance hosting the repo, e.g. `https://codeberg.org` for
+    /// `https://codeberg.org/owner/name`, whichever alias or config fetched it
+    pub fn instance_url(&self) -> Option<&str> {
+        self.url
+            .trim_end_matches('/')
+            .strip_suffix(self.name.as_str())?
+            .strip_suffix('/')
+    }
+}
+
+#[cfg(test)]
+impl Repo {
+    /// A GitHub repo with nothing known beyond its name, for tests to override the
+    /// fields they exercise: `Repo { stars_today: Some(5), ..Repo::test("owner/repo") }`
+    pub fn test(name: &str) -> Self {
+        Self {
+            provider: "github".to_string(),
+            icon: "[GH]".to_string(),
+            name: name.to_string(),
+            language: None,
+            description: None,
+            url: format!("https://github.com/{name}"),
+            stars_today: None,
+            stars_total: None,
+            last_activity: None,
+            topics: Vec::new(),
+            is_starred: false,
+            is_cloned: false,
+            is_seen: false,
+            movement: None,
+            health: None,
+            homepage: None,
+            license: None,
+            is_sponsorable: false,
+            has_binaries: false,
+            has_container: false,
+            is_suspicious: false,
+        }
+    }
+}
+
+/// Rank movement of a repository compared to yesterday's snapshot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Movement {
+    Up(usize),
+    Down(usize),
+    Same,
+    New,
 }
 
 /// Configuration for provider behavior
@@ -27,18 +110,117 @@ pub struct ProviderCfg {
     pub timeout_secs: u64,
     pub token: Option<String>,
     pub base_url: Option<String>,    // For Gitea
-    pub exclude_topics: Vec<String>, // For GitHub
+    pub topics: TopicFilter, // Applied before paging: GitHub uses the API, Gitea looks topics up
+    pub gitea_ranking: GiteaRanking, // For Gitea
+    pub weekly: bool,        // For GitHub: the weekly trending page, for backfill
+    pub query: Option<String>, // For Gitee: the search query; Reddit: `sub+sub`
+    pub hub_kinds: Vec<HubKind>, // For Hugging Face: the repository kinds to list
 }
 
+/// How the Gitea provider decides what is "trending" on an instance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GiteaRanking {
+    /// Recently pushed repositories with at least one star
+    #[default]
+    Updated,
+    /// Stars received in the last 24h, counted from repository activity feeds
+    RecentStars,
+    /// Most-starred among repositories updated in the last 24h
+    StarredUpdatedToday,
+    /// Newest repositories that already collected stars, most-starred first
+    NewestWithStars,
+}
+
+/// Kinds of Hugging Face Hub repositories
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HubKind {
+    Models,
+    Datasets,
+    Spaces,
+}
+
+impl HubKind {
+    /// Path segment of the kind in API and web URLs (models live at the root)
+    pub fn web_prefix(self) -> &'static str {
+        match self {
+            Self::Models => "",
+            Self::Datasets => "datasets/",
+            Self::Spaces => "spaces/",
+        }
+    }
+
+    pub fn api_path(self) -> &'static str {
+        match self {
+            Self::Models => "models",
+            Self::Datasets => "datasets",
+            Self::Spaces => "spaces",
+        }
+    }
+}
+
+/// Maximum number of languages API lookups per provider fetch
+pub const LANGUAGE_LOOKUP_LIMIT: usize = 20;
+
 /// Language filter configuration
 #[derive(Debug, Clone)]
 pub struct LanguageFilter {
     pub languages: Vec<String>,
+    /// Keep repositories whose language could not be determined
+    pub include_unknown: bool,
+    /// Minimum share (percent) at which a secondary language also matches
+    pub secondary_min_percent: Option<f64>,
 }
 
 impl LanguageFilter {
     pub fn new(languages: Vec<String>) -> Self {
-        Self { languages }
+        Self {
+            languages,
+            include_unknown: false,
+            secondary_min_percent: None,
+        }
+    }
+
+    /// Keep repositories without a known language instead of dropping them
+    pub fn with_unknown(mut self, include_unknown: bool) -> Self {
+        self.include_unknown = include_unknown;
+        self
+    }
+
+    /// Also match repositories whose secondary language reaches `min_percent` of the code
+    pub fn with_secondary(mut self, min_percent: Option<f64>) -> Self {
+        self.secondary_min_percent = min_percent;
+        self
+    }
+
+    /// Whether non-matching repositories are worth checking for secondary languages
+    pub fn checks_secondary(&self) -> bool {
+        !self.languages.is_empty() && self.secondary_min_percent.is_some()
+    }
+
+    /// Match against a languages breakdown (`{"Rust": 1234, "TypeScript": 5678}`)
+    pub fn matches_breakdown(&self, breakdown: &HashMap<String, f64>) -> bool {
+        let Some(min_percent) = self.secondary_min_percent else {
+            return false;
+        };
+        let total: f64 = breakdown.values().sum();
+        if total <= 0.0 {
+            return false;
+        }
+
+        breakdown.iter().any(|(language, bytes)| {
+            bytes / total * 100.0 >= min_percent
+                && self
+                    .languages
+                    .iter()
+                    .any(|filter| language.eq_ignore_ascii_case(filter))
+        })
+    }
+
+    /// Whether unknown languages are worth looking up (they would be dropped otherwise)
+    pub fn needs_lookup(&self) -> bool {
+        !self.languages.is_empty() && !self.include_unknown
     }
 
     pub fn matches(&self, language: Option<&String>) -> bool {
@@ -46,12 +228,114 @@ impl LanguageFilter {
             return true;
         }
 
-        language.is_some_and(|lang| {
-            self.languages
-                .iter()
-                .any(|filter| lang.eq_ignore_ascii_case(filter))
+        let Some(language) = language else {
+            return self.include_unknown;
+        };
+
+        self.languages
+            .iter()
+            .any(|filter| language.eq_ignore_ascii_case(filter))
+    }
+}
+
+/// Infer a language from topics such as `rust` or `golang`
+pub fn language_from_topics(topics: &[String]) -> Option<String> {
+    // Common programming language tags (lowercase for comparison)
+    let languages = [
+        "rust",
+        "go",
+        "golang",
+        "python",
+        "javascript",
+        "typescript",
+        "java",
+        "c",
+        "cpp",
+        "c++",
+        "csharp",
+        "c#",
+        "ruby",
+        "php",
+        "swift",
+        "kotlin",
+        "scala",
+        "haskell",
+        "elixir",
+        "erlang",
+        "zig",
+        "nim",
+        "dart",
+        "lua",
+        "ocaml",
+    ];
+
+    topics
+        .iter()
+        .find(|topic| {
+            let lower = topic.to_lowercase();
+            // Use exact match instead of contains to avoid false positives
+            languages.iter().any(|&lang| lower == lang)
+        })
+        .map(|s| {
+            // Normalize language name
+            let lower = s.to_lowercase();
+            match lower.as_str() {
+                "golang" => "Go".to_string(),
+                "cpp" | "c++" => "C++".to_string(),
+                "csharp" | "c#" => "C#".to_string(),
+                "javascript" => "JavaScript".to_string(),
+                "typescript" => "TypeScript".to_string(),
+                "php" => "PHP".to_string(),
+                "ocaml" => "OCaml".to_string(),
+                _ => {
+                    // Capitalize first letter
+                    let mut chars = lower.chars();
+                    match chars.next() {
+                        None => String::new(),
+                        Some(first) => first.to_uppercase().chain(chars).collect(),
+                    }
+                }
+            }
         })
+}
+
+/// Most used language from a languages API response (`{"Rust": 1234, "Shell": 56}`)
+pub fn primary_language(languages: HashMap<String, f64>) -> Option<String> {
+    languages
+        .into_iter()
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(language, _)| language)
+}
+
+/// License id worth filtering on: forges report unrecognized licenses as
+/// `NOASSERTION` (GitHub) or `other` (GitLab)
+pub fn recognized_license(license: Option<String>) -> Option<String> {
+    let license = license?.trim().to_string();
+    let unknown = license.is_empty()
+        || license.eq_ignore_ascii_case("NOASSERTION")
+        || license.eq_ignore_ascii_case("other");
+    (!unknown).then_some(license)
+}
+
+/// Homepage worth showing: non-empty and not just a link back to the repository
+pub fn useful_homepage(homepage: Option<String>, repo_url: &str) -> Option<String> {
+    let homepage = homepage?.trim().to_string();
+    if homepage.is_empty() {
+        return None;
     }
+    let homepage = if homepage.contains("://") {
+        homepage
+    } else {
+        format!("https://{homepage}")
+    };
+
+    let normalize = |url: &str| {
+        let url = url.split_once("://").map_or(url, |(_, rest)| rest);
+        url.trim_start_matches("www.")
+            .trim_end_matches('/')
+            .to_lowercase()
+    };
+    (normalize(&homepage) != normalize(repo_url)).then_some(homepage)
 }
 
 /// Provider trait for fetching trending repositories
@@ -79,6 +363,25 @@ pub trait Provider: Send + Sync {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_instance_url() {
+        let repo = Repo {
+            provider: "gitea".to_string(),
+            url: "https://git.example.org/gitea/owner/name/".to_string(),
+            ..Repo::test("owner/name")
+        };
+        assert_eq!(repo.instance_url(), Some("https://git.example.org/gitea"));
+        assert_eq!(
+            Repo::test("owner/name").instance_url(),
+            Some("https://github.com")
+        );
+        let moved = Repo {
+            url: "https://example.org/other".to_string(),
+            ..Repo::test("owner/name")
+        };
+        assert_eq!(moved.instance_url(), None);
+    }
+
     #[test]
     fn test_language_filter_empty() {
         let filter = LanguageFilter::new(vec![]);
@@ -96,4 +399,68 @@ mod tests {
         assert!(!filter.matches(Some(&"Python".to_string())));
         assert!(!filter.matches(None));
     }
+
+    #[test]
+    fn test_language_filter_include_unknown() {
+        let filter = LanguageFilter::new(vec!["rust".to_string()]).with_unknown(true);
+        assert!(filter.matches(None));
+        assert!(!filter.matches(Some(&"Go".to_string())));
+        assert!(!filter.needs_lookup());
+        assert!(LanguageFilter::new(vec!["rust".to_string()]).needs_lookup());
+    }
+
+    #[test]
+    fn test_language_filter_secondary() {
+        let breakdown = HashMap::from([
+            ("TypeScript".to_string(), 7000.0),
+            ("Rust".to_string(), 3000.0),
+        ]);
+
+        let filter = LanguageFilter::new(vec!["rust".to_string()]);
+        assert!(!filter.checks_secondary());
+        assert!(!filter.matches_breakdown(&breakdown));
+
+        let filter = filter.with_secondary(Some(25.0));
+        assert!(filter.checks_secondary());
+        assert!(filter.matches_breakdown(&breakdown));
+        assert!(!filter
+            .clone()
+            .with_secondary(Some(40.0))
+            .matches_breakdown(&breakdown));
+        assert!(!filter.matches_breakdown(&HashMap::new()));
+    }
+
+    #[test]
+    fn test_language_from_topics() {
+        assert_eq!(
+            language_from_topics(&["web".to_string(), "golang".to_string()]),
+            Some("Go".to_string())
+        );
+        assert_eq!(
+            language_from_topics(&["TypeScript".to_string()]),
+            Some("TypeScript".to_string())
+        );
+    }
+
+    #[test]
+    fn test_useful_homepage() {
+        let url = "https://github.com/owner/repo";
+        assert_eq!(
+            useful_homepage(Some("docs.example.org".to_string()), url),
+            Some("https://docs.example.org".to_string())
+        );
+        assert_eq!(
+            useful_homepage(Some("http://github.com/owner/repo/".to_string()), url),
+            None
+        );
+        assert_eq!(useful_homepage(Some("  ".to_string()), url), None);
+        assert_eq!(useful_homepage(None, url), None);
+    }
+
+    #[test]
+    fn test_primary_language() {
+        let languages = HashMap::from([("Shell".to_string(), 120.0), ("Rust".to_string(), 9000.0)]);
+        assert_eq!(primary_language(languages), Some("Rust".to_string()));
+        assert_eq!(primary_language(HashMap::new()), None);
+    }
 }
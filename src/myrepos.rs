@@ -0,0 +1,313 @@
+use anyhow::{Context, Result};
+use chrono::{NaiveDate, Utc};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use crate::config::Config;
+use crate::providers::GitHub;
+use crate::render::OutputFormat;
+use crate::state;
+
+/// Counters tracked for one of your repositories
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepoStats {
+    pub stars: u64,
+    pub open_issues: u64,
+    /// Views over the last 14 days; needs a token with push access to the repo
+    #[serde(default)]
+    pub views: Option<u64>,
+}
+
+/// Stats of every listed repository on one day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DayStats {
+    date: String, // Format: YYYY-MM-DD
+    stats: BTreeMap<String, RepoStats>,
+    /// Repos whose fetch failed that day; they are retried the next day
+    #[serde(default)]
+    failed: BTreeSet<String>,
+}
+
+/// Today's stats plus the most recent ones from an earlier day
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct StatsFile {
+    current: Option<DayStats>,
+    previous: Option<DayStats>,
+}
+
+impl StatsFile {
+    /// Repos in `repos` that haven't been fetched or tried yet today
+    fn missing<'a>(&self, today: &str, repos: &'a [String]) -> Vec<&'a String> {
+        match self.current.as_ref().filter(|c| c.date == today) {
+            Some(current) => repos
+                .iter()
+                .filter(|r| !current.stats.contains_key(*r) && !current.failed.contains(*r))
+                .collect(),
+            None => repos.iter().collect(),
+        }
+    }
+
+    /// Merge stats fetched today into today's, rotating the old day out on a new day
+    fn roll(
+        &mut self,
+        today: NaiveDate,
+        stats: BTreeMap<String, RepoStats>,
+        failed: BTreeSet<String>,
+    ) {
+        let today = today.format("%Y-%m-%d").to_string();
+        if self.current.as_ref().is_some_and(|c| c.date != today) {
+            self.previous = self.current.take();
+        }
+        let current = self.current.get_or_insert_with(|| DayStats {
+            date: today,
+            stats: BTreeMap::new(),
+            failed: BTreeSet::new(),
+        });
+        current.stats.extend(stats);
+        current.failed.extend(failed);
+    }
+}
+
+/// Daily stats of your repositories, fetched at most once per day
+pub struct MyReposStore {
+    stats_file: PathBuf,
+}
+
+impl MyReposStore {
+    /// Create a new store in the cache directory
+    pub fn new() -> Result<Self> {
+        let cache_dir = state::cache_dir()?;
+
+        Ok(Self {
+            stats_file: cache_dir.join("myrepos.json"),
+        })
+    }
+
+    /// Create a store with a custom directory (for testing)
+    #[cfg(test)]
+    fn with_dir(cache_dir: &std::path::Path) -> Self {
+        Self {
+            stats_file: cache_dir.join("myrepos.json"),
+        }
+    }
+
+    async fn load(&self) -> Result<StatsFile> {
+        Ok(state::read_json::<StatsFile>(&self.stats_file)
+            .await?
+            .unwrap_or_default())
+    }
+
+    async fn save(&self, file: &StatsFile) -> Result<()> {
+        let content =
+            serde_json::to_string_pretty(file).context("Failed to serialize repo stats")?;
+        state::write_atomic(&self.stats_file, &content)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to write repo stats file: {}",
+                    self.stats_file.display()
+                )
+            })
+    }
+}
+
+/// Dashboard of the repos in `[section.myrepos]`, one line each (empty when none)
+///
+/// Stats are fetched from GitHub once per day and compared with the last earlier
+/// day. Repos that fail to load are left out and tried again the next day.
+pub async fn dashboard(config: &Config, format: OutputFormat) -> Result<String> {
+    let repos = &config.section.myrepos.repos;
+    if repos.is_empty() || !matches!(format, OutputFormat::Motd | OutputFormat::Accessible) {
+        return Ok(String::new());
+    }
+
+    let store = MyReposStore::new()?;
+    let mut file = store.load().await?;
+    let today = Utc::now().date_naive();
+    let today_str = today.format("%Y-%m-%d").to_string();
+
+    let missing = file.missing(&today_str, repos);
+    if !missing.is_empty() {
+        let github = GitHub::new(config.general.github_timeout_secs)?;
+        let token = config.auth.github_token.as_deref();
+        let mut stats = BTreeMap::new();
+        let mut failed = BTreeSet::new();
+        for repo in missing {
+            match github.repo_stats(repo, token).await {
+                Ok(repo_stats) => {
+                    stats.insert(repo.clone(), repo_stats);
+                }
+                Err(_) => {
+                    failed.insert(repo.clone());
+                }
+            }
+        }
+        file.roll(today, stats, failed);
+        store.save(&file).await?;
+    }
+
+    let current = file.current.as_ref().map(|c| &c.stats);
+    let previous = file.previous.as_ref().map(|p| &p.stats);
+    Ok(render(repos, current, previous, format))
+}
+
+fn render(
+    repos: &[String],
+    current: Option<&BTreeMap<String, RepoStats>>,
+    previous: Option<&BTreeMap<String, RepoStats>>,
+    format: OutputFormat,
+) -> String {
+    let Some(current) = current else {
+        return String::new();
+    };
+    let width = repos.iter().map(|r| r.chars().count()).max().unwrap_or(0);
+
+    let mut out = String::new();
+    for repo in repos {
+        let Some(stats) = current.get(repo) else {
+            continue;
+        };
+        let before = previous.and_then(|p| p.get(repo));
+        let delta = |now: u64, then: Option<u64>| match then {
+            Some(then) if now != then => {
+                #[allow(clippy::cast_possible_wrap)]
+                let change = now as i64 - then as i64;
+                format!(" ({change:+})")
+            }
+            _ => String::new(),
+        };
+        let star_change = delta(stats.stars, before.map(|b| b.stars));
+        let issue_change = delta(stats.open_issues, before.map(|b| b.open_issues));
+        let views = stats
+            .views
+            .map(|v| (v, delta(v, before.and_then(|b| b.views))));
+
+        if matches!(format, OutputFormat::Accessible) {
+            let _ = write!(
+                out,
+                "Your repository {repo}: {} stars{star_change}, {} open issues{issue_change}",
+                stats.stars, stats.open_issues
+            );
+            if let Some((views, change)) = views {
+                let _ = write!(out, ", {views} views in 14 days{change}");
+            }
+        } else {
+            let _ = write!(
+                out,
+                "  📊 {:<width$}  {}  {}",
+                repo.bright_cyan(),
+                format!("★{}{star_change}", stats.stars).bright_green(),
+                format!("issues {}{issue_change}", stats.open_issues).yellow()
+            );
+            if let Some((views, change)) = views {
+                let _ = write!(out, "  {}", format!("views {views}{change}").bright_black());
+            }
+        }
+        out.push('\n');
+    }
+
+    if out.is_empty() {
+        out
+    } else {
+        format!("\n{out}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(stars: u64, open_issues: u64, views: Option<u64>) -> RepoStats {
+        RepoStats {
+            stars,
+            open_issues,
+            views,
+        }
+    }
+
+    #[test]
+    fn test_render_deltas() {
+        colored::control::set_override(false);
+        let repos = vec!["me/tool".to_string(), "me/missing".to_string()];
+        let current = BTreeMap::from([("me/tool".to_string(), stats(120, 4, Some(300)))]);
+        let previous = BTreeMap::from([("me/tool".to_string(), stats(100, 5, None))]);
+
+        let out = render(&repos, Some(&current), Some(&previous), OutputFormat::Motd);
+        assert!(out.contains("★120 (+20)"));
+        assert!(out.contains("issues 4 (-1)"));
+        assert!(out.contains("views 300"));
+        assert!(!out.contains("me/missing"));
+
+        let out = render(&repos, Some(&current), None, OutputFormat::Accessible);
+        assert!(out.contains("Your repository me/tool: 120 stars, 4 open issues, 300 views"));
+
+        assert!(render(&repos, None, None, OutputFormat::Motd).is_empty());
+    }
+
+    #[test]
+    fn test_failed_repos_wait_for_the_next_day() {
+        let repos = vec!["me/tool".to_string(), "me/typo".to_string()];
+        let day = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let mut file = StatsFile::default();
+
+        file.roll(
+            day,
+            BTreeMap::from([(repos[0].clone(), stats(1, 0, None))]),
+            BTreeSet::from([repos[1].clone()]),
+        );
+        assert!(file.missing("2024-05-01", &repos).is_empty());
+        assert_eq!(file.missing("2024-05-02", &repos).len(), 2);
+
+        // A later fetch of one repo keeps the stats of the others
+        file.roll(
+            day,
+            BTreeMap::from([("me/new".to_string(), stats(7, 1, None))]),
+            BTreeSet::new(),
+        );
+        let current = &file.current.as_ref().unwrap().stats;
+        assert_eq!(current.len(), 2);
+        assert_eq!(current[&repos[0]].stars, 1);
+    }
+
+    #[tokio::test]
+    async fn test_roll_keeps_previous_day() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-myrepos-test-{}",
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let store = MyReposStore::with_dir(&temp_dir);
+        let repos = vec!["me/tool".to_string()];
+        let day1 = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let day2 = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
+
+        let mut file = store.load().await.unwrap();
+        file.roll(
+            day1,
+            BTreeMap::from([(repos[0].clone(), stats(1, 0, None))]),
+            BTreeSet::new(),
+        );
+        store.save(&file).await.unwrap();
+
+        let mut file = store.load().await.unwrap();
+        assert!(file.missing("2024-05-01", &repos).is_empty());
+        assert_eq!(file.missing("2024-05-02", &repos), vec![&repos[0]]);
+        file.roll(
+            day2,
+            BTreeMap::from([(repos[0].clone(), stats(2, 0, None))]),
+            BTreeSet::new(),
+        );
+        file.roll(
+            day2,
+            BTreeMap::from([(repos[0].clone(), stats(3, 0, None))]),
+            BTreeSet::new(),
+        );
+
+        assert_eq!(file.previous.as_ref().unwrap().date, "2024-05-01");
+        assert_eq!(file.current.as_ref().unwrap().stats[&repos[0]].stars, 3);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
@@ -0,0 +1,133 @@
+use anyhow::{Context, Result};
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::bookmarks::Bookmark;
+
+/// Notes written and skipped by one export
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ExportSummary {
+    pub written: usize,
+    /// Notes that already existed; they are left alone so edits in the vault survive
+    pub skipped: usize,
+}
+
+/// Write one Markdown note with YAML front matter per repository into `dir`
+///
+/// Notes are named after the repository (`owner__repo.md`, so `a-b/c` and `a/b-c` stay apart). Existing notes are never
+/// overwritten.
+pub async fn export(dir: &Path, entries: &[Bookmark]) -> Result<ExportSummary> {
+    tokio::fs::create_dir_all(dir)
+        .await
+        .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+
+    let mut summary = ExportSummary::default();
+    for entry in entries {
+        let path = dir.join(file_name(&entry.name));
+        if tokio::fs::try_exists(&path).await.unwrap_or(false) {
+            summary.skipped += 1;
+            continue;
+        }
+        tokio::fs::write(&path, note(entry))
+            .await
+            .with_context(|| format!("Failed to write note: {}", path.display()))?;
+        summary.written += 1;
+    }
+    Ok(summary)
+}
+
+/// File name for a repository note, safe on every platform
+///
+/// The owner separator becomes `__`, which repository names on the forges can't contain,
+/// so names that differ only in where the `/` sits don't share a note.
+fn file_name(name: &str) -> String {
+    let mut stem = String::with_capacity(name.len() + 1);
+    for c in name.chars() {
+        match c {
+            '/' => stem.push_str("__"),
+            '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => stem.push('-'),
+            c => stem.push(c),
+        }
+    }
+    format!("{stem}.md")
+}
+
+fn note(entry: &Bookmark) -> String {
+    // JSON strings are valid YAML scalars, which sidesteps YAML's quoting rules
+    let quote = |s: &str| serde_json::Value::from(s).to_string();
+
+    let mut out = String::from("---\n");
+    let _ = writeln!(out, "title: {}", quote(&entry.name));
+    let _ = writeln!(out, "url: {}", quote(&entry.url));
+    if let Some(stars) = entry.stars {
+        let _ = writeln!(out, "stars: {stars}");
+    }
+    if let Some(ref language) = entry.language {
+        let _ = writeln!(out, "language: {}", quote(language));
+    }
+    let tags: Vec<String> = entry.tags().iter().map(|t| quote(t)).collect();
+    let _ = writeln!(out, "tags: [{}]", tags.join(", "));
+    let _ = writeln!(out, "found: {}", entry.added.format("%Y-%m-%d"));
+    out.push_str("---\n\n");
+
+    let _ = writeln!(out, "# {}\n", entry.name);
+    if let Some(ref description) = entry.description {
+        let _ = writeln!(out, "{description}\n");
+    }
+    let _ = writeln!(out, "<{}>", entry.url);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_export_writes_and_keeps_notes() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-notes-test-{}",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let entry = Bookmark {
+            description: Some("A \"fast\" tool".to_string()),
+            language: Some("Rust".to_string()),
+            topics: vec!["cli".to_string()],
+            stars: Some(42),
+            ..Bookmark::from_url("owner/tool", "https://github.com/owner/tool")
+        };
+
+        let summary = export(&temp_dir, std::slice::from_ref(&entry))
+            .await
+            .unwrap();
+        assert_eq!(summary.written, 1);
+
+        let path = temp_dir.join("owner__tool.md");
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with(
+            "---\ntitle: \"owner/tool\"\nurl: \"https://github.com/owner/tool\"\nstars: 42\n"
+        ));
+        assert!(content.contains("tags: [\"rust\", \"cli\"]\n"));
+        assert!(content.contains("\n---\n\n# owner/tool\n\nA \"fast\" tool\n"));
+
+        std::fs::write(&path, "edited").unwrap();
+        let summary = export(&temp_dir, &[entry]).await.unwrap();
+        assert_eq!(
+            summary,
+            ExportSummary {
+                written: 0,
+                skipped: 1
+            }
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "edited");
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_file_name_keeps_owners_apart() {
+        assert_eq!(file_name("a-b/c"), "a-b__c.md");
+        assert_eq!(file_name("a/b-c"), "a__b-c.md");
+        assert_eq!(file_name("group/sub/project"), "group__sub__project.md");
+        assert_eq!(file_name("we:ird/name?"), "we-ird__name-.md");
+    }
+}
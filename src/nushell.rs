@@ -0,0 +1,150 @@
+use clap::Command;
+use serde_json::Value;
+use std::fmt::Write;
+
+/// Format a JSON value as NUON (Nushell Object Notation)
+///
+/// Strings keep their JSON escaping, which NUON accepts; record keys are bare
+/// when they are plain identifiers.
+pub fn to_nuon(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => Value::String(s.clone()).to_string(),
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(to_nuon).collect();
+            format!("[{}]", items.join(", "))
+        }
+        Value::Object(fields) => {
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|(key, value)| format!("{}: {}", nuon_key(key), to_nuon(value)))
+                .collect();
+            format!("{{{}}}", fields.join(", "))
+        }
+    }
+}
+
+fn nuon_key(key: &str) -> String {
+    let mut chars = key.chars();
+    let is_bare = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if is_bare {
+        key.to_string()
+    } else {
+        Value::String(key.to_string()).to_string()
+    }
+}
+
+/// Generate Nushell completions as `export extern` definitions for every subcommand
+///
+/// Arguments with a fixed set of values get a completer listing them.
+pub fn completions(cmd: &mut Command) -> String {
+    cmd.build();
+    let mut out = String::new();
+    write_command(&mut out, cmd, cmd.get_name());
+    out
+}
+
+fn write_command(out: &mut String, cmd: &Command, path: &str) {
+    // Value completers have to be defined before the extern that uses them
+    for arg in cmd.get_arguments() {
+        let values: Vec<String> = arg
+            .get_possible_values()
+            .iter()
+            .filter(|value| !value.is_hide_set())
+            .map(|value| Value::String(value.get_name().to_string()).to_string())
+            .collect();
+        if !values.is_empty() && arg.get_action().takes_values() {
+            let _ = writeln!(
+                out,
+                "def \"nu-complete {path} {}\" [] {{ [{}] }}\n",
+                arg.get_id(),
+                values.join(" ")
+            );
+        }
+    }
+
+    let _ = writeln!(out, "export extern \"{path}\" [");
+    for arg in cmd.get_arguments().filter(|arg| !arg.is_hide_set()) {
+        let mut line = if arg.is_positional() {
+            let optional = if arg.is_required_set() { "" } else { "?" };
+            format!("    {}{optional}", arg.get_id())
+        } else {
+            let long = arg.get_long().unwrap_or(arg.get_id().as_str());
+            match arg.get_short() {
+                Some(short) => format!("    --{long}({short})"),
+                None => format!("    --{long}"),
+            }
+        };
+        if arg.get_action().takes_values() {
+            line.push_str(": string");
+            if arg.get_possible_values().iter().any(|v| !v.is_hide_set()) {
+                let _ = write!(line, "@\"nu-complete {path} {}\"", arg.get_id());
+            }
+        }
+        if let Some(help) = arg.get_help() {
+            let _ = write!(line, "  # {help}");
+        }
+        let _ = writeln!(out, "{line}");
+    }
+    let _ = writeln!(out, "]\n");
+
+    for sub in cmd.get_subcommands().filter(|sub| !sub.is_hide_set()) {
+        write_command(out, sub, &format!("{path} {}", sub.get_name()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{Arg, ArgAction};
+    use serde_json::json;
+
+    #[test]
+    fn test_to_nuon() {
+        let value = json!([{
+            "name": "owner/repo",
+            "stars_today": 120,
+            "language": null,
+            "is_seen": false,
+            "topics": ["cli", "rust"],
+            "odd key": "a \"quoted\" value"
+        }]);
+        let nuon = to_nuon(&value);
+        assert!(nuon.starts_with("[{"));
+        assert!(nuon.contains(r#"name: "owner/repo""#));
+        assert!(nuon.contains("stars_today: 120"));
+        assert!(nuon.contains("language: null"));
+        assert!(nuon.contains(r#"topics: ["cli", "rust"]"#));
+        assert!(nuon.contains(r#""odd key": "a \"quoted\" value""#));
+    }
+
+    #[test]
+    fn test_completions() {
+        let mut cmd = Command::new("tool")
+            .arg(
+                Arg::new("json")
+                    .long("json")
+                    .action(ArgAction::SetTrue)
+                    .help("Output as JSON"),
+            )
+            .arg(
+                Arg::new("once_per")
+                    .long("once-per")
+                    .value_parser(["day", "hour"]),
+            )
+            .subcommand(Command::new("star").arg(Arg::new("repo").required(true)));
+
+        let out = completions(&mut cmd);
+        assert!(out.contains("export extern \"tool\" ["));
+        assert!(out.contains("    --json  # Output as JSON"));
+        assert!(out.contains(r#"def "nu-complete tool once_per" [] { ["day" "hour"] }"#));
+        assert!(out.contains(r#"--once-per: string@"nu-complete tool once_per""#));
+        assert!(out.contains("export extern \"tool star\" ["));
+        assert!(out.contains("    repo: string"));
+    }
+}
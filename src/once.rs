@@ -0,0 +1,125 @@
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::state;
+
+/// Window within which trotd runs at most once
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OncePer {
+    Day,
+    Hour,
+    Login,
+}
+
+/// Marker recording the last window trotd ran in and what it printed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Marker {
+    window: String,
+    output: String,
+}
+
+/// Filesystem-based guard that skips repeated runs within a window
+pub struct OnceGuard {
+    marker_file: PathBuf,
+}
+
+impl OnceGuard {
+    /// Create a new guard instance
+    pub fn new() -> Result<Self> {
+        let cache_dir = state::cache_dir()?;
+
+        Ok(Self {
+            marker_file: cache_dir.join("once.json"),
+        })
+    }
+
+    /// Return the previous output if trotd already ran in the current window
+    pub async fn previous_output(&self, per: OncePer) -> Option<String> {
+        let marker: Marker = state::read_json(&self.marker_file).await.ok()??;
+        (marker.window == window_key(per)).then_some(marker.output)
+    }
+
+    /// Record a completed run in the current window
+    pub async fn record(&self, per: OncePer, output: &str) -> Result<()> {
+        let marker = Marker {
+            window: window_key(per),
+            output: output.to_string(),
+        };
+        let content =
+            serde_json::to_string_pretty(&marker).context("Failed to serialize once marker")?;
+        state::write_atomic(&self.marker_file, &content)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to write once marker: {}",
+                    self.marker_file.display()
+                )
+            })
+    }
+}
+
+/// Identify the current window, e.g. `day:2025-01-31` or `login:3`
+fn window_key(per: OncePer) -> String {
+    let now = chrono::Utc::now();
+    match per {
+        OncePer::Day => format!("day:{}", now.format("%Y-%m-%d")),
+        OncePer::Hour => format!("hour:{}", now.format("%Y-%m-%dT%H")),
+        OncePer::Login => format!("login:{}", login_id()),
+    }
+}
+
+/// Best-effort identifier of the current login session
+///
+/// Uses the systemd-logind session when available and falls back to the boot id,
+/// so at worst the guard behaves like "once per boot".
+fn login_id() -> String {
+    if let Ok(session) = std::env::var("XDG_SESSION_ID") {
+        if !session.is_empty() {
+            return session;
+        }
+    }
+
+    std::fs::read_to_string("/proc/sys/kernel/random/boot_id").map_or_else(
+        |_| format!("day-{}", chrono::Utc::now().format("%Y-%m-%d")),
+        |id| format!("boot-{}", id.trim()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_once_guard_roundtrip() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-once-test-{}",
+            chrono::Utc::now().timestamp()
+        ));
+        let guard = OnceGuard {
+            marker_file: temp_dir.join("once.json"),
+        };
+
+        // Never ran before
+        assert!(guard.previous_output(OncePer::Day).await.is_none());
+
+        guard.record(OncePer::Day, "rendered\n").await.unwrap();
+        assert_eq!(
+            guard.previous_output(OncePer::Day).await.as_deref(),
+            Some("rendered\n")
+        );
+
+        // A different window kind does not count as having run
+        assert!(guard.previous_output(OncePer::Hour).await.is_none());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_window_keys() {
+        assert!(window_key(OncePer::Day).starts_with("day:"));
+        assert!(window_key(OncePer::Hour).starts_with("hour:"));
+        assert!(window_key(OncePer::Login).starts_with("login:"));
+    }
+}
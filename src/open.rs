@@ -0,0 +1,115 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::model::Repo;
+use crate::state;
+
+/// Repositories of the last rendered run, so `open` can refer to them by position
+pub struct LastShown {
+    file: PathBuf,
+}
+
+impl LastShown {
+    /// Create a new last-shown store instance
+    pub fn new() -> Result<Self> {
+        let cache_dir = state::cache_dir()?;
+
+        Ok(Self::with_dir(&cache_dir))
+    }
+
+    fn with_dir(cache_dir: &Path) -> Self {
+        Self {
+            file: cache_dir.join("last-shown.json"),
+        }
+    }
+
+    /// Load the repositories of the last run (empty if nothing was shown yet)
+    pub async fn load(&self) -> Result<Vec<Repo>> {
+        Ok(state::read_json(&self.file).await?.unwrap_or_default())
+    }
+
+    /// Save the repositories of the current run
+    pub async fn save(&self, repos: &[Repo]) -> Result<()> {
+        let content =
+            serde_json::to_string_pretty(repos).context("Failed to serialize shown repos")?;
+        state::write_atomic(&self.file, &content)
+            .await
+            .with_context(|| format!("Failed to write file: {}", self.file.display()))
+    }
+}
+
+/// Find a repository by 1-based position or by name (`owner/repo`)
+pub fn resolve<'a>(repos: &'a [Repo], selector: &str) -> Option<&'a Repo> {
+    match selector.parse::<usize>() {
+        Ok(position) => position.checked_sub(1).and_then(|idx| repos.get(idx)),
+        Err(_) => repos
+            .iter()
+            .find(|repo| repo.name.eq_ignore_ascii_case(selector)),
+    }
+}
+
+/// Open a URL with the platform's default handler
+pub fn open_url(url: &str) -> Result<()> {
+    let mut cmd = if cfg!(target_os = "macos") {
+        std::process::Command::new("open")
+    } else if cfg!(target_os = "windows") {
+        let mut cmd = std::process::Command::new("cmd");
+        cmd.args(["/C", "start", ""]);
+        cmd
+    } else {
+        std::process::Command::new("xdg-open")
+    };
+
+    let status = cmd
+        .arg(url)
+        .status()
+        .context("Failed to launch a browser")?;
+    if !status.success() {
+        anyhow::bail!("Opening {url} failed ({status})");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_repo(name: &str) -> Repo {
+        Repo {
+            homepage: Some("https://example.org".to_string()),
+            ..Repo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_resolve_by_position_and_name() {
+        let repos = vec![create_test_repo("a/one"), create_test_repo("b/two")];
+
+        assert_eq!(resolve(&repos, "2").map(|r| r.name.as_str()), Some("b/two"));
+        assert_eq!(
+            resolve(&repos, "A/One").map(|r| r.name.as_str()),
+            Some("a/one")
+        );
+        assert!(resolve(&repos, "0").is_none());
+        assert!(resolve(&repos, "3").is_none());
+        assert!(resolve(&repos, "c/three").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_last_shown_roundtrip() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-open-test-{}",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let store = LastShown::with_dir(&temp_dir);
+
+        assert!(store.load().await.unwrap().is_empty());
+
+        store.save(&[create_test_repo("a/one")]).await.unwrap();
+        let repos = store.load().await.unwrap();
+        assert_eq!(repos.len(), 1);
+        assert_eq!(repos[0].homepage.as_deref(), Some("https://example.org"));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
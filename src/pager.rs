@@ -0,0 +1,93 @@
+use std::io::{self, IsTerminal, Write};
+use std::process::Stdio;
+
+use crate::hooks;
+
+/// Pager used when neither `general.pager` nor `$PAGER` is set
+const DEFAULT_PAGER: &str = "less";
+
+/// `less` options like git's: quit if one screen, keep colors, don't clear the screen
+const LESS_OPTIONS: &str = "FRX";
+
+/// Print `output`, through a pager when it doesn't fit on the terminal
+///
+/// Paging only happens when stdout is a terminal and `output` has more lines than
+/// the terminal is high. The pager is `configured` (`general.pager`), else
+/// `$PAGER`, else `less`; an empty command or `cat` turns paging off. If the
+/// pager can't be started the output is printed as usual.
+pub fn print(output: &str, configured: Option<&str>) {
+    let stdout = io::stdout();
+    let height = terminal_size::terminal_size().map(|(_, h)| usize::from(h.0));
+    let command = pager_command(configured, std::env::var("PAGER").ok());
+    match command {
+        Some(command) if stdout.is_terminal() && exceeds(output, height) => {
+            if page(output, &command).is_err() {
+                print!("{output}");
+            }
+        }
+        _ => print!("{output}"),
+    }
+}
+
+/// The pager to run, or `None` when paging is turned off
+fn pager_command(configured: Option<&str>, env: Option<String>) -> Option<String> {
+    let command = match configured {
+        Some(command) => command.to_string(),
+        None => env.unwrap_or_else(|| DEFAULT_PAGER.to_string()),
+    };
+    let command = command.trim();
+    if command.is_empty() || command == "cat" {
+        None
+    } else {
+        Some(command.to_string())
+    }
+}
+
+/// Whether `output` needs more lines than the terminal has, leaving one for the prompt
+fn exceeds(output: &str, height: Option<usize>) -> bool {
+    height.is_some_and(|height| output.lines().count() >= height)
+}
+
+fn page(output: &str, command: &str) -> io::Result<()> {
+    let mut cmd = hooks::shell_command(command);
+    if std::env::var_os("LESS").is_none() {
+        cmd.env("LESS", LESS_OPTIONS);
+    }
+    let mut child = cmd.stdin(Stdio::piped()).spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // The user may quit the pager before reading everything
+        match stdin.write_all(output.as_bytes()) {
+            Err(e) if e.kind() != io::ErrorKind::BrokenPipe => return Err(e),
+            _ => {}
+        }
+    }
+    child.wait()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pager_command() {
+        assert_eq!(pager_command(None, None).as_deref(), Some("less"));
+        assert_eq!(
+            pager_command(None, Some("most".to_string())).as_deref(),
+            Some("most")
+        );
+        assert_eq!(
+            pager_command(Some("bat -p"), Some("most".to_string())).as_deref(),
+            Some("bat -p")
+        );
+        assert_eq!(pager_command(Some(""), Some("most".to_string())), None);
+        assert_eq!(pager_command(None, Some("cat".to_string())), None);
+    }
+
+    #[test]
+    fn test_exceeds() {
+        assert!(exceeds("a\nb\nc\n", Some(3)));
+        assert!(!exceeds("a\nb\n", Some(3)));
+        assert!(!exceeds("a\nb\nc\n", None));
+    }
+}
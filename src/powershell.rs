@@ -0,0 +1,52 @@
+/// Subcommands whose positional argument is a repository name
+const REPO_SUBCOMMANDS: &[&str] = &["star", "unstar", "open", "clone"];
+
+/// Extend the generated PowerShell completer with repository names from the last run
+///
+/// The names come from the hidden `complete-repos` subcommand, so they follow
+/// whatever trotd showed most recently. Scripts without the expected shape are
+/// returned unchanged.
+pub fn with_repo_completion(script: &str, bin_name: &str) -> String {
+    let anchor = "    $completions.Where{";
+    let Some(pos) = script.rfind(anchor) else {
+        return script.to_string();
+    };
+
+    let commands: Vec<String> = REPO_SUBCOMMANDS
+        .iter()
+        .map(|sub| format!("'{bin_name};{sub}'"))
+        .collect();
+    let block = format!(
+        "    if ($command -in @({}) -and -not $wordToComplete.StartsWith('-')) {{
+        $completions += @(& '{bin_name}' complete-repos 2>$null | ForEach-Object {{
+            [CompletionResult]::new($_, $_, [CompletionResultType]::ParameterValue, $_)
+        }})
+    }}
+
+",
+        commands.join(", ")
+    );
+
+    let mut out = String::with_capacity(script.len() + block.len());
+    out.push_str(&script[..pos]);
+    out.push_str(&block);
+    out.push_str(&script[pos..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_repo_completion() {
+        let script =
+            "    $completions = @(switch ($command) {\n    })\n\n    $completions.Where{ $_ }\n}\n";
+        let out = with_repo_completion(script, "git-trending");
+        assert!(out.contains("'git-trending;star', 'git-trending;unstar'"));
+        assert!(out.contains("& 'git-trending' complete-repos"));
+        assert!(out.find("complete-repos") < out.find("$completions.Where"));
+
+        assert_eq!(with_repo_completion("other", "git-trending"), "other");
+    }
+}
@@ -0,0 +1,97 @@
+use std::io::{IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+use crate::quiet;
+
+const FRAMES: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// Interval between spinner redraws
+pub const TICK: Duration = Duration::from_millis(100);
+
+/// Whether an interactive progress line can be shown (stderr is a TTY and not quiet)
+pub fn available() -> bool {
+    std::io::stderr().is_terminal() && !quiet::enabled()
+}
+
+/// Animated single-line spinner listing providers that are still being fetched
+pub struct Progress {
+    pending: Vec<String>,
+    started: Instant,
+    frame: usize,
+    enabled: bool,
+}
+
+impl Progress {
+    /// Create a spinner for the given providers; a disabled spinner never draws
+    pub fn new(pending: Vec<String>, enabled: bool) -> Self {
+        Self {
+            pending,
+            started: Instant::now(),
+            frame: 0,
+            enabled,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Mark a provider as finished
+    pub fn finish(&mut self, provider: &str) {
+        self.pending.retain(|p| p != provider);
+    }
+
+    /// Advance the animation and redraw the status line
+    pub fn tick(&mut self) {
+        if !self.enabled || self.pending.is_empty() {
+            return;
+        }
+        let line = status_line(&self.pending, self.started.elapsed(), self.frame);
+        self.frame = self.frame.wrapping_add(1);
+        let mut stderr = std::io::stderr();
+        let _ = write!(stderr, "\r\x1b[2K{line}");
+        let _ = stderr.flush();
+    }
+
+    /// Erase the status line so regular output starts on a clean line
+    pub fn clear(&self) {
+        if self.enabled {
+            let mut stderr = std::io::stderr();
+            let _ = write!(stderr, "\r\x1b[2K");
+            let _ = stderr.flush();
+        }
+    }
+}
+
+/// Format the spinner line, e.g. `⠋ Fetching github, gitea (2.3s)`
+fn status_line(pending: &[String], elapsed: Duration, frame: usize) -> String {
+    format!(
+        "{} Fetching {} ({:.1}s)",
+        FRAMES[frame % FRAMES.len()],
+        pending.join(", "),
+        elapsed.as_secs_f64()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_line() {
+        let pending = vec!["github".to_string(), "gitea".to_string()];
+        assert_eq!(
+            status_line(&pending, Duration::from_millis(2300), 0),
+            "⠋ Fetching github, gitea (2.3s)"
+        );
+        assert!(status_line(&pending, Duration::ZERO, FRAMES.len() + 1).starts_with('⠙'));
+    }
+
+    #[test]
+    fn test_finish_removes_provider() {
+        let mut progress = Progress::new(vec!["github".to_string(), "gitlab".to_string()], false);
+        progress.finish("github");
+        assert_eq!(progress.pending, vec!["gitlab".to_string()]);
+        assert!(!progress.is_enabled());
+    }
+}
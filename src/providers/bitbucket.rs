@@ -0,0 +1,274 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::future::join_all;
+use serde::Deserialize;
+
+use crate::http::HttpClient;
+use crate::model::{
+    language_from_topics, useful_homepage, LanguageFilter, Provider, ProviderCfg, Repo,
+};
+
+/// API root used when no base URL is configured
+const API_URL: &str = "https://api.bitbucket.org/2.0";
+
+/// Recently updated repos whose watchers are looked up before ranking
+const WATCHER_LOOKUP_LIMIT: usize = 30;
+
+/// Bitbucket Cloud provider using the REST 2.0 API
+///
+/// Bitbucket has no stars, so recently updated public repos are ranked by watchers.
+pub struct Bitbucket {
+    http: HttpClient,
+}
+
+#[derive(Debug, Deserialize)]
+struct Page<T> {
+    values: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketRepo {
+    full_name: String,
+    description: Option<String>,
+    /// Lowercase (`rust`, `c++`), empty when unknown
+    language: Option<String>,
+    updated_on: Option<String>,
+    website: Option<String>,
+    links: Links,
+}
+
+#[derive(Debug, Deserialize)]
+struct Links {
+    html: Link,
+}
+
+#[derive(Debug, Deserialize)]
+struct Link {
+    href: String,
+}
+
+impl Bitbucket {
+    pub fn new(timeout_secs: u64) -> Result<Self> {
+        Ok(Self {
+            http: HttpClient::new(timeout_secs)?,
+        })
+    }
+
+    /// Create a Bitbucket provider with a custom `HttpClient`
+    #[allow(dead_code)]
+    pub fn with_client(http: HttpClient) -> Self {
+        Self { http }
+    }
+
+    /// Public repositories updated within the last `days` days, most recent first
+    async fn fetch_repos(
+        &self,
+        api: &str,
+        days: i64,
+        authorization: Option<&str>,
+    ) -> Result<Vec<BitbucketRepo>> {
+        let since = (chrono::Utc::now() - chrono::Duration::days(days)).format("%Y-%m-%d");
+        let url = format!("{api}/repositories?q=updated_on%3E{since}&sort=-updated_on&pagelen=100");
+        let page: Page<BitbucketRepo> = self.http.get_json_with_auth(&url, authorization).await?;
+        Ok(page.values)
+    }
+
+    /// Number of watchers, Bitbucket's closest thing to stars
+    async fn watchers(
+        &self,
+        api: &str,
+        full_name: &str,
+        authorization: Option<&str>,
+    ) -> Option<u64> {
+        #[derive(Deserialize)]
+        struct Count {
+            size: u64,
+        }
+
+        let url = format!("{api}/repositories/{full_name}/watchers?pagelen=1");
+        let count: Count = self
+            .http
+            .get_json_with_auth(&url, authorization)
+            .await
+            .ok()?;
+        Some(count.size)
+    }
+}
+
+/// Display name of a Bitbucket language (`rust` → `Rust`); `None` when unset
+fn normalize_language(language: Option<String>) -> Option<String> {
+    let language = language.filter(|l| !l.trim().is_empty())?;
+    language_from_topics(std::slice::from_ref(&language)).or(Some(language))
+}
+
+#[async_trait]
+impl Provider for Bitbucket {
+    fn id(&self) -> &'static str {
+        "bitbucket"
+    }
+
+    fn icon(&self) -> &'static str {
+        "[BB]"
+    }
+
+    async fn top_today(
+        &self,
+        cfg: &ProviderCfg,
+        offset: usize,
+        limit: usize,
+        langs: &LanguageFilter,
+    ) -> Result<Vec<Repo>> {
+        let api = cfg
+            .base_url
+            .as_deref()
+            .unwrap_or(API_URL)
+            .trim_end_matches('/');
+        let authorization = cfg.token.as_deref();
+        let days = if cfg.weekly { 7 } else { 1 };
+
+        let candidates: Vec<_> = self
+            .fetch_repos(api, days, authorization)
+            .await?
+            .into_iter()
+            .map(|repo| {
+                let language = normalize_language(repo.language.clone());
+                (repo, language)
+            })
+            .filter(|(_, language)| langs.matches(language.as_ref()))
+            .take(WATCHER_LOOKUP_LIMIT)
+            .collect();
+
+        let lookups = candidates
+            .iter()
+            .map(|(repo, _)| self.watchers(api, &repo.full_name, authorization));
+        let watchers = join_all(lookups).await;
+
+        let mut ranked: Vec<_> = candidates.into_iter().zip(watchers).collect();
+        // Stable sort keeps the most recently updated first among equals
+        ranked.sort_by_key(|(_, watchers)| std::cmp::Reverse(watchers.unwrap_or(0)));
+
+        let repos = ranked
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|((repo, language), watchers)| {
+                let last_activity = repo
+                    .updated_on
+                    .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
+                    .map(|dt| dt.with_timezone(&chrono::Utc));
+                let url = repo.links.html.href;
+
+                Repo {
+                    provider: self.id().to_string(),
+                    icon: self.icon().to_string(),
+                    name: repo.full_name,
+                    language,
+                    description: repo.description.filter(|d| !d.trim().is_empty()),
+                    homepage: useful_homepage(repo.website, &url),
+                    url,
+                    stars_today: None, // Bitbucket has no stars, let alone daily ones
+                    stars_total: watchers,
+                    last_activity,
+                    topics: Vec::new(),
+                    is_starred: false,
+                    is_cloned: false,
+                    is_seen: false,
+                    movement: None,
+                    health: None,
+                    license: None,
+                    is_sponsorable: false,
+                    has_binaries: false,
+                    has_container: false,
+                    is_suspicious: false,
+                }
+            })
+            .collect();
+
+        Ok(repos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::TopicFilter;
+    use crate::model::GiteaRanking;
+
+    #[test]
+    fn test_normalize_language() {
+        assert_eq!(
+            normalize_language(Some("rust".to_string())).as_deref(),
+            Some("Rust")
+        );
+        assert_eq!(
+            normalize_language(Some("c++".to_string())).as_deref(),
+            Some("C++")
+        );
+        assert_eq!(
+            normalize_language(Some("cobol".to_string())).as_deref(),
+            Some("cobol")
+        );
+        assert_eq!(normalize_language(Some(String::new())), None);
+    }
+
+    #[tokio::test]
+    async fn test_ranked_by_watchers() {
+        let mut server = mockito::Server::new_async().await;
+        let updated_on = chrono::Utc::now().to_rfc3339();
+        let repo = |name: &str, language: &str| {
+            format!(
+                r#"{{"full_name": "team/{name}", "description": "", "language": "{language}",
+                    "updated_on": "{updated_on}", "website": null,
+                    "links": {{"html": {{"href": "https://bitbucket.org/team/{name}"}}}}}}"#
+            )
+        };
+        let listing = server
+            .mock("GET", "/repositories")
+            .match_query(mockito::Matcher::Any)
+            .match_header("authorization", "Bearer secret")
+            .with_body(format!(
+                r#"{{"values": [{}, {}, {}]}}"#,
+                repo("quiet", "rust"),
+                repo("popular", "rust"),
+                repo("other", "python")
+            ))
+            .create_async()
+            .await;
+        for (name, size) in [("quiet", 1), ("popular", 40)] {
+            server
+                .mock(
+                    "GET",
+                    format!("/repositories/team/{name}/watchers").as_str(),
+                )
+                .match_query(mockito::Matcher::Any)
+                .with_body(format!(r#"{{"size": {size}, "values": []}}"#))
+                .create_async()
+                .await;
+        }
+
+        let http = HttpClient::builder().max_retries(0).build().unwrap();
+        let bitbucket = Bitbucket::with_client(http);
+        let cfg = ProviderCfg {
+            timeout_secs: 5,
+            token: Some("Bearer secret".to_string()),
+            base_url: Some(server.url()),
+            topics: TopicFilter::default(),
+            gitea_ranking: GiteaRanking::default(),
+            weekly: false,
+            query: None,
+            hub_kinds: Vec::new(),
+        };
+        let repos = bitbucket
+            .top_today(&cfg, 0, 5, &LanguageFilter::new(vec!["rust".to_string()]))
+            .await
+            .unwrap();
+
+        listing.assert_async().await;
+        let names: Vec<_> = repos.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["team/popular", "team/quiet"]);
+        assert_eq!(repos[0].stars_total, Some(40));
+        assert_eq!(repos[0].language.as_deref(), Some("Rust"));
+        assert_eq!(repos[0].description, None);
+        assert_eq!(repos[0].provider, "bitbucket");
+    }
+}
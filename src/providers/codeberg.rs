@@ -0,0 +1,123 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::model::{GiteaRanking, LanguageFilter, Provider, ProviderCfg, Repo};
+use crate::providers::Gitea;
+
+/// Base URL used when `[codeberg] base_url` isn't set
+const CODEBERG_URL: &str = "https://codeberg.org";
+
+/// Codeberg provider: Codeberg runs Forgejo, so the Gitea API does the fetching
+pub struct Codeberg {
+    gitea: Gitea,
+}
+
+impl Codeberg {
+    pub fn new(timeout_secs: u64) -> Result<Self> {
+        Ok(Self {
+            gitea: Gitea::new(timeout_secs)?,
+        })
+    }
+
+    /// Create a Codeberg provider on top of a custom Gitea client
+    #[allow(dead_code)]
+    pub fn with_gitea(gitea: Gitea) -> Self {
+        Self { gitea }
+    }
+}
+
+#[async_trait]
+impl Provider for Codeberg {
+    fn id(&self) -> &'static str {
+        "codeberg"
+    }
+
+    fn icon(&self) -> &'static str {
+        "[CB]"
+    }
+
+    /// Repos starred most in the last 24h; on a quiet day, or when the activity
+    /// feeds fail, the most-starred repos updated today stand in
+    async fn top_today(
+        &self,
+        cfg: &ProviderCfg,
+        offset: usize,
+        limit: usize,
+        langs: &LanguageFilter,
+    ) -> Result<Vec<Repo>> {
+        let mut cfg = cfg.clone();
+        cfg.base_url = cfg.base_url.or_else(|| Some(CODEBERG_URL.to_string()));
+
+        let ranked = self.gitea.top_today(&cfg, offset, limit, langs).await;
+        let repos = match ranked {
+            Ok(repos) if !repos.is_empty() || cfg.gitea_ranking != GiteaRanking::RecentStars => {
+                repos
+            }
+            _ => {
+                cfg.gitea_ranking = GiteaRanking::StarredUpdatedToday;
+                self.gitea.top_today(&cfg, offset, limit, langs).await?
+            }
+        };
+
+        Ok(repos
+            .into_iter()
+            .map(|repo| Repo {
+                provider: self.id().to_string(),
+                icon: self.icon().to_string(),
+                ..repo
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::TopicFilter;
+    use crate::http::HttpClient;
+
+    #[tokio::test]
+    async fn test_quiet_day_falls_back_to_starred_updated_today() {
+        let mut server = mockito::Server::new_async().await;
+        let updated_at = chrono::Utc::now().to_rfc3339();
+        let search = server
+            .mock("GET", "/api/v1/repos/search")
+            .match_query(mockito::Matcher::Any)
+            .with_body(format!(
+                r#"{{"data": [{{"full_name": "a/quiet", "html_url": "https://codeberg.org/a/quiet",
+                    "stars_count": 4, "language": "Rust", "updated_at": "{updated_at}"}}]}}"#
+            ))
+            .expect(2)
+            .create_async()
+            .await;
+        let feed = server
+            .mock("GET", "/api/v1/repos/a/quiet/activities/feeds")
+            .match_query(mockito::Matcher::Any)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let codeberg = Codeberg::with_gitea(Gitea::with_client(HttpClient::new(5).unwrap()));
+        let cfg = ProviderCfg {
+            timeout_secs: 5,
+            token: None,
+            base_url: Some(server.url()),
+            topics: TopicFilter::default(),
+            gitea_ranking: GiteaRanking::RecentStars,
+            weekly: false,
+            query: None,
+            hub_kinds: Vec::new(),
+        };
+        let repos = codeberg
+            .top_today(&cfg, 0, 5, &LanguageFilter::new(vec![]))
+            .await
+            .unwrap();
+
+        search.assert_async().await;
+        feed.assert_async().await;
+        assert_eq!(repos.len(), 1);
+        assert_eq!(repos[0].provider, "codeberg");
+        assert_eq!(repos[0].icon, "[CB]");
+        assert_eq!(repos[0].stars_today, None);
+    }
+}
@@ -0,0 +1,255 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::future::join_all;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+use crate::http::HttpClient;
+use crate::model::{useful_homepage, LanguageFilter, Provider, ProviderCfg, Repo};
+
+/// API root used when no base URL is configured
+const API_URL: &str = "https://crates.io/api/v1";
+
+/// Every crate is Rust, so the language filter is checked once against this
+const LANGUAGE: &str = "Rust";
+
+/// crates.io provider: new and just-updated crates ranked by recent downloads
+///
+/// The summary lists recently published and updated crates; their downloads on the
+/// last complete day (or week) stand in for today's stars.
+pub struct Crates {
+    http: HttpClient,
+}
+
+#[derive(Debug, Deserialize)]
+struct Summary {
+    #[serde(default)]
+    new_crates: Vec<Crate>,
+    #[serde(default)]
+    just_updated: Vec<Crate>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Crate {
+    name: String,
+    description: Option<String>,
+    homepage: Option<String>,
+    repository: Option<String>,
+    #[serde(default)]
+    downloads: u64,
+    updated_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Downloads {
+    #[serde(default)]
+    version_downloads: Vec<DailyDownloads>,
+    meta: Option<DownloadsMeta>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DownloadsMeta {
+    /// Downloads of versions beyond the most recent ones listed per version
+    #[serde(default)]
+    extra_downloads: Vec<DailyDownloads>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DailyDownloads {
+    date: chrono::NaiveDate,
+    downloads: u64,
+}
+
+impl Crates {
+    pub fn new(timeout_secs: u64) -> Result<Self> {
+        Ok(Self {
+            http: HttpClient::new(timeout_secs)?,
+        })
+    }
+
+    /// Create a crates.io provider with a custom `HttpClient`
+    #[allow(dead_code)]
+    pub fn with_client(http: HttpClient) -> Self {
+        Self { http }
+    }
+
+    /// Downloads of `name` on the `days` complete days before today
+    async fn recent_downloads(&self, api: &str, name: &str, days: i64) -> Option<u64> {
+        let url = format!("{api}/crates/{name}/downloads");
+        let downloads: Downloads = self.http.get_json(&url, None).await.ok()?;
+        let today = chrono::Utc::now().date_naive();
+        let since = today - chrono::Duration::days(days);
+
+        let extra = downloads
+            .meta
+            .into_iter()
+            .flat_map(|meta| meta.extra_downloads);
+        Some(
+            downloads
+                .version_downloads
+                .into_iter()
+                .chain(extra)
+                .filter(|day| day.date >= since && day.date < today)
+                .map(|day| day.downloads)
+                .sum(),
+        )
+    }
+}
+
+#[async_trait]
+impl Provider for Crates {
+    fn id(&self) -> &'static str {
+        "crates"
+    }
+
+    fn icon(&self) -> &'static str {
+        "[CR]"
+    }
+
+    async fn top_today(
+        &self,
+        cfg: &ProviderCfg,
+        offset: usize,
+        limit: usize,
+        langs: &LanguageFilter,
+    ) -> Result<Vec<Repo>> {
+        let language = LANGUAGE.to_string();
+        if !langs.matches(Some(&language)) {
+            return Ok(Vec::new());
+        }
+        let api = cfg
+            .base_url
+            .as_deref()
+            .unwrap_or(API_URL)
+            .trim_end_matches('/');
+        let days = if cfg.weekly { 7 } else { 1 };
+
+        let summary: Summary = self.http.get_json(&format!("{api}/summary"), None).await?;
+        let mut names = HashSet::new();
+        let candidates: Vec<_> = summary
+            .new_crates
+            .into_iter()
+            .chain(summary.just_updated)
+            .filter(|krate| names.insert(krate.name.clone()))
+            .collect();
+
+        let lookups = candidates
+            .iter()
+            .map(|krate| self.recent_downloads(api, &krate.name, days));
+        let downloads = join_all(lookups).await;
+
+        let mut ranked: Vec<_> = candidates.into_iter().zip(downloads).collect();
+        ranked.sort_by_key(|(_, downloads)| std::cmp::Reverse(downloads.unwrap_or(0)));
+
+        Ok(ranked
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|(krate, downloads)| {
+                let url = format!("https://crates.io/crates/{}", krate.name);
+                let homepage = krate.repository.or(krate.homepage);
+
+                Repo {
+                    provider: self.id().to_string(),
+                    icon: self.icon().to_string(),
+                    name: krate.name,
+                    language: Some(language.clone()),
+                    description: krate.description.filter(|d| !d.trim().is_empty()),
+                    homepage: useful_homepage(homepage, &url),
+                    url,
+                    stars_today: downloads,
+                    stars_total: Some(krate.downloads),
+                    last_activity: krate
+                        .updated_at
+                        .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
+                        .map(|dt| dt.with_timezone(&chrono::Utc)),
+                    topics: Vec::new(),
+                    is_starred: false,
+                    is_cloned: false,
+                    is_seen: false,
+                    movement: None,
+                    health: None,
+                    license: None,
+                    is_sponsorable: false,
+                    has_binaries: false,
+                    has_container: false,
+                    is_suspicious: false,
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::TopicFilter;
+    use crate::model::GiteaRanking;
+
+    #[tokio::test]
+    async fn test_ranked_by_yesterdays_downloads() {
+        let mut server = mockito::Server::new_async().await;
+        let krate = |name: &str| {
+            format!(
+                r#"{{"name": "{name}", "description": "The {name} crate", "homepage": null,
+                    "repository": "https://github.com/org/{name}", "downloads": 1000,
+                    "updated_at": "2024-05-01T10:00:00+00:00"}}"#
+            )
+        };
+        server
+            .mock("GET", "/summary")
+            .with_body(format!(
+                r#"{{"new_crates": [{}, {}], "just_updated": [{}]}}"#,
+                krate("fresh"),
+                krate("busy"),
+                krate("fresh")
+            ))
+            .create_async()
+            .await;
+
+        let today = chrono::Utc::now().date_naive();
+        let yesterday = today - chrono::Duration::days(1);
+        for (name, downloads) in [("fresh", 5), ("busy", 300)] {
+            server
+                .mock("GET", format!("/crates/{name}/downloads").as_str())
+                .with_body(format!(
+                    r#"{{"version_downloads": [
+                            {{"version": 1, "downloads": {downloads}, "date": "{yesterday}"}},
+                            {{"version": 1, "downloads": 9999, "date": "{today}"}}],
+                        "meta": {{"extra_downloads": [{{"date": "{yesterday}", "downloads": 1}}]}}}}"#
+                ))
+                .create_async()
+                .await;
+        }
+
+        let http = HttpClient::builder().max_retries(0).build().unwrap();
+        let crates = Crates::with_client(http);
+        let cfg = ProviderCfg {
+            timeout_secs: 5,
+            token: None,
+            base_url: Some(server.url()),
+            topics: TopicFilter::default(),
+            gitea_ranking: GiteaRanking::default(),
+            weekly: false,
+            query: None,
+            hub_kinds: Vec::new(),
+        };
+        let repos = crates
+            .top_today(&cfg, 0, 5, &LanguageFilter::new(vec!["rust".to_string()]))
+            .await
+            .unwrap();
+
+        let names: Vec<_> = repos.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["busy", "fresh"]);
+        assert_eq!(repos[0].stars_today, Some(301));
+        assert_eq!(repos[0].stars_total, Some(1000));
+        assert_eq!(repos[0].url, "https://crates.io/crates/busy");
+        assert_eq!(
+            repos[0].homepage.as_deref(),
+            Some("https://github.com/org/busy")
+        );
+
+        let go = LanguageFilter::new(vec!["go".to_string()]);
+        assert!(crates.top_today(&cfg, 0, 5, &go).await.unwrap().is_empty());
+    }
+}
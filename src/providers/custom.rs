@@ -0,0 +1,273 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use crate::config::CustomProviderConfig;
+use crate::http::HttpClient;
+use crate::model::{useful_homepage, LanguageFilter, Provider, ProviderCfg, Repo};
+
+/// Provider declared under `[[custom]]`: any JSON API, mapped to repos by field paths
+///
+/// Entries keep the API's order. Without `{offset}` in the URL the whole list is
+/// fetched and paged locally.
+pub struct Custom {
+    http: HttpClient,
+    def: CustomProviderConfig,
+    /// `Provider` hands out static strings, interned so rebuilding leaks nothing new
+    id: &'static str,
+    icon: &'static str,
+}
+
+impl Custom {
+    pub fn new(def: CustomProviderConfig, timeout_secs: u64) -> Result<Self> {
+        Ok(Self::with_client(def, HttpClient::new(timeout_secs)?))
+    }
+
+    /// Create a custom provider with a custom `HttpClient`
+    pub fn with_client(def: CustomProviderConfig, http: HttpClient) -> Self {
+        Self {
+            http,
+            id: intern(&def.name),
+            icon: intern(&def.icon()),
+            def,
+        }
+    }
+
+    fn repo(&self, entry: &Value) -> Option<Repo> {
+        let fields = &self.def.fields;
+        let text = |path: &str| {
+            lookup(entry, path)
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(String::from)
+        };
+        let count = |path: &str| {
+            lookup(entry, path).and_then(|v| {
+                v.as_u64()
+                    .or_else(|| v.as_str().and_then(|s| s.trim().parse().ok()))
+            })
+        };
+
+        let url = text(&fields.url)?;
+        let topics = match lookup(entry, &fields.topics) {
+            Some(Value::Array(topics)) => topics
+                .iter()
+                .filter_map(Value::as_str)
+                .map(String::from)
+                .collect(),
+            Some(Value::String(topics)) => topics
+                .split(',')
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .map(String::from)
+                .collect(),
+            _ => Vec::new(),
+        };
+        let last_activity = lookup(entry, &fields.last_activity).and_then(|v| match v {
+            Value::String(s) => chrono::DateTime::parse_from_rfc3339(s)
+                .ok()
+                .map(|dt| dt.with_timezone(&chrono::Utc)),
+            _ => chrono::DateTime::from_timestamp(v.as_i64()?, 0),
+        });
+
+        Some(Repo {
+            provider: self.id.to_string(),
+            icon: self.icon.to_string(),
+            name: text(&fields.name).unwrap_or_else(|| url.clone()),
+            language: text(&fields.language),
+            description: text(&fields.description),
+            homepage: useful_homepage(text(&fields.homepage), &url),
+            url,
+            stars_today: count(&fields.stars_today),
+            stars_total: count(&fields.stars_total),
+            last_activity,
+            topics,
+            is_starred: false,
+            is_cloned: false,
+            is_seen: false,
+            movement: None,
+            health: None,
+            license: None,
+            is_sponsorable: false,
+            has_binaries: false,
+            has_container: false,
+            is_suspicious: false,
+        })
+    }
+}
+
+/// A `'static` copy of `text`, leaked once per distinct string
+///
+/// `bench` builds providers again every cycle, so each name and icon is kept once.
+fn intern(text: &str) -> &'static str {
+    static INTERNED: OnceLock<Mutex<HashMap<String, &'static str>>> = OnceLock::new();
+    let mut interned = INTERNED
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if let Some(&text) = interned.get(text) {
+        return text;
+    }
+    let leaked: &'static str = Box::leak(text.to_string().into_boxed_str());
+    interned.insert(text.to_string(), leaked);
+    leaked
+}
+
+/// Follow a path like `$.data.items[0].name` (or `data.items.0.name`) into `value`
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    let path = path.trim();
+    let path = path.strip_prefix('$').unwrap_or(path);
+    path.split(['.', '[', ']'])
+        .filter(|key| !key.is_empty())
+        .try_fold(value, |value, key| match value {
+            Value::Array(items) => items.get(key.parse::<usize>().ok()?),
+            _ => value.get(key),
+        })
+}
+
+#[async_trait]
+impl Provider for Custom {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    fn icon(&self) -> &'static str {
+        self.icon
+    }
+
+    async fn top_today(
+        &self,
+        cfg: &ProviderCfg,
+        offset: usize,
+        limit: usize,
+        langs: &LanguageFilter,
+    ) -> Result<Vec<Repo>> {
+        let paged = self.def.url.contains("{offset}");
+        let url = self
+            .def
+            .url
+            .replace("{offset}", &offset.to_string())
+            .replace("{limit}", &limit.to_string());
+        let auth = cfg
+            .token
+            .as_deref()
+            .map(|token| (self.def.auth_header.as_str(), token));
+
+        let response: Value = self.http.get_json_with_header(&url, auth).await?;
+        let entries = lookup(&response, &self.def.items)
+            .and_then(Value::as_array)
+            .with_context(|| {
+                format!(
+                    "{}: no list at `{}` in the response from {url}",
+                    self.id, self.def.items
+                )
+            })?;
+
+        Ok(entries
+            .iter()
+            .filter_map(|entry| self.repo(entry))
+            .filter(|repo| langs.matches(repo.language.as_ref()))
+            .skip(if paged { 0 } else { offset })
+            .take(limit)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::TopicFilter;
+    use crate::model::GiteaRanking;
+
+    #[test]
+    fn test_intern_reuses_strings() {
+        let first = intern("internal");
+        let again = intern(&String::from("internal"));
+        assert!(std::ptr::eq(first, again));
+        assert_eq!(intern("[IN]"), "[IN]");
+    }
+
+    #[test]
+    fn test_lookup() {
+        let value = serde_json::json!({"data": {"items": [{"name": "a"}, {"name": "b"}]}});
+        assert_eq!(
+            lookup(&value, "$.data.items[1].name"),
+            Some(&Value::from("b"))
+        );
+        assert_eq!(lookup(&value, "data.items.0.name"), Some(&Value::from("a")));
+        assert_eq!(lookup(&value, "$"), Some(&value));
+        assert_eq!(lookup(&value, "$.data.missing"), None);
+    }
+
+    #[tokio::test]
+    async fn test_fields_mapped_from_config() {
+        let mut server = mockito::Server::new_async().await;
+        let trending = server
+            .mock("GET", "/api/trending")
+            .match_query(mockito::Matcher::UrlEncoded("limit".into(), "2".into()))
+            .match_header("PRIVATE-TOKEN", "secret")
+            .with_body(
+                r#"{"result": {"projects": [
+                    {"path": "team/tool", "web": "https://git.example.com/team/tool",
+                     "lang": "Go", "stats": {"recent": "12", "total": 300},
+                     "labels": "cli, ops", "pushed": 1714557600},
+                    {"path": "team/site", "web": "https://git.example.com/team/site",
+                     "lang": "TypeScript", "stats": {"recent": 3, "total": 40}},
+                    {"path": "team/lib", "web": "https://git.example.com/team/lib",
+                     "lang": "Go", "stats": {"recent": 1, "total": 10}}
+                ]}}"#,
+            )
+            .create_async()
+            .await;
+
+        let toml_str = format!(
+            r#"
+            name = "internal"
+            url = "{}/api/trending?limit={{limit}}"
+            auth_header = "PRIVATE-TOKEN"
+            items = "$.result.projects"
+
+            [fields]
+            name = "path"
+            url = "web"
+            language = "lang"
+            stars_today = "stats.recent"
+            stars_total = "$.stats.total"
+            topics = "labels"
+            last_activity = "pushed"
+            "#,
+            server.url()
+        );
+        let def: CustomProviderConfig = toml::from_str(&toml_str).unwrap();
+        let http = HttpClient::builder().max_retries(0).build().unwrap();
+        let custom = Custom::with_client(def, http);
+        let cfg = ProviderCfg {
+            timeout_secs: 5,
+            token: Some("secret".to_string()),
+            base_url: None,
+            topics: TopicFilter::default(),
+            gitea_ranking: GiteaRanking::default(),
+            weekly: false,
+            query: None,
+            hub_kinds: Vec::new(),
+        };
+        let repos = custom
+            .top_today(&cfg, 0, 2, &LanguageFilter::new(vec!["go".to_string()]))
+            .await
+            .unwrap();
+
+        trending.assert_async().await;
+        assert_eq!(custom.id(), "internal");
+        let names: Vec<_> = repos.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["team/tool", "team/lib"]);
+        assert_eq!(repos[0].provider, "internal");
+        assert_eq!(repos[0].icon, "[IN]");
+        assert_eq!(repos[0].stars_today, Some(12));
+        assert_eq!(repos[0].stars_total, Some(300));
+        assert_eq!(repos[0].topics, ["cli", "ops"]);
+        assert!(repos[0].last_activity.is_some());
+    }
+}
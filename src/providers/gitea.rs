@@ -1,9 +1,15 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use futures::future::join_all;
 use serde::Deserialize;
+use std::collections::HashMap;
 
 use crate::http::HttpClient;
-use crate::model::{LanguageFilter, Provider, ProviderCfg, Repo};
+use crate::lookup::{entry_names, readme_name, Entry, FileContent};
+use crate::model::{
+    language_from_topics, primary_language, useful_homepage, GiteaRanking, LanguageFilter,
+    Provider, ProviderCfg, Repo, LANGUAGE_LOOKUP_LIMIT,
+};
 
 /// Gitea provider using search API with configurable base URL
 pub struct Gitea {
@@ -23,6 +29,32 @@ struct GiteaRepository {
     stars_count: Option<u64>,
     language: Option<String>,
     updated_at: Option<String>,
+    #[serde(default)]
+    topics: Vec<String>,
+    #[serde(default)]
+    website: Option<String>,
+    /// Filled in by the `recent-stars` ranking
+    #[serde(skip)]
+    stars_today: Option<u64>,
+}
+
+/// Number of recently updated repos whose activity feed is checked for `recent-stars`,
+/// one request each
+const RECENT_STARS_CANDIDATES: usize = 10;
+
+/// Repos without topics in the search results whose topics are looked up for a topic filter
+const TOPIC_LOOKUP_LIMIT: usize = 30;
+
+/// Parse an RFC 3339 timestamp from the Gitea API
+fn parse_time(value: Option<&str>) -> Option<chrono::DateTime<chrono::Utc>> {
+    value
+        .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+}
+
+/// Sort repositories by total stars, most-starred first
+fn sort_by_stars(repos: &mut [GiteaRepository]) {
+    repos.sort_by_key(|repo| std::cmp::Reverse(repo.stars_count));
 }
 
 impl Gitea {
@@ -38,25 +70,232 @@ impl Gitea {
         Self { http }
     }
 
-    /// Fetch repositories from Gitea instance
+    /// Fetch repositories from Gitea instance ranked by the given strategy
     async fn fetch_repos(
         &self,
         base_url: &str,
         token: Option<&str>,
+        ranking: GiteaRanking,
     ) -> Result<Vec<GiteaRepository>> {
-        // Search for recently updated repos (sorted by update time)
-        let url = format!("{base_url}/api/v1/repos/search?sort=updated&order=desc&limit=100");
+        let sort = match ranking {
+            // The search endpoint sorts by creation date as `created`
+            GiteaRanking::NewestWithStars => "created",
+            _ => "updated",
+        };
+        let url = format!("{base_url}/api/v1/repos/search?sort={sort}&order=desc&limit=100");
 
         let response: GiteaSearchResponse = self.http.get_json(&url, token).await?;
 
         // Return repos with at least 1 star (Gitea has smaller community)
-        let filtered: Vec<_> = response
+        let starred: Vec<_> = response
             .data
             .into_iter()
             .filter(|repo| repo.stars_count.unwrap_or(0) >= 1)
             .collect();
 
-        Ok(filtered)
+        match ranking {
+            GiteaRanking::Updated => Ok(starred),
+            GiteaRanking::StarredUpdatedToday => {
+                let since = chrono::Utc::now() - chrono::Duration::hours(24);
+                let mut today: Vec<_> = starred
+                    .into_iter()
+                    .filter(|repo| {
+                        parse_time(repo.updated_at.as_deref()).is_some_and(|t| t >= since)
+                    })
+                    .collect();
+                sort_by_stars(&mut today);
+                Ok(today)
+            }
+            GiteaRanking::NewestWithStars => {
+                let mut newest = starred;
+                sort_by_stars(&mut newest);
+                Ok(newest)
+            }
+            GiteaRanking::RecentStars => self.rank_by_recent_stars(base_url, token, starred).await,
+        }
+    }
+
+    /// Count stars from the last 24h in each candidate's activity feed and rank by them
+    ///
+    /// Repository activity feeds need Gitea 1.22+ (or Forgejo); only the most recently
+    /// updated candidates are checked to bound the number of requests.
+    async fn rank_by_recent_stars(
+        &self,
+        base_url: &str,
+        token: Option<&str>,
+        candidates: Vec<GiteaRepository>,
+    ) -> Result<Vec<GiteaRepository>> {
+        #[derive(Deserialize)]
+        struct Activity {
+            op_type: String,
+            created: Option<String>,
+        }
+
+        let since = chrono::Utc::now() - chrono::Duration::hours(24);
+        let candidates: Vec<_> = candidates
+            .into_iter()
+            .take(RECENT_STARS_CANDIDATES)
+            .collect();
+
+        let counts = join_all(candidates.iter().map(|repo| {
+            let url = format!(
+                "{base_url}/api/v1/repos/{}/activities/feeds?limit=50",
+                repo.full_name
+            );
+            async move {
+                let feed: Vec<Activity> = self.http.get_json(&url, token).await?;
+                Ok::<_, anyhow::Error>(
+                    feed.iter()
+                        .filter(|a| a.op_type == "star_repo")
+                        .filter(|a| parse_time(a.created.as_deref()).is_some_and(|t| t >= since))
+                        .count() as u64,
+                )
+            }
+        }))
+        .await;
+
+        if !counts.is_empty() && counts.iter().all(Result::is_err) {
+            anyhow::bail!(
+                "{base_url} does not expose repository activity feeds; use another gitea ranking"
+            );
+        }
+
+        let mut ranked: Vec<_> = candidates
+            .into_iter()
+            .zip(counts)
+            .filter_map(|(mut repo, count)| {
+                let count = count.ok().filter(|c| *c > 0)?;
+                repo.stars_today = Some(count);
+                Some(repo)
+            })
+            .collect();
+        ranked.sort_by_key(|repo| std::cmp::Reverse(repo.stars_today));
+        Ok(ranked)
+    }
+
+    /// Look up the primary language of repositories through the languages API
+    async fn detect_languages(
+        &self,
+        base_url: &str,
+        names: Vec<String>,
+        token: Option<&str>,
+    ) -> HashMap<String, String> {
+        let lookups = names.into_iter().map(|name| async move {
+            let url = format!("{base_url}/api/v1/repos/{name}/languages");
+            let languages: HashMap<String, f64> = self.http.get_json(&url, token).await.ok()?;
+            primary_language(languages).map(|language| (name, language))
+        });
+        join_all(lookups).await.into_iter().flatten().collect()
+    }
+
+    /// Topics of repos whose search result didn't include them (older Gitea versions)
+    async fn lookup_topics(
+        &self,
+        base_url: &str,
+        names: Vec<String>,
+        token: Option<&str>,
+    ) -> HashMap<String, Vec<String>> {
+        #[derive(Deserialize)]
+        struct Topics {
+            #[serde(default)]
+            topics: Vec<String>,
+        }
+
+        let lookups = names.into_iter().map(|name| async move {
+            let url = format!("{base_url}/api/v1/repos/{name}/topics");
+            let found: Topics = self.http.get_json(&url, token).await.ok()?;
+            Some((name, found.topics))
+        });
+        join_all(lookups).await.into_iter().flatten().collect()
+    }
+
+    /// Whether the latest release ships uploaded assets (prebuilt binaries)
+    pub async fn has_release_assets(
+        &self,
+        base_url: &str,
+        name: &str,
+        token: Option<&str>,
+    ) -> Result<bool> {
+        #[derive(Deserialize)]
+        struct Release {
+            #[serde(default)]
+            assets: Vec<serde_json::Value>,
+        }
+
+        let url = format!("{base_url}/api/v1/repos/{name}/releases/latest");
+        let release: Release = self.http.get_json(&url, token).await?;
+        Ok(!release.assets.is_empty())
+    }
+
+    /// SPDX ids of the licenses Gitea (1.22 and later) detects in a repository
+    pub async fn licenses(
+        &self,
+        base_url: &str,
+        name: &str,
+        token: Option<&str>,
+    ) -> Result<Vec<String>> {
+        let url = format!("{base_url}/api/v1/repos/{name}/licenses");
+        self.http.get_json(&url, token).await
+    }
+
+    /// Names of the files and directories at the repository root
+    pub async fn root_files(
+        &self,
+        base_url: &str,
+        name: &str,
+        token: Option<&str>,
+    ) -> Result<Vec<String>> {
+        let url = format!("{base_url}/api/v1/repos/{name}/contents");
+        let entries: Vec<Entry> = self.http.get_json(&url, token).await?;
+        Ok(entry_names(entries))
+    }
+
+    /// Text of the README at the repository root, `None` without one
+    pub async fn readme(
+        &self,
+        base_url: &str,
+        name: &str,
+        token: Option<&str>,
+    ) -> Result<Option<String>> {
+        let files = self.root_files(base_url, name, token).await?;
+        let Some(file) = readme_name(&files) else {
+            return Ok(None);
+        };
+        let url = format!("{base_url}/api/v1/repos/{name}/contents/{file}");
+        let file: FileContent = self.http.get_json(&url, token).await?;
+        file.text().map(Some)
+    }
+
+    /// Get all starred repositories (`owner/repo`) of the authenticated user
+    pub async fn get_user_stars(&self, base_url: &str, token: &str) -> Result<Vec<String>> {
+        #[derive(Deserialize)]
+        struct StarredRepo {
+            full_name: String,
+        }
+
+        let mut all_repos = Vec::new();
+        let mut page = 1;
+        let limit = 50;
+
+        loop {
+            let url = format!("{base_url}/api/v1/user/starred?limit={limit}&page={page}");
+
+            let repos: Vec<StarredRepo> = self.http.get_json(&url, Some(token)).await?;
+
+            if repos.is_empty() {
+                break;
+            }
+
+            all_repos.extend(repos.into_iter().map(|r| r.full_name));
+            page += 1;
+
+            // Safety limit to avoid infinite loops
+            if page > 50 {
+                break;
+            }
+        }
+
+        Ok(all_repos)
     }
 }
 
@@ -79,18 +318,61 @@ impl Provider for Gitea {
     ) -> Result<Vec<Repo>> {
         let base_url = cfg.base_url.as_deref().unwrap_or("https://gitea.com");
 
-        let repositories = self.fetch_repos(base_url, cfg.token.as_deref()).await?;
+        let mut repositories = self
+            .fetch_repos(base_url, cfg.token.as_deref(), cfg.gitea_ranking)
+            .await?;
+
+        for r in &mut repositories {
+            r.language = r
+                .language
+                .take()
+                .or_else(|| language_from_topics(&r.topics));
+        }
+        if langs.needs_lookup() {
+            let unknown = repositories
+                .iter()
+                .filter(|r| r.language.is_none())
+                .map(|r| r.full_name.clone())
+                .take(LANGUAGE_LOOKUP_LIMIT)
+                .collect();
+            let detected = self
+                .detect_languages(base_url, unknown, cfg.token.as_deref())
+                .await;
+            for r in &mut repositories {
+                if r.language.is_none() {
+                    r.language = detected.get(&r.full_name).cloned();
+                }
+            }
+        }
+
+        repositories.retain(|r| langs.matches(r.language.as_ref()));
+
+        // Topic filters would drop every repo of an instance that leaves topics out
+        if !cfg.topics.is_empty() {
+            let missing = repositories
+                .iter()
+                .filter(|r| r.topics.is_empty())
+                .map(|r| r.full_name.clone())
+                .take(TOPIC_LOOKUP_LIMIT)
+                .collect();
+            let mut found = self
+                .lookup_topics(base_url, missing, cfg.token.as_deref())
+                .await;
+            for r in &mut repositories {
+                if let Some(topics) = found.remove(&r.full_name) {
+                    r.topics = topics;
+                }
+            }
+        }
 
         let repos = repositories
             .into_iter()
-            .filter(|r| langs.matches(r.language.as_ref()))
+            .filter(|r| cfg.topics.matches(&r.topics))
             .skip(offset)
             .take(limit)
             .map(|r| {
-                let last_activity = r
-                    .updated_at
-                    .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
-                    .map(|dt| dt.with_timezone(&chrono::Utc));
+                let last_activity = parse_time(r.updated_at.as_deref());
+                let homepage = useful_homepage(r.website, &r.html_url);
 
                 Repo {
                     provider: self.id().to_string(),
@@ -99,11 +381,21 @@ impl Provider for Gitea {
                     language: r.language,
                     description: r.description,
                     url: r.html_url,
-                    stars_today: None, // Gitea API doesn't provide daily stars
+                    stars_today: r.stars_today, // Only known with the recent-stars ranking
                     stars_total: r.stars_count,
                     last_activity,
-                    topics: vec![], // Gitea API doesn't provide topics in search
+                    topics: r.topics, // Only returned by newer Gitea versions
                     is_starred: false,
+                    is_cloned: false,
+                    is_seen: false,
+                    movement: None,
+                    health: None,
+                    homepage,
+                    license: None,
+                    is_sponsorable: false,
+                    has_binaries: false,
+                    has_container: false,
+                    is_suspicious: false,
                 }
             })
             .collect();
@@ -115,6 +407,7 @@ impl Provider for Gitea {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::filter::TopicFilter;
 
     #[test]
     fn test_gitea_provider_metadata() {
@@ -123,6 +416,146 @@ mod tests {
         assert_eq!(gitea.icon(), "[GE]");
     }
 
+    fn create_test_repo(name: &str, stars: u64) -> GiteaRepository {
+        GiteaRepository {
+            full_name: name.to_string(),
+            description: None,
+            html_url: format!("https://gitea.com/{name}"),
+            stars_count: Some(stars),
+            language: None,
+            updated_at: Some("2025-01-01T00:00:00Z".to_string()),
+            topics: vec![],
+            website: None,
+            stars_today: None,
+        }
+    }
+
+    #[test]
+    fn test_sort_by_stars() {
+        let mut repos = vec![
+            create_test_repo("a/low", 2),
+            create_test_repo("a/high", 40),
+            create_test_repo("a/mid", 9),
+        ];
+        sort_by_stars(&mut repos);
+        let names: Vec<_> = repos.iter().map(|r| r.full_name.as_str()).collect();
+        assert_eq!(names, ["a/high", "a/mid", "a/low"]);
+    }
+
+    #[test]
+    fn test_parse_time() {
+        let repo = create_test_repo("a/repo", 1);
+        assert!(parse_time(repo.updated_at.as_deref()).is_some());
+        assert!(parse_time(Some("yesterday")).is_none());
+        assert!(parse_time(None).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_search_sort_per_ranking() {
+        let mut server = mockito::Server::new_async().await;
+        let http = HttpClient::builder().max_retries(0).build().unwrap();
+        let gitea = Gitea::with_client(http);
+
+        for (ranking, sort) in [
+            (GiteaRanking::Updated, "updated"),
+            (GiteaRanking::RecentStars, "updated"),
+            (GiteaRanking::StarredUpdatedToday, "updated"),
+            (GiteaRanking::NewestWithStars, "created"),
+        ] {
+            let search = server
+                .mock("GET", "/api/v1/repos/search")
+                .match_query(mockito::Matcher::AllOf(vec![
+                    mockito::Matcher::UrlEncoded("sort".into(), sort.into()),
+                    mockito::Matcher::UrlEncoded("order".into(), "desc".into()),
+                ]))
+                .with_body(r#"{"data": []}"#)
+                .create_async()
+                .await;
+            let repos = gitea
+                .fetch_repos(&server.url(), None, ranking)
+                .await
+                .unwrap();
+            search.assert_async().await;
+            assert!(repos.is_empty(), "{ranking:?}");
+            search.remove_async().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_topic_filter_applied_before_paging() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/repos/search")
+            .match_query(mockito::Matcher::Any)
+            .with_body(
+                r#"{"data": [
+                    {"full_name": "a/list", "html_url": "https://gitea.test/a/list",
+                     "stars_count": 9, "topics": ["awesome"]},
+                    {"full_name": "a/tool", "html_url": "https://gitea.test/a/tool",
+                     "stars_count": 5, "topics": ["cli"]},
+                    {"full_name": "a/untagged", "html_url": "https://gitea.test/a/untagged",
+                     "stars_count": 3}
+                ]}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/v1/repos/a/untagged/topics")
+            .with_body(r#"{"topics": ["awesome"]}"#)
+            .create_async()
+            .await;
+        server
+            .mock(
+                "GET",
+                mockito::Matcher::Regex("/repos/a/(list|tool)/topics".into()),
+            )
+            .expect(0)
+            .create_async()
+            .await;
+
+        let http = HttpClient::builder().max_retries(0).build().unwrap();
+        let gitea = Gitea::with_client(http);
+        let cfg = ProviderCfg {
+            timeout_secs: 5,
+            token: None,
+            base_url: Some(server.url()),
+            topics: TopicFilter {
+                include: vec![],
+                exclude: vec!["awesome".to_string()],
+            },
+            gitea_ranking: GiteaRanking::Updated,
+            weekly: false,
+            query: None,
+            hub_kinds: Vec::new(),
+        };
+        let langs = LanguageFilter::new(vec![]);
+
+        // Excluded repos don't take a slot, so the second page starts after the kept ones
+        let first = gitea.top_today(&cfg, 0, 1, &langs).await.unwrap();
+        let names: Vec<_> = first.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["a/tool"]);
+        let second = gitea.top_today(&cfg, 1, 1, &langs).await.unwrap();
+        assert!(second.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_lookup_topics() {
+        let mut server = mockito::Server::new_async().await;
+        let found = server
+            .mock("GET", "/api/v1/repos/a/tagged/topics")
+            .with_body(r#"{"topics": ["rust", "cli"]}"#)
+            .create_async()
+            .await;
+        let http = HttpClient::builder().max_retries(0).build().unwrap();
+        let gitea = Gitea::with_client(http);
+
+        let names = vec!["a/tagged".to_string(), "a/missing".to_string()];
+        let topics = gitea.lookup_topics(&server.url(), names, None).await;
+        found.assert_async().await;
+        assert_eq!(topics["a/tagged"], ["rust", "cli"]);
+        assert!(!topics.contains_key("a/missing"));
+    }
+
     #[tokio::test]
     async fn test_gitea_api() {
         // Use max_retries(0) to avoid retry delays in tests
@@ -136,7 +569,11 @@ mod tests {
             timeout_secs: 10,
             token: None,
             base_url: Some("https://gitea.com".to_string()),
-            exclude_topics: vec![],
+            topics: TopicFilter::default(),
+            gitea_ranking: GiteaRanking::default(),
+            weekly: false,
+            query: None,
+            hub_kinds: Vec::new(),
         };
         let filter = LanguageFilter::new(vec![]);
 
@@ -0,0 +1,220 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::http::HttpClient;
+use crate::model::{
+    language_from_topics, useful_homepage, LanguageFilter, Provider, ProviderCfg, Repo,
+};
+
+/// API root used when no base URL is configured
+const API_URL: &str = "https://gitee.com/api/v5";
+
+/// Repos with fewer stars aren't considered trending
+const MIN_STARS: u64 = 10;
+
+/// Gitee provider using the open API v5 search
+///
+/// The API has no trending endpoint, so the most recently pushed repos matching
+/// `[gitee] query` are ranked by stars.
+pub struct Gitee {
+    http: HttpClient,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteeRepo {
+    full_name: String,
+    html_url: String,
+    description: Option<String>,
+    #[serde(default)]
+    stargazers_count: u64,
+    language: Option<String>,
+    pushed_at: Option<String>,
+    homepage: Option<String>,
+}
+
+impl Gitee {
+    pub fn new(timeout_secs: u64) -> Result<Self> {
+        Ok(Self {
+            http: HttpClient::new(timeout_secs)?,
+        })
+    }
+
+    /// Create a Gitee provider with a custom `HttpClient`
+    #[allow(dead_code)]
+    pub fn with_client(http: HttpClient) -> Self {
+        Self { http }
+    }
+
+    /// Most recently pushed repos matching `query`
+    async fn search(&self, api: &str, query: &str, token: Option<&str>) -> Result<Vec<GiteeRepo>> {
+        let mut url = format!(
+            "{api}/search/repositories?q={}&sort=last_push_at&order=desc&per_page=100",
+            encode(query)
+        );
+        // Gitee takes the token as a query parameter rather than a header
+        if let Some(token) = token {
+            url.push_str("&access_token=");
+            url.push_str(&encode(token));
+        }
+        self.http.get_json(&url, None).await
+    }
+}
+
+/// Percent-encode a query parameter value
+fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                char::from(byte).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+#[async_trait]
+impl Provider for Gitee {
+    fn id(&self) -> &'static str {
+        "gitee"
+    }
+
+    fn icon(&self) -> &'static str {
+        "[GT]"
+    }
+
+    async fn top_today(
+        &self,
+        cfg: &ProviderCfg,
+        offset: usize,
+        limit: usize,
+        langs: &LanguageFilter,
+    ) -> Result<Vec<Repo>> {
+        let api = cfg
+            .base_url
+            .as_deref()
+            .unwrap_or(API_URL)
+            .trim_end_matches('/');
+        let query = cfg.query.as_deref().unwrap_or_default();
+        let days = if cfg.weekly { 7 } else { 1 };
+        let since = chrono::Utc::now() - chrono::Duration::days(days);
+
+        let mut repos: Vec<_> = self
+            .search(api, query, cfg.token.as_deref())
+            .await?
+            .into_iter()
+            .filter(|repo| repo.stargazers_count >= MIN_STARS)
+            .filter_map(|repo| {
+                let pushed = repo
+                    .pushed_at
+                    .as_deref()
+                    .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+                    .map(|dt| dt.with_timezone(&chrono::Utc))
+                    .filter(|pushed| *pushed >= since)?;
+                let language = repo
+                    .language
+                    .clone()
+                    .filter(|l| !l.trim().is_empty())
+                    .map(|l| language_from_topics(std::slice::from_ref(&l)).unwrap_or(l));
+                Some((repo, language, pushed))
+            })
+            .filter(|(_, language, _)| langs.matches(language.as_ref()))
+            .collect();
+        repos.sort_by_key(|(repo, _, _)| std::cmp::Reverse(repo.stargazers_count));
+
+        Ok(repos
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|(repo, language, pushed)| Repo {
+                provider: self.id().to_string(),
+                icon: self.icon().to_string(),
+                name: repo.full_name,
+                language,
+                description: repo.description.filter(|d| !d.trim().is_empty()),
+                homepage: useful_homepage(repo.homepage, &repo.html_url),
+                url: repo.html_url,
+                stars_today: None, // Gitee's API doesn't expose daily stars
+                stars_total: Some(repo.stargazers_count),
+                last_activity: Some(pushed),
+                topics: Vec::new(),
+                is_starred: false,
+                is_cloned: false,
+                is_seen: false,
+                movement: None,
+                health: None,
+                license: None,
+                is_sponsorable: false,
+                has_binaries: false,
+                has_container: false,
+                is_suspicious: false,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::TopicFilter;
+    use crate::model::GiteaRanking;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode("开源"), "%E5%BC%80%E6%BA%90");
+        assert_eq!(encode("a b&c"), "a%20b%26c");
+    }
+
+    #[tokio::test]
+    async fn test_recent_repos_ranked_by_stars() {
+        let mut server = mockito::Server::new_async().await;
+        let now = chrono::Utc::now();
+        let repo = |name: &str, stars: u64, pushed: chrono::DateTime<chrono::Utc>| {
+            format!(
+                r#"{{"full_name": "org/{name}", "html_url": "https://gitee.com/org/{name}",
+                    "description": "", "stargazers_count": {stars}, "language": "Java",
+                    "pushed_at": "{}", "homepage": null}}"#,
+                pushed.to_rfc3339()
+            )
+        };
+        let search = server
+            .mock("GET", "/search/repositories")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("q".into(), "开源".into()),
+                mockito::Matcher::UrlEncoded("access_token".into(), "secret".into()),
+            ]))
+            .with_body(format!(
+                "[{}, {}, {}, {}]",
+                repo("small", 40, now),
+                repo("big", 900, now),
+                repo("stale", 5000, now - chrono::Duration::days(30)),
+                repo("tiny", 2, now)
+            ))
+            .create_async()
+            .await;
+
+        let http = HttpClient::builder().max_retries(0).build().unwrap();
+        let gitee = Gitee::with_client(http);
+        let cfg = ProviderCfg {
+            timeout_secs: 5,
+            token: Some("secret".to_string()),
+            base_url: Some(server.url()),
+            topics: TopicFilter::default(),
+            gitea_ranking: GiteaRanking::default(),
+            weekly: false,
+            query: Some("开源".to_string()),
+            hub_kinds: Vec::new(),
+        };
+        let repos = gitee
+            .top_today(&cfg, 0, 5, &LanguageFilter::new(vec![]))
+            .await
+            .unwrap();
+
+        search.assert_async().await;
+        let names: Vec<_> = repos.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["org/big", "org/small"]);
+        assert_eq!(repos[0].provider, "gitee");
+        assert_eq!(repos[0].description, None);
+    }
+}
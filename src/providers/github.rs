@@ -1,10 +1,22 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use async_trait::async_trait;
+use futures::future::join_all;
 use scraper::{Html, Selector};
 use serde::Deserialize;
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
 
+use crate::anomaly::StarSignals;
+use crate::enrich::{Enrichment, Plan};
+use crate::health::HealthSignals;
 use crate::http::HttpClient;
-use crate::model::{LanguageFilter, Provider, ProviderCfg, Repo};
+use crate::lookup::{entry_names, Entry, FileContent};
+use crate::model::{
+    language_from_topics, primary_language, recognized_license, useful_homepage, LanguageFilter,
+    Provider, ProviderCfg, Repo, LANGUAGE_LOOKUP_LIMIT,
+};
+use crate::myrepos::RepoStats;
+use crate::safety::SafetySignals;
 
 /// GitHub provider using HTML scraping of trending page
 pub struct GitHub {
@@ -21,6 +33,37 @@ struct TrendingRepo {
     topics: Vec<String>,
 }
 
+const GRAPHQL_URL: &str = "https://api.github.com/graphql";
+
+/// Contributors counted for the health score's bus factor
+const CONTRIBUTOR_LIMIT: usize = 5;
+
+#[derive(Debug, Deserialize)]
+struct GraphQlResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlError {
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+/// A GitHub Stars list of the authenticated user
+#[derive(Debug, Deserialize)]
+struct UserList {
+    id: String,
+    name: String,
+}
+
 #[derive(Debug, Deserialize)]
 struct GitHubSearchResponse {
     items: Vec<GitHubRepository>,
@@ -35,6 +78,16 @@ struct GitHubRepository {
     language: Option<String>,
     topics: Vec<String>,
     updated_at: String,
+    #[serde(default)]
+    homepage: Option<String>,
+    #[serde(default)]
+    license: Option<License>,
+}
+
+/// A license as the REST API reports it
+#[derive(Debug, Deserialize)]
+struct License {
+    spdx_id: Option<String>,
 }
 
 impl GitHub {
@@ -65,18 +118,123 @@ impl GitHub {
         Ok(response.items)
     }
 
-    /// Fetch trending repositories from GitHub by scraping the trending page
-    async fn fetch_trending(&self, language: Option<&str>) -> Result<Vec<TrendingRepo>> {
+    /// Most-starred repositories with a topic created in the last 7 days
+    pub async fn new_in_topic(
+        &self,
+        topic: &str,
+        token: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<Repo>> {
+        let week_ago = (chrono::Utc::now() - chrono::Duration::days(7))
+            .format("%Y-%m-%d")
+            .to_string();
+
+        let url = format!(
+            "https://api.github.com/search/repositories?q=topic:{topic}+created:>={week_ago}&sort=stars&order=desc&per_page={limit}"
+        );
+
+        let response: GitHubSearchResponse = self.http.get_json(&url, token).await?;
+        Ok(response
+            .items
+            .into_iter()
+            .map(|r| self.api_repo(r))
+            .collect())
+    }
+
+    /// Convert a Search API repository into a `Repo`
+    fn api_repo(&self, r: GitHubRepository) -> Repo {
+        let last_activity = chrono::DateTime::parse_from_rfc3339(&r.updated_at)
+            .ok()
+            .map(|dt| dt.with_timezone(&chrono::Utc));
+        let homepage = useful_homepage(r.homepage, &r.html_url);
+        let license = recognized_license(r.license.and_then(|l| l.spdx_id));
+
+        Repo {
+            provider: self.id().to_string(),
+            icon: self.icon().to_string(),
+            name: r.full_name,
+            language: r.language,
+            description: r.description,
+            url: r.html_url,
+            stars_today: None, // API doesn't provide daily stars
+            stars_total: Some(r.stargazers_count),
+            last_activity,
+            topics: r.topics,
+            is_starred: false,
+            is_cloned: false,
+            is_seen: false,
+            movement: None,
+            health: None,
+            homepage,
+            license,
+            is_sponsorable: false,
+            has_binaries: false,
+            has_container: false,
+            is_suspicious: false,
+        }
+    }
+
+    /// Fetch trending repositories from GitHub by scraping the daily or weekly trending page
+    async fn fetch_trending(
+        &self,
+        language: Option<&str>,
+        weekly: bool,
+    ) -> Result<Vec<TrendingRepo>> {
+        let since = if weekly { "weekly" } else { "daily" };
         let url = if let Some(lang) = language {
-            format!("https://github.com/trending/{lang}?since=daily")
+            format!("https://github.com/trending/{lang}?since={since}")
         } else {
-            "https://github.com/trending?since=daily".to_string()
+            format!("https://github.com/trending?since={since}")
         };
 
         let html = self.http.get_html(&url).await?;
         Self::parse_trending_html(&html)
     }
 
+    /// Fetch the trending pages for the language filter
+    ///
+    /// Polyglot repos are only listed under their primary language, so with secondary
+    /// matching enabled the overall page is checked too. Returns the repos plus the
+    /// names that match through a secondary language.
+    async fn fetch_trending_filtered(
+        &self,
+        token: Option<&str>,
+        langs: &LanguageFilter,
+        weekly: bool,
+    ) -> Result<(Vec<TrendingRepo>, HashSet<String>)> {
+        if langs.languages.is_empty() {
+            return Ok((self.fetch_trending(None, weekly).await?, HashSet::new()));
+        }
+
+        // Try fetching for each language filter and combine results
+        let mut all_repos = Vec::new();
+        for lang in &langs.languages {
+            if let Ok(repos) = self.fetch_trending(Some(lang), weekly).await {
+                all_repos.extend(repos);
+            }
+        }
+        if !langs.checks_secondary() {
+            return Ok((all_repos, HashSet::new()));
+        }
+
+        if let Ok(repos) = self.fetch_trending(None, weekly).await {
+            for repo in repos {
+                if !all_repos.iter().any(|r| r.name == repo.name) {
+                    all_repos.push(repo);
+                }
+            }
+        }
+        let candidates = all_repos
+            .iter()
+            .filter(|r| !langs.matches(r.language.as_ref()))
+            .map(|r| r.name.clone())
+            .take(LANGUAGE_LOOKUP_LIMIT)
+            .collect();
+        let breakdowns = self.language_breakdowns(candidates, token).await;
+
+        Ok((all_repos, Self::secondary_matches(&breakdowns, langs)))
+    }
+
     /// Parse HTML from GitHub trending page
     fn parse_trending_html(html: &str) -> Result<Vec<TrendingRepo>> {
         let document = Html::parse_document(html);
@@ -124,7 +282,7 @@ impl GitHub {
                 .next()
                 .map(|e| e.text().collect::<String>().trim().to_string());
 
-            // Extract stars (today and total)
+            // Extract stars (today and total); the weekly page has "stars this week"
             let stars_text: Vec<String> = article
                 .select(&star_selector)
                 .map(|e| e.text().collect::<String>().trim().to_string())
@@ -132,7 +290,7 @@ impl GitHub {
 
             let stars_total = stars_text
                 .iter()
-                .find(|s| !s.contains("stars today"))
+                .find(|s| !s.contains("stars today") && !s.contains("stars this week"))
                 .and_then(|s| {
                     s.replace(',', "")
                         .split_whitespace()
@@ -184,67 +342,33 @@ impl Provider for GitHub {
         limit: usize,
         langs: &LanguageFilter,
     ) -> Result<Vec<Repo>> {
-        // Use API if topic exclusion is configured (API provides topics)
-        if !cfg.exclude_topics.is_empty() {
-            let api_repos = self.fetch_trending_api(cfg.token.as_deref()).await?;
+        // Use API if topic filtering is configured (API provides topics)
+        if !cfg.topics.is_empty() {
+            let mut api_repos = self.fetch_trending_api(cfg.token.as_deref()).await?;
+            let secondary = self
+                .infer_languages(&mut api_repos, cfg.token.as_deref(), langs)
+                .await;
 
             let repos = api_repos
                 .into_iter()
-                .filter(|r| {
-                    // Filter by language
-                    if !langs.matches(r.language.as_ref()) {
-                        return false;
-                    }
-                    // Filter by excluded topics
-                    !r.topics.iter().any(|topic| {
-                        cfg.exclude_topics
-                            .iter()
-                            .any(|excluded| topic.eq_ignore_ascii_case(excluded))
-                    })
-                })
+                .filter(|r| langs.matches(r.language.as_ref()) || secondary.contains(&r.full_name))
+                .filter(|r| cfg.topics.matches(&r.topics))
                 .skip(offset)
                 .take(limit)
-                .map(|r| {
-                    let last_activity = chrono::DateTime::parse_from_rfc3339(&r.updated_at)
-                        .ok()
-                        .map(|dt| dt.with_timezone(&chrono::Utc));
-
-                    Repo {
-                        provider: self.id().to_string(),
-                        icon: self.icon().to_string(),
-                        name: r.full_name,
-                        language: r.language,
-                        description: r.description,
-                        url: r.html_url,
-                        stars_today: None, // API doesn't provide daily stars
-                        stars_total: Some(r.stargazers_count),
-                        last_activity,
-                        topics: r.topics,
-                        is_starred: false,
-                    }
-                })
+                .map(|r| self.api_repo(r))
                 .collect();
 
             return Ok(repos);
         }
 
         // Fall back to HTML scraping (original behavior)
-        let trending = if langs.languages.is_empty() {
-            self.fetch_trending(None).await?
-        } else {
-            // Try fetching for each language filter and combine results
-            let mut all_repos = Vec::new();
-            for lang in &langs.languages {
-                if let Ok(repos) = self.fetch_trending(Some(lang)).await {
-                    all_repos.extend(repos);
-                }
-            }
-            all_repos
-        };
+        let (trending, secondary) = self
+            .fetch_trending_filtered(cfg.token.as_deref(), langs, cfg.weekly)
+            .await?;
 
         let repos = trending
             .into_iter()
-            .filter(|r| langs.matches(r.language.as_ref()))
+            .filter(|r| langs.matches(r.language.as_ref()) || secondary.contains(&r.name))
             .skip(offset)
             .take(limit)
             .map(|r| Repo {
@@ -259,6 +383,17 @@ impl Provider for GitHub {
                 last_activity: Some(chrono::Utc::now()), // GitHub trending = active today
                 topics: r.topics,
                 is_starred: false,
+                is_cloned: false,
+                is_seen: false,
+                movement: None,
+                health: None,
+                // Not on the trending page; the batched lookup fills it in for `show_homepage`
+                homepage: None,
+                license: None,
+                is_sponsorable: false,
+                has_binaries: false,
+                has_container: false,
+                is_suspicious: false,
             })
             .collect();
 
@@ -267,6 +402,71 @@ impl Provider for GitHub {
 }
 
 impl GitHub {
+    /// Fill missing languages from topics, then from the languages API if the filter needs them
+    ///
+    /// Returns the repositories that match the filter through a secondary language.
+    async fn infer_languages(
+        &self,
+        repos: &mut [GitHubRepository],
+        token: Option<&str>,
+        langs: &LanguageFilter,
+    ) -> HashSet<String> {
+        for r in repos.iter_mut() {
+            r.language = r
+                .language
+                .take()
+                .or_else(|| language_from_topics(&r.topics));
+        }
+
+        let candidates: Vec<String> = repos
+            .iter()
+            .filter(|r| match r.language {
+                None => langs.needs_lookup() || langs.checks_secondary(),
+                Some(_) => langs.checks_secondary() && !langs.matches(r.language.as_ref()),
+            })
+            .map(|r| r.full_name.clone())
+            .take(LANGUAGE_LOOKUP_LIMIT)
+            .collect();
+        if candidates.is_empty() {
+            return HashSet::new();
+        }
+
+        let mut breakdowns = self.language_breakdowns(candidates, token).await;
+        let secondary = Self::secondary_matches(&breakdowns, langs);
+        for r in repos.iter_mut() {
+            if r.language.is_none() {
+                r.language = breakdowns.remove(&r.full_name).and_then(primary_language);
+            }
+        }
+        secondary
+    }
+
+    /// Repositories whose languages breakdown matches the filter
+    fn secondary_matches(
+        breakdowns: &HashMap<String, HashMap<String, f64>>,
+        langs: &LanguageFilter,
+    ) -> HashSet<String> {
+        breakdowns
+            .iter()
+            .filter(|(_, breakdown)| langs.matches_breakdown(breakdown))
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Fetch the languages breakdown (bytes per language) of repositories
+    async fn language_breakdowns(
+        &self,
+        names: Vec<String>,
+        token: Option<&str>,
+    ) -> HashMap<String, HashMap<String, f64>> {
+        let lookups = names.into_iter().map(|name| async move {
+            let url = format!("https://api.github.com/repos/{name}/languages");
+            let languages: HashMap<String, f64> = self.http.get_json(&url, token).await.ok()?;
+            Some((name, languages))
+        });
+        join_all(lookups).await.into_iter().flatten().collect()
+    }
+
     /// Star a repository on GitHub
     pub async fn star_repo(&self, owner: &str, repo: &str, token: &str) -> Result<()> {
         let url = format!("https://api.github.com/user/starred/{owner}/{repo}");
@@ -274,6 +474,517 @@ impl GitHub {
         Ok(())
     }
 
+    /// Unstar a repository on GitHub
+    pub async fn unstar_repo(&self, owner: &str, repo: &str, token: &str) -> Result<()> {
+        let url = format!("https://api.github.com/user/starred/{owner}/{repo}");
+        self.http.delete(&url, Some(token)).await?;
+        Ok(())
+    }
+
+    /// Fetch the description of a repository
+    pub async fn repo_description(
+        &self,
+        owner: &str,
+        repo: &str,
+        token: &str,
+    ) -> Result<Option<String>> {
+        #[derive(Deserialize)]
+        struct RepoInfo {
+            description: Option<String>,
+        }
+
+        let url = format!("https://api.github.com/repos/{owner}/{repo}");
+        let info: RepoInfo = self.http.get_json(&url, Some(token)).await?;
+        Ok(info.description)
+    }
+
+    /// Look up the homepage of a repository, which the trending page doesn't list
+    pub async fn repo_homepage(
+        &self,
+        owner: &str,
+        repo: &str,
+        token: Option<&str>,
+    ) -> Result<Option<String>> {
+        #[derive(Deserialize)]
+        struct RepoInfo {
+            homepage: Option<String>,
+            html_url: String,
+        }
+
+        let url = format!("https://api.github.com/repos/{owner}/{repo}");
+        let info: RepoInfo = self.http.get_json(&url, token).await?;
+        Ok(useful_homepage(info.homepage, &info.html_url))
+    }
+
+    /// Add a starred repository to a named Stars list, creating the list if missing
+    ///
+    /// Returns `true` when the list had to be created.
+    pub async fn add_to_list(
+        &self,
+        owner: &str,
+        repo: &str,
+        list_name: &str,
+        token: &str,
+    ) -> Result<bool> {
+        #[derive(Deserialize)]
+        struct RepoData {
+            repository: Option<RepoNode>,
+        }
+        #[derive(Deserialize)]
+        struct RepoNode {
+            id: String,
+        }
+
+        let data: RepoData = self
+            .graphql(
+                "query($owner: String!, $name: String!) { repository(owner: $owner, name: $name) { id } }",
+                json!({ "owner": owner, "name": repo }),
+                token,
+            )
+            .await?;
+        let repo_id = data
+            .repository
+            .with_context(|| format!("Repository not found: {owner}/{repo}"))?
+            .id;
+
+        let lists = self.user_lists(token).await?;
+        let (target_id, created) = match find_list(&lists, list_name) {
+            Some(list) => (list.id.clone(), false),
+            None => (self.create_list(list_name, token).await?, true),
+        };
+
+        // The mutation replaces the item's list memberships, so keep the existing ones
+        let mut list_ids = vec![target_id.clone()];
+        for list in &lists {
+            if list.id != target_id && self.list_contains(&list.id, &repo_id, token).await? {
+                list_ids.push(list.id.clone());
+            }
+        }
+
+        let _: serde_json::Value = self
+            .graphql(
+                "mutation($item: ID!, $lists: [ID!]!) { updateUserListsForItem(input: { itemId: $item, listIds: $lists }) { clientMutationId } }",
+                json!({ "item": repo_id, "lists": list_ids }),
+                token,
+            )
+            .await?;
+
+        Ok(created)
+    }
+
+    /// All Stars lists of the authenticated user
+    async fn user_lists(&self, token: &str) -> Result<Vec<UserList>> {
+        #[derive(Deserialize)]
+        struct Data {
+            viewer: Viewer,
+        }
+        #[derive(Deserialize)]
+        struct Viewer {
+            lists: Connection<UserList>,
+        }
+
+        let mut lists = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let data: Data = self
+                .graphql(
+                    "query($cursor: String) { viewer { lists(first: 100, after: $cursor) { nodes { id name } pageInfo { hasNextPage endCursor } } } }",
+                    json!({ "cursor": cursor }),
+                    token,
+                )
+                .await?;
+            lists.extend(data.viewer.lists.nodes);
+            match data.viewer.lists.page_info {
+                PageInfo {
+                    has_next_page: true,
+                    end_cursor: Some(next),
+                } => cursor = Some(next),
+                _ => break,
+            }
+        }
+
+        Ok(lists)
+    }
+
+    /// Create a Stars list and return its ID
+    async fn create_list(&self, name: &str, token: &str) -> Result<String> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Data {
+            create_user_list: Created,
+        }
+        #[derive(Deserialize)]
+        struct Created {
+            list: UserList,
+        }
+
+        let data: Data = self
+            .graphql(
+                "mutation($name: String!) { createUserList(input: { name: $name }) { list { id name } } }",
+                json!({ "name": name }),
+                token,
+            )
+            .await?;
+        Ok(data.create_user_list.list.id)
+    }
+
+    /// Whether a Stars list already contains the repository with the given node ID
+    async fn list_contains(&self, list_id: &str, repo_id: &str, token: &str) -> Result<bool> {
+        #[derive(Deserialize)]
+        struct Data {
+            node: Option<ListNode>,
+        }
+        #[derive(Deserialize)]
+        struct ListNode {
+            items: Connection<ItemNode>,
+        }
+        #[derive(Deserialize)]
+        struct ItemNode {
+            id: Option<String>,
+        }
+
+        let mut cursor: Option<String> = None;
+        loop {
+            let data: Data = self
+                .graphql(
+                    "query($id: ID!, $cursor: String) { node(id: $id) { ... on UserList { items(first: 100, after: $cursor) { nodes { ... on Repository { id } } pageInfo { hasNextPage endCursor } } } } }",
+                    json!({ "id": list_id, "cursor": cursor }),
+                    token,
+                )
+                .await?;
+            let Some(list) = data.node else {
+                return Ok(false);
+            };
+            if list
+                .items
+                .nodes
+                .iter()
+                .any(|item| item.id.as_deref() == Some(repo_id))
+            {
+                return Ok(true);
+            }
+            match list.items.page_info {
+                PageInfo {
+                    has_next_page: true,
+                    end_cursor: Some(next),
+                } => cursor = Some(next),
+                _ => return Ok(false),
+            }
+        }
+    }
+
+    /// Run a GraphQL query and surface GraphQL-level errors
+    async fn graphql<T: serde::de::DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+        token: &str,
+    ) -> Result<T> {
+        let body = json!({ "query": query, "variables": variables });
+        let response: GraphQlResponse<T> =
+            self.http.post_json(GRAPHQL_URL, Some(token), &body).await?;
+
+        if let Some(error) = response.errors.first() {
+            anyhow::bail!("GitHub GraphQL error: {}", error.message);
+        }
+        response.data.context("GitHub GraphQL response had no data")
+    }
+
+    /// Whether the latest release ships uploaded assets (prebuilt binaries)
+    pub async fn has_release_assets(&self, name: &str, token: Option<&str>) -> Result<bool> {
+        #[derive(Deserialize)]
+        struct Release {
+            #[serde(default)]
+            assets: Vec<serde_json::Value>,
+        }
+
+        let url = format!("https://api.github.com/repos/{name}/releases/latest");
+        let release: Release = self.http.get_json(&url, token).await?;
+        Ok(!release.assets.is_empty())
+    }
+
+    /// SPDX id of the license GitHub recognizes in a repository
+    pub async fn repo_license(&self, name: &str, token: Option<&str>) -> Result<Option<String>> {
+        #[derive(Deserialize)]
+        struct RepoInfo {
+            license: Option<License>,
+        }
+
+        let url = format!("https://api.github.com/repos/{name}");
+        let info: RepoInfo = self.http.get_json(&url, token).await?;
+        Ok(recognized_license(info.license.and_then(|l| l.spdx_id)))
+    }
+
+    /// Stars, open issues and (with push access) 14-day views of a repository
+    pub async fn repo_stats(&self, name: &str, token: Option<&str>) -> Result<RepoStats> {
+        #[derive(Deserialize)]
+        struct RepoInfo {
+            stargazers_count: u64,
+            open_issues_count: u64,
+        }
+        #[derive(Deserialize)]
+        struct Views {
+            count: u64,
+        }
+
+        let url = format!("https://api.github.com/repos/{name}");
+        let info: RepoInfo = self.http.get_json(&url, token).await?;
+
+        // Traffic needs push access; other tokens (or none) simply get no views
+        let views = match token {
+            Some(token) => {
+                let url = format!("https://api.github.com/repos/{name}/traffic/views");
+                self.http
+                    .get_json::<Views>(&url, Some(token))
+                    .await
+                    .ok()
+                    .map(|v| v.count)
+            }
+            None => None,
+        };
+
+        Ok(RepoStats {
+            stars: info.stargazers_count,
+            open_issues: info.open_issues_count,
+            views,
+        })
+    }
+
+    /// Names of the files and directories at the repository root
+    pub async fn root_files(&self, name: &str, token: Option<&str>) -> Result<Vec<String>> {
+        let url = format!("https://api.github.com/repos/{name}/contents");
+        let entries: Vec<Entry> = self.http.get_json(&url, token).await?;
+        Ok(entry_names(entries))
+    }
+
+    /// Text of the README GitHub shows for a repository
+    pub async fn readme(&self, name: &str, token: Option<&str>) -> Result<String> {
+        let url = format!("https://api.github.com/repos/{name}/readme");
+        let file: FileContent = self.http.get_json(&url, token).await?;
+        file.text()
+    }
+
+    /// Whether `ghcr.io/owner/repo` is a public container image with at least one tag
+    pub async fn has_ghcr_image(&self, name: &str) -> Result<bool> {
+        #[derive(Deserialize)]
+        struct RegistryToken {
+            token: String,
+        }
+        #[derive(Deserialize)]
+        struct Tags {
+            #[serde(default)]
+            tags: Option<Vec<String>>,
+        }
+
+        // Image names are lowercase; anonymous pull tokens cover public images
+        let image = name.to_lowercase();
+        let url = format!("https://ghcr.io/token?scope=repository:{image}:pull");
+        let token: RegistryToken = self.http.get_json(&url, None).await?;
+        let url = format!("https://ghcr.io/v2/{image}/tags/list?n=1");
+        let tags: Tags = self.http.get_json(&url, Some(&token.token)).await?;
+        Ok(tags.tags.is_some_and(|tags| !tags.is_empty()))
+    }
+
+    /// Owners among `owners` (user or organization logins) with a GitHub Sponsors listing
+    pub async fn sponsorable_owners(
+        &self,
+        owners: &[String],
+        token: &str,
+    ) -> Result<HashSet<String>> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Owner {
+            #[serde(default)]
+            has_sponsors_listing: bool,
+        }
+
+        if owners.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let variables: serde_json::Map<String, serde_json::Value> = owners
+            .iter()
+            .enumerate()
+            .map(|(i, owner)| (format!("o{i}"), json!(owner)))
+            .collect();
+        let data: HashMap<String, Option<Owner>> = self
+            .graphql(
+                &sponsors_query(owners.len()),
+                serde_json::Value::Object(variables),
+                token,
+            )
+            .await?;
+
+        Ok(owners
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| {
+                data.get(&format!("o{i}"))
+                    .and_then(Option::as_ref)
+                    .is_some_and(|owner| owner.has_sponsors_listing)
+            })
+            .map(|(_, owner)| owner.clone())
+            .collect())
+    }
+
+    /// Planned enrichments of several repositories (`owner/repo`) in one GraphQL query
+    ///
+    /// Results follow the order of `names`; repos that couldn't be resolved are `None`.
+    pub async fn repo_batch(
+        &self,
+        names: &[String],
+        plan: Plan,
+        token: &str,
+    ) -> Result<Vec<Option<Enrichment>>> {
+        let mut variables = serde_json::Map::new();
+        for (i, name) in names.iter().enumerate() {
+            let (owner, repo) = name
+                .split_once('/')
+                .with_context(|| format!("Invalid repository name: {name}"))?;
+            variables.insert(format!("o{i}"), json!(owner));
+            variables.insert(format!("n{i}"), json!(repo));
+        }
+        let body = json!({
+            "query": batch_query(names.len(), plan),
+            "variables": variables,
+        });
+
+        // Unresolvable repos come back as errors next to the data of the others
+        let response: GraphQlResponse<HashMap<String, Option<BatchRepo>>> =
+            self.http.post_json(GRAPHQL_URL, Some(token), &body).await?;
+        let mut data = match (response.data, response.errors.first()) {
+            (Some(data), _) => data,
+            (None, Some(error)) => anyhow::bail!("GitHub GraphQL error: {}", error.message),
+            (None, None) => anyhow::bail!("GitHub GraphQL response had no data"),
+        };
+
+        Ok((0..names.len())
+            .map(|i| {
+                data.remove(&format!("r{i}"))
+                    .flatten()
+                    .map(BatchRepo::into_enrichment)
+            })
+            .collect())
+    }
+
+    /// Collect the signals used for the project health score
+    pub async fn repo_health(
+        &self,
+        owner: &str,
+        repo: &str,
+        token: Option<&str>,
+    ) -> Result<HealthSignals> {
+        #[derive(Deserialize)]
+        struct RepoInfo {
+            pushed_at: Option<String>,
+            open_issues_count: Option<u64>,
+            stargazers_count: Option<u64>,
+        }
+        #[derive(Deserialize)]
+        struct Release {
+            published_at: Option<String>,
+        }
+        #[derive(Deserialize)]
+        struct Contributor {}
+
+        let base = format!("https://api.github.com/repos/{owner}/{repo}");
+        let info: RepoInfo = self.http.get_json(&base, token).await?;
+
+        // No release and no contributor access are common; treat them as unknown
+        let release: Option<Release> = self
+            .http
+            .get_json(&format!("{base}/releases/latest"), token)
+            .await
+            .ok();
+        let contributors: Option<Vec<Contributor>> = self
+            .http
+            .get_json(
+                &format!("{base}/contributors?per_page={CONTRIBUTOR_LIMIT}"),
+                token,
+            )
+            .await
+            .ok();
+
+        Ok(HealthSignals {
+            last_push: parse_timestamp(info.pushed_at),
+            last_release: parse_timestamp(release.and_then(|r| r.published_at)),
+            open_issues: info.open_issues_count,
+            stars: info.stargazers_count,
+            contributors: contributors.map(|c| c.len()),
+        })
+    }
+
+    /// Collect the signals a star spike is judged against
+    pub async fn star_signals(
+        &self,
+        owner: &str,
+        repo: &str,
+        token: Option<&str>,
+    ) -> Result<StarSignals> {
+        #[derive(Deserialize)]
+        struct RepoInfo {
+            created_at: Option<String>,
+        }
+        #[derive(Deserialize)]
+        struct Contributor {
+            contributions: u64,
+        }
+
+        let base = format!("https://api.github.com/repos/{owner}/{repo}");
+        let info: RepoInfo = self.http.get_json(&base, token).await?;
+        // The top contributors' commits are enough to tell a handful of commits apart
+        let contributors: Option<Vec<Contributor>> = self
+            .http
+            .get_json(
+                &format!("{base}/contributors?per_page={CONTRIBUTOR_LIMIT}"),
+                token,
+            )
+            .await
+            .ok();
+
+        Ok(StarSignals {
+            created_at: parse_timestamp(info.created_at),
+            commits: contributors
+                .as_ref()
+                .map(|c| c.iter().map(|c| c.contributions).sum()),
+            contributors: contributors.map(|c| c.len()),
+        })
+    }
+
+    /// Owner account age, repository age and README of a repository about to be cloned
+    ///
+    /// Each part that can't be fetched is left unknown.
+    pub async fn safety_signals(
+        &self,
+        owner: &str,
+        repo: &str,
+        token: Option<&str>,
+    ) -> SafetySignals {
+        #[derive(Deserialize)]
+        struct Created {
+            created_at: Option<String>,
+        }
+
+        let account: Option<Created> = self
+            .http
+            .get_json(&format!("https://api.github.com/users/{owner}"), token)
+            .await
+            .ok();
+        let base = format!("https://api.github.com/repos/{owner}/{repo}");
+        let info: Option<Created> = self.http.get_json(&base, token).await.ok();
+        let readme = self
+            .http
+            .get_text(&format!("{base}/readme"), "application/vnd.github.raw")
+            .await
+            .ok();
+
+        SafetySignals {
+            account_created: parse_timestamp(account.and_then(|a| a.created_at)),
+            repo_created: parse_timestamp(info.and_then(|i| i.created_at)),
+            readme,
+        }
+    }
+
     /// Check if a repository is starred
     #[allow(dead_code)]
     pub async fn check_starred(&self, owner: &str, repo: &str, token: &str) -> Result<bool> {
@@ -315,9 +1026,255 @@ impl GitHub {
     }
 }
 
+/// A page of GraphQL connection nodes
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Connection<T> {
+    nodes: Vec<T>,
+    page_info: PageInfo,
+}
+
+/// One repository of a batched query; fields that weren't asked for are absent
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct BatchRepo {
+    owner: Option<BatchOwner>,
+    latest_release: Option<BatchRelease>,
+    object: Option<BatchTree>,
+    pushed_at: Option<String>,
+    stargazer_count: Option<u64>,
+    issues: Option<BatchCount>,
+    pull_requests: Option<BatchCount>,
+    default_branch_ref: Option<BatchBranch>,
+    viewer_has_starred: bool,
+    created_at: Option<String>,
+    /// The default branch again under an alias, for the star spike signals
+    commit_count: Option<BatchBranch>,
+    homepage_url: Option<String>,
+    license_info: Option<BatchLicense>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct BatchLicense {
+    spdx_id: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct BatchOwner {
+    has_sponsors_listing: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct BatchRelease {
+    published_at: Option<String>,
+    release_assets: BatchCount,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct BatchCount {
+    total_count: u64,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct BatchTree {
+    entries: Vec<BatchEntry>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct BatchEntry {
+    name: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct BatchBranch {
+    target: Option<BatchCommit>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct BatchCommit {
+    history: Option<BatchHistory>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct BatchHistory {
+    total_count: Option<u64>,
+    nodes: Vec<BatchCommitNode>,
+}
+
+impl BatchBranch {
+    fn history(self) -> Option<BatchHistory> {
+        self.target.and_then(|c| c.history)
+    }
+}
+
+impl BatchHistory {
+    /// Distinct authors of the fetched commits, capped like the REST lookup
+    fn authors(&self) -> usize {
+        let authors: HashSet<&str> = self
+            .nodes
+            .iter()
+            .filter_map(|n| n.author.as_ref())
+            .filter_map(|a| {
+                a.user
+                    .as_ref()
+                    .map(|u| u.login.as_str())
+                    .or(a.email.as_deref())
+            })
+            .collect();
+        authors.len().min(CONTRIBUTOR_LIMIT)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct BatchCommitNode {
+    author: Option<BatchAuthor>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct BatchAuthor {
+    email: Option<String>,
+    user: Option<BatchUser>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct BatchUser {
+    login: String,
+}
+
+impl BatchRepo {
+    fn into_enrichment(self) -> Enrichment {
+        let release = self.latest_release.unwrap_or_default();
+        let contributors = self
+            .default_branch_ref
+            .and_then(BatchBranch::history)
+            .map(|history| history.authors());
+        let commits = self.commit_count.and_then(BatchBranch::history);
+        let open_issues = self
+            .issues
+            .zip(self.pull_requests)
+            .map(|(issues, prs)| issues.total_count + prs.total_count);
+
+        Enrichment {
+            sponsorable: self.owner.is_some_and(|o| o.has_sponsors_listing),
+            starred: self.viewer_has_starred,
+            star_signals: StarSignals {
+                created_at: parse_timestamp(self.created_at),
+                commits: commits.as_ref().and_then(|h| h.total_count),
+                contributors: commits.as_ref().map(BatchHistory::authors),
+            },
+            has_release_assets: release.release_assets.total_count > 0,
+            root_files: self
+                .object
+                .map(|tree| tree.entries.into_iter().map(|e| e.name).collect())
+                .unwrap_or_default(),
+            health: HealthSignals {
+                last_push: parse_timestamp(self.pushed_at),
+                last_release: parse_timestamp(release.published_at),
+                open_issues,
+                stars: self.stargazer_count,
+                contributors,
+            },
+            homepage: self.homepage_url,
+            license: recognized_license(self.license_info.and_then(|l| l.spdx_id)),
+        }
+    }
+}
+
+/// Find a Stars list by name, ignoring case
+fn find_list<'a>(lists: &'a [UserList], name: &str) -> Option<&'a UserList> {
+    lists
+        .iter()
+        .find(|list| list.name.trim().eq_ignore_ascii_case(name.trim()))
+}
+
+/// One aliased `repository` lookup per repo with the fields of the planned enrichments
+fn batch_query(count: usize, plan: Plan) -> String {
+    let mut fields = vec!["__typename"];
+    if plan.sponsors {
+        fields.push("owner { ... on Sponsorable { hasSponsorsListing } }");
+    }
+    if plan.binaries || plan.health {
+        fields.push("latestRelease { publishedAt releaseAssets { totalCount } }");
+    }
+    if plan.containers {
+        fields.push(r#"object(expression: "HEAD:") { ... on Tree { entries { name } } }"#);
+    }
+    if plan.starred {
+        fields.push("viewerHasStarred");
+    }
+    if plan.suspicious {
+        fields.push("createdAt commitCount: defaultBranchRef { target { ... on Commit { history(first: 50) { totalCount nodes { author { email user { login } } } } } } }");
+    }
+    if plan.homepage {
+        fields.push("homepageUrl");
+    }
+    if plan.license {
+        fields.push("licenseInfo { spdxId }");
+    }
+    if plan.health {
+        fields.push("pushedAt stargazerCount issues(states: OPEN) { totalCount } pullRequests(states: OPEN) { totalCount }");
+        fields.push("defaultBranchRef { target { ... on Commit { history(first: 50) { nodes { author { email user { login } } } } } } }");
+    }
+    let fields = fields.join(" ");
+
+    let params: Vec<String> = (0..count)
+        .map(|i| format!("$o{i}: String!, $n{i}: String!"))
+        .collect();
+    let repos: Vec<String> = (0..count)
+        .map(|i| format!("r{i}: repository(owner: $o{i}, name: $n{i}) {{ {fields} }}"))
+        .collect();
+    format!("query({}) {{ {} }}", params.join(", "), repos.join(" "))
+}
+
+/// Parse an RFC 3339 timestamp from the API
+fn parse_timestamp(ts: Option<String>) -> Option<chrono::DateTime<chrono::Utc>> {
+    ts.and_then(|t| chrono::DateTime::parse_from_rfc3339(&t).ok())
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+}
+
+/// One aliased `repositoryOwner` lookup per owner, batched into a single query
+fn sponsors_query(count: usize) -> String {
+    let params: Vec<String> = (0..count).map(|i| format!("$o{i}: String!")).collect();
+    let fields: Vec<String> = (0..count)
+        .map(|i| {
+            format!("o{i}: repositoryOwner(login: $o{i}) {{ ... on Sponsorable {{ hasSponsorsListing }} }}")
+        })
+        .collect();
+    format!("query({}) {{ {} }}", params.join(", "), fields.join(" "))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::filter::TopicFilter;
+    use crate::model::GiteaRanking;
+
+    #[test]
+    fn test_find_list_ignores_case() {
+        let lists = vec![
+            UserList {
+                id: "L1".to_string(),
+                name: "To Try".to_string(),
+            },
+            UserList {
+                id: "L2".to_string(),
+                name: "Rust".to_string(),
+            },
+        ];
+        assert_eq!(find_list(&lists, "to try").unwrap().id, "L1");
+        assert_eq!(find_list(&lists, " Rust ").unwrap().id, "L2");
+        assert!(find_list(&lists, "Go").is_none());
+    }
 
     #[test]
     fn test_github_provider_metadata() {
@@ -340,7 +1297,11 @@ mod tests {
             timeout_secs: 10,
             token: None,
             base_url: None,
-            exclude_topics: vec![],
+            topics: TopicFilter::default(),
+            gitea_ranking: GiteaRanking::default(),
+            weekly: false,
+            query: None,
+            hub_kinds: Vec::new(),
         };
         let filter = LanguageFilter::new(vec![]);
 
@@ -360,4 +1321,54 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_parse_weekly_trending_html() {
+        let html = r#"<article class="Box-row">
+            <h2><a href="/owner/repo">owner / repo</a></h2>
+            <p>A repo</p>
+            <span class="d-inline-block float-sm-right">1,234 stars this week</span>
+        </article>"#;
+        let repos = GitHub::parse_trending_html(html).unwrap();
+        assert_eq!(repos[0].name, "owner/repo");
+        assert_eq!(repos[0].stars_today, None);
+        assert_eq!(repos[0].stars_total, None);
+    }
+
+    #[test]
+    fn test_sponsors_query() {
+        let query = sponsors_query(2);
+        assert!(query.starts_with("query($o0: String!, $o1: String!) {"));
+        assert!(query.contains("o1: repositoryOwner(login: $o1)"));
+        assert!(query.contains("... on Sponsorable { hasSponsorsListing }"));
+    }
+
+    #[test]
+    fn test_batch_query_has_planned_fields() {
+        let plan = Plan {
+            containers: true,
+            ..Plan::default()
+        };
+        let query = batch_query(2, plan);
+        assert!(
+            query.starts_with("query($o0: String!, $n0: String!, $o1: String!, $n1: String!) {")
+        );
+        assert!(query.contains("r1: repository(owner: $o1, name: $n1) { __typename object("));
+        assert!(!query.contains("latestRelease"));
+        assert!(!query.contains("hasSponsorsListing"));
+        assert!(!query.contains("viewerHasStarred"));
+
+        let query = batch_query(
+            1,
+            Plan {
+                health: true,
+                starred: true,
+                ..plan
+            },
+        );
+        assert!(query.contains("latestRelease { publishedAt"));
+        assert!(query.contains(" viewerHasStarred "));
+        assert!(!query.contains("commitCount"));
+        assert!(query.contains("history(first: 50)"));
+    }
 }
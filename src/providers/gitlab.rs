@@ -1,9 +1,15 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use futures::future::join_all;
 use serde::Deserialize;
+use std::collections::HashMap;
 
 use crate::http::HttpClient;
-use crate::model::{LanguageFilter, Provider, ProviderCfg, Repo};
+use crate::lookup::{entry_names, readme_name, Entry, FileContent};
+use crate::model::{
+    language_from_topics, primary_language, recognized_license, LanguageFilter, Provider,
+    ProviderCfg, Repo, LANGUAGE_LOOKUP_LIMIT,
+};
 
 /// GitLab provider using explore API
 pub struct GitLab {
@@ -20,6 +26,9 @@ struct GitLabProject {
     web_url: String,
     #[serde(default)]
     topics: Vec<String>,
+    /// Name of `topics` before GitLab 14.5
+    #[serde(default)]
+    tag_list: Vec<String>,
     last_activity_at: Option<String>,
 }
 
@@ -36,8 +45,12 @@ impl GitLab {
         Self { http }
     }
 
-    /// Fetch recently active projects from GitLab
-    async fn fetch_projects(&self, token: Option<&str>) -> Result<Vec<GitLabProject>> {
+    /// Fetch recently active projects from a GitLab instance
+    async fn fetch_projects(
+        &self,
+        base_url: &str,
+        token: Option<&str>,
+    ) -> Result<Vec<GitLabProject>> {
         // Get date from 7 days ago in ISO format
         let week_ago = (chrono::Utc::now() - chrono::Duration::days(7))
             .format("%Y-%m-%dT00:00:00Z")
@@ -45,7 +58,7 @@ impl GitLab {
 
         // Search for projects with recent activity, sorted by activity date (descending)
         let url = format!(
-            "https://gitlab.com/api/v4/projects?order_by=last_activity_at&sort=desc&last_activity_after={week_ago}&per_page=100"
+            "{base_url}/api/v4/projects?order_by=last_activity_at&sort=desc&last_activity_after={week_ago}&per_page=100"
         );
 
         let projects: Vec<GitLabProject> = self.http.get_json(&url, token).await?;
@@ -57,56 +70,149 @@ impl GitLab {
             .collect())
     }
 
-    /// Extract language from topics (GitLab uses topics, not a dedicated language field)
-    fn extract_language(topics: &[String]) -> Option<String> {
-        // Common programming language tags (lowercase for comparison)
-        let languages = [
-            "rust",
-            "go",
-            "golang",
-            "python",
-            "javascript",
-            "typescript",
-            "java",
-            "c",
-            "cpp",
-            "c++",
-            "csharp",
-            "c#",
-            "ruby",
-            "php",
-            "swift",
-            "kotlin",
-            "scala",
-            "haskell",
-            "elixir",
-            "erlang",
-        ];
-
-        topics
-            .iter()
-            .find(|topic| {
-                let lower = topic.to_lowercase();
-                // Use exact match instead of contains to avoid false positives
-                languages.iter().any(|&lang| lower == lang)
-            })
-            .map(|s| {
-                // Normalize language name
-                let lower = s.to_lowercase();
-                match lower.as_str() {
-                    "golang" => "Go".to_string(),
-                    "c++" => "C++".to_string(),
-                    "c#" => "C#".to_string(),
-                    _ => {
-                        // Capitalize first letter
-                        let mut chars = s.chars();
-                        match chars.next() {
-                            None => String::new(),
-                            Some(first) => first.to_uppercase().chain(chars).collect(),
-                        }
-                    }
-                }
-            })
+    /// Get all starred projects (`namespace/project`) of the authenticated user
+    pub async fn get_user_stars(&self, base_url: &str, token: &str) -> Result<Vec<String>> {
+        #[derive(Deserialize)]
+        struct User {
+            id: u64,
+        }
+        #[derive(Deserialize)]
+        struct StarredProject {
+            path_with_namespace: String,
+        }
+
+        let user: User = self
+            .http
+            .get_json(&format!("{base_url}/api/v4/user"), Some(token))
+            .await?;
+
+        let mut all_projects = Vec::new();
+        let mut page = 1;
+        let per_page = 100;
+
+        loop {
+            let url = format!(
+                "{base_url}/api/v4/users/{}/starred_projects?per_page={per_page}&page={page}&simple=true",
+                user.id
+            );
+
+            let projects: Vec<StarredProject> = self.http.get_json(&url, Some(token)).await?;
+
+            if projects.is_empty() {
+                break;
+            }
+
+            all_projects.extend(projects.into_iter().map(|p| p.path_with_namespace));
+            page += 1;
+
+            // Safety limit to avoid infinite loops
+            if page > 50 {
+                break;
+            }
+        }
+
+        Ok(all_projects)
+    }
+
+    /// Look up the primary language of projects through the languages API
+    async fn detect_languages(
+        &self,
+        base_url: &str,
+        paths: Vec<String>,
+        token: Option<&str>,
+    ) -> HashMap<String, String> {
+        let lookups = paths.into_iter().map(|path| async move {
+            let url = format!(
+                "{base_url}/api/v4/projects/{}/languages",
+                path.replace('/', "%2F")
+            );
+            let languages: HashMap<String, f64> = self.http.get_json(&url, token).await.ok()?;
+            primary_language(languages).map(|language| (path, language))
+        });
+        join_all(lookups).await.into_iter().flatten().collect()
+    }
+
+    /// Names of the files and directories at the repository root
+    pub async fn root_files(
+        &self,
+        base_url: &str,
+        path: &str,
+        token: Option<&str>,
+    ) -> Result<Vec<String>> {
+        let url = format!(
+            "{base_url}/api/v4/projects/{}/repository/tree?per_page=100",
+            path.replace('/', "%2F")
+        );
+        let entries: Vec<Entry> = self.http.get_json(&url, token).await?;
+        Ok(entry_names(entries))
+    }
+
+    /// Text of the README at the repository root, `None` without one
+    pub async fn readme(
+        &self,
+        base_url: &str,
+        path: &str,
+        token: Option<&str>,
+    ) -> Result<Option<String>> {
+        let files = self.root_files(base_url, path, token).await?;
+        let Some(file) = readme_name(&files) else {
+            return Ok(None);
+        };
+        let url = format!(
+            "{base_url}/api/v4/projects/{}/repository/files/{file}?ref=HEAD",
+            path.replace('/', "%2F")
+        );
+        let file: FileContent = self.http.get_json(&url, token).await?;
+        file.text().map(Some)
+    }
+
+    /// Key of the license GitLab detects in a project, e.g. `mit` or `apache-2.0`
+    pub async fn license(
+        &self,
+        base_url: &str,
+        path: &str,
+        token: Option<&str>,
+    ) -> Result<Option<String>> {
+        #[derive(Deserialize)]
+        struct Project {
+            license: Option<License>,
+        }
+        #[derive(Deserialize)]
+        struct License {
+            key: Option<String>,
+        }
+
+        let url = format!(
+            "{base_url}/api/v4/projects/{}?license=true",
+            path.replace('/', "%2F")
+        );
+        let project: Project = self.http.get_json(&url, token).await?;
+        Ok(recognized_license(project.license.and_then(|l| l.key)))
+    }
+
+    /// Whether the latest release links downloadable assets (source archives don't count)
+    pub async fn has_release_assets(
+        &self,
+        base_url: &str,
+        path: &str,
+        token: Option<&str>,
+    ) -> Result<bool> {
+        #[derive(Deserialize)]
+        struct Release {
+            assets: Assets,
+        }
+        #[derive(Deserialize)]
+        struct Assets {
+            #[serde(default)]
+            links: Vec<serde_json::Value>,
+        }
+
+        let url = format!(
+            "{base_url}/api/v4/projects/{}/releases/permalink/latest",
+            path.replace('/', "%2F")
+        );
+        let release: Release = self.http.get_json(&url, token).await?;
+        Ok(!release.assets.links.is_empty())
     }
 }
 
@@ -127,15 +233,41 @@ impl Provider for GitLab {
         limit: usize,
         langs: &LanguageFilter,
     ) -> Result<Vec<Repo>> {
-        let projects = self.fetch_projects(cfg.token.as_deref()).await?;
+        let base_url = cfg.base_url.as_deref().unwrap_or("https://gitlab.com");
+        let projects = self.fetch_projects(base_url, cfg.token.as_deref()).await?;
 
-        let repos = projects
+        // GitLab has no language field; topics are the cheap first guess
+        let mut projects: Vec<_> = projects
             .into_iter()
-            .map(|p| {
-                let language = Self::extract_language(&p.topics);
+            .map(|mut p| {
+                if p.topics.is_empty() {
+                    p.topics = std::mem::take(&mut p.tag_list);
+                }
+                let language = language_from_topics(&p.topics);
                 (p, language)
             })
-            .filter(|(_, lang)| langs.matches(lang.as_ref()))
+            .collect();
+
+        if langs.needs_lookup() {
+            let unknown = projects
+                .iter()
+                .filter(|(_, lang)| lang.is_none())
+                .map(|(p, _)| p.path_with_namespace.clone())
+                .take(LANGUAGE_LOOKUP_LIMIT)
+                .collect();
+            let detected = self
+                .detect_languages(base_url, unknown, cfg.token.as_deref())
+                .await;
+            for (p, lang) in &mut projects {
+                if lang.is_none() {
+                    *lang = detected.get(&p.path_with_namespace).cloned();
+                }
+            }
+        }
+
+        let repos = projects
+            .into_iter()
+            .filter(|(p, lang)| langs.matches(lang.as_ref()) && cfg.topics.matches(&p.topics))
             .skip(offset)
             .take(limit)
             .map(|(p, language)| {
@@ -156,6 +288,16 @@ impl Provider for GitLab {
                     last_activity,
                     topics: p.topics,
                     is_starred: false,
+                    is_cloned: false,
+                    is_seen: false,
+                    movement: None,
+                    health: None,
+                    homepage: None,
+                    license: None,
+                    is_sponsorable: false,
+                    has_binaries: false,
+                    has_container: false,
+                    is_suspicious: false,
                 }
             })
             .collect();
@@ -170,6 +312,20 @@ impl Provider for GitLab {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::filter::TopicFilter;
+
+    #[test]
+    fn test_tag_list_fallback() {
+        let project: GitLabProject = serde_json::from_str(
+            r#"{"name": "p", "path_with_namespace": "g/p", "description": null,
+                "star_count": 1, "web_url": "https://gitlab.com/g/p",
+                "tag_list": ["rust"], "last_activity_at": null}"#,
+        )
+        .unwrap();
+        assert!(project.topics.is_empty());
+        assert_eq!(project.tag_list, ["rust"]);
+    }
+    use crate::model::GiteaRanking;
 
     #[test]
     fn test_gitlab_provider_metadata() {
@@ -181,14 +337,14 @@ mod tests {
     #[test]
     fn test_extract_language() {
         assert_eq!(
-            GitLab::extract_language(&["rust".to_string(), "cli".to_string()]),
+            language_from_topics(&["rust".to_string(), "cli".to_string()]),
             Some("Rust".to_string())
         );
         assert_eq!(
-            GitLab::extract_language(&["web".to_string(), "python".to_string()]),
+            language_from_topics(&["web".to_string(), "python".to_string()]),
             Some("Python".to_string())
         );
-        assert_eq!(GitLab::extract_language(&["web".to_string()]), None);
+        assert_eq!(language_from_topics(&["web".to_string()]), None);
     }
 
     #[tokio::test]
@@ -204,7 +360,11 @@ mod tests {
             timeout_secs: 10,
             token: None,
             base_url: None,
-            exclude_topics: vec![],
+            topics: TopicFilter::default(),
+            gitea_ranking: GiteaRanking::default(),
+            weekly: false,
+            query: None,
+            hub_kinds: Vec::new(),
         };
         let filter = LanguageFilter::new(vec![]);
 
@@ -0,0 +1,175 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::links::{repo_link, Post, Resolver};
+use crate::http::HttpClient;
+use crate::model::{LanguageFilter, Provider, ProviderCfg, Repo};
+
+/// Algolia HN Search API root used when no base URL is configured
+const API_URL: &str = "https://hn.algolia.com/api/v1";
+
+/// Hacker News provider: today's Show HN posts that link to a GitHub or GitLab repo
+///
+/// Points stand in for today's stars. GitHub repos are looked up for their language,
+/// description and star count.
+pub struct HackerNews {
+    resolver: Resolver,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    hits: Vec<Hit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Hit {
+    title: Option<String>,
+    url: Option<String>,
+    #[serde(default)]
+    points: u64,
+    created_at_i: Option<i64>,
+}
+
+impl HackerNews {
+    pub fn new(timeout_secs: u64) -> Result<Self> {
+        Ok(Self::with_client(HttpClient::new(timeout_secs)?))
+    }
+
+    /// Create a Hacker News provider with a custom `HttpClient`
+    #[allow(dead_code)]
+    pub fn with_client(http: HttpClient) -> Self {
+        Self {
+            resolver: Resolver::new(http),
+        }
+    }
+
+    /// Show HN posts created within the last `days` days
+    async fn show_hn(&self, api: &str, days: i64) -> Result<Vec<Hit>> {
+        let since = (chrono::Utc::now() - chrono::Duration::days(days)).timestamp();
+        let url = format!(
+            "{api}/search?tags=show_hn&numericFilters=created_at_i%3E{since}&hitsPerPage=200"
+        );
+        let response: SearchResponse = self.resolver.http.get_json(&url, None).await?;
+        Ok(response.hits)
+    }
+}
+
+#[async_trait]
+impl Provider for HackerNews {
+    fn id(&self) -> &'static str {
+        "hackernews"
+    }
+
+    fn icon(&self) -> &'static str {
+        "[HN]"
+    }
+
+    async fn top_today(
+        &self,
+        cfg: &ProviderCfg,
+        offset: usize,
+        limit: usize,
+        langs: &LanguageFilter,
+    ) -> Result<Vec<Repo>> {
+        let api = cfg
+            .base_url
+            .as_deref()
+            .unwrap_or(API_URL)
+            .trim_end_matches('/');
+        let days = if cfg.weekly { 7 } else { 1 };
+
+        let posts = self
+            .show_hn(api, days)
+            .await?
+            .into_iter()
+            .filter_map(|hit| {
+                let (forge, path) = repo_link(hit.url.as_deref()?)?;
+                Some(Post {
+                    forge,
+                    path,
+                    title: hit.title.map(|t| {
+                        t.strip_prefix("Show HN:")
+                            .map_or(t.as_str(), str::trim)
+                            .to_string()
+                    }),
+                    points: hit.points,
+                    created_at: hit
+                        .created_at_i
+                        .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0)),
+                })
+            })
+            .collect();
+
+        Ok(self
+            .resolver
+            .repos(self, posts, cfg, offset, limit, langs)
+            .await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::TopicFilter;
+    use crate::model::GiteaRanking;
+
+    #[tokio::test]
+    async fn test_show_hn_posts_ranked_by_points() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/search")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "tags".into(),
+                "show_hn".into(),
+            ))
+            .with_body(
+                r#"{"hits": [
+                    {"title": "Show HN: A blog post", "url": "https://example.com/post", "points": 500},
+                    {"title": "Show HN: Tiny tool", "url": "https://gitlab.com/me/tool", "points": 40,
+                     "created_at_i": 1714557600},
+                    {"title": "Show HN: Fast thing", "url": "https://github.com/org/fast", "points": 120},
+                    {"title": "Show HN: Fast thing again", "url": "https://github.com/org/fast#readme", "points": 3},
+                    {"title": "Ask HN: no link", "url": null, "points": 90}
+                ]}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/repos/org/fast")
+            .with_body(
+                r#"{"full_name": "org/fast", "description": "Fast thing", "stargazers_count": 900,
+                    "language": "Rust", "homepage": null}"#,
+            )
+            .create_async()
+            .await;
+
+        let http = HttpClient::builder().max_retries(0).build().unwrap();
+        let hn = HackerNews {
+            resolver: Resolver::with_github_api(http, server.url()),
+        };
+        let cfg = ProviderCfg {
+            timeout_secs: 5,
+            token: None,
+            base_url: Some(server.url()),
+            topics: TopicFilter::default(),
+            gitea_ranking: GiteaRanking::default(),
+            weekly: false,
+            query: None,
+            hub_kinds: Vec::new(),
+        };
+        let repos = hn
+            .top_today(&cfg, 0, 5, &LanguageFilter::new(vec![]))
+            .await
+            .unwrap();
+
+        let names: Vec<_> = repos.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["org/fast", "me/tool"]);
+        assert_eq!(repos[0].stars_today, Some(120));
+        assert_eq!(repos[0].stars_total, Some(900));
+        assert_eq!(repos[0].language.as_deref(), Some("Rust"));
+        assert_eq!(repos[1].url, "https://gitlab.com/me/tool");
+        assert_eq!(repos[1].description.as_deref(), Some("Tiny tool"));
+        assert!(repos[1].last_activity.is_some());
+    }
+}
@@ -0,0 +1,235 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::future::join_all;
+use serde::Deserialize;
+
+use crate::http::HttpClient;
+use crate::model::{HubKind, LanguageFilter, Provider, ProviderCfg, Repo};
+
+/// Hub URL used when no base URL is configured
+const HUB_URL: &str = "https://huggingface.co";
+
+/// Hugging Face Hub provider: trending models, datasets and spaces
+///
+/// Likes stand in for total stars and the Hub's trending score (recent likes) for
+/// today's stars.
+pub struct HuggingFace {
+    http: HttpClient,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HubRepo {
+    id: String,
+    #[serde(default)]
+    likes: u64,
+    /// Downloads over the last 30 days; spaces have none
+    downloads: Option<u64>,
+    #[serde(default)]
+    trending_score: Option<i64>,
+    #[serde(rename = "pipeline_tag")]
+    pipeline_tag: Option<String>,
+    #[serde(rename = "library_name")]
+    library_name: Option<String>,
+    /// Spaces only: gradio, streamlit, docker or static
+    sdk: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl HuggingFace {
+    pub fn new(timeout_secs: u64) -> Result<Self> {
+        Ok(Self {
+            http: HttpClient::new(timeout_secs)?,
+        })
+    }
+
+    /// Create a Hugging Face provider with a custom `HttpClient`
+    #[allow(dead_code)]
+    pub fn with_client(http: HttpClient) -> Self {
+        Self { http }
+    }
+
+    /// Trending repositories of one kind
+    async fn trending(
+        &self,
+        hub: &str,
+        kind: HubKind,
+        limit: usize,
+        token: Option<&str>,
+    ) -> Result<Vec<(HubKind, HubRepo)>> {
+        let url = format!(
+            "{hub}/api/{}?sort=trendingScore&direction=-1&limit={limit}",
+            kind.api_path()
+        );
+        let repos: Vec<HubRepo> = self.http.get_json(&url, token).await?;
+        Ok(repos.into_iter().map(|repo| (kind, repo)).collect())
+    }
+}
+
+/// One-line summary, since the listing carries no description
+fn summary(kind: HubKind, repo: &HubRepo) -> Option<String> {
+    let what = match kind {
+        HubKind::Models => repo
+            .library_name
+            .as_deref()
+            .map(|lib| format!("{lib} model")),
+        HubKind::Datasets => Some("Dataset".to_string()),
+        HubKind::Spaces => repo.sdk.as_deref().map(|sdk| format!("{sdk} space")),
+    };
+    let downloads = repo
+        .downloads
+        .filter(|n| *n > 0)
+        .map(|n| format!("{n} downloads in the last 30 days"));
+    match (what, downloads) {
+        (Some(what), Some(downloads)) => Some(format!("{what}, {downloads}")),
+        (what, downloads) => what.or(downloads),
+    }
+}
+
+#[async_trait]
+impl Provider for HuggingFace {
+    fn id(&self) -> &'static str {
+        "huggingface"
+    }
+
+    fn icon(&self) -> &'static str {
+        "[HF]"
+    }
+
+    async fn top_today(
+        &self,
+        cfg: &ProviderCfg,
+        offset: usize,
+        limit: usize,
+        langs: &LanguageFilter,
+    ) -> Result<Vec<Repo>> {
+        let hub = cfg
+            .base_url
+            .as_deref()
+            .unwrap_or(HUB_URL)
+            .trim_end_matches('/');
+        let token = cfg.token.as_deref();
+        // The pipeline tag (text-generation, ...) doubles as the language, so a
+        // language filter needs headroom
+        let fetch = if langs.needs_lookup() {
+            100
+        } else {
+            offset + limit
+        };
+
+        let fetched = join_all(
+            cfg.hub_kinds
+                .iter()
+                .map(|kind| self.trending(hub, *kind, fetch, token)),
+        )
+        .await;
+        let mut repos = Vec::new();
+        for result in fetched {
+            repos.extend(result?);
+        }
+        repos.sort_by_key(|(_, repo)| std::cmp::Reverse(repo.trending_score.unwrap_or(0)));
+
+        Ok(repos
+            .into_iter()
+            .filter(|(_, repo)| langs.matches(repo.pipeline_tag.as_ref()))
+            .skip(offset)
+            .take(limit)
+            .map(|(kind, repo)| Repo {
+                provider: self.id().to_string(),
+                icon: self.icon().to_string(),
+                description: summary(kind, &repo),
+                url: format!("{hub}/{}{}", kind.web_prefix(), repo.id),
+                name: repo.id,
+                language: repo.pipeline_tag,
+                stars_today: repo
+                    .trending_score
+                    .and_then(|score| u64::try_from(score).ok()),
+                stars_total: Some(repo.likes),
+                last_activity: repo
+                    .last_modified
+                    .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
+                    .map(|dt| dt.with_timezone(&chrono::Utc)),
+                topics: Vec::new(),
+                is_starred: false,
+                is_cloned: false,
+                is_seen: false,
+                movement: None,
+                health: None,
+                homepage: None,
+                license: None,
+                is_sponsorable: false,
+                has_binaries: false,
+                has_container: false,
+                is_suspicious: false,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::TopicFilter;
+    use crate::model::GiteaRanking;
+
+    #[tokio::test]
+    async fn test_models_and_datasets_merged_by_trending_score() {
+        let mut server = mockito::Server::new_async().await;
+        let models = server
+            .mock("GET", "/api/models")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "sort".into(),
+                "trendingScore".into(),
+            ))
+            .with_body(
+                r#"[{"id": "org/llm", "likes": 900, "downloads": 120000, "trendingScore": 80,
+                     "pipeline_tag": "text-generation", "library_name": "transformers"},
+                    {"id": "org/tts", "likes": 50, "downloads": 0, "trendingScore": 5,
+                     "pipeline_tag": "text-to-speech"}]"#,
+            )
+            .create_async()
+            .await;
+        let datasets = server
+            .mock("GET", "/api/datasets")
+            .match_query(mockito::Matcher::Any)
+            .with_body(
+                r#"[{"id": "org/corpus", "likes": 300, "downloads": 4000, "trendingScore": 40}]"#,
+            )
+            .create_async()
+            .await;
+
+        let http = HttpClient::builder().max_retries(0).build().unwrap();
+        let hf = HuggingFace::with_client(http);
+        let cfg = ProviderCfg {
+            timeout_secs: 5,
+            token: None,
+            base_url: Some(server.url()),
+            topics: TopicFilter::default(),
+            gitea_ranking: GiteaRanking::default(),
+            weekly: false,
+            query: None,
+            hub_kinds: vec![HubKind::Models, HubKind::Datasets],
+        };
+        let repos = hf
+            .top_today(&cfg, 0, 3, &LanguageFilter::new(vec![]))
+            .await
+            .unwrap();
+
+        models.assert_async().await;
+        datasets.assert_async().await;
+        let names: Vec<_> = repos.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["org/llm", "org/corpus", "org/tts"]);
+        assert_eq!(repos[0].stars_today, Some(80));
+        assert_eq!(repos[0].stars_total, Some(900));
+        assert_eq!(repos[0].language.as_deref(), Some("text-generation"));
+        assert_eq!(
+            repos[0].description.as_deref(),
+            Some("transformers model, 120000 downloads in the last 30 days")
+        );
+        assert_eq!(
+            repos[1].url,
+            format!("{}/datasets/org/corpus", server.url())
+        );
+        assert_eq!(repos[2].description, None);
+    }
+}
@@ -0,0 +1,221 @@
+use futures::future::join_all;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+use crate::http::HttpClient;
+use crate::model::{useful_homepage, LanguageFilter, Provider, ProviderCfg, Repo};
+
+/// GitHub API root for the repo lookups
+const GITHUB_API: &str = "https://api.github.com";
+
+/// GitHub repos looked up when a language filter needs their languages
+const LOOKUP_LIMIT: usize = 30;
+
+/// Forge a post links to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Forge {
+    GitHub,
+    GitLab,
+}
+
+/// A post on a link aggregator resolved to the repository it links to
+#[derive(Debug)]
+pub struct Post {
+    pub forge: Forge,
+    /// `owner/repo`, or `group/subgroup/repo` on GitLab
+    pub path: String,
+    pub title: Option<String>,
+    /// Points or upvotes, standing in for today's stars
+    pub points: u64,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRepo {
+    full_name: String,
+    description: Option<String>,
+    stargazers_count: u64,
+    language: Option<String>,
+    homepage: Option<String>,
+}
+
+/// Turns posts linking to repositories into ranked repos, looking GitHub repos up for
+/// their language, description and star count
+pub struct Resolver {
+    pub http: HttpClient,
+    github_api: String,
+}
+
+impl Resolver {
+    pub fn new(http: HttpClient) -> Self {
+        Self {
+            http,
+            github_api: GITHUB_API.to_string(),
+        }
+    }
+
+    /// Resolve GitHub repos through a different API root (for testing)
+    #[cfg(test)]
+    pub fn with_github_api(http: HttpClient, github_api: String) -> Self {
+        Self { http, github_api }
+    }
+
+    async fn github_repo(&self, path: &str, token: Option<&str>) -> Option<GitHubRepo> {
+        let url = format!("{}/repos/{path}", self.github_api);
+        self.http.get_json(&url, token).await.ok()
+    }
+
+    /// Rank `posts` by points, keeping each repository's best post
+    pub async fn repos(
+        &self,
+        provider: &dyn Provider,
+        mut posts: Vec<Post>,
+        cfg: &ProviderCfg,
+        offset: usize,
+        limit: usize,
+        langs: &LanguageFilter,
+    ) -> Vec<Repo> {
+        posts.sort_by_key(|post| std::cmp::Reverse(post.points));
+        // A repo posted twice keeps its best-scoring post
+        let mut seen = HashSet::new();
+        posts.retain(|post| seen.insert(post.path.to_ascii_lowercase()));
+
+        let wanted = if langs.needs_lookup() {
+            LOOKUP_LIMIT
+        } else {
+            offset + limit
+        };
+        posts.truncate(wanted);
+
+        let token = cfg.token.as_deref();
+        let lookups = posts.iter().map(|post| async move {
+            match post.forge {
+                Forge::GitHub => self.github_repo(&post.path, token).await,
+                Forge::GitLab => None,
+            }
+        });
+        let details = join_all(lookups).await;
+
+        posts
+            .into_iter()
+            .zip(details)
+            .filter(|(_, details)| {
+                langs.matches(details.as_ref().and_then(|d| d.language.as_ref()))
+            })
+            .skip(offset)
+            .take(limit)
+            .map(|(post, details)| {
+                let (name, description, stars_total, language, homepage) = match details {
+                    Some(d) => (
+                        d.full_name,
+                        d.description
+                            .filter(|d| !d.trim().is_empty())
+                            .or(post.title),
+                        Some(d.stargazers_count),
+                        d.language,
+                        d.homepage,
+                    ),
+                    None => (post.path, post.title, None, None, None),
+                };
+                let url = match post.forge {
+                    Forge::GitHub => format!("https://github.com/{name}"),
+                    Forge::GitLab => format!("https://gitlab.com/{name}"),
+                };
+
+                Repo {
+                    provider: provider.id().to_string(),
+                    icon: provider.icon().to_string(),
+                    name,
+                    language,
+                    description,
+                    homepage: useful_homepage(homepage, &url),
+                    url,
+                    stars_today: Some(post.points),
+                    stars_total,
+                    last_activity: post.created_at,
+                    topics: Vec::new(),
+                    is_starred: false,
+                    is_cloned: false,
+                    is_seen: false,
+                    movement: None,
+                    health: None,
+                    license: None,
+                    is_sponsorable: false,
+                    has_binaries: false,
+                    has_container: false,
+                    is_suspicious: false,
+                }
+            })
+            .collect()
+    }
+}
+
+/// The GitHub or GitLab repository a link points into, `None` for other links
+pub fn repo_link(url: &str) -> Option<(Forge, String)> {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))?;
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+    let (host, path) = rest.split_once('/')?;
+    let forge = match host.to_ascii_lowercase().trim_start_matches("www.") {
+        "github.com" => Forge::GitHub,
+        "gitlab.com" => Forge::GitLab,
+        _ => return None,
+    };
+
+    let segments: Vec<_> = path.split('/').filter(|s| !s.is_empty()).collect();
+    let segments = match forge {
+        Forge::GitHub => segments.get(..2)?,
+        // GitLab nests groups; pages inside a project start at `/-/`
+        Forge::GitLab => {
+            let end = segments
+                .iter()
+                .position(|s| *s == "-")
+                .unwrap_or(segments.len());
+            segments.get(..end).filter(|s| s.len() >= 2)?
+        }
+    };
+    let path = segments.join("/");
+    let path = path.strip_suffix(".git").unwrap_or(&path).to_string();
+    Some((forge, path))
+}
+
+/// The first repository linked anywhere in free text, such as a Markdown post body
+pub fn find_repo_link(text: &str) -> Option<(Forge, String)> {
+    text.split(|c: char| c.is_whitespace() || "()[]<>\"'".contains(c))
+        .map(|word| word.trim_end_matches(['.', ',', ';', ':', '!']))
+        .find_map(repo_link)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repo_link() {
+        assert_eq!(
+            repo_link("https://github.com/owner/repo/tree/main/docs?tab=readme"),
+            Some((Forge::GitHub, "owner/repo".to_string()))
+        );
+        assert_eq!(
+            repo_link("https://www.github.com/owner/repo.git"),
+            Some((Forge::GitHub, "owner/repo".to_string()))
+        );
+        assert_eq!(
+            repo_link("https://gitlab.com/group/sub/repo/-/blob/main/README.md"),
+            Some((Forge::GitLab, "group/sub/repo".to_string()))
+        );
+        assert_eq!(repo_link("https://github.com/owner"), None);
+        assert_eq!(repo_link("https://example.com/owner/repo"), None);
+
+        assert_eq!(
+            find_repo_link("I built a tool.\n\nCode: [here](https://github.com/me/tool), enjoy"),
+            Some((Forge::GitHub, "me/tool".to_string()))
+        );
+        assert_eq!(
+            find_repo_link("Source at https://gitlab.com/group/app."),
+            Some((Forge::GitLab, "group/app".to_string()))
+        );
+        assert_eq!(find_repo_link("No links, see https://example.com"), None);
+    }
+}
@@ -1,7 +1,53 @@
+use anyhow::Result;
+
+use crate::config::Config;
+use crate::model::Provider;
+
+pub mod bitbucket;
+pub mod codeberg;
+pub mod crates;
+pub mod custom;
 pub mod gitea;
+pub mod gitee;
 pub mod github;
 pub mod gitlab;
+pub mod hackernews;
+pub mod huggingface;
+mod links;
+pub mod reddit;
 
+pub use bitbucket::Bitbucket;
+pub use codeberg::Codeberg;
+pub use crates::Crates;
+pub use custom::Custom;
 pub use gitea::Gitea;
+pub use gitee::Gitee;
 pub use github::GitHub;
 pub use gitlab::GitLab;
+pub use hackernews::HackerNews;
+pub use huggingface::HuggingFace;
+pub use reddit::Reddit;
+
+/// Instantiate a provider by id or alias using its configured timeout
+pub fn build(id: &str, config: &Config) -> Result<Box<dyn Provider>> {
+    let timeout_secs = config.get_timeout_secs(id);
+    // Aliases build the provider they point at; its instance comes with `provider_cfg`
+    let (id, _) = config.resolve_provider(id);
+    let id = id.as_str();
+    Ok(match id {
+        "github" => Box::new(GitHub::new(timeout_secs)?),
+        "gitlab" => Box::new(GitLab::new(timeout_secs)?),
+        "gitea" => Box::new(Gitea::new(timeout_secs)?),
+        "codeberg" => Box::new(Codeberg::new(timeout_secs)?),
+        "bitbucket" => Box::new(Bitbucket::new(timeout_secs)?),
+        "gitee" => Box::new(Gitee::new(timeout_secs)?),
+        "huggingface" => Box::new(HuggingFace::new(timeout_secs)?),
+        "crates" => Box::new(Crates::new(timeout_secs)?),
+        "hackernews" => Box::new(HackerNews::new(timeout_secs)?),
+        "reddit" => Box::new(Reddit::new(timeout_secs)?),
+        _ => match config.custom_provider(id) {
+            Some(def) => Box::new(Custom::new(def.clone(), timeout_secs)?),
+            None => anyhow::bail!("Unknown provider: {id}"),
+        },
+    })
+}
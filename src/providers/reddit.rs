@@ -0,0 +1,190 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::links::{find_repo_link, repo_link, Post, Resolver};
+use crate::http::HttpClient;
+use crate::model::{LanguageFilter, Provider, ProviderCfg, Repo};
+
+/// Site root used when no base URL is configured
+const SITE_URL: &str = "https://www.reddit.com";
+
+/// Reddit provider: today's top posts in the configured subreddits that link to a
+/// GitHub or GitLab repo
+///
+/// Upvotes stand in for today's stars. GitHub repos are looked up for their language,
+/// description and star count.
+pub struct Reddit {
+    resolver: Resolver,
+}
+
+#[derive(Debug, Deserialize)]
+struct Listing {
+    data: ListingData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListingData {
+    #[serde(default)]
+    children: Vec<Child>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Child {
+    data: Link,
+}
+
+#[derive(Debug, Deserialize)]
+struct Link {
+    title: Option<String>,
+    /// The linked page, or the post itself for text posts
+    url: Option<String>,
+    /// Body of a text post, in Markdown
+    #[serde(default)]
+    selftext: String,
+    #[serde(default)]
+    score: i64,
+    created_utc: Option<f64>,
+}
+
+impl Reddit {
+    pub fn new(timeout_secs: u64) -> Result<Self> {
+        Ok(Self::with_client(HttpClient::new(timeout_secs)?))
+    }
+
+    /// Create a Reddit provider with a custom `HttpClient`
+    #[allow(dead_code)]
+    pub fn with_client(http: HttpClient) -> Self {
+        Self {
+            resolver: Resolver::new(http),
+        }
+    }
+
+    /// Top posts of the day (or week) across `subs`, joined as `rust+selfhosted`
+    async fn top_posts(&self, site: &str, subs: &str, weekly: bool) -> Result<Vec<Link>> {
+        let period = if weekly { "week" } else { "day" };
+        let url = format!("{site}/r/{subs}/top.json?t={period}&limit=100");
+        let listing: Listing = self.resolver.http.get_json(&url, None).await?;
+        Ok(listing.data.children.into_iter().map(|c| c.data).collect())
+    }
+}
+
+#[async_trait]
+impl Provider for Reddit {
+    fn id(&self) -> &'static str {
+        "reddit"
+    }
+
+    fn icon(&self) -> &'static str {
+        "[RD]"
+    }
+
+    async fn top_today(
+        &self,
+        cfg: &ProviderCfg,
+        offset: usize,
+        limit: usize,
+        langs: &LanguageFilter,
+    ) -> Result<Vec<Repo>> {
+        let Some(subs) = cfg.query.as_deref().filter(|subs| !subs.is_empty()) else {
+            return Ok(Vec::new());
+        };
+        let site = cfg
+            .base_url
+            .as_deref()
+            .unwrap_or(SITE_URL)
+            .trim_end_matches('/');
+
+        let posts = self
+            .top_posts(site, subs, cfg.weekly)
+            .await?
+            .into_iter()
+            .filter_map(|link| {
+                // Text posts usually link the repo somewhere in their body
+                let (forge, path) = link
+                    .url
+                    .as_deref()
+                    .and_then(repo_link)
+                    .or_else(|| find_repo_link(&link.selftext))?;
+                Some(Post {
+                    forge,
+                    path,
+                    title: link.title,
+                    points: u64::try_from(link.score).unwrap_or(0),
+                    #[allow(clippy::cast_possible_truncation)]
+                    created_at: link
+                        .created_utc
+                        .and_then(|ts| chrono::DateTime::from_timestamp(ts as i64, 0)),
+                })
+            })
+            .collect();
+
+        Ok(self
+            .resolver
+            .repos(self, posts, cfg, offset, limit, langs)
+            .await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::TopicFilter;
+    use crate::model::GiteaRanking;
+
+    #[tokio::test]
+    async fn test_repo_posts_ranked_by_upvotes() {
+        let mut server = mockito::Server::new_async().await;
+        let top = server
+            .mock("GET", "/r/rust+selfhosted/top.json")
+            .match_query(mockito::Matcher::UrlEncoded("t".into(), "day".into()))
+            .with_body(
+                r#"{"data": {"children": [
+                    {"data": {"title": "Meme", "url": "https://i.redd.it/cat.png", "score": 900}},
+                    {"data": {"title": "I made a dashboard", "score": 310, "created_utc": 1714557600.0,
+                              "url": "https://www.reddit.com/r/selfhosted/comments/abc/dash/",
+                              "selftext": "Source: [GitLab](https://gitlab.com/me/dash)."}},
+                    {"data": {"title": "Fast thing 1.0", "url": "https://github.com/org/fast", "score": 450}},
+                    {"data": {"title": "Fast thing, again", "url": "https://github.com/org/fast", "score": 12}}
+                ]}}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/repos/org/fast")
+            .with_body(
+                r#"{"full_name": "org/fast", "description": "Fast thing", "stargazers_count": 900,
+                    "language": "Rust", "homepage": null}"#,
+            )
+            .create_async()
+            .await;
+
+        let http = HttpClient::builder().max_retries(0).build().unwrap();
+        let reddit = Reddit {
+            resolver: Resolver::with_github_api(http, server.url()),
+        };
+        let cfg = ProviderCfg {
+            timeout_secs: 5,
+            token: None,
+            base_url: Some(server.url()),
+            topics: TopicFilter::default(),
+            gitea_ranking: GiteaRanking::default(),
+            weekly: false,
+            query: Some("rust+selfhosted".to_string()),
+            hub_kinds: Vec::new(),
+        };
+        let repos = reddit
+            .top_today(&cfg, 0, 5, &LanguageFilter::new(vec![]))
+            .await
+            .unwrap();
+
+        top.assert_async().await;
+        let names: Vec<_> = repos.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["org/fast", "me/dash"]);
+        assert_eq!(repos[0].stars_today, Some(450));
+        assert_eq!(repos[0].stars_total, Some(900));
+        assert_eq!(repos[1].url, "https://gitlab.com/me/dash");
+        assert_eq!(repos[1].description.as_deref(), Some("I made a dashboard"));
+        assert!(repos[1].last_activity.is_some());
+    }
+}
@@ -0,0 +1,25 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Process-wide switch for `--quiet`
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable quiet mode
+pub fn set(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Whether warnings and notices on stderr are suppressed
+pub fn enabled() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Print a warning or notice to stderr unless `--quiet` is set
+macro_rules! notice {
+    ($($arg:tt)*) => {
+        if !$crate::quiet::enabled() {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+pub(crate) use notice;
@@ -0,0 +1,76 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::model::Repo;
+
+/// Interleave repos from all providers into one ranking
+///
+/// Providers measure popularity differently (daily stars, total stars, recent
+/// activity), so repos are scored by their rank within their own provider:
+/// `weight / rank`. A provider weighted 2.0 puts its second entry level with
+/// another provider's first. Ties keep the incoming order.
+pub fn merge(repos: &mut [Repo], weights: &BTreeMap<String, f64>) {
+    let mut ranks: HashMap<String, usize> = HashMap::new();
+    let mut scored: Vec<(f64, Repo)> = repos
+        .iter()
+        .map(|repo| {
+            let rank = ranks.entry(repo.provider.clone()).or_insert(0);
+            *rank += 1;
+            let weight = weights.get(&repo.provider).copied().unwrap_or(1.0);
+            #[allow(clippy::cast_precision_loss)]
+            let score = weight / *rank as f64;
+            (score, repo.clone())
+        })
+        .collect();
+
+    // Stable sort keeps provider order for equal scores
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    for (slot, (_, repo)) in repos.iter_mut().zip(scored) {
+        *slot = repo;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_repo(provider: &str, name: &str) -> Repo {
+        Repo {
+            provider: provider.to_string(),
+            url: format!("https://example.com/{name}"),
+            ..Repo::test(name)
+        }
+    }
+
+    fn names(repos: &[Repo]) -> Vec<&str> {
+        repos.iter().map(|r| r.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_merge_interleaves_providers() {
+        let mut repos = vec![
+            create_test_repo("github", "gh1"),
+            create_test_repo("github", "gh2"),
+            create_test_repo("github", "gh3"),
+            create_test_repo("gitea", "ge1"),
+            create_test_repo("gitea", "ge2"),
+        ];
+
+        merge(&mut repos, &BTreeMap::new());
+        assert_eq!(names(&repos), ["gh1", "ge1", "gh2", "ge2", "gh3"]);
+    }
+
+    #[test]
+    fn test_merge_applies_weights() {
+        let mut repos = vec![
+            create_test_repo("github", "gh1"),
+            create_test_repo("github", "gh2"),
+            create_test_repo("gitea", "ge1"),
+            create_test_repo("gitea", "ge2"),
+            create_test_repo("gitea", "ge3"),
+        ];
+        let weights = BTreeMap::from([("gitea".to_string(), 2.0)]);
+
+        merge(&mut repos, &weights);
+        assert_eq!(names(&repos), ["ge1", "gh1", "ge2", "ge3", "gh2"]);
+    }
+}
@@ -0,0 +1,233 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::config::{ReadLaterConfig, ReadLaterService};
+use crate::http::HttpClient;
+
+/// Omnivore's hosted GraphQL endpoint, used when no `url` is configured
+const OMNIVORE_API: &str = "https://api-prod.omnivore.app/api/graphql";
+
+/// A URL to save, with what the services can store alongside it
+#[derive(Debug, Default)]
+pub struct Link<'a> {
+    pub url: &'a str,
+    pub title: Option<&'a str>,
+    pub description: Option<&'a str>,
+    /// Added to the configured tags
+    pub tags: Vec<String>,
+}
+
+impl<'a> Link<'a> {
+    pub fn new(url: &'a str) -> Self {
+        Self {
+            url,
+            ..Self::default()
+        }
+    }
+}
+
+/// Send a link to the configured read-later service
+///
+/// Linkding updates the bookmark instead when the URL was saved before.
+pub async fn save(config: &ReadLaterConfig, link: &Link<'_>, timeout_secs: u64) -> Result<()> {
+    let service = config.service.context(
+        "No read-later service configured. Set service, url and token under [readlater].",
+    )?;
+    let http = HttpClient::new(timeout_secs)?;
+
+    let authorization = match service {
+        ReadLaterService::Wallabag => {
+            let token = wallabag_token(&http, config).await?;
+            Some(format!("Bearer {token}"))
+        }
+        ReadLaterService::Omnivore => Some(required(config.token.as_ref(), "token")?.clone()),
+        ReadLaterService::Linkding => Some(format!(
+            "Token {}",
+            required(config.token.as_ref(), "token")?
+        )),
+    };
+
+    let (endpoint, body) = save_request(service, config, link)?;
+    let response: Value = http
+        .post_json_with_auth(&endpoint, authorization.as_deref(), &body)
+        .await?;
+
+    // GraphQL reports failures in the body of a successful response
+    if let Some(codes) = response.pointer("/data/saveUrl/errorCodes") {
+        anyhow::bail!("Omnivore rejected the URL: {codes}");
+    }
+    if let Some(errors) = response.get("errors") {
+        anyhow::bail!("Omnivore rejected the URL: {errors}");
+    }
+    Ok(())
+}
+
+/// Endpoint and JSON body that save `link` with the given service
+fn save_request(
+    service: ReadLaterService,
+    config: &ReadLaterConfig,
+    link: &Link<'_>,
+) -> Result<(String, Value)> {
+    let url = link.url;
+    let mut tags = config.tags.clone();
+    for tag in &link.tags {
+        if !tags.contains(tag) {
+            tags.push(tag.clone());
+        }
+    }
+
+    let request = match service {
+        ReadLaterService::Wallabag => {
+            let mut body = json!({ "url": url, "tags": tags.join(",") });
+            if let Some(title) = link.title {
+                body["title"] = json!(title);
+            }
+            (format!("{}/api/entries.json", base_url(config)?), body)
+        }
+        ReadLaterService::Omnivore => {
+            let labels: Vec<Value> = tags.iter().map(|t| json!({ "name": t })).collect();
+            (
+                config.url.as_deref().map_or_else(
+                    || OMNIVORE_API.to_string(),
+                    |u| u.trim_end_matches('/').to_string(),
+                ),
+                json!({
+                    "query": "mutation SaveUrl($input: SaveUrlInput!) { saveUrl(input: $input) { \
+                              ... on SaveSuccess { url } ... on SaveError { errorCodes } } }",
+                    "variables": {
+                        "input": {
+                            "clientRequestId": request_id(),
+                            "source": "api",
+                            "url": url,
+                            "labels": labels,
+                        }
+                    }
+                }),
+            )
+        }
+        ReadLaterService::Linkding => {
+            let mut body = json!({ "url": url, "tag_names": tags });
+            if let Some(title) = link.title {
+                body["title"] = json!(title);
+            }
+            if let Some(description) = link.description {
+                body["description"] = json!(description);
+            }
+            (format!("{}/api/bookmarks/", base_url(config)?), body)
+        }
+    };
+    Ok(request)
+}
+
+/// Exchange the configured credentials for a short-lived Wallabag access token
+async fn wallabag_token(http: &HttpClient, config: &ReadLaterConfig) -> Result<String> {
+    #[derive(Deserialize)]
+    struct TokenResponse {
+        access_token: String,
+    }
+
+    let body = json!({
+        "grant_type": "password",
+        "client_id": required(config.client_id.as_ref(), "client_id")?,
+        "client_secret": required(config.client_secret.as_ref(), "client_secret")?,
+        "username": required(config.username.as_ref(), "username")?,
+        "password": required(config.password.as_ref(), "password")?,
+    });
+    let endpoint = format!("{}/oauth/v2/token", base_url(config)?);
+    let response: TokenResponse = http
+        .post_json_with_auth(&endpoint, None, &body)
+        .await
+        .context("Wallabag login failed")?;
+    Ok(response.access_token)
+}
+
+fn base_url(config: &ReadLaterConfig) -> Result<&str> {
+    Ok(required(config.url.as_ref(), "url")?.trim_end_matches('/'))
+}
+
+fn required<'a>(value: Option<&'a String>, key: &str) -> Result<&'a String> {
+    value
+        .filter(|v| !v.trim().is_empty())
+        .with_context(|| format!("Missing `{key}` under [readlater]"))
+}
+
+/// Unique client request ID in UUID shape, derived from the current time
+fn request_id() -> String {
+    let nanos = chrono::Utc::now()
+        .timestamp_nanos_opt()
+        .unwrap_or_default()
+        .unsigned_abs();
+    let hex = format!("{:032x}", u128::from(nanos) * 0x9e37_79b9_7f4a_7c15);
+    format!(
+        "{}-{}-4{}-8{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[13..16],
+        &hex[17..20],
+        &hex[20..32]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(service: ReadLaterService) -> ReadLaterConfig {
+        ReadLaterConfig {
+            service: Some(service),
+            url: Some("https://read.example.org/".to_string()),
+            token: Some("secret".to_string()),
+            tags: vec!["trotd".to_string()],
+            ..ReadLaterConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_save_request_per_service() {
+        let url = "https://github.com/owner/tool";
+        let link = Link::new(url);
+
+        let cfg = config(ReadLaterService::Linkding);
+        let (endpoint, body) = save_request(ReadLaterService::Linkding, &cfg, &link).unwrap();
+        assert_eq!(endpoint, "https://read.example.org/api/bookmarks/");
+        assert_eq!(body, json!({ "url": url, "tag_names": ["trotd"] }));
+
+        let cfg = config(ReadLaterService::Wallabag);
+        let (endpoint, body) = save_request(ReadLaterService::Wallabag, &cfg, &link).unwrap();
+        assert_eq!(endpoint, "https://read.example.org/api/entries.json");
+        assert_eq!(body["tags"], "trotd");
+
+        let mut cfg = config(ReadLaterService::Omnivore);
+        cfg.url = None;
+        let (endpoint, body) = save_request(ReadLaterService::Omnivore, &cfg, &link).unwrap();
+        assert_eq!(endpoint, OMNIVORE_API);
+        assert_eq!(body["variables"]["input"]["url"], url);
+        assert_eq!(body["variables"]["input"]["labels"][0]["name"], "trotd");
+
+        let cfg = ReadLaterConfig::default();
+        assert!(save_request(ReadLaterService::Linkding, &cfg, &link).is_err());
+    }
+
+    #[test]
+    fn test_link_details_and_tags() {
+        let link = Link {
+            title: Some("owner/tool"),
+            description: Some("A tool"),
+            tags: vec!["rust".to_string(), "trotd".to_string()],
+            ..Link::new("https://github.com/owner/tool")
+        };
+        let cfg = config(ReadLaterService::Linkding);
+        let (_, body) = save_request(ReadLaterService::Linkding, &cfg, &link).unwrap();
+        assert_eq!(body["title"], "owner/tool");
+        assert_eq!(body["description"], "A tool");
+        assert_eq!(body["tag_names"], json!(["trotd", "rust"]));
+    }
+
+    #[test]
+    fn test_request_id_shape() {
+        let id = request_id();
+        let groups: Vec<usize> = id.split('-').map(str::len).collect();
+        assert_eq!(groups, [8, 4, 4, 4, 12]);
+    }
+}
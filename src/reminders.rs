@@ -0,0 +1,215 @@
+use anyhow::{Context, Result};
+use chrono::{Days, Months, NaiveDate, Utc};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::render::OutputFormat;
+use crate::state;
+
+/// A repository to bring back on a later date
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reminder {
+    pub name: String,
+    pub url: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub due: NaiveDate,
+}
+
+/// Date `delay` after `from`, for delays like `3d`, `2w`, `1m` or `1y`
+pub fn due_date(from: NaiveDate, delay: &str) -> Result<NaiveDate> {
+    let delay = delay.trim();
+    let unit_at = delay.char_indices().last().map_or(0, |(idx, _)| idx);
+    let (count, unit) = (&delay[..unit_at], &delay[unit_at..]);
+    let count: u32 = count
+        .parse()
+        .ok()
+        .filter(|n| *n > 0)
+        .with_context(|| format!("Invalid delay `{delay}`: use e.g. 3d, 2w, 1m or 1y"))?;
+
+    let due = match unit {
+        "d" => from.checked_add_days(Days::new(count.into())),
+        "w" => from.checked_add_days(Days::new(u64::from(count) * 7)),
+        "m" => from.checked_add_months(Months::new(count)),
+        "y" => count
+            .checked_mul(12)
+            .and_then(|months| from.checked_add_months(Months::new(months))),
+        _ => anyhow::bail!("Invalid delay `{delay}`: use e.g. 3d, 2w, 1m or 1y"),
+    };
+    due.with_context(|| format!("Delay `{delay}` is too far in the future"))
+}
+
+/// Pending reminders, kept in the data directory
+pub struct ReminderStore {
+    reminders_file: PathBuf,
+}
+
+impl ReminderStore {
+    /// Create a new reminder store
+    pub fn new() -> Result<Self> {
+        let data_dir = state::data_dir()?;
+
+        Ok(Self::with_dir(&data_dir))
+    }
+
+    fn with_dir(data_dir: &Path) -> Self {
+        Self {
+            reminders_file: data_dir.join("reminders.json"),
+        }
+    }
+
+    /// Pending reminders, soonest first
+    pub async fn list(&self) -> Result<Vec<Reminder>> {
+        Ok(state::read_json(&self.reminders_file)
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Add a reminder, replacing an earlier one for the same repository
+    pub async fn add(&self, reminder: Reminder) -> Result<()> {
+        let mut reminders = self.list().await?;
+        reminders.retain(|r| r.url != reminder.url);
+        reminders.push(reminder);
+        reminders.sort_by_key(|r| r.due);
+        self.save(&reminders).await
+    }
+
+    /// Remove and return the reminders due on or before `today`
+    pub async fn take_due(&self, today: NaiveDate) -> Result<Vec<Reminder>> {
+        let (due, pending): (Vec<Reminder>, Vec<Reminder>) =
+            self.list().await?.into_iter().partition(|r| r.due <= today);
+        if !due.is_empty() {
+            self.save(&pending).await?;
+        }
+        Ok(due)
+    }
+
+    async fn save(&self, reminders: &[Reminder]) -> Result<()> {
+        let content =
+            serde_json::to_string_pretty(reminders).context("Failed to serialize reminders")?;
+        state::write_atomic(&self.reminders_file, &content)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to write reminders file: {}",
+                    self.reminders_file.display()
+                )
+            })
+    }
+}
+
+/// "Reminders" section with the repos due today, each shown once
+///
+/// A `title` replaces the built-in heading.
+pub async fn section(format: OutputFormat, title: Option<&str>) -> Result<String> {
+    if !matches!(format, OutputFormat::Motd | OutputFormat::Accessible) {
+        return Ok(String::new());
+    }
+    let due = ReminderStore::new()?
+        .take_due(Utc::now().date_naive())
+        .await?;
+    Ok(render(&due, format, title))
+}
+
+fn render(due: &[Reminder], format: OutputFormat, title: Option<&str>) -> String {
+    if due.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::new();
+    if matches!(format, OutputFormat::Accessible) {
+        if let Some(title) = title {
+            let _ = writeln!(out, "{title}");
+        }
+        for reminder in due {
+            let _ = write!(out, "Reminder: {}", reminder.name);
+            if let Some(ref description) = reminder.description {
+                let _ = write!(out, ", {description}");
+            }
+            let _ = writeln!(out, ", {}", reminder.url);
+        }
+    } else {
+        let title = title.unwrap_or("⏰ Reminders:");
+        let _ = writeln!(out, "{}", title.bright_yellow().bold());
+        for reminder in due {
+            let _ = writeln!(
+                out,
+                "  {} {}",
+                reminder.name.bright_cyan().bold(),
+                reminder.url.bright_black()
+            );
+        }
+    }
+    format!("\n{out}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn reminder(name: &str, due: NaiveDate) -> Reminder {
+        Reminder {
+            name: name.to_string(),
+            url: format!("https://github.com/{name}"),
+            description: None,
+            due,
+        }
+    }
+
+    #[test]
+    fn test_due_date() {
+        let from = date(2024, 1, 31);
+        assert_eq!(due_date(from, "3d").unwrap(), date(2024, 2, 3));
+        assert_eq!(due_date(from, "2w").unwrap(), date(2024, 2, 14));
+        assert_eq!(due_date(from, "1m").unwrap(), date(2024, 2, 29));
+        assert_eq!(due_date(from, "1y").unwrap(), date(2025, 1, 31));
+        assert!(due_date(from, "0d").is_err());
+        assert!(due_date(from, "2x").is_err());
+        assert!(due_date(from, "w").is_err());
+        assert!(due_date(from, "").is_err());
+        assert!(due_date(from, "2é").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_take_due_once() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-reminders-test-{}",
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let store = ReminderStore::with_dir(&temp_dir);
+
+        store
+            .add(reminder("a/later", date(2024, 6, 1)))
+            .await
+            .unwrap();
+        store
+            .add(reminder("b/soon", date(2024, 5, 1)))
+            .await
+            .unwrap();
+        store
+            .add(reminder("b/soon", date(2024, 5, 2)))
+            .await
+            .unwrap();
+        assert_eq!(store.list().await.unwrap()[0].due, date(2024, 5, 2));
+
+        assert!(store.take_due(date(2024, 5, 1)).await.unwrap().is_empty());
+        let due = store.take_due(date(2024, 5, 10)).await.unwrap();
+        assert_eq!(due, [reminder("b/soon", date(2024, 5, 2))]);
+        assert!(store.take_due(date(2024, 5, 10)).await.unwrap().is_empty());
+        assert_eq!(store.list().await.unwrap().len(), 1);
+
+        colored::control::set_override(false);
+        assert_eq!(
+            render(&due, OutputFormat::Accessible, None),
+            "\nReminder: b/soon, https://github.com/b/soon\n"
+        );
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
@@ -0,0 +1,191 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::config::{extend_unique, Config, PresetConfig};
+use crate::http::HttpClient;
+use crate::state;
+
+/// Rules a team shares from one file, merged into every member's config
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RemoteRules {
+    /// Added to `general.blocklist`
+    #[serde(default)]
+    pub blocklist: Vec<String>,
+    /// Added to every provider's `include_topics`
+    #[serde(default)]
+    pub include_topics: Vec<String>,
+    /// Added to every provider's `exclude_topics`
+    #[serde(default)]
+    pub exclude_topics: Vec<String>,
+    /// Presets for `--preset`; a local preset of the same name wins
+    #[serde(default)]
+    pub preset: BTreeMap<String, PresetConfig>,
+}
+
+impl RemoteRules {
+    /// Parse a rules file; `.toml` sources are TOML, anything else JSON or TOML
+    fn parse(content: &str, source: &str) -> Result<Self> {
+        if Path::new(source)
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"))
+        {
+            return toml::from_str(content).context("Failed to parse remote rules as TOML");
+        }
+        serde_json::from_str(content)
+            .or_else(|_| toml::from_str(content))
+            .context("Failed to parse remote rules as JSON or TOML")
+    }
+
+    /// Merge into `config`; local settings are kept and shared lists are added
+    fn merge_into(self, config: &mut Config) {
+        extend_unique(&mut config.general.blocklist, &self.blocklist);
+        config.extend_topics(&self.include_topics, &self.exclude_topics);
+        for (name, preset) in self.preset {
+            config.preset.entry(name).or_insert(preset);
+        }
+    }
+}
+
+/// Timeout for fetching the rules file
+const FETCH_TIMEOUT_SECS: u64 = 10;
+
+/// Last fetched rules file, reused within the cache TTL and when a fetch fails
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedRules {
+    source: String,
+    fetched_at: DateTime<Utc>,
+    content: String,
+}
+
+/// Fetch the rules of `general.remote_rules_url` and merge them into the config
+///
+/// URLs are cached for `cache_ttl_mins`; when a fetch fails the last copy is used.
+/// Anything that isn't an `http(s)://` URL is read as a local file path. With
+/// `offline` a URL is never fetched and only its cached copy is used, however old.
+pub async fn apply(config: &mut Config, offline: bool) -> Result<()> {
+    let Some(source) = config
+        .general
+        .remote_rules_url
+        .clone()
+        .filter(|s| !s.trim().is_empty())
+    else {
+        return Ok(());
+    };
+
+    let content = if source.starts_with("http://") || source.starts_with("https://") {
+        // Without state (--no-state) the rules are fetched on every run
+        let cache_file = state::cache_dir()
+            .ok()
+            .map(|dir| dir.join("remote-rules.json"));
+        fetch_cached(cache_file.as_deref(), &source, config, offline).await?
+    } else {
+        tokio::fs::read_to_string(&source)
+            .await
+            .with_context(|| format!("Failed to read remote rules: {source}"))?
+    };
+
+    RemoteRules::parse(&content, &source)
+        .with_context(|| format!("Invalid remote rules: {source}"))?
+        .merge_into(config);
+    Ok(())
+}
+
+async fn fetch_cached(
+    cache_file: Option<&Path>,
+    source: &str,
+    config: &Config,
+    offline: bool,
+) -> Result<String> {
+    let cached: Option<CachedRules> = match cache_file {
+        Some(file) => state::read_json::<CachedRules>(file).await.ok().flatten(),
+        None => None,
+    }
+    .filter(|c| c.source == source);
+
+    let ttl = chrono::Duration::minutes(
+        i64::try_from(config.general.cache_ttl_mins).unwrap_or(i64::MAX / 60_000),
+    );
+    if let Some(ref cached) = cached {
+        if offline || Utc::now() - cached.fetched_at < ttl {
+            return Ok(cached.content.clone());
+        }
+    }
+
+    if offline {
+        anyhow::bail!("Not fetching {source} in a dry run and no cached copy exists");
+    }
+
+    let http = HttpClient::new(FETCH_TIMEOUT_SECS)?;
+    match http
+        .get_text(source, "application/json, application/toml, text/plain")
+        .await
+    {
+        Ok(content) => {
+            let entry = CachedRules {
+                source: source.to_string(),
+                fetched_at: Utc::now(),
+                content: content.clone(),
+            };
+            if let Some(file) = cache_file {
+                let json = serde_json::to_string_pretty(&entry)
+                    .context("Failed to serialize rules cache")?;
+                state::write_atomic(file, &json).await?;
+            }
+            Ok(content)
+        }
+        Err(e) => cached.map(|c| c.content).ok_or(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_json_and_toml() {
+        let json = r#"{"blocklist": ["spam/*"], "exclude_topics": ["crypto"]}"#;
+        let rules = RemoteRules::parse(json, "https://example.org/rules").unwrap();
+        assert_eq!(rules.blocklist, ["spam/*"]);
+
+        let toml = "blocklist = [\"spam/*\"]\n\n[preset.team]\nlanguages = [\"rust\"]\n";
+        let rules = RemoteRules::parse(toml, "https://example.org/rules.toml").unwrap();
+        assert_eq!(rules.preset["team"].languages, ["rust"]);
+        assert_eq!(RemoteRules::parse(toml, "rules").unwrap().preset.len(), 1);
+
+        assert!(RemoteRules::parse("{\"blocklsit\": []}", "rules.json").is_err());
+    }
+
+    #[test]
+    fn test_merge_keeps_local_settings() {
+        let mut config = Config::default();
+        config.general.blocklist = vec!["spam/*".to_string()];
+        config.preset.insert(
+            "team".to_string(),
+            PresetConfig {
+                min_stars: Some(5),
+                ..PresetConfig::default()
+            },
+        );
+
+        let rules = RemoteRules {
+            blocklist: vec!["SPAM/*".to_string(), "other/repo".to_string()],
+            exclude_topics: vec!["crypto".to_string()],
+            preset: BTreeMap::from([
+                ("team".to_string(), PresetConfig::default()),
+                ("shared".to_string(), PresetConfig::default()),
+            ]),
+            ..RemoteRules::default()
+        };
+        rules.merge_into(&mut config);
+
+        assert_eq!(config.general.blocklist, ["spam/*", "other/repo"]);
+        assert_eq!(config.gitlab.exclude_topics, ["crypto"]);
+        assert_eq!(config.codeberg.exclude_topics, ["crypto"]);
+        assert_eq!(config.preset["team"].min_stars, Some(5));
+        assert!(config.preset.contains_key("shared"));
+    }
+}
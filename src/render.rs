@@ -1,29 +1,192 @@
 use chrono::{Duration, Utc};
+use clap::ValueEnum;
 use colored::Colorize;
+use regex::Regex;
 use serde_json::json;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::LazyLock;
+use unicode_width::UnicodeWidthChar;
 
-use crate::model::Repo;
+use crate::config::{Config, DescriptionConfig, DisplayConfig, StatusbarConfig};
+use crate::health::Health;
+use crate::history;
+use crate::jsonschema;
+use crate::model::{language_from_topics, Movement, Repo};
+use crate::nushell;
+use crate::statusbar;
+use crate::table;
+use crate::terminal;
+use crate::theme::Theme;
 
-/// Output format
-#[derive(Debug, Clone, Copy)]
+/// Output format (`--format`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum OutputFormat {
+    /// The message of the day, one block per provider
     Motd,
+    /// One JSON document with a versioned envelope
     Json,
+    /// Screen-reader-friendly: no color or symbols, one labeled fact per line
+    Accessible,
+    /// NUON records for Nushell (`| from nuon`)
+    Nuon,
+    /// One compact JSON object per line, e.g. for jq or `ConvertFrom-Json`
+    #[value(name = "jsonl", alias = "ndjson")]
+    JsonLines,
+    /// An RSS 2.0 feed
+    Rss,
+    /// A Markdown table with links
+    Markdown,
+    /// CSV rows (provider, name, url, language, stars, topics)
+    Csv,
+    /// Tab-separated rows with the same columns as csv
+    Tsv,
+    /// JSON for a Waybar custom module
+    Waybar,
+    /// One line for polybar/i3blocks, shaped by `[statusbar] template`
+    #[value(name = "statusbar")]
+    StatusLine,
+    /// The hottest repo as a tmux status-line segment
+    Tmux,
+    /// An aligned table of name, language, stars and last activity
+    Table,
+    /// A user-supplied template, rendered by [`crate::template::Template`]
+    Template,
+}
+
+impl OutputFormat {
+    /// Whether the output is read in a terminal rather than parsed by another program
+    pub fn is_for_terminal(self) -> bool {
+        matches!(self, Self::Motd | Self::Accessible | Self::Table)
+    }
+
+    /// Whether a status bar polls the output, so it must not use up the MOTD's seen list
+    /// or log each refresh to the activity log
+    pub fn is_status_bar(self) -> bool {
+        matches!(self, Self::Waybar | Self::StatusLine | Self::Tmux)
+    }
 }
 
-/// Render repositories in MOTD format
-pub fn render(repos: &[Repo], format: OutputFormat) {
+/// Display settings the renderer takes from the config
+#[derive(Debug, Clone, Default)]
+pub struct RenderOptions {
+    pub description: DescriptionConfig,
+    pub display: DisplayConfig,
+    pub statusbar: StatusbarConfig,
+    /// Use ASCII markers instead of emoji and symbols (legacy consoles)
+    pub ascii: bool,
+    /// Wrap repo names in OSC 8 hyperlinks to the repository
+    pub hyperlinks: bool,
+    pub theme: Theme,
+    /// Total stars per day of each repo URL over the last week, oldest first
+    pub star_history: HashMap<String, Vec<u64>>,
+    /// Columns the MOTD lines fit descriptions into; unknown when piped, where
+    /// `description.max_length` applies instead
+    pub width: Option<usize>,
+}
+
+impl RenderOptions {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            description: config.description,
+            display: config.display,
+            statusbar: config.statusbar.clone(),
+            ascii: config.display.ascii(),
+            hyperlinks: config
+                .display
+                .hyperlinks
+                .unwrap_or_else(terminal::supports_hyperlinks),
+            theme: Theme::from_config(&config.theme),
+            star_history: HashMap::new(),
+            width: match config.display.width {
+                Some(0) => None,
+                Some(width) => Some(width),
+                None => terminal::width(),
+            },
+        }
+    }
+
+    fn symbols(&self) -> &'static Symbols {
+        if self.ascii {
+            &ASCII_SYMBOLS
+        } else {
+            &UNICODE_SYMBOLS
+        }
+    }
+}
+
+/// Markers used in MOTD output; indicator markers are two columns wide
+struct Symbols {
+    starred: &'static str,
+    sponsor: &'static str,
+    binaries: &'static str,
+    container: &'static str,
+    suspicious: &'static str,
+    cloned: &'static str,
+    seen: &'static str,
+    star: &'static str,
+    up: &'static str,
+    down: &'static str,
+    health: &'static str,
+    homepage: &'static str,
+}
+
+const UNICODE_SYMBOLS: Symbols = Symbols {
+    starred: "⭐",
+    sponsor: "♥",
+    binaries: "📦",
+    container: "🐳",
+    suspicious: "⚠ ",
+    cloned: "📁",
+    seen: "👁",
+    star: "★",
+    up: "▲",
+    down: "▼",
+    health: "✚",
+    homepage: "⌂",
+};
+
+const ASCII_SYMBOLS: Symbols = Symbols {
+    starred: "* ",
+    sponsor: "$",
+    binaries: "B ",
+    container: "D ",
+    suspicious: "! ",
+    cloned: "C ",
+    seen: "S ",
+    star: "*",
+    up: "+",
+    down: "-",
+    health: "h",
+    homepage: "->",
+};
+
+/// Render repositories into a string without printing
+///
+/// Descriptions are sanitized for MOTD and accessible output; JSON keeps them raw.
+pub fn render_to_string(repos: &[Repo], format: OutputFormat, opts: &RenderOptions) -> String {
     match format {
-        OutputFormat::Motd => render_motd(repos),
+        // Templates need the loaded file; without one this falls back to MOTD
+        OutputFormat::Motd | OutputFormat::Template => render_motd(repos, opts),
         OutputFormat::Json => render_json(repos),
+        OutputFormat::Accessible => render_accessible(repos, opts),
+        OutputFormat::Nuon => render_nuon(repos),
+        OutputFormat::JsonLines => render_json_lines(repos),
+        OutputFormat::Rss => render_rss(repos),
+        OutputFormat::Markdown => render_markdown(repos, opts),
+        OutputFormat::Csv => render_delimited(repos, ','),
+        OutputFormat::Tsv => render_delimited(repos, '\t'),
+        OutputFormat::Waybar => render_waybar(repos),
+        OutputFormat::StatusLine => statusbar::render(repos, &opts.statusbar),
+        OutputFormat::Tmux => statusbar::render_tmux(repos, &opts.statusbar),
+        OutputFormat::Table => table::render(repos, opts.ascii),
     }
 }
 
 /// Render plain MOTD format with table alignment
-fn render_motd(repos: &[Repo]) {
+fn render_motd(repos: &[Repo], opts: &RenderOptions) -> String {
     if repos.is_empty() {
-        println!("No trending repositories found today.");
-        return;
+        return "No trending repositories found today.\n".to_string();
     }
 
     // Calculate column widths for alignment
@@ -41,11 +204,121 @@ fn render_motd(repos: &[Repo]) {
         .unwrap_or(0)
         .min(15); // Cap language width at 15 chars
 
+    // Only reserve the cloned column when something is actually cloned
+    let cloned_column = repos.iter().any(|r| r.is_cloned);
+    // Seen markers only appear in --show-all runs that include repeats
+    let seen_column = repos.iter().any(|r| r.is_seen);
+    // Sponsor badges only exist when the Sponsors lookup ran
+    let sponsor_column = repos.iter().any(|r| r.is_sponsorable);
+    // Release markers only exist when the release lookup ran
+    let binaries_column = repos.iter().any(|r| r.has_binaries);
+    let container_column = repos.iter().any(|r| r.has_container);
+    let suspicious_column = repos.iter().any(|r| r.is_suspicious);
+
+    // Movement markers need a snapshot from yesterday to compare against
+    let movement_column = repos.iter().any(|r| r.movement.is_some());
+
+    // Health badges only exist when enrichment was requested
+    let health_column = repos.iter().any(|r| r.health.is_some());
+
+    // Sparklines need star counts from at least two days
+    let sparkline_column = opts.display.sparklines
+        && repos.iter().any(|r| {
+            opts.star_history
+                .get(&r.url)
+                .is_some_and(|days| days.len() > 1)
+        });
+
+    let mut out = String::new();
     for repo in repos {
-        render_repo_motd(repo, max_name_len, max_lang_len);
+        let _ = writeln!(
+            out,
+            "{}",
+            render_repo_motd(
+                repo,
+                max_name_len,
+                max_lang_len,
+                Columns {
+                    cloned: cloned_column,
+                    seen: seen_column,
+                    sponsor: sponsor_column,
+                    binaries: binaries_column,
+                    container: container_column,
+                    suspicious: suspicious_column,
+                    movement: movement_column,
+                    health: health_column,
+                    sparkline: sparkline_column,
+                },
+                opts,
+            )
+        );
+    }
+    out
+}
+
+/// Wrap `text` in an OSC 8 terminal hyperlink to `url`
+fn hyperlink(url: &str, text: &str) -> String {
+    format!("\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\")
+}
+
+/// Sanitize a description for display: markup, whitespace and emoji per config
+fn sanitize_description(desc: &str, opts: &DescriptionConfig) -> String {
+    let cleaned = if opts.strip_markup {
+        clean_description(&strip_html(desc))
+    } else {
+        collapse_whitespace(desc)
+    };
+
+    match opts.max_emoji {
+        Some(max) => limit_emoji(&cleaned, max),
+        None => cleaned,
     }
 }
 
+static TAG_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"</?[a-zA-Z][^>]*>").unwrap());
+
+/// An emoji plus its variation selector, skin tone and ZWJ-joined parts
+const EMOJI: &str = r"\p{Extended_Pictographic}(?:\x{FE0F}|[\x{1F3FB}-\x{1F3FF}]|\x{200D}\p{Extended_Pictographic})*";
+static EMOJI_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(EMOJI).unwrap());
+static EMOJI_RUN_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"({EMOJI})(?:\s*{EMOJI})+")).unwrap());
+
+static SPACES_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\s+").unwrap());
+
+/// Remove HTML tags and decode the few entities that show up in descriptions
+fn strip_html(desc: &str) -> String {
+    TAG_RE
+        .replace_all(desc, " ")
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Collapse runs of emoji into one and keep at most `max` emoji overall
+fn limit_emoji(desc: &str, max: usize) -> String {
+    let collapsed = EMOJI_RUN_RE.replace_all(desc, "$1");
+
+    let mut kept = 0;
+    let limited = EMOJI_RE.replace_all(&collapsed, |caps: &regex::Captures| {
+        kept += 1;
+        if kept <= max {
+            caps[0].to_string()
+        } else {
+            String::new()
+        }
+    });
+
+    collapse_whitespace(&limited)
+}
+
+/// Collapse whitespace (including paragraph breaks) into single spaces
+fn collapse_whitespace(text: &str) -> String {
+    SPACES_RE.replace_all(text, " ").trim().to_string()
+}
+
 /// Clean description by removing/simplifying markdown syntax
 fn clean_description(desc: &str) -> String {
     let mut result = desc.to_string();
@@ -65,11 +338,10 @@ fn clean_description(desc: &str) -> String {
     // Remove bold/italic markers
     result = result.replace("**", "").replace("__", "");
 
-    // Collapse multiple spaces
-    let spaces_re = regex::Regex::new(r"\s+").unwrap();
-    result = spaces_re.replace_all(&result, " ").to_string();
+    // Remove inline code markers
+    result = result.replace('`', "");
 
-    result.trim().to_string()
+    collapse_whitespace(&result)
 }
 
 /// Clean up truncated text to remove incomplete words or markdown
@@ -102,7 +374,7 @@ fn clean_truncated_text(text: &str) -> String {
 }
 
 /// Format recency from `last_activity` timestamp
-fn format_recency(repo: &Repo) -> String {
+pub fn format_recency(repo: &Repo) -> String {
     match repo.last_activity {
         Some(dt) => {
             let now = Utc::now();
@@ -124,21 +396,43 @@ fn format_recency(repo: &Repo) -> String {
     }
 }
 
+/// Width of the sparkline column, one bar per day
+const HISTORY_WIDTH: usize = 7;
+
+/// Optional MOTD columns, each shown only when at least one repo uses it
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::struct_excessive_bools)]
+struct Columns {
+    cloned: bool,
+    seen: bool,
+    sponsor: bool,
+    binaries: bool,
+    container: bool,
+    suspicious: bool,
+    movement: bool,
+    health: bool,
+    sparkline: bool,
+}
+
 /// Render a single repository in MOTD format with colors and alignment
-fn render_repo_motd(repo: &Repo, name_width: usize, lang_width: usize) {
-    // Icon (colored by provider)
-    let icon = match repo.provider.as_str() {
-        "github" => repo.icon.bright_purple(),
-        "gitlab" => repo.icon.bright_red(),
-        "gitea" => repo.icon.bright_green(),
-        _ => repo.icon.white(),
-    };
+fn render_repo_motd(
+    repo: &Repo,
+    name_width: usize,
+    lang_width: usize,
+    columns: Columns,
+    opts: &RenderOptions,
+) -> String {
+    let theme = &opts.theme;
+    let icon = theme.icon.apply(&repo.icon, &repo.provider);
 
-    // Starred indicator
-    let starred_indicator = if repo.is_starred {
-        "⭐".to_string()
+    let symbols = opts.symbols();
+    let indicators = format_indicators(repo, columns, symbols);
+
+    // Rank movement since yesterday
+    let movement = if columns.movement {
+        format_movement(repo.movement, symbols)
     } else {
-        "  ".to_string()
+        String::new()
     };
 
     // Name (truncate if too long, pad for alignment)
@@ -148,8 +442,20 @@ fn render_repo_motd(repo: &Repo, name_width: usize, lang_width: usize) {
     } else {
         repo.name.clone()
     };
-    let name_padded = format!("{name_display:<name_width$}");
-    let name = name_padded.bright_cyan().bold();
+    let padding = " ".repeat(name_width.saturating_sub(name_display.chars().count()));
+    // Dim repeats so the new entries of the day stand out
+    let name = theme.name.apply(&name_display, &repo.provider);
+    let name = if repo.is_seen {
+        name.dimmed()
+    } else {
+        name.bold()
+    };
+    // Padding stays outside the link so only the name itself is clickable
+    let name = if opts.hyperlinks {
+        format!("{}{padding}", hyperlink(&repo.url, &name.to_string()))
+    } else {
+        format!("{name}{padding}")
+    };
 
     // Language (pad for alignment)
     let lang_display = repo.language.as_deref().unwrap_or("-");
@@ -160,19 +466,39 @@ fn render_repo_motd(repo: &Repo, name_width: usize, lang_width: usize) {
         lang_display.to_string()
     };
     let lang_padded = format!("{lang_truncated:<lang_width$}");
-    let lang = lang_padded.bright_yellow();
+    let lang = theme.language.apply(&lang_padded, &repo.provider);
 
     // Stars
     let stars = if let Some(stars_today) = repo.stars_today {
-        format!("★{stars_today:<4} today")
-            .bright_green()
-            .to_string()
+        let text = format!("{}{stars_today:<4} today", symbols.star);
+        theme.stars.apply(&text, &repo.provider).to_string()
     } else if let Some(stars_total) = repo.stars_total {
-        format!("★{stars_total:<10}").bright_black().to_string()
+        let text = format!("{}{stars_total:<10}", symbols.star);
+        theme.stars_total.apply(&text, &repo.provider).to_string()
     } else {
         format!("{:<11}", "").to_string()
     };
 
+    // Star growth over the last week
+    let sparkline = if columns.sparkline {
+        let days = opts
+            .star_history
+            .get(&repo.url)
+            .map_or(&[][..], Vec::as_slice);
+        let line = history::sparkline(days, opts.ascii);
+        let padded = format!("{line:<HISTORY_WIDTH$} ");
+        theme.stars.apply(&padded, &repo.provider).to_string()
+    } else {
+        String::new()
+    };
+
+    // Health badge
+    let health = if columns.health {
+        format_health(repo.health.as_ref(), symbols)
+    } else {
+        String::new()
+    };
+
     // Recency
     let recency = format_recency(repo);
     let recency_colored = match recency.as_str() {
@@ -181,32 +507,602 @@ fn render_repo_motd(repo: &Repo, name_width: usize, lang_width: usize) {
         _ => recency.bright_black(),
     };
 
-    // Description (truncate for remaining space)
-    let desc = if let Some(ref d) = repo.description {
-        let cleaned = clean_description(d);
-        if cleaned.chars().count() > 45 {
-            let truncated: String = cleaned.chars().take(42).collect();
-            let final_text = clean_truncated_text(&truncated);
-            format!("{final_text}...")
-        } else {
-            cleaned
+    let topics = format_topics(repo, &opts.display);
+    let homepage = match repo.homepage {
+        Some(ref homepage) if opts.display.show_homepage => {
+            format!(" {} {homepage}", symbols.homepage)
+                .bright_black()
+                .to_string()
         }
-    } else {
-        String::new()
+        _ => String::new(),
     };
 
     // Print aligned columns
     let recency_padded = format!("{recency_colored:<10}");
-    println!(
-        "{starred_indicator}{icon} {name} {lang} {stars} {recency_padded} {}",
-        desc.white()
+    let prefix = format!(
+        "{indicators}{icon} {movement}{name} {lang} {stars} {sparkline}{health}{recency_padded} "
+    );
+
+    let cleaned = repo
+        .description
+        .as_deref()
+        .map(|d| sanitize_description(d, &opts.description))
+        .unwrap_or_default();
+    let prefix_width = visible_width(&prefix);
+    let suffix_width = visible_width(&topics) + visible_width(&homepage);
+    let lines = description_lines(&cleaned, prefix_width, suffix_width, opts);
+
+    let indent = " ".repeat(prefix_width);
+    let mut out = prefix;
+    for (idx, line) in lines.iter().enumerate() {
+        if idx > 0 {
+            let _ = write!(out, "\n{indent}");
+        }
+        let _ = write!(out, "{}", theme.description.apply(line, &repo.provider));
+    }
+    let _ = write!(out, "{topics}{homepage}");
+    out
+}
+
+/// Description in the space the terminal leaves after the other columns, cut or
+/// wrapped, or cut at `max_length` when the width is unknown
+fn description_lines(
+    text: &str,
+    prefix_width: usize,
+    suffix_width: usize,
+    opts: &RenderOptions,
+) -> Vec<String> {
+    match opts.width {
+        Some(width) if opts.description.wrap => {
+            wrap_words(text, width.saturating_sub(prefix_width))
+        }
+        Some(width) => {
+            let room = width.saturating_sub(prefix_width + suffix_width);
+            // A terminal too narrow for any description leaves it out
+            if room == 0 {
+                return vec![String::new()];
+            }
+            vec![truncate_description(text, room)]
+        }
+        None => vec![truncate_description(text, opts.description.max_length)],
+    }
+}
+
+/// Cut `text` to `max` columns with a trailing "..." (0 = no limit)
+fn truncate_description(text: &str, max: usize) -> String {
+    if max == 0 || unicode_width::UnicodeWidthStr::width(text) <= max {
+        return text.to_string();
+    }
+    let mut used = 0;
+    let truncated: String = text
+        .chars()
+        .take_while(|c| {
+            used += c.width().unwrap_or(0);
+            used <= max.saturating_sub(3)
+        })
+        .collect();
+    let final_text = clean_truncated_text(&truncated);
+    if final_text.is_empty() {
+        return String::new();
+    }
+    format!("{final_text}...")
+}
+
+/// Break `text` into lines of at most `max` columns at spaces (a longer word gets its own line)
+fn wrap_words(text: &str, max: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let needed = unicode_width::UnicodeWidthStr::width(line.as_str())
+            + usize::from(!line.is_empty())
+            + unicode_width::UnicodeWidthStr::width(word);
+        if !line.is_empty() && needed > max {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    lines.push(line);
+    lines
+}
+
+/// Terminal columns of `text`, skipping ANSI colors and OSC 8 hyperlinks
+fn visible_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            width += c.width().unwrap_or(0);
+            continue;
+        }
+        match chars.next() {
+            // CSI: up to the final letter
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            }
+            // OSC: up to the string terminator (ESC \ or BEL)
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' || (c == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    width
+}
+
+/// Rough category of a topic, used to color its tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TopicCategory {
+    Language,
+    Ai,
+    Web,
+    Infra,
+    Tooling,
+    Other,
+}
+
+/// Categorize a topic by well-known tags
+fn topic_category(topic: &str) -> TopicCategory {
+    let topic = topic.to_lowercase();
+    if language_from_topics(std::slice::from_ref(&topic)).is_some() {
+        return TopicCategory::Language;
+    }
+    match topic.as_str() {
+        "ai" | "llm" | "llms" | "machine-learning" | "deep-learning" | "ml" | "openai"
+        | "chatgpt" | "gpt" | "agents" | "rag" | "nlp" | "pytorch" | "transformers" => {
+            TopicCategory::Ai
+        }
+        "web" | "frontend" | "backend" | "react" | "vue" | "svelte" | "nextjs" | "css" | "html"
+        | "http" | "api" | "browser" | "wasm" | "webassembly" => TopicCategory::Web,
+        "docker" | "kubernetes" | "k8s" | "devops" | "cloud" | "infrastructure" | "terraform"
+        | "database" | "self-hosted" | "selfhosted" | "linux" | "nix" => TopicCategory::Infra,
+        "cli" | "terminal" | "tui" | "command-line" | "developer-tools" | "devtools" | "tool"
+        | "tools" | "editor" | "neovim" | "vim" | "git" => TopicCategory::Tooling,
+        _ => TopicCategory::Other,
+    }
+}
+
+/// Format up to `max_topics` topics as `#tag`s with a leading space (empty when hidden)
+fn format_topics(repo: &Repo, display: &DisplayConfig) -> String {
+    let mut out = String::new();
+    for topic in repo.topics.iter().take(display.max_topics) {
+        let tag = format!("#{topic}");
+        let tag = if display.color_topics {
+            match topic_category(topic) {
+                TopicCategory::Language => tag.bright_yellow(),
+                TopicCategory::Ai => tag.bright_magenta(),
+                TopicCategory::Web => tag.bright_blue(),
+                TopicCategory::Infra => tag.cyan(),
+                TopicCategory::Tooling => tag.green(),
+                TopicCategory::Other => tag.bright_black(),
+            }
+        } else {
+            tag.bright_black()
+        };
+        let _ = write!(out, " {tag}");
+    }
+    out
+}
+
+/// Status markers in front of the icon; optional columns only take space when used
+fn format_indicators(repo: &Repo, columns: Columns, symbols: &Symbols) -> String {
+    // Farmed-stars warning goes first so it isn't missed
+    let suspicious_indicator = match (columns.suspicious, repo.is_suspicious) {
+        (false, _) => String::new(),
+        (true, true) => symbols.suspicious.bright_red().to_string(),
+        (true, false) => "  ".to_string(),
+    };
+
+    // Starred indicator
+    let starred_indicator = if repo.is_starred {
+        symbols.starred.to_string()
+    } else {
+        "  ".to_string()
+    };
+
+    // Sponsorable owner indicator
+    let sponsor_indicator = match (columns.sponsor, repo.is_sponsorable) {
+        (false, _) => String::new(),
+        (true, true) => format!("{} ", symbols.sponsor.bright_magenta()),
+        (true, false) => "  ".to_string(),
+    };
+
+    // Prebuilt binaries indicator
+    let binaries_indicator = match (columns.binaries, repo.has_binaries) {
+        (false, _) => "",
+        (true, true) => symbols.binaries,
+        (true, false) => "  ",
+    };
+
+    // Container image indicator
+    let container_indicator = match (columns.container, repo.has_container) {
+        (false, _) => "",
+        (true, true) => symbols.container,
+        (true, false) => "  ",
+    };
+
+    // Already-cloned indicator
+    let cloned_indicator = match (columns.cloned, repo.is_cloned) {
+        (false, _) => "",
+        (true, true) => symbols.cloned,
+        (true, false) => "  ",
+    };
+
+    // Already-seen indicator
+    let seen_indicator = match (columns.seen, repo.is_seen) {
+        (false, _) => "",
+        (true, true) => symbols.seen,
+        (true, false) => "  ",
+    };
+
+    format!("{suspicious_indicator}{starred_indicator}{sponsor_indicator}{binaries_indicator}{container_indicator}{cloned_indicator}{seen_indicator}")
+}
+
+/// Format a rank movement marker, padded to a fixed width with a trailing space
+fn format_movement(movement: Option<Movement>, symbols: &Symbols) -> String {
+    let marker = match movement {
+        Some(Movement::Up(n)) => format!("{:<4}", format!("{}{n}", symbols.up)).bright_green(),
+        Some(Movement::Down(n)) => format!("{:<4}", format!("{}{n}", symbols.down)).bright_red(),
+        Some(Movement::Same) => format!("{:<4}", "=").bright_black(),
+        Some(Movement::New) => format!("{:<4}", "new").bright_blue(),
+        None => format!("{:<4}", "").normal(),
+    };
+    format!("{marker} ")
+}
+
+/// Format a compact health badge, padded to a fixed width with a trailing space
+fn format_health(health: Option<&Health>, symbols: &Symbols) -> String {
+    let badge = match health {
+        Some(h) => {
+            let text = format!("{:<4}", format!("{}{}", symbols.health, h.score));
+            match h.score {
+                70.. => text.bright_green(),
+                40..=69 => text.yellow(),
+                _ => text.bright_red(),
+            }
+        }
+        None => format!("{:<4}", "").normal(),
+    };
+    format!("{badge} ")
+}
+
+/// Render screen-reader-friendly plain text: no color or symbols, one labeled fact per line
+fn render_accessible(repos: &[Repo], opts: &RenderOptions) -> String {
+    if repos.is_empty() {
+        return "No trending repositories found today.\n".to_string();
+    }
+
+    let mut out = String::new();
+    for (idx, repo) in repos.iter().enumerate() {
+        if idx > 0 {
+            out.push('\n');
+        }
+        let _ = writeln!(
+            out,
+            "Repository {} of {}: {}",
+            idx + 1,
+            repos.len(),
+            repo.name
+        );
+        let _ = writeln!(out, "Provider: {}", provider_label(&repo.provider));
+        if let Some(ref language) = repo.language {
+            let _ = writeln!(out, "Language: {language}");
+        }
+        if let Some(stars_today) = repo.stars_today {
+            let _ = writeln!(out, "Stars today: {stars_today}");
+        }
+        if let Some(stars_total) = repo.stars_total {
+            let _ = writeln!(out, "Total stars: {stars_total}");
+        }
+        let _ = writeln!(out, "Last activity: {}", format_recency(repo));
+        if let Some(movement) = repo.movement {
+            let change = match movement {
+                Movement::Up(n) => format!("up {n}"),
+                Movement::Down(n) => format!("down {n}"),
+                Movement::Same => "unchanged".to_string(),
+                Movement::New => "new today".to_string(),
+            };
+            let _ = writeln!(out, "Rank change since yesterday: {change}");
+        }
+        if let Some(ref health) = repo.health {
+            let _ = writeln!(out, "Health score: {} out of 100", health.score);
+        }
+        if repo.is_starred {
+            let _ = writeln!(out, "Starred by you: yes");
+        }
+        if repo.is_sponsorable {
+            let _ = writeln!(out, "Accepts sponsorships: yes");
+        }
+        if repo.has_binaries {
+            let _ = writeln!(out, "Prebuilt binaries: yes");
+        }
+        if repo.has_container {
+            let _ = writeln!(out, "Container image: yes");
+        }
+        if repo.is_suspicious {
+            let _ = writeln!(
+                out,
+                "Warning: star spike on a new repository with little history"
+            );
+        }
+        if repo.is_cloned {
+            let _ = writeln!(out, "Already cloned: yes");
+        }
+        if repo.is_seen {
+            let _ = writeln!(out, "Already seen today: yes");
+        }
+        if let Some(ref description) = repo.description {
+            let cleaned = sanitize_description(description, &opts.description);
+            if !cleaned.is_empty() {
+                let _ = writeln!(out, "Description: {cleaned}");
+            }
+        }
+        if opts.display.max_topics > 0 && !repo.topics.is_empty() {
+            let topics: Vec<&str> = repo
+                .topics
+                .iter()
+                .take(opts.display.max_topics)
+                .map(String::as_str)
+                .collect();
+            let _ = writeln!(out, "Topics: {}", topics.join(", "));
+        }
+        let _ = writeln!(out, "Link: {}", repo.url);
+        if let Some(ref homepage) = repo.homepage {
+            let _ = writeln!(out, "Homepage: {homepage}");
+        }
+    }
+    out
+}
+
+/// Human-readable provider name
+fn provider_label(provider: &str) -> &str {
+    match provider {
+        "github" => "GitHub",
+        "gitlab" => "GitLab",
+        "gitea" => "Gitea",
+        "codeberg" => "Codeberg",
+        "bitbucket" => "Bitbucket",
+        "gitee" => "Gitee",
+        "huggingface" => "Hugging Face",
+        "crates" => "crates.io",
+        "hackernews" => "Hacker News",
+        "reddit" => "Reddit",
+        other => other,
+    }
+}
+
+/// Render JSON format: the repos with the schema version of the output
+fn render_json(repos: &[Repo]) -> String {
+    let output = json!({
+        "schema_version": jsonschema::SCHEMA_VERSION,
+        "repos": repos,
+    });
+    format!("{}\n", serde_json::to_string_pretty(&output).unwrap())
+}
+
+/// Render one compact JSON object per line (NDJSON)
+fn render_json_lines(repos: &[Repo]) -> String {
+    let mut output = String::new();
+    for repo in repos {
+        let _ = writeln!(output, "{}", json!(repo));
+    }
+    output
+}
+
+/// Render a Markdown table with linked names, language, stars and description
+fn render_markdown(repos: &[Repo], opts: &RenderOptions) -> String {
+    if repos.is_empty() {
+        return "No trending repositories found today.\n".to_string();
+    }
+
+    let mut out = String::from("| Repository | Provider | Language | Stars | Description |\n");
+    out.push_str("| --- | --- | --- | ---: | --- |\n");
+    for repo in repos {
+        let stars = match (repo.stars_today, repo.stars_total) {
+            (Some(today), _) => format!("+{today} today"),
+            (None, Some(total)) => total.to_string(),
+            (None, None) => String::new(),
+        };
+        let description = repo
+            .description
+            .as_deref()
+            .map(|d| sanitize_description(d, &opts.description))
+            .unwrap_or_default();
+        let _ = writeln!(
+            out,
+            "| [{}]({}) | {} | {} | {stars} | {} |",
+            markdown_escape(&repo.name),
+            repo.url,
+            provider_label(&repo.provider),
+            markdown_escape(repo.language.as_deref().unwrap_or("")),
+            markdown_escape(&description)
+        );
+    }
+    out
+}
+
+/// Escape characters that would break a Markdown table cell or link text
+fn markdown_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '|' | '[' | ']' | '*' | '_' | '`' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Render one row per repo with a header, comma- or tab-separated
+///
+/// Topics are joined with `;`. CSV fields are quoted when needed (RFC 4180); TSV
+/// fields have tabs and line breaks replaced by spaces.
+fn render_delimited(repos: &[Repo], delimiter: char) -> String {
+    let header = [
+        "provider",
+        "name",
+        "url",
+        "language",
+        "stars_today",
+        "stars_total",
+        "topics",
+    ];
+    let mut out = header.join(&delimiter.to_string());
+    out.push('\n');
+
+    for repo in repos {
+        let fields = [
+            repo.provider.clone(),
+            repo.name.clone(),
+            repo.url.clone(),
+            repo.language.clone().unwrap_or_default(),
+            repo.stars_today.map(|n| n.to_string()).unwrap_or_default(),
+            repo.stars_total.map(|n| n.to_string()).unwrap_or_default(),
+            repo.topics.join(";"),
+        ];
+        let fields: Vec<String> = fields
+            .iter()
+            .map(|field| delimited_field(field, delimiter))
+            .collect();
+        out.push_str(&fields.join(&delimiter.to_string()));
+        out.push('\n');
+    }
+    out
+}
+
+fn delimited_field(field: &str, delimiter: char) -> String {
+    if delimiter == '\t' {
+        return field.replace(['\t', '\n', '\r'], " ");
+    }
+    if field.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Render the JSON object a Waybar custom module reads (`return-type: json`)
+///
+/// The tooltip uses Pango markup, so its text is escaped.
+fn render_waybar(repos: &[Repo]) -> String {
+    let Some(top) = repos.first() else {
+        let empty = json!({"text": "", "tooltip": "No trending repositories", "class": "empty"});
+        return format!("{empty}\n");
+    };
+
+    let star_count = |repo: &Repo| match (repo.stars_today, repo.stars_total) {
+        (Some(today), _) => format!("★{today}"),
+        (None, Some(total)) => format!("★{total}"),
+        (None, None) => String::new(),
+    };
+    let tooltip: Vec<String> = repos
+        .iter()
+        .map(|repo| {
+            let line = format!("{} {} {}", repo.icon, repo.name, star_count(repo));
+            match repo.language {
+                Some(ref language) => xml_escape(&format!("{line} ({language})")),
+                None => xml_escape(line.trim_end()),
+            }
+        })
+        .collect();
+
+    let output = json!({
+        "text": format!("{} {}", star_count(top), top.name).trim().to_string(),
+        "tooltip": tooltip.join("\n"),
+        "class": "trending",
+    });
+    format!("{output}\n")
+}
+
+/// Render an RSS 2.0 feed with one item per repo
+///
+/// Item GUIDs include the date, so a repo trending again on a later day shows up
+/// as a new item in feed readers.
+fn render_rss(repos: &[Repo]) -> String {
+    let now = Utc::now();
+    let date = now.format("%Y-%m-%d");
+    let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    output.push_str("<rss version=\"2.0\">\n<channel>\n");
+    output.push_str("<title>Trending repositories</title>\n");
+    output.push_str("<link>https://github.com/trending</link>\n");
+    output.push_str(
+        "<description>Today's trending repositories from GitHub, GitLab and Gitea</description>\n",
+    );
+    let _ = writeln!(
+        output,
+        "<lastBuildDate>{}</lastBuildDate>",
+        now.to_rfc2822()
     );
+
+    for repo in repos {
+        let mut summary = repo.description.clone().unwrap_or_default();
+        let stats: Vec<String> = [
+            repo.language.clone(),
+            repo.stars_today.map(|n| format!("+{n} stars today")),
+            repo.stars_total.map(|n| format!("{n} stars")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !stats.is_empty() {
+            if !summary.is_empty() {
+                summary.push_str(" — ");
+            }
+            summary.push_str(&stats.join(", "));
+        }
+
+        output.push_str("<item>\n");
+        let _ = writeln!(output, "<title>{}</title>", xml_escape(&repo.name));
+        let _ = writeln!(output, "<link>{}</link>", xml_escape(&repo.url));
+        let _ = writeln!(
+            output,
+            "<description>{}</description>",
+            xml_escape(&summary)
+        );
+        let _ = writeln!(
+            output,
+            "<category>{}</category>",
+            xml_escape(&repo.provider)
+        );
+        let _ = writeln!(
+            output,
+            "<guid isPermaLink=\"false\">{}#{date}</guid>",
+            xml_escape(&repo.url)
+        );
+        let _ = writeln!(output, "<pubDate>{}</pubDate>", now.to_rfc2822());
+        output.push_str("</item>\n");
+    }
+
+    output.push_str("</channel>\n</rss>\n");
+    output
 }
 
-/// Render JSON format
-fn render_json(repos: &[Repo]) {
-    let output = json!(repos);
-    println!("{}", serde_json::to_string_pretty(&output).unwrap());
+/// Escape text for XML element content
+pub fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Render NUON for Nushell, one record per line so it reads as a table
+fn render_nuon(repos: &[Repo]) -> String {
+    let records: Vec<String> = repos
+        .iter()
+        .map(|repo| format!("  {}", nushell::to_nuon(&json!(repo))))
+        .collect();
+    format!("[\n{}\n]\n", records.join(",\n"))
 }
 
 #[cfg(test)]
@@ -216,63 +1112,289 @@ mod tests {
     #[test]
     fn test_render_empty() {
         let repos = vec![];
-        // This will print to stdout, but won't panic
-        render(&repos, OutputFormat::Motd);
+        let output = render_to_string(&repos, OutputFormat::Motd, &RenderOptions::default());
+        assert_eq!(output, "No trending repositories found today.\n");
+    }
+
+    #[test]
+    fn test_status_bar_formats() {
+        for format in [
+            OutputFormat::Waybar,
+            OutputFormat::StatusLine,
+            OutputFormat::Tmux,
+        ] {
+            assert!(format.is_status_bar(), "{format:?}");
+        }
+        assert!(!OutputFormat::Motd.is_status_bar());
+        assert!(!OutputFormat::Json.is_status_bar());
     }
 
     #[test]
     fn test_render_json() {
         let repos = vec![Repo {
-            provider: "github".to_string(),
-            icon: "[GH]".to_string(),
-            name: "test/repo".to_string(),
             language: Some("Rust".to_string()),
             description: Some("Test repository".to_string()),
-            url: "https://github.com/test/repo".to_string(),
             stars_today: Some(10),
             stars_total: Some(100),
             last_activity: Some(Utc::now()),
             topics: vec!["rust".to_string(), "cli".to_string()],
-            is_starred: false,
+            ..Repo::test("test/repo")
+        }];
+
+        let output = render_to_string(&repos, OutputFormat::Json, &RenderOptions::default());
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed["schema_version"], jsonschema::SCHEMA_VERSION);
+        let parsed: Vec<Repo> = serde_json::from_value(parsed["repos"].clone()).unwrap();
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].name, "test/repo");
+
+        // One complete object per line, without a surrounding array
+        let repos = [repos[0].clone(), repos[0].clone()];
+        let output = render_to_string(&repos, OutputFormat::JsonLines, &RenderOptions::default());
+        assert_eq!(output.lines().count(), 2);
+        for line in output.lines() {
+            let repo: Repo = serde_json::from_str(line).unwrap();
+            assert_eq!(repo.name, "test/repo");
+        }
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let repos = vec![Repo {
+            language: Some("Rust".to_string()),
+            description: Some("Pipes | and *stars*".to_string()),
+            stars_today: Some(10),
+            ..Repo::test("test/repo")
+        }];
+
+        let output = render_to_string(&repos, OutputFormat::Markdown, &RenderOptions::default());
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("| Repository |"));
+        assert_eq!(
+            lines[2],
+            r"| [test/repo](https://github.com/test/repo) | GitHub | Rust | +10 today | Pipes \| and \*stars\* |"
+        );
+    }
+
+    #[test]
+    fn test_render_delimited() {
+        let repos = vec![Repo {
+            language: Some("C, C++".to_string()),
+            stars_today: Some(10),
+            topics: vec!["cli".to_string(), "rust".to_string()],
+            ..Repo::test("test/repo")
+        }];
+        let opts = RenderOptions::default();
+
+        let csv = render_to_string(&repos, OutputFormat::Csv, &opts);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "provider,name,url,language,stars_today,stars_total,topics"
+        );
+        assert_eq!(
+            lines[1],
+            r#"github,test/repo,https://github.com/test/repo,"C, C++",10,,cli;rust"#
+        );
+
+        let tsv = render_to_string(&repos, OutputFormat::Tsv, &opts);
+        assert_eq!(
+            tsv.lines().nth(1),
+            Some("github\ttest/repo\thttps://github.com/test/repo\tC, C++\t10\t\tcli;rust")
+        );
+    }
+
+    #[test]
+    fn test_render_waybar() {
+        let repos = vec![
+            Repo {
+                language: Some("C++".to_string()),
+                stars_today: Some(10),
+                ..Repo::test("test/repo")
+            },
+            Repo {
+                provider: "gitea".to_string(),
+                icon: "[GE]".to_string(),
+                url: "https://gitea.com/a&b/repo".to_string(),
+                ..Repo::test("a&b/repo")
+            },
+        ];
+
+        let output = render_to_string(&repos, OutputFormat::Waybar, &RenderOptions::default());
+        assert_eq!(output.lines().count(), 1);
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed["text"], "★10 test/repo");
+        assert_eq!(
+            parsed["tooltip"],
+            "[GH] test/repo ★10 (C++)\n[GE] a&amp;b/repo"
+        );
+        assert_eq!(parsed["class"], "trending");
+
+        let empty = render_to_string(&[], OutputFormat::Waybar, &RenderOptions::default());
+        let parsed: serde_json::Value = serde_json::from_str(&empty).unwrap();
+        assert_eq!(parsed["class"], "empty");
+    }
+
+    #[test]
+    fn test_render_rss() {
+        let repos = vec![Repo {
+            language: Some("Rust".to_string()),
+            description: Some("Fast & <safe>".to_string()),
+            stars_today: Some(10),
+            ..Repo::test("test/repo")
         }];
 
-        render(&repos, OutputFormat::Json);
+        let output = render_to_string(&repos, OutputFormat::Rss, &RenderOptions::default());
+        assert!(output.starts_with("<?xml"));
+        assert!(output.contains("<title>test/repo</title>"));
+        assert!(output.contains("<link>https://github.com/test/repo</link>"));
+        assert!(output.contains("Fast &amp; &lt;safe&gt; — Rust, +10 stars today"));
+        assert!(output.trim_end().ends_with("</rss>"));
+        assert_eq!(output.matches("<item>").count(), 1);
+    }
+
+    #[test]
+    fn test_render_nuon() {
+        let repos = vec![Repo {
+            language: Some("Rust".to_string()),
+            stars_today: Some(10),
+            ..Repo::test("test/repo")
+        }];
+
+        let output = render_to_string(&repos, OutputFormat::Nuon, &RenderOptions::default());
+        assert!(output.starts_with("[\n  {provider: \"github\""));
+        assert!(output.contains("stars_today: 10"));
+        assert!(output.ends_with("}\n]\n"));
     }
 
     #[test]
     fn test_render_motd() {
         let repos = vec![
             Repo {
-                provider: "github".to_string(),
-                icon: "[GH]".to_string(),
-                name: "rust-lang/rust".to_string(),
                 language: Some("Rust".to_string()),
                 description: Some(
                     "Empowering everyone to build reliable and efficient software.".to_string(),
                 ),
-                url: "https://github.com/rust-lang/rust".to_string(),
                 stars_today: Some(50),
                 stars_total: Some(90000),
                 last_activity: Some(Utc::now()),
                 topics: vec!["rust".to_string(), "compiler".to_string()],
                 is_starred: true,
+                ..Repo::test("rust-lang/rust")
             },
             Repo {
                 provider: "gitlab".to_string(),
                 icon: "[GL]".to_string(),
-                name: "gitlab-org/gitlab".to_string(),
                 language: Some("Ruby".to_string()),
-                description: None,
                 url: "https://gitlab.com/gitlab-org/gitlab".to_string(),
-                stars_today: None,
                 stars_total: Some(5000),
                 last_activity: Some(Utc::now() - Duration::days(3)),
                 topics: vec!["gitlab".to_string(), "ruby".to_string()],
-                is_starred: false,
+                ..Repo::test("gitlab-org/gitlab")
             },
         ];
 
-        render(&repos, OutputFormat::Motd);
+        let output = render_to_string(&repos, OutputFormat::Motd, &RenderOptions::default());
+        assert_eq!(output.lines().count(), 2);
+        assert!(output.contains("rust-lang/rust"));
+        assert!(output.contains("gitlab-org/gitlab"));
+    }
+
+    #[test]
+    fn test_render_motd_cloned_column() {
+        let mut repo = Repo {
+            language: Some("Rust".to_string()),
+            ..Repo::test("owner/repo")
+        };
+        assert!(!render_to_string(
+            &[repo.clone()],
+            OutputFormat::Motd,
+            &RenderOptions::default()
+        )
+        .contains("📁"));
+
+        repo.is_cloned = true;
+        assert!(
+            render_to_string(&[repo], OutputFormat::Motd, &RenderOptions::default()).contains("📁")
+        );
+    }
+
+    #[test]
+    fn test_format_movement() {
+        colored::control::set_override(false);
+        assert_eq!(
+            format_movement(Some(Movement::Up(3)), &UNICODE_SYMBOLS),
+            "▲3   "
+        );
+        assert_eq!(
+            format_movement(Some(Movement::Down(12)), &UNICODE_SYMBOLS),
+            "▼12  "
+        );
+        assert_eq!(
+            format_movement(Some(Movement::New), &UNICODE_SYMBOLS),
+            "new  "
+        );
+        assert_eq!(format_movement(None, &UNICODE_SYMBOLS), "     ");
+        assert_eq!(
+            format_movement(Some(Movement::Up(3)), &ASCII_SYMBOLS),
+            "+3   "
+        );
+    }
+
+    #[test]
+    fn test_format_health() {
+        colored::control::set_override(false);
+        let health = Health {
+            score: 87,
+            components: vec![],
+        };
+        assert_eq!(format_health(Some(&health), &UNICODE_SYMBOLS), "✚87  ");
+        assert_eq!(format_health(None, &UNICODE_SYMBOLS), "     ");
+    }
+
+    #[test]
+    fn test_render_accessible() {
+        let repo = Repo {
+            language: Some("Rust".to_string()),
+            description: Some("A **fast** tool".to_string()),
+            stars_today: Some(123),
+            last_activity: Some(Utc::now()),
+            is_starred: true,
+            movement: Some(Movement::Up(2)),
+            ..Repo::test("owner/repo")
+        };
+
+        let output = render_to_string(
+            &[repo.clone(), repo],
+            OutputFormat::Accessible,
+            &RenderOptions::default(),
+        );
+        assert!(output.starts_with("Repository 1 of 2: owner/repo\nProvider: GitHub\n"));
+        assert!(output.contains("Stars today: 123\n"));
+        assert!(output.contains("Rank change since yesterday: up 2\n"));
+        assert!(output.contains("Starred by you: yes\n"));
+        assert!(output.contains("Description: A fast tool\n"));
+        assert!(!output.contains('⭐') && !output.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_render_motd_seen_column() {
+        let mut repo = Repo {
+            language: Some("Rust".to_string()),
+            ..Repo::test("owner/repo")
+        };
+        assert!(!render_to_string(
+            &[repo.clone()],
+            OutputFormat::Motd,
+            &RenderOptions::default()
+        )
+        .contains("👁"));
+
+        repo.is_seen = true;
+        assert!(
+            render_to_string(&[repo], OutputFormat::Motd, &RenderOptions::default()).contains("👁")
+        );
     }
 
     #[test]
@@ -304,6 +1426,170 @@ mod tests {
         assert_eq!(cleaned, "Project logo here");
     }
 
+    #[test]
+    fn test_sanitize_description_html_and_paragraphs() {
+        let desc = "<p>A <b>fast</b> tool &amp; more</p>\n\nSecond   paragraph";
+        let cleaned = sanitize_description(desc, &DescriptionConfig::default());
+        assert_eq!(cleaned, "A fast tool & more Second paragraph");
+
+        let raw = DescriptionConfig {
+            strip_markup: false,
+            ..DescriptionConfig::default()
+        };
+        assert_eq!(
+            sanitize_description("**bold**\n\ntext", &raw),
+            "**bold** text"
+        );
+    }
+
+    #[test]
+    fn test_limit_emoji() {
+        assert_eq!(limit_emoji("🚀🚀🚀 Fast 🔥 tool ✨", 2), "🚀 Fast 🔥 tool");
+        assert_eq!(limit_emoji("🚀 🔥 Fast tool", 5), "🚀 Fast tool");
+        assert_eq!(limit_emoji("Fast 👍🏽 tool", 0), "Fast tool");
+    }
+
+    #[test]
+    fn test_description_max_length() {
+        colored::control::set_override(false);
+        let repo = Repo {
+            language: Some("Rust".to_string()),
+            description: Some("x".repeat(60)),
+            ..Repo::test("owner/repo")
+        };
+
+        let short = RenderOptions {
+            description: DescriptionConfig {
+                max_length: 10,
+                ..DescriptionConfig::default()
+            },
+            ..RenderOptions::default()
+        };
+        let output = render_to_string(std::slice::from_ref(&repo), OutputFormat::Motd, &short);
+        assert!(output.contains(&format!("{}...", "x".repeat(7))));
+        assert!(!output.contains(&"x".repeat(8)));
+
+        let unlimited = RenderOptions {
+            description: DescriptionConfig {
+                max_length: 0,
+                ..DescriptionConfig::default()
+            },
+            ..RenderOptions::default()
+        };
+        let output = render_to_string(&[repo], OutputFormat::Motd, &unlimited);
+        assert!(output.contains(&"x".repeat(60)));
+    }
+
+    #[test]
+    fn test_topic_category() {
+        assert_eq!(topic_category("Rust"), TopicCategory::Language);
+        assert_eq!(topic_category("llm"), TopicCategory::Ai);
+        assert_eq!(topic_category("react"), TopicCategory::Web);
+        assert_eq!(topic_category("kubernetes"), TopicCategory::Infra);
+        assert_eq!(topic_category("cli"), TopicCategory::Tooling);
+        assert_eq!(topic_category("awesome"), TopicCategory::Other);
+    }
+
+    #[test]
+    fn test_render_topics() {
+        colored::control::set_override(false);
+        let repo = Repo {
+            language: Some("Rust".to_string()),
+            description: Some("A tool".to_string()),
+            topics: vec!["rust".to_string(), "cli".to_string(), "tui".to_string()],
+            ..Repo::test("owner/repo")
+        };
+
+        // Hidden by default
+        let output = render_to_string(
+            std::slice::from_ref(&repo),
+            OutputFormat::Motd,
+            &RenderOptions::default(),
+        );
+        assert!(!output.contains("#rust"));
+
+        let opts = RenderOptions {
+            display: DisplayConfig {
+                max_topics: 2,
+                ..DisplayConfig::default()
+            },
+            ..RenderOptions::default()
+        };
+        let output = render_to_string(std::slice::from_ref(&repo), OutputFormat::Motd, &opts);
+        assert!(output.contains("A tool #rust #cli\n"));
+
+        let output = render_to_string(&[repo], OutputFormat::Accessible, &opts);
+        assert!(output.contains("Topics: rust, cli\n"));
+    }
+
+    #[test]
+    fn test_description_fits_width() {
+        colored::control::set_override(false);
+        let repos = vec![Repo {
+            language: Some("Rust".to_string()),
+            description: Some(
+                "A fast and friendly tool that does many useful things for many people".to_string(),
+            ),
+            ..Repo::test("owner/repo")
+        }];
+        let mut opts = RenderOptions {
+            width: Some(60),
+            ..RenderOptions::default()
+        };
+        let output = render_to_string(&repos, OutputFormat::Motd, &opts);
+        let line = output.lines().next().unwrap();
+        assert!(visible_width(line) <= 60, "{line}");
+        assert!(line.ends_with("..."));
+
+        opts.description.wrap = true;
+        let output = render_to_string(&repos, OutputFormat::Motd, &opts);
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|l| visible_width(l) <= 60));
+        assert!(lines[1].starts_with("   "));
+        assert!(output.contains("people"));
+
+        assert_eq!(
+            wrap_words("one two three", 7),
+            vec!["one two".to_string(), "three".to_string()]
+        );
+        assert_eq!(visible_width("\x1b[1;32mab\x1b[0m"), 2);
+        assert_eq!(
+            visible_width("\x1b]8;;https://x.y\x1b\\ab\x1b]8;;\x1b\\"),
+            2
+        );
+    }
+
+    #[test]
+    fn test_render_hyperlinks() {
+        colored::control::set_override(false);
+        let repo = |name: &str| Repo {
+            language: Some("Rust".to_string()),
+            ..Repo::test(name)
+        };
+        let repos = vec![repo("owner/repo"), repo("owner/longer-repo")];
+        let opts = RenderOptions {
+            hyperlinks: true,
+            ..RenderOptions::default()
+        };
+        let output = render_to_string(&repos, OutputFormat::Motd, &opts);
+        assert!(output.contains(
+            "\x1b]8;;https://github.com/owner/repo\x1b\\owner/repo\x1b]8;;\x1b\\       "
+        ));
+
+        let output = render_to_string(&repos, OutputFormat::Motd, &RenderOptions::default());
+        assert!(!output.contains("\x1b]8;;"));
+
+        // Sparklines need two days of history for at least one repo
+        let mut opts = RenderOptions::default();
+        opts.star_history
+            .insert("https://github.com/owner/repo".to_string(), vec![1, 5, 9]);
+        let output = render_to_string(&repos, OutputFormat::Motd, &opts);
+        assert!(output.contains(" ▁▄█     "));
+        opts.display.sparklines = false;
+        assert!(!render_to_string(&repos, OutputFormat::Motd, &opts).contains('▄'));
+    }
+
     #[test]
     fn test_clean_truncated_incomplete_link() {
         // Simulates truncating "[README](https://..." to "[README](h"
@@ -0,0 +1,204 @@
+use anyhow::{bail, Result};
+
+use crate::interactive;
+use crate::model::{LanguageFilter, Repo};
+
+/// Help shown by `?` in `repl`, on top of the shared repo actions
+pub const HELP: &str = "\
+  n, next          fetch the next page from every provider
+  filter K=V ...   narrow the list: lang=, provider=, stars= (minimum), topic=
+  filter           show the active filters
+  filter clear     drop all filters
+";
+
+/// One line typed at the `repl` prompt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// An action shared with the `--interactive` browser
+    Action(interactive::Command),
+    Next,
+    Filter(Vec<Filter>),
+    ShowFilters,
+    ClearFilters,
+}
+
+/// Narrows the in-memory result set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Filter {
+    Lang(String),
+    Provider(String),
+    MinStars(u64),
+    Topic(String),
+}
+
+impl Filter {
+    /// Parse a `key=value` term
+    pub fn parse(term: &str) -> Result<Self> {
+        let Some((key, value)) = term.split_once('=').filter(|(_, v)| !v.is_empty()) else {
+            bail!("Expected key=value, got \"{term}\"");
+        };
+        Ok(match key.to_ascii_lowercase().as_str() {
+            "lang" | "language" => Self::Lang(value.to_string()),
+            "provider" | "p" => Self::Provider(value.to_string()),
+            "stars" | "min-stars" => match value.parse() {
+                Ok(min) => Self::MinStars(min),
+                Err(_) => bail!("stars= takes a number, got \"{value}\""),
+            },
+            "topic" => Self::Topic(value.to_string()),
+            _ => bail!("Unknown filter \"{key}\" (lang, provider, stars or topic)"),
+        })
+    }
+
+    pub fn matches(&self, repo: &Repo) -> bool {
+        match self {
+            Self::Lang(lang) => {
+                LanguageFilter::new(vec![lang.clone()]).matches(repo.language.as_ref())
+            }
+            Self::Provider(provider) => repo.provider.eq_ignore_ascii_case(provider),
+            Self::MinStars(min) => repo.stars_total.unwrap_or(0) >= *min,
+            Self::Topic(topic) => repo.topics.iter().any(|t| t.eq_ignore_ascii_case(topic)),
+        }
+    }
+}
+
+impl std::fmt::Display for Filter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Lang(lang) => write!(f, "lang={lang}"),
+            Self::Provider(provider) => write!(f, "provider={provider}"),
+            Self::MinStars(min) => write!(f, "stars={min}"),
+            Self::Topic(topic) => write!(f, "topic={topic}"),
+        }
+    }
+}
+
+/// Parse a prompt line against a list of `repo_count` repos
+pub fn parse(line: &str, repo_count: usize) -> Result<Command> {
+    let mut words = line.split_whitespace();
+    match words.next().map(str::to_ascii_lowercase).as_deref() {
+        Some("n" | "next") if words.next().is_none() => Ok(Command::Next),
+        Some("f" | "filter") => {
+            let terms: Vec<_> = words.collect();
+            match terms.as_slice() {
+                [] => Ok(Command::ShowFilters),
+                ["clear"] => Ok(Command::ClearFilters),
+                terms => Ok(Command::Filter(
+                    terms
+                        .iter()
+                        .map(|term| Filter::parse(term))
+                        .collect::<Result<_>>()?,
+                )),
+            }
+        }
+        _ => match interactive::parse(line, repo_count) {
+            Some(command) => Ok(Command::Action(command)),
+            None => bail!("Unknown command or position, type ? for help"),
+        },
+    }
+}
+
+/// Everything fetched in a `repl` session, seen through the active filters
+#[derive(Debug, Default)]
+pub struct Session {
+    repos: Vec<Repo>,
+    filters: Vec<Filter>,
+}
+
+impl Session {
+    /// Add a fetched page, skipping repos already in the session
+    pub fn extend(&mut self, page: Vec<Repo>) -> usize {
+        let before = self.repos.len();
+        for repo in page {
+            if !self.repos.iter().any(|known| known.url == repo.url) {
+                self.repos.push(repo);
+            }
+        }
+        self.repos.len() - before
+    }
+
+    /// Add filters; a new filter replaces an earlier one with the same key
+    pub fn filter(&mut self, filters: Vec<Filter>) {
+        for filter in filters {
+            self.filters
+                .retain(|f| std::mem::discriminant(f) != std::mem::discriminant(&filter));
+            self.filters.push(filter);
+        }
+    }
+
+    pub fn clear_filters(&mut self) {
+        self.filters.clear();
+    }
+
+    pub fn filters(&self) -> &[Filter] {
+        &self.filters
+    }
+
+    /// The repos passing every filter, in fetch order
+    pub fn view(&self) -> Vec<Repo> {
+        self.repos
+            .iter()
+            .filter(|repo| self.filters.iter().all(|f| f.matches(repo)))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repo(name: &str, provider: &str, language: &str, stars: u64) -> Repo {
+        Repo {
+            provider: provider.to_string(),
+            icon: String::new(),
+            language: Some(language.to_string()),
+            url: format!("https://example.com/{name}"),
+            stars_total: Some(stars),
+            topics: vec!["cli".to_string()],
+            ..Repo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("next", 3).unwrap(), Command::Next);
+        assert_eq!(
+            parse("o 3", 3).unwrap(),
+            Command::Action(interactive::Command::Open(2))
+        );
+        assert_eq!(
+            parse("filter lang=rust stars=50", 3).unwrap(),
+            Command::Filter(vec![Filter::Lang("rust".to_string()), Filter::MinStars(50)])
+        );
+        assert_eq!(parse("filter", 3).unwrap(), Command::ShowFilters);
+        assert_eq!(parse("filter clear", 3).unwrap(), Command::ClearFilters);
+        assert!(parse("filter stars=many", 3).is_err());
+        assert!(parse("filter colour=red", 3).is_err());
+        assert!(parse("o 4", 3).is_err());
+    }
+
+    #[test]
+    fn test_session_filters_and_dedupes() {
+        let mut session = Session::default();
+        let added = session.extend(vec![
+            repo("a/cli", "github", "Rust", 500),
+            repo("b/web", "gitlab", "Go", 20),
+        ]);
+        assert_eq!(added, 2);
+        assert_eq!(
+            session.extend(vec![repo("a/cli", "github", "Rust", 501)]),
+            0
+        );
+
+        session.filter(vec![Filter::Lang("rust".to_string())]);
+        assert_eq!(session.view().len(), 1);
+        session.filter(vec![Filter::Lang("go".to_string()), Filter::MinStars(10)]);
+        assert_eq!(session.filters().len(), 2);
+        assert_eq!(session.view()[0].name, "b/web");
+
+        session.filter(vec![Filter::Provider("github".to_string())]);
+        assert!(session.view().is_empty());
+        session.clear_filters();
+        assert_eq!(session.view().len(), 2);
+    }
+}
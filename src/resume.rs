@@ -0,0 +1,94 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::state;
+
+/// Providers that failed in the last run, to be fetched again by `--resume`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialRun {
+    pub failed: Vec<String>,
+    pub failed_at: DateTime<Utc>,
+}
+
+impl PartialRun {
+    /// Whether `provider` still needs fetching
+    pub fn needs(&self, provider: &str) -> bool {
+        self.failed.iter().any(|p| p == provider)
+    }
+}
+
+/// Record of a partially failed run, kept in the cache directory
+pub struct ResumeState {
+    state_file: PathBuf,
+}
+
+impl ResumeState {
+    /// Create a new resume state instance
+    pub fn new() -> Result<Self> {
+        let cache_dir = state::cache_dir()?;
+
+        Ok(Self::with_dir(&cache_dir))
+    }
+
+    fn with_dir(cache_dir: &Path) -> Self {
+        Self {
+            state_file: cache_dir.join("partial-run.json"),
+        }
+    }
+
+    /// The last partially failed run, if it hasn't been completed since
+    pub async fn pending(&self) -> Option<PartialRun> {
+        state::read_json(&self.state_file).await.ok().flatten()
+    }
+
+    /// Remember the providers that failed while the others succeeded
+    pub async fn record(&self, failed: Vec<String>) -> Result<()> {
+        let run = PartialRun {
+            failed,
+            failed_at: Utc::now(),
+        };
+        let content =
+            serde_json::to_string_pretty(&run).context("Failed to serialize partial run")?;
+        state::write_atomic(&self.state_file, &content)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to write resume state: {}",
+                    self.state_file.display()
+                )
+            })
+    }
+
+    /// Forget the partial run once every provider succeeded
+    pub async fn clear(&self) -> Result<()> {
+        state::remove(&self.state_file).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_record_and_clear() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-resume-test-{}",
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let state = ResumeState::with_dir(&temp_dir);
+        assert!(state.pending().await.is_none());
+
+        state.record(vec!["gitlab".to_string()]).await.unwrap();
+        let run = state.pending().await.unwrap();
+        assert!(run.needs("gitlab"));
+        assert!(!run.needs("github"));
+
+        state.clear().await.unwrap();
+        assert!(state.pending().await.is_none());
+        state.clear().await.unwrap();
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
@@ -0,0 +1,260 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
+
+use tokio::io::AsyncWriteExt;
+
+use crate::state;
+
+/// How long to wait for a concurrent run before showing the previous output instead
+const WAIT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Lock files whose holder can't be checked (no `/proc` or `kill`) are taken over
+/// after this long
+const STALE_AFTER: Duration = Duration::from_secs(600);
+
+/// Marker recording the last completed run and what it printed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LastRun {
+    args: Vec<String>,
+    /// Unix time in milliseconds
+    finished_at_ms: i64,
+    output: String,
+}
+
+/// Short-lived lock that serializes concurrent runs (e.g. several shells opening at once)
+///
+/// Without it, simultaneous runs each fetch and advance the fetch offset, so every
+/// terminal shows a different slice of the list. The run holding the lock records
+/// its output, which identical invocations that waited on it then print as-is. The
+/// lock file holds the holder's PID, so a crashed run's lock is taken over at once.
+pub struct RunLock {
+    lock_file: PathBuf,
+    last_run_file: PathBuf,
+}
+
+/// Held while a run is in progress; releases the lock when dropped
+pub struct RunLockGuard {
+    lock_file: PathBuf,
+    /// When this run started waiting (Unix time in milliseconds), if another run held the lock
+    waited_since: Option<i64>,
+}
+
+impl RunLockGuard {
+    /// Start of the wait for a concurrent run, if there was one
+    pub fn waited_since(&self) -> Option<i64> {
+        self.waited_since
+    }
+}
+
+impl Drop for RunLockGuard {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.lock_file);
+    }
+}
+
+impl RunLock {
+    /// Create a new run lock instance
+    pub fn new() -> Result<Self> {
+        let cache_dir = state::cache_dir()?;
+
+        Ok(Self::with_dir(&cache_dir))
+    }
+
+    fn with_dir(cache_dir: &std::path::Path) -> Self {
+        Self {
+            lock_file: cache_dir.join("run.lock"),
+            last_run_file: cache_dir.join("last-run.json"),
+        }
+    }
+
+    /// Acquire the lock, waiting briefly for a concurrent run to finish
+    ///
+    /// Returns `None` when the other run takes longer; the caller then shows the
+    /// previous output or proceeds without the lock rather than hanging the shell.
+    pub async fn acquire(&self) -> Option<RunLockGuard> {
+        self.acquire_within(WAIT_TIMEOUT).await
+    }
+
+    async fn acquire_within(&self, timeout: Duration) -> Option<RunLockGuard> {
+        if let Some(parent) = self.lock_file.parent() {
+            tokio::fs::create_dir_all(parent).await.ok()?;
+        }
+
+        let start = Instant::now();
+        let mut waited_since = None;
+        loop {
+            let created = tokio::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&self.lock_file)
+                .await;
+            match created {
+                Ok(mut file) => {
+                    let _ = file
+                        .write_all(std::process::id().to_string().as_bytes())
+                        .await;
+                    let _ = file.flush().await;
+                    return Some(RunLockGuard {
+                        lock_file: self.lock_file.clone(),
+                        waited_since,
+                    });
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    if self.is_stale().await {
+                        let _ = tokio::fs::remove_file(&self.lock_file).await;
+                        continue;
+                    }
+                    waited_since.get_or_insert_with(|| chrono::Utc::now().timestamp_millis());
+                }
+                Err(_) => return None,
+            }
+
+            if start.elapsed() >= timeout {
+                return None;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    }
+
+    /// Whether the lock file was left behind by a run that never finished
+    ///
+    /// The holder's PID decides; a lock without one (its holder may be writing it
+    /// right now) or on a system where processes can't be checked only goes stale
+    /// after [`STALE_AFTER`].
+    async fn is_stale(&self) -> bool {
+        let holder = tokio::fs::read_to_string(&self.lock_file)
+            .await
+            .ok()
+            .and_then(|pid| pid.trim().parse::<u32>().ok());
+        if let Some(alive) = holder.and_then(is_running) {
+            return !alive;
+        }
+
+        let Ok(metadata) = tokio::fs::metadata(&self.lock_file).await else {
+            return false;
+        };
+        metadata
+            .modified()
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+            .is_some_and(|age| age > STALE_AFTER)
+    }
+
+    /// Output of an identical invocation that finished after `since` (Unix time in
+    /// milliseconds), i.e. the run this one waited for
+    pub async fn output_since(&self, args: &[String], since: i64) -> Option<String> {
+        let last: LastRun = state::read_json(&self.last_run_file).await.ok()??;
+        (last.args == args && last.finished_at_ms >= since).then_some(last.output)
+    }
+
+    /// Record the output of a completed run for concurrent invocations
+    pub async fn record(&self, args: &[String], output: &str) -> Result<()> {
+        let last = LastRun {
+            args: args.to_vec(),
+            finished_at_ms: chrono::Utc::now().timestamp_millis(),
+            output: output.to_string(),
+        };
+        let content =
+            serde_json::to_string_pretty(&last).context("Failed to serialize last run")?;
+        state::write_atomic(&self.last_run_file, &content)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to write last run marker: {}",
+                    self.last_run_file.display()
+                )
+            })
+    }
+}
+
+/// Whether a process is running: `None` when that can't be checked
+fn is_running(pid: u32) -> Option<bool> {
+    if cfg!(target_os = "linux") {
+        return Some(std::path::Path::new(&format!("/proc/{pid}")).exists());
+    }
+    if cfg!(unix) {
+        // Signal 0 only checks that the process exists (EPERM still means it does)
+        let output = std::process::Command::new("kill")
+            .args(["-0", &pid.to_string()])
+            .stderr(std::process::Stdio::piped())
+            .output()
+            .ok()?;
+        let denied = String::from_utf8_lossy(&output.stderr).contains("ermitted");
+        return Some(output.status.success() || denied);
+    }
+    None
+}
+
+/// Arguments of the current invocation, used to match concurrent runs
+pub fn invocation_args() -> Vec<String> {
+    std::env::args().skip(1).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_lock(name: &str) -> (RunLock, PathBuf) {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-runlock-{name}-{}",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        (RunLock::with_dir(&temp_dir), temp_dir)
+    }
+
+    #[tokio::test]
+    async fn test_lock_is_exclusive_until_dropped() {
+        let (lock, temp_dir) = temp_lock("exclusive");
+
+        let guard = lock.acquire_within(Duration::ZERO).await;
+        assert!(guard.is_some());
+        assert!(lock
+            .acquire_within(Duration::from_millis(150))
+            .await
+            .is_none());
+
+        drop(guard);
+        assert!(lock.acquire_within(Duration::ZERO).await.is_some());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_crashed_holder_is_taken_over() {
+        let (lock, temp_dir) = temp_lock("crashed");
+        std::fs::create_dir_all(&temp_dir).unwrap();
+
+        // A PID above Linux's pid_max can't be running
+        std::fs::write(temp_dir.join("run.lock"), "4194305").unwrap();
+        let guard = lock.acquire_within(Duration::ZERO).await.unwrap();
+        assert!(guard.waited_since().is_none());
+        drop(guard);
+
+        std::fs::write(temp_dir.join("run.lock"), std::process::id().to_string()).unwrap();
+        assert!(lock.acquire_within(Duration::ZERO).await.is_none());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_output_since_matches_args_and_time() {
+        let (lock, temp_dir) = temp_lock("recent");
+        let args = vec!["--json".to_string()];
+        let before = chrono::Utc::now().timestamp_millis();
+
+        assert!(lock.output_since(&args, before).await.is_none());
+
+        lock.record(&args, "[]\n").await.unwrap();
+        assert_eq!(
+            lock.output_since(&args, before).await.as_deref(),
+            Some("[]\n")
+        );
+        assert!(lock.output_since(&[], before).await.is_none());
+        let after = chrono::Utc::now().timestamp_millis() + 1;
+        assert!(lock.output_since(&args, after).await.is_none());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
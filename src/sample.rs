@@ -0,0 +1,125 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::Config;
+use crate::model::Repo;
+
+/// How many times deeper than the sample each provider is fetched
+const POOL_FACTOR: usize = 3;
+
+/// Fetch each provider `POOL_FACTOR` times deeper than usual, and at least the
+/// sample size deep, so there is a pool to sample (or `--has-binaries` to filter) from
+pub fn deepen(config: &mut Config, sample: usize) {
+    let deeper = |max: usize| max.max(sample).saturating_mul(POOL_FACTOR);
+    let general = &mut config.general;
+    general.max_per_provider = deeper(general.max_per_provider);
+    for max in [
+        &mut general.github_max_entries,
+        &mut general.gitlab_max_entries,
+        &mut general.gitea_max_entries,
+        &mut general.codeberg_max_entries,
+        &mut general.bitbucket_max_entries,
+        &mut general.gitee_max_entries,
+        &mut general.huggingface_max_entries,
+        &mut general.crates_max_entries,
+        &mut general.hackernews_max_entries,
+        &mut general.reddit_max_entries,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        *max = deeper(*max);
+    }
+}
+
+/// A seed from the clock, for runs without `--seed`
+pub fn random_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() ^ u64::from(d.subsec_nanos()).rotate_left(32))
+        .unwrap_or_default()
+}
+
+/// Keep `count` repos picked at random by `seed`, in their original order
+///
+/// The same seed and pool always give the same sample.
+pub fn sample(repos: &mut Vec<Repo>, count: usize, seed: u64) {
+    if repos.len() <= count {
+        return;
+    }
+    let mut rng = SplitMix64(seed);
+    let mut picked: Vec<usize> = (0..repos.len()).collect();
+    // Partial Fisher-Yates: the first `count` slots end up a uniform sample
+    for i in 0..count {
+        let remaining = (picked.len() - i) as u64;
+        let j = i + usize::try_from(rng.next() % remaining).unwrap_or(0);
+        picked.swap(i, j);
+    }
+    picked.truncate(count);
+    picked.sort_unstable();
+
+    let mut index = 0;
+    repos.retain(|_| {
+        let keep = picked.binary_search(&index).is_ok();
+        index += 1;
+        keep
+    });
+}
+
+/// Small deterministic generator; the sample doesn't need more than this
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(repos: &[Repo]) -> Vec<String> {
+        repos.iter().map(|r| r.name.clone()).collect()
+    }
+
+    #[test]
+    fn test_sample_is_seeded_and_keeps_order() {
+        let pool: Vec<Repo> = (0..20).map(|i| Repo::test(&format!("o/r{i:02}"))).collect();
+
+        let mut first = pool.clone();
+        sample(&mut first, 5, 42);
+        let mut again = pool.clone();
+        sample(&mut again, 5, 42);
+        assert_eq!(first.len(), 5);
+        assert_eq!(names(&first), names(&again));
+        let mut sorted = names(&first);
+        sorted.sort();
+        assert_eq!(names(&first), sorted, "original order is kept");
+
+        let seeds_differ = (1..10).any(|seed| {
+            let mut other = pool.clone();
+            sample(&mut other, 5, seed);
+            names(&other) != names(&first)
+        });
+        assert!(seeds_differ);
+
+        let mut small = pool[..3].to_vec();
+        sample(&mut small, 5, 42);
+        assert_eq!(small.len(), 3);
+    }
+
+    #[test]
+    fn test_deepen() {
+        let mut config = Config::default();
+        config.general.max_per_provider = 10;
+        config.general.gitlab_max_entries = Some(2);
+        deepen(&mut config, 5);
+        assert_eq!(config.general.max_per_provider, 30);
+        assert_eq!(config.general.gitlab_max_entries, Some(15));
+        assert_eq!(config.general.github_max_entries, None);
+    }
+}
@@ -0,0 +1,450 @@
+use std::fmt;
+use std::path::Path;
+
+use crate::config::{
+    AuthConfig, Config, HooksConfig, IconStyle, ReadLaterConfig, ReadLaterService, ThemeColor,
+    ThemeConfig, ThemePreset, BUILTIN_PROVIDERS,
+};
+
+/// Deprecated keys and the replacement hint shown to the user
+const DEPRECATED_KEYS: &[(&str, &str)] = &[(
+    "general.timeout_secs",
+    "use `github_timeout_secs`, `gitlab_timeout_secs` and `gitea_timeout_secs` instead",
+)];
+
+/// Severity of a config diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A single problem found while validating a config file
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+    /// Format the diagnostic with its file location, e.g. `trotd.toml:4:1: ...`
+    pub fn located<'a>(&'a self, path: &'a Path) -> impl fmt::Display + 'a {
+        Located {
+            diagnostic: self,
+            path,
+        }
+    }
+}
+
+struct Located<'a> {
+    diagnostic: &'a Diagnostic,
+    path: &'a Path,
+}
+
+impl fmt::Display for Located<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = self.diagnostic;
+        write!(f, "{}", self.path.display())?;
+        if let Some(line) = d.line {
+            write!(f, ":{line}")?;
+            if let Some(column) = d.column {
+                write!(f, ":{column}")?;
+            }
+        }
+        let level = match d.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, ": {level}: {}", d.message)?;
+        if let Some(ref help) = d.help {
+            write!(f, "\n  help: {help}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse and validate config file content
+///
+/// Returns the config together with non-fatal warnings (unknown and deprecated keys),
+/// or a single error diagnostic when the content cannot be deserialized.
+pub fn parse(content: &str) -> Result<(Config, Vec<Diagnostic>), Diagnostic> {
+    let mut ignored = Vec::new();
+    let deserializer = toml::Deserializer::new(content);
+    let config: Config = serde_ignored::deserialize(deserializer, |path| {
+        ignored.push(path.to_string());
+    })
+    .map_err(|e| type_error(content, &e))?;
+
+    if let Some(error) = custom_name_error(content, &config) {
+        return Err(error);
+    }
+
+    let known = known_keys();
+    let mut diagnostics: Vec<Diagnostic> = ignored
+        .iter()
+        .map(|path| unknown_key(content, &known, path))
+        .collect();
+
+    for (path, help) in DEPRECATED_KEYS {
+        if let Some(line) = find_key_line(content, path) {
+            diagnostics.push(Diagnostic {
+                severity: Severity::Warning,
+                line: Some(line),
+                column: None,
+                message: format!("`{path}` is deprecated"),
+                help: Some((*help).to_string()),
+            });
+        }
+    }
+
+    diagnostics.sort_by_key(|d| d.line);
+    Ok((config, diagnostics))
+}
+
+/// Error for a `[[custom]]` provider whose name is taken by a built-in provider, a
+/// `[aliases]` entry or an earlier custom provider, which would shadow or be shadowed
+fn custom_name_error(content: &str, config: &Config) -> Option<Diagnostic> {
+    config.custom.iter().enumerate().find_map(|(i, custom)| {
+        let name = custom.name.as_str();
+        let taken_by = if let Some((id, _)) = BUILTIN_PROVIDERS
+            .iter()
+            .find(|(id, short)| *id == name || *short == name)
+        {
+            format!("the built-in `{id}` provider")
+        } else if config.aliases.contains_key(name) {
+            "an alias in `[aliases]`".to_string()
+        } else if config.custom[..i].iter().any(|c| c.name == name) {
+            "another `[[custom]]` provider".to_string()
+        } else {
+            return None;
+        };
+
+        let quoted = format!("\"{name}\"");
+        let line = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| {
+                let line = line.trim_start();
+                line.starts_with("name") && line.contains(&quoted)
+            })
+            .nth(config.custom[..i].iter().filter(|c| c.name == name).count())
+            .map(|(idx, _)| idx + 1);
+        Some(Diagnostic {
+            severity: Severity::Error,
+            line,
+            column: None,
+            message: format!("custom provider name `{name}` is already used by {taken_by}"),
+            help: Some("give the `[[custom]]` provider a different `name`".to_string()),
+        })
+    })
+}
+
+/// Build an error diagnostic from a deserialization failure
+fn type_error(content: &str, error: &toml::de::Error) -> Diagnostic {
+    let (line, column) = error
+        .span()
+        .map(|span| line_col(content, span.start))
+        .unzip();
+
+    let message = error.message().trim().to_string();
+    let help = if message.contains("expected usize")
+        || message.contains("expected u64")
+        || message.contains("expected u32")
+    {
+        Some("use a whole number without quotes, e.g. `max_per_provider = 3`".to_string())
+    } else if message.contains("expected a boolean") {
+        Some("use `true` or `false` without quotes".to_string())
+    } else if message.contains("expected a sequence") {
+        Some("use a list, e.g. `language_filter = [\"rust\", \"go\"]`".to_string())
+    } else if message.contains("expected a string") {
+        Some("wrap the value in double quotes".to_string())
+    } else {
+        None
+    };
+
+    Diagnostic {
+        severity: Severity::Error,
+        line,
+        column,
+        message,
+        help,
+    }
+}
+
+/// Build a warning for a key that no config field consumed
+fn unknown_key(content: &str, known: &toml::Table, path: &str) -> Diagnostic {
+    let (section, key) = path.rsplit_once('.').unwrap_or(("", path));
+
+    let candidates: Vec<&String> = if section.is_empty() {
+        known.keys().collect()
+    } else {
+        known
+            .get(section)
+            .and_then(toml::Value::as_table)
+            .map(|t| t.keys().collect())
+            .unwrap_or_default()
+    };
+
+    let help = closest_match(key, &candidates).map(|c| format!("did you mean `{c}`?"));
+
+    Diagnostic {
+        severity: Severity::Warning,
+        line: find_key_line(content, path),
+        column: None,
+        message: format!("unknown key `{path}` is ignored"),
+        help,
+    }
+}
+
+/// All keys the config understands, derived from a fully populated default config
+fn known_keys() -> toml::Table {
+    let mut sample = Config::default();
+    sample.general.github_max_entries = Some(0);
+    sample.general.gitlab_max_entries = Some(0);
+    sample.general.gitea_max_entries = Some(0);
+    sample.general.codeberg_max_entries = Some(0);
+    sample.general.bitbucket_max_entries = Some(0);
+    sample.general.gitee_max_entries = Some(0);
+    sample.general.huggingface_max_entries = Some(0);
+    sample.general.crates_max_entries = Some(0);
+    sample.general.hackernews_max_entries = Some(0);
+    sample.general.reddit_max_entries = Some(0);
+    sample.general.min_stars = Some(0);
+    sample.general.max_stars = Some(0);
+    sample.general.secondary_language_percent = Some(0.0);
+    sample.general.remote_rules_url = Some(String::new());
+    sample.general.template = Some(String::new());
+    sample.general.pager = Some(String::new());
+    sample.auth = AuthConfig {
+        github_token: Some(String::new()),
+        gitlab_token: Some(String::new()),
+        gitea_token: Some(String::new()),
+        codeberg_token: Some(String::new()),
+        bitbucket_token: Some(String::new()),
+        bitbucket_username: Some(String::new()),
+        gitee_token: Some(String::new()),
+        huggingface_token: Some(String::new()),
+    };
+    sample.clone.root = Some(String::new());
+    sample.clone.known_bad_url = Some(String::new());
+    sample.description.max_emoji = Some(0);
+    sample.display.ascii_symbols = Some(false);
+    sample.display.hyperlinks = Some(false);
+    sample.display.width = Some(0);
+    let color = ThemeColor::try_from("none".to_string()).ok();
+    sample.theme = ThemeConfig {
+        preset: ThemePreset::Dark,
+        icon: color.clone(),
+        name: color.clone(),
+        stars: color.clone(),
+        language: color.clone(),
+        description: color,
+    };
+    sample.icons.style = Some(IconStyle::Emoji);
+    sample.statusbar.on_click = Some(String::new());
+    sample.statusbar.max_width = Some(0);
+    sample.spam.threshold = Some(0);
+    sample.todo.command = Some(String::new());
+    sample.readlater = ReadLaterConfig {
+        service: Some(ReadLaterService::Linkding),
+        url: Some(String::new()),
+        token: Some(String::new()),
+        tags: Vec::new(),
+        client_id: Some(String::new()),
+        client_secret: Some(String::new()),
+        username: Some(String::new()),
+        password: Some(String::new()),
+    };
+    sample.hooks = HooksConfig {
+        post_fetch: Some(String::new()),
+        pre_render: Some(String::new()),
+        on_new_repo: Some(String::new()),
+    };
+
+    toml::Table::try_from(&sample).unwrap_or_default()
+}
+
+/// Find the closest candidate by edit distance, if it is close enough to be a likely typo
+fn closest_match<'a>(key: &str, candidates: &[&'a String]) -> Option<&'a str> {
+    let max_distance = (key.chars().count() / 3).max(2);
+    candidates
+        .iter()
+        .map(|c| (levenshtein(key, c), c.as_str()))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, c)| c)
+}
+
+/// Classic Levenshtein edit distance
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+
+    prev[b.len()]
+}
+
+/// Convert a byte offset into 1-based line and column numbers
+fn line_col(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset.min(content.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rfind('\n')
+        .map_or(before.len(), |nl| before.len() - nl - 1)
+        + 1;
+    (line, column)
+}
+
+/// Locate the 1-based line defining a dotted key path such as `general.max_per_provider`
+fn find_key_line(content: &str, path: &str) -> Option<usize> {
+    let (section, key) = path.rsplit_once('.').unwrap_or(("", path));
+    let mut current_section = String::new();
+
+    for (idx, raw) in content.lines().enumerate() {
+        let line = raw.trim();
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+
+        if line.starts_with('[') {
+            current_section = line
+                .trim_matches(|c| c == '[' || c == ']')
+                .trim()
+                .to_string();
+            // An unknown top-level table is reported at its header
+            if section.is_empty() && current_section == key {
+                return Some(idx + 1);
+            }
+            continue;
+        }
+
+        if current_section == section {
+            if let Some(rest) = line.strip_prefix(key) {
+                if rest.trim_start().starts_with('=') {
+                    return Some(idx + 1);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_config_has_no_diagnostics() {
+        let content = toml::to_string_pretty(&Config::default()).unwrap();
+        let (_, diagnostics) = parse(&content).unwrap();
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+
+    #[test]
+    fn test_titled_sections_are_known() {
+        let content =
+            "[general]\nsections = [\"trending\", { name = \"myrepos\", title = \"Mine\" }]\n";
+        let (config, diagnostics) = parse(content).unwrap();
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+        assert_eq!(config.general.sections[1].title(), Some("Mine"));
+    }
+
+    #[test]
+    fn test_unknown_key_with_suggestion() {
+        let content =
+            "[general]\nmax_per_provider = 3\nascii_olny = true\n\n[auth]\ngithub_tokn = \"x\"\n";
+        let (config, diagnostics) = parse(content).unwrap();
+        assert_eq!(config.general.max_per_provider, 3);
+        assert_eq!(diagnostics.len(), 2);
+
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(diagnostics[0].line, Some(3));
+        assert!(diagnostics[0].message.contains("general.ascii_olny"));
+        assert_eq!(
+            diagnostics[0].help.as_deref(),
+            Some("did you mean `ascii_only`?")
+        );
+
+        assert_eq!(diagnostics[1].line, Some(6));
+        assert_eq!(
+            diagnostics[1].help.as_deref(),
+            Some("did you mean `github_token`?")
+        );
+    }
+
+    #[test]
+    fn test_custom_name_clashes() {
+        let custom =
+            |name: &str| format!("[[custom]]\nname = \"{name}\"\nurl = \"https://x.test\"\n");
+
+        let error = parse(&custom("gh")).unwrap_err();
+        assert_eq!(error.severity, Severity::Error);
+        assert_eq!(error.line, Some(2));
+        assert!(
+            error.message.contains("built-in `github`"),
+            "{}",
+            error.message
+        );
+
+        let aliased = format!("[aliases]\nwork = \"gitea\"\n\n{}", custom("work"));
+        assert!(parse(&aliased).unwrap_err().message.contains("[aliases]"));
+
+        let twice = format!("{}\n{}", custom("internal"), custom("internal"));
+        assert_eq!(parse(&twice).unwrap_err().line, Some(6));
+
+        assert!(parse(&custom("internal")).is_ok());
+    }
+
+    #[test]
+    fn test_unknown_section() {
+        let content = "[generl]\nmax_per_provider = 3\n";
+        let (_, diagnostics) = parse(content).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line, Some(1));
+        assert_eq!(
+            diagnostics[0].help.as_deref(),
+            Some("did you mean `general`?")
+        );
+    }
+
+    #[test]
+    fn test_deprecated_key() {
+        let content = "[general]\ntimeout_secs = 6\n";
+        let (_, diagnostics) = parse(content).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line, Some(2));
+        assert!(diagnostics[0].message.contains("deprecated"));
+    }
+
+    #[test]
+    fn test_type_mismatch_location() {
+        let content = "[general]\nascii_only = true\nmax_per_provider = \"5\"\n";
+        let error = parse(content).unwrap_err();
+        assert_eq!(error.severity, Severity::Error);
+        assert_eq!(error.line, Some(3));
+        assert_eq!(error.column, Some(20));
+        assert!(error.help.as_deref().unwrap().contains("whole number"));
+
+        let located = error.located(Path::new("trotd.toml")).to_string();
+        assert!(located.starts_with("trotd.toml:3:20: error: "));
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("same", "same"), 0);
+    }
+}
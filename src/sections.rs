@@ -0,0 +1,165 @@
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::config::{Config, OutputSection};
+use crate::model::Repo;
+use crate::render::{render_to_string, OutputFormat, RenderOptions};
+use crate::template::Template;
+use crate::{alerts, follow, history, myrepos, reminders};
+
+/// Render the output: the sections of `general.sections` in order for MOTD and
+/// accessible output, the trending list alone for every other format
+///
+/// The alerts section looks at `trending`, every repo fetched today, rather than
+/// just the `repos` left to show. Sections that fail to load are left out;
+/// `verbose` reports why.
+pub async fn render(
+    repos: &[Repo],
+    trending: &[Repo],
+    config: &Config,
+    format: OutputFormat,
+    opts: &RenderOptions,
+    template: Option<&Template>,
+    verbose: bool,
+) -> Result<String> {
+    if let Some(template) = template {
+        return template.render(repos);
+    }
+    if !matches!(format, OutputFormat::Motd | OutputFormat::Accessible) {
+        return Ok(render_to_string(repos, format, opts));
+    }
+
+    let mut out = String::new();
+    for spec in &config.general.sections {
+        let title = spec.title();
+        let section = match spec.section() {
+            OutputSection::Alerts if config.general.trending_alert => Ok(alerts::section(
+                trending,
+                &config.general.my_accounts,
+                format,
+                title,
+            )),
+            OutputSection::Alerts => continue,
+            OutputSection::Trending => {
+                Ok(titled(render_to_string(repos, format, opts), title, format))
+            }
+            OutputSection::Topics => follow::section(config, format)
+                .await
+                .map(|body| titled(body, title, format)),
+            OutputSection::Reminders => reminders::section(format, title).await,
+            OutputSection::Myrepos => myrepos::dashboard(config, format)
+                .await
+                .map(|body| titled(body, title, format)),
+            OutputSection::Languages => history::language_section(format, title, opts.ascii).await,
+        };
+        match section {
+            Ok(section) => out.push_str(&section),
+            Err(e) => {
+                if verbose {
+                    eprintln!("⚠ Failed to load {}: {e}", describe(spec.section()));
+                }
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn describe(section: OutputSection) -> &'static str {
+    match section {
+        OutputSection::Alerts => "trending alerts",
+        OutputSection::Trending => "trending repos",
+        OutputSection::Topics => "followed topics",
+        OutputSection::Reminders => "reminders",
+        OutputSection::Myrepos => "your repo stats",
+        OutputSection::Languages => "language trends",
+    }
+}
+
+/// Put `title` above a section without a heading of its own, after its leading blank lines
+fn titled(body: String, title: Option<&str>, format: OutputFormat) -> String {
+    let Some(title) = title.filter(|_| !body.is_empty()) else {
+        return body;
+    };
+    let heading = if matches!(format, OutputFormat::Accessible) {
+        title.normal()
+    } else {
+        title.bold()
+    };
+    let rest = body.trim_start_matches('\n');
+    let lead = &body[..body.len() - rest.len()];
+    format!("{lead}{heading}\n{rest}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SectionSpec;
+
+    fn repo(name: &str) -> Repo {
+        Repo {
+            language: Some("Rust".to_string()),
+            stars_today: Some(10),
+            ..Repo::test(name)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sections_in_configured_order() {
+        colored::control::set_override(false);
+        let mut config = Config::default();
+        config.general.my_accounts = vec!["me".to_string()];
+        config.general.trending_alert = true;
+        config.general.sections = vec![
+            SectionSpec::Titled {
+                name: OutputSection::Trending,
+                title: Some("Hot today".to_string()),
+            },
+            SectionSpec::Name(OutputSection::Alerts),
+        ];
+        let repos = [repo("me/tool")];
+        // Shown in an earlier run, so only in today's fetched list
+        let trending = [repo("me/tool"), repo("me/older")];
+        let opts = RenderOptions::default();
+
+        let output = render(
+            &repos,
+            &trending,
+            &config,
+            OutputFormat::Accessible,
+            &opts,
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+        assert!(output.starts_with("Hot today\n"));
+        let alert = output.find("Your repository is trending").unwrap();
+        assert!(alert > output.find("me/tool").unwrap());
+        assert!(output.contains("is trending today: me/older"));
+
+        config.general.sections = vec![SectionSpec::Name(OutputSection::Alerts)];
+        let json = render(
+            &repos,
+            &trending,
+            &config,
+            OutputFormat::Json,
+            &opts,
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+        assert!(json.contains("\"repos\""), "other formats ignore sections");
+    }
+
+    #[test]
+    fn test_titled_keeps_leading_blank_line() {
+        colored::control::set_override(false);
+        assert_eq!(
+            titled("\n  a\n".to_string(), Some("Mine"), OutputFormat::Motd),
+            "\nMine\n  a\n"
+        );
+        assert_eq!(titled(String::new(), Some("Mine"), OutputFormat::Motd), "");
+        assert_eq!(titled("a\n".to_string(), None, OutputFormat::Motd), "a\n");
+    }
+}
@@ -1,9 +1,13 @@
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
 use std::path::PathBuf;
 
+use crate::config::Config;
 use crate::model::Repo;
+use crate::state;
 
 /// Seen repositories tracker with daily reset and pagination offset
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,6 +16,129 @@ struct SeenEntry {
     seen_repos: HashSet<String>, // Set of "owner/repo" names
     #[serde(default)]
     fetch_offset: usize, // Track position in trending list for pagination
+    #[serde(default)]
+    fetched_lists: BTreeMap<String, Vec<FetchedRepo>>, // Today's fetches per `fetch_key`, in rank order
+    #[serde(default)]
+    batches: Vec<SeenBatch>, // Today's runs that marked repos seen, oldest first
+}
+
+/// Repositories one run marked as seen, for `seen undo`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SeenBatch {
+    repos: Vec<String>,
+    fetch_offset: usize, // Offset before the run advanced it
+}
+
+/// What `--show-all` needs to replay a fetched repo
+///
+/// Per-run details such as starred status, health, movement and badges are worked
+/// out again on replay.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct FetchedRepo {
+    provider: String,
+    icon: String,
+    name: String,
+    url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stars_today: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stars_total: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    last_activity: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    topics: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    homepage: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    license: Option<String>,
+}
+
+impl From<&Repo> for FetchedRepo {
+    fn from(repo: &Repo) -> Self {
+        Self {
+            provider: repo.provider.clone(),
+            icon: repo.icon.clone(),
+            name: repo.name.clone(),
+            url: repo.url.clone(),
+            language: repo.language.clone(),
+            description: repo.description.clone(),
+            stars_today: repo.stars_today,
+            stars_total: repo.stars_total,
+            last_activity: repo.last_activity,
+            topics: repo.topics.clone(),
+            homepage: repo.homepage.clone(),
+            license: repo.license.clone(),
+        }
+    }
+}
+
+impl From<FetchedRepo> for Repo {
+    fn from(fetched: FetchedRepo) -> Self {
+        Self {
+            provider: fetched.provider,
+            icon: fetched.icon,
+            name: fetched.name,
+            language: fetched.language,
+            description: fetched.description,
+            url: fetched.url,
+            stars_today: fetched.stars_today,
+            stars_total: fetched.stars_total,
+            last_activity: fetched.last_activity,
+            topics: fetched.topics,
+            is_starred: false,
+            is_cloned: false,
+            is_seen: false,
+            movement: None,
+            health: None,
+            homepage: fetched.homepage,
+            license: fetched.license,
+            is_sponsorable: false,
+            has_binaries: false,
+            has_container: false,
+            is_suspicious: false,
+        }
+    }
+}
+
+/// Key of today's fetched list for a run: runs with other language or topic filters
+/// fetch other repos, so each combination keeps its own list
+pub fn fetch_key(config: &Config) -> String {
+    let mut languages: Vec<String> = config
+        .general
+        .language_filter
+        .iter()
+        .map(|l| l.to_lowercase())
+        .collect();
+    languages.sort();
+    languages.dedup();
+
+    let mut key = format!("lang={}", languages.join(","));
+    for (provider, topics) in config.topic_filters() {
+        let _ = write!(
+            key,
+            " {provider}=+{}-{}",
+            topics.include.join(","),
+            topics.exclude.join(",")
+        );
+    }
+    key
+}
+
+impl SeenEntry {
+    /// Empty entry for today
+    fn today() -> Self {
+        Self {
+            date: SeenTracker::today(),
+            seen_repos: HashSet::new(),
+            fetch_offset: 0,
+            fetched_lists: BTreeMap::new(),
+            batches: Vec::new(),
+        }
+    }
 }
 
 /// Filesystem-based seen tracker that resets daily
@@ -22,9 +149,7 @@ pub struct SeenTracker {
 impl SeenTracker {
     /// Create a new seen tracker instance
     pub fn new() -> Result<Self> {
-        let cache_dir = dirs::cache_dir()
-            .context("Failed to determine cache directory")?
-            .join("trotd");
+        let cache_dir = state::cache_dir()?;
 
         Ok(Self {
             seen_file: cache_dir.join("seen.json"),
@@ -38,12 +163,9 @@ impl SeenTracker {
 
     /// Load seen entry for today
     async fn get_entry(&self) -> Result<Option<SeenEntry>> {
-        if !self.seen_file.exists() {
+        let Some(entry) = state::read_json::<SeenEntry>(&self.seen_file).await? else {
             return Ok(None);
-        }
-
-        let content = tokio::fs::read_to_string(&self.seen_file).await?;
-        let entry: SeenEntry = serde_json::from_str(&content)?;
+        };
 
         // Check if data is from today
         if entry.date == Self::today() {
@@ -80,18 +202,47 @@ impl SeenTracker {
             .await
     }
 
-    /// Mark repositories as seen
+    /// Mark repositories as seen, remembering them as one batch for `seen undo`
     pub async fn mark_seen(&self, repos: &[Repo]) -> Result<()> {
-        // Load existing seen set
-        let mut seen_repos = self.get_seen().await.unwrap_or_default();
+        let mut entry = self
+            .get_entry()
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or_else(SeenEntry::today);
 
-        // Add new repos
-        for repo in repos {
-            seen_repos.insert(repo.name.clone());
+        let added: Vec<String> = repos
+            .iter()
+            .filter(|repo| entry.seen_repos.insert(repo.name.clone()))
+            .map(|repo| repo.name.clone())
+            .collect();
+        if !added.is_empty() {
+            entry.batches.push(SeenBatch {
+                repos: added,
+                fetch_offset: entry.fetch_offset,
+            });
         }
 
-        // Save updated entry
-        self.save_seen(seen_repos).await
+        self.save_entry(&entry).await
+    }
+
+    /// Forget the most recently seen batch and restore the fetch offset from before it
+    ///
+    /// Returns the repositories that will show again, `None` when today has nothing to undo.
+    pub async fn undo_last_batch(&self) -> Result<Option<Vec<String>>> {
+        let Some(mut entry) = self.get_entry().await? else {
+            return Ok(None);
+        };
+        let Some(batch) = entry.batches.pop() else {
+            return Ok(None);
+        };
+
+        for name in &batch.repos {
+            entry.seen_repos.remove(name);
+        }
+        entry.fetch_offset = batch.fetch_offset;
+        self.save_entry(&entry).await?;
+        Ok(Some(batch.repos))
     }
 
     /// Save seen repositories with offset
@@ -100,33 +251,55 @@ impl SeenTracker {
         seen_repos: HashSet<String>,
         offset: usize,
     ) -> Result<()> {
-        // Ensure cache directory exists
-        if let Some(parent) = self.seen_file.parent() {
-            tokio::fs::create_dir_all(parent).await.with_context(|| {
-                format!("Failed to create cache directory: {}", parent.display())
-            })?;
-        }
-
-        let entry = SeenEntry {
-            date: Self::today(),
-            seen_repos,
-            fetch_offset: offset,
-        };
+        let mut entry = self
+            .get_entry()
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or_else(SeenEntry::today);
+        entry.seen_repos = seen_repos;
+        entry.fetch_offset = offset;
+        self.save_entry(&entry).await
+    }
 
+    /// Write a full entry to disk
+    async fn save_entry(&self, entry: &SeenEntry) -> Result<()> {
         let content =
-            serde_json::to_string_pretty(&entry).context("Failed to serialize seen entry")?;
+            serde_json::to_string_pretty(entry).context("Failed to serialize seen entry")?;
 
-        tokio::fs::write(&self.seen_file, content)
+        state::write_atomic(&self.seen_file, &content)
             .await
-            .with_context(|| format!("Failed to write seen file: {}", self.seen_file.display()))?;
+            .with_context(|| format!("Failed to write seen file: {}", self.seen_file.display()))
+    }
 
-        Ok(())
+    /// Every repository runs with the same [`fetch_key`] fetched so far today, in
+    /// original rank order
+    pub async fn fetched_today(&self, key: &str) -> Vec<Repo> {
+        self.get_entry()
+            .await
+            .ok()
+            .flatten()
+            .and_then(|mut e| e.fetched_lists.remove(key))
+            .map(|fetched| fetched.into_iter().map(Repo::from).collect())
+            .unwrap_or_default()
     }
 
-    /// Save seen repositories (without changing offset)
-    async fn save_seen(&self, seen_repos: HashSet<String>) -> Result<()> {
-        let current_offset = self.get_fetch_offset().await;
-        self.save_seen_with_offset(seen_repos, current_offset).await
+    /// Merge freshly fetched repositories into today's cumulative list for `key`
+    ///
+    /// New repositories are appended so later pages keep their rank order; known
+    /// ones are updated in place with the latest star counts.
+    pub async fn record_fetched(&self, key: &str, repos: &[Repo]) -> Result<()> {
+        let mut entry = self.get_entry().await?.unwrap_or_else(SeenEntry::today);
+        let fetched = entry.fetched_lists.entry(key.to_string()).or_default();
+
+        for repo in repos {
+            match fetched.iter_mut().find(|r| r.url == repo.url) {
+                Some(existing) => *existing = FetchedRepo::from(repo),
+                None => fetched.push(FetchedRepo::from(repo)),
+            }
+        }
+
+        self.save_entry(&entry).await
     }
 
     /// Filter out already-seen repositories
@@ -143,14 +316,9 @@ impl SeenTracker {
     /// Clear all seen data
     #[allow(dead_code)]
     pub async fn clear(&self) -> Result<()> {
-        if self.seen_file.exists() {
-            tokio::fs::remove_file(&self.seen_file)
-                .await
-                .with_context(|| {
-                    format!("Failed to remove seen file: {}", self.seen_file.display())
-                })?;
-        }
-        Ok(())
+        state::remove(&self.seen_file)
+            .await
+            .with_context(|| format!("Failed to remove seen file: {}", self.seen_file.display()))
     }
 }
 
@@ -161,17 +329,12 @@ mod tests {
 
     fn create_test_repo(name: &str) -> Repo {
         Repo {
-            provider: "github".to_string(),
-            icon: "[GH]".to_string(),
-            name: name.to_string(),
             language: Some("Rust".to_string()),
             description: Some("Test repository".to_string()),
-            url: format!("https://github.com/{name}"),
             stars_today: Some(10),
             stars_total: Some(100),
             last_activity: Some(Utc::now()),
-            topics: vec![],
-            is_starred: false,
+            ..Repo::test(name)
         }
     }
 
@@ -237,4 +400,103 @@ mod tests {
         let _ = tracker.clear().await;
         let _ = std::fs::remove_dir_all(&temp_dir);
     }
+
+    #[tokio::test]
+    async fn test_record_fetched_keeps_rank_order() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-seen-fetched-{}",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let tracker = SeenTracker {
+            seen_file: temp_dir.join("seen.json"),
+        };
+
+        tracker
+            .record_fetched(
+                "lang=",
+                &[create_test_repo("a/first"), create_test_repo("a/second")],
+            )
+            .await
+            .unwrap();
+        tracker.increment_fetch_offset(2).await.unwrap();
+
+        let mut updated = create_test_repo("a/first");
+        updated.stars_today = Some(99);
+        updated.is_starred = true;
+        tracker
+            .record_fetched("lang=", &[create_test_repo("a/third"), updated])
+            .await
+            .unwrap();
+        tracker
+            .record_fetched("lang=rust", &[create_test_repo("a/rusty")])
+            .await
+            .unwrap();
+
+        let fetched = tracker.fetched_today("lang=").await;
+        let names: Vec<_> = fetched.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["a/first", "a/second", "a/third"]);
+        assert_eq!(fetched[0].stars_today, Some(99));
+        // Per-run details aren't stored
+        assert!(!fetched[0].is_starred);
+        assert_eq!(fetched[0].language.as_deref(), Some("Rust"));
+        let rust_only = tracker.fetched_today("lang=rust").await;
+        assert_eq!(rust_only.len(), 1);
+        assert!(tracker.fetched_today("lang=go").await.is_empty());
+        // Offset updates must not drop the fetched list
+        assert_eq!(tracker.get_fetch_offset().await, 2);
+
+        let _ = tracker.clear().await;
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_fetch_key() {
+        let mut config = Config::default();
+        config.general.language_filter = vec!["Rust".to_string(), "go".to_string()];
+        let key = fetch_key(&config);
+        assert!(key.starts_with("lang=go,rust"));
+
+        config.general.language_filter = vec!["go".to_string(), "rust".to_string()];
+        assert_eq!(fetch_key(&config), key);
+        config.github.include_topics = vec!["cli".to_string()];
+        assert_ne!(fetch_key(&config), key);
+    }
+
+    #[tokio::test]
+    async fn test_undo_last_batch() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-seen-undo-{}",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let tracker = SeenTracker {
+            seen_file: temp_dir.join("seen.json"),
+        };
+        assert_eq!(tracker.undo_last_batch().await.unwrap(), None);
+
+        // Two runs, as main records them: mark seen, then advance the offset
+        tracker
+            .mark_seen(&[create_test_repo("a/one"), create_test_repo("a/two")])
+            .await
+            .unwrap();
+        tracker.increment_fetch_offset(2).await.unwrap();
+        tracker
+            .mark_seen(&[create_test_repo("a/two"), create_test_repo("a/three")])
+            .await
+            .unwrap();
+        tracker.increment_fetch_offset(2).await.unwrap();
+
+        let undone = tracker.undo_last_batch().await.unwrap();
+        assert_eq!(undone, Some(vec!["a/three".to_string()]));
+        assert_eq!(tracker.get_fetch_offset().await, 2);
+        let seen = tracker.get_seen().await.unwrap();
+        assert!(seen.contains("a/two") && !seen.contains("a/three"));
+
+        tracker.undo_last_batch().await.unwrap();
+        assert!(tracker.get_seen().await.unwrap().is_empty());
+        assert_eq!(tracker.get_fetch_offset().await, 0);
+        assert_eq!(tracker.undo_last_batch().await.unwrap(), None);
+
+        let _ = tracker.clear().await;
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
 }
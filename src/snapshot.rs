@@ -0,0 +1,229 @@
+use anyhow::{Context, Result};
+use chrono::{Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::model::{Movement, Repo};
+use crate::state;
+
+/// Provider-local ranks of every repository fetched on one day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DaySnapshot {
+    date: String,                   // Format: YYYY-MM-DD
+    ranks: BTreeMap<String, usize>, // Repo URL -> 1-based rank within its provider
+}
+
+/// Today's snapshot plus the most recent one from an earlier day
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SnapshotFile {
+    current: Option<DaySnapshot>,
+    previous: Option<DaySnapshot>,
+}
+
+/// Filesystem-based daily rank snapshots used to compute rank movement
+pub struct SnapshotStore {
+    snapshot_file: PathBuf,
+}
+
+impl SnapshotStore {
+    /// Create a new snapshot store instance
+    pub fn new() -> Result<Self> {
+        let cache_dir = state::cache_dir()?;
+
+        Ok(Self {
+            snapshot_file: cache_dir.join("snapshot.json"),
+        })
+    }
+
+    /// Create a snapshot store with a custom directory (for testing)
+    #[cfg(test)]
+    fn with_dir(cache_dir: &std::path::Path) -> Self {
+        Self {
+            snapshot_file: cache_dir.join("snapshot.json"),
+        }
+    }
+
+    /// Record today's ranks and annotate each repo with its movement since yesterday
+    ///
+    /// `offset` is the pagination offset the providers were fetched from, so ranks
+    /// stay comparable across runs. Repos keep `movement: None` when there is no
+    /// snapshot from yesterday to compare against.
+    pub async fn annotate(&self, repos: &mut [Repo], offset: usize) -> Result<()> {
+        let today = Utc::now().date_naive();
+        let ranks = provider_ranks(repos, offset);
+
+        let mut file = state::read_json::<SnapshotFile>(&self.snapshot_file)
+            .await?
+            .unwrap_or_default();
+        file.roll(today, ranks);
+
+        let yesterday = (today - Duration::days(1)).format("%Y-%m-%d").to_string();
+        let previous = file.previous.as_ref().filter(|p| p.date == yesterday);
+        let current = file.current.as_ref();
+
+        if let (Some(previous), Some(current)) = (previous, current) {
+            for repo in repos.iter_mut() {
+                if let Some(&rank) = current.ranks.get(&repo.url) {
+                    repo.movement = Some(movement(previous.ranks.get(&repo.url).copied(), rank));
+                }
+            }
+        }
+
+        let content =
+            serde_json::to_string_pretty(&file).context("Failed to serialize snapshot")?;
+        state::write_atomic(&self.snapshot_file, &content)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to write snapshot file: {}",
+                    self.snapshot_file.display()
+                )
+            })
+    }
+}
+
+impl SnapshotFile {
+    /// Merge ranks into today's snapshot, rotating the old one out on a new day
+    fn roll(&mut self, today: NaiveDate, ranks: BTreeMap<String, usize>) {
+        let today = today.format("%Y-%m-%d").to_string();
+
+        match self.current {
+            Some(ref mut current) if current.date == today => {
+                // Keep the first rank seen today; later paginated runs only add entries
+                for (url, rank) in ranks {
+                    current.ranks.entry(url).or_insert(rank);
+                }
+            }
+            _ => {
+                self.previous = self.current.take();
+                self.current = Some(DaySnapshot { date: today, ranks });
+            }
+        }
+    }
+}
+
+/// 1-based rank of each repo within its provider's fetched list
+fn provider_ranks(repos: &[Repo], offset: usize) -> BTreeMap<String, usize> {
+    let mut positions: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut ranks = BTreeMap::new();
+
+    for repo in repos {
+        let position = positions.entry(repo.provider.as_str()).or_insert(offset);
+        *position += 1;
+        ranks.entry(repo.url.clone()).or_insert(*position);
+    }
+
+    ranks
+}
+
+/// Movement from yesterday's rank to today's
+fn movement(previous: Option<usize>, current: usize) -> Movement {
+    match previous {
+        None => Movement::New,
+        Some(prev) if prev > current => Movement::Up(prev - current),
+        Some(prev) if prev < current => Movement::Down(current - prev),
+        Some(_) => Movement::Same,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_repo(provider: &str, name: &str) -> Repo {
+        Repo {
+            provider: provider.to_string(),
+            language: Some("Rust".to_string()),
+            url: format!("https://{provider}.com/{name}"),
+            stars_today: Some(10),
+            stars_total: Some(100),
+            ..Repo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_provider_ranks_are_per_provider() {
+        let repos = vec![
+            create_test_repo("github", "a/one"),
+            create_test_repo("gitlab", "b/one"),
+            create_test_repo("github", "a/two"),
+        ];
+        let ranks = provider_ranks(&repos, 5);
+        assert_eq!(ranks["https://github.com/a/one"], 6);
+        assert_eq!(ranks["https://github.com/a/two"], 7);
+        assert_eq!(ranks["https://gitlab.com/b/one"], 6);
+    }
+
+    #[test]
+    fn test_movement() {
+        assert_eq!(movement(None, 1), Movement::New);
+        assert_eq!(movement(Some(5), 2), Movement::Up(3));
+        assert_eq!(movement(Some(1), 4), Movement::Down(3));
+        assert_eq!(movement(Some(2), 2), Movement::Same);
+    }
+
+    #[test]
+    fn test_roll_rotates_on_new_day() {
+        let day1 = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let day2 = NaiveDate::from_ymd_opt(2025, 1, 2).unwrap();
+        let mut file = SnapshotFile::default();
+
+        file.roll(day1, BTreeMap::from([("x".to_string(), 1)]));
+        // Same day: existing ranks win, new entries are added
+        file.roll(
+            day1,
+            BTreeMap::from([("x".to_string(), 3), ("y".to_string(), 4)]),
+        );
+        let current = file.current.as_ref().unwrap();
+        assert_eq!(current.ranks["x"], 1);
+        assert_eq!(current.ranks["y"], 4);
+        assert!(file.previous.is_none());
+
+        file.roll(day2, BTreeMap::from([("y".to_string(), 1)]));
+        assert_eq!(file.previous.as_ref().unwrap().date, "2025-01-01");
+        assert_eq!(file.current.as_ref().unwrap().date, "2025-01-02");
+    }
+
+    #[tokio::test]
+    async fn test_annotate_against_yesterday() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "trotd-snapshot-test-{}",
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let store = SnapshotStore::with_dir(&temp_dir);
+
+        // Without a snapshot from yesterday nothing is annotated
+        let mut repos = vec![
+            create_test_repo("github", "a/one"),
+            create_test_repo("github", "a/two"),
+        ];
+        store.annotate(&mut repos, 0).await.unwrap();
+        assert!(repos.iter().all(|r| r.movement.is_none()));
+
+        // Pretend today's snapshot was taken yesterday
+        let yesterday = (Utc::now().date_naive() - Duration::days(1))
+            .format("%Y-%m-%d")
+            .to_string();
+        let mut file: SnapshotFile = state::read_json(&store.snapshot_file)
+            .await
+            .unwrap()
+            .unwrap();
+        file.current.as_mut().unwrap().date = yesterday;
+        state::write_atomic(&store.snapshot_file, &serde_json::to_string(&file).unwrap())
+            .await
+            .unwrap();
+
+        let mut repos = vec![
+            create_test_repo("github", "a/two"),
+            create_test_repo("github", "a/three"),
+            create_test_repo("github", "a/one"),
+        ];
+        store.annotate(&mut repos, 0).await.unwrap();
+        assert_eq!(repos[0].movement, Some(Movement::Up(1)));
+        assert_eq!(repos[1].movement, Some(Movement::New));
+        assert_eq!(repos[2].movement, Some(Movement::Down(2)));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
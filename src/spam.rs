@@ -0,0 +1,298 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use regex::Regex;
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use crate::config::{Config, SpamAction, SpamConfig};
+use crate::lookup::{self, RepoCheck};
+use crate::model::Repo;
+use crate::providers::{GitHub, GitLab, Gitea};
+
+/// Marketing phrases typical of growth-hacked repositories (matched case-insensitively)
+const MARKETING_KEYWORDS: &[&str] = &[
+    "passive income",
+    "make money",
+    "earn money",
+    "get rich",
+    "airdrop",
+    "giveaway",
+    "discount",
+    "coupon",
+    "promo code",
+    "limited time",
+    "100% free",
+    "click here",
+    "sign up now",
+    "join now",
+    "free followers",
+    "mod apk",
+    "cracked",
+    "unlimited free",
+];
+
+/// Points a description or README that is little more than links adds to the score
+const LINK_ONLY_POINTS: u8 = 20;
+
+/// Words of text (outside links) below which a README counts as link-only
+const README_MIN_WORDS: usize = 20;
+
+static EMOJI_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\p{Extended_Pictographic}").unwrap());
+static URL_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"https?://\S+").unwrap());
+/// Markdown images and links (with their text), HTML tags and bare URLs
+static README_LINK_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"!?\[[^\]]*\]\([^)]*\)|<[^>]+>|https?://\S+").unwrap());
+
+/// Likelihood (0-100) that a repository is spam, from its name, description and topics
+///
+/// Points: emoji density up to 30, an ALL-CAPS name 20, marketing phrases 10 each up
+/// to 30, and a description or (with `link_only_readme`) README that is little more
+/// than links 20.
+pub fn score(repo: &Repo, extra_keywords: &[String], link_only_readme: bool) -> u8 {
+    let description = repo.description.as_deref().unwrap_or_default();
+    let link_points = if link_only_readme {
+        LINK_ONLY_POINTS
+    } else {
+        link_only_points(description)
+    };
+    emoji_points(description)
+        + caps_points(&repo.name)
+        + keyword_points(repo, extra_keywords)
+        + link_points
+}
+
+fn emoji_points(description: &str) -> u8 {
+    let emoji = EMOJI_RE.find_iter(description).count();
+    let words = description.split_whitespace().count().max(1);
+
+    // Emoji per word, in percent
+    match emoji * 100 / words {
+        30.. => 30,
+        15..=29 => 15,
+        _ => 0,
+    }
+}
+
+fn caps_points(name: &str) -> u8 {
+    let repo_name = name.rsplit('/').next().unwrap_or(name);
+    let letters: Vec<char> = repo_name.chars().filter(|c| c.is_alphabetic()).collect();
+    if letters.len() >= 4 && letters.iter().all(|c| c.is_uppercase()) {
+        20
+    } else {
+        0
+    }
+}
+
+fn keyword_points(repo: &Repo, extra_keywords: &[String]) -> u8 {
+    let text = format!(
+        "{} {} {}",
+        repo.name,
+        repo.description.as_deref().unwrap_or_default(),
+        repo.topics.join(" ")
+    )
+    .to_lowercase();
+
+    let hits = MARKETING_KEYWORDS
+        .iter()
+        .copied()
+        .chain(extra_keywords.iter().map(String::as_str))
+        .filter(|keyword| text.contains(&keyword.to_lowercase()))
+        .count();
+    u8::try_from(hits.min(3) * 10).unwrap_or(30)
+}
+
+fn link_only_points(description: &str) -> u8 {
+    if !URL_RE.is_match(description) {
+        return 0;
+    }
+    let remaining = URL_RE.replace_all(description, "");
+    if remaining.split_whitespace().count() < 3 {
+        LINK_ONLY_POINTS
+    } else {
+        0
+    }
+}
+
+/// Whether a README links somewhere but says next to nothing itself
+fn is_link_only(readme: &str) -> bool {
+    if !README_LINK_RE.is_match(readme) {
+        return false;
+    }
+    let text = README_LINK_RE.replace_all(readme, " ");
+    let words = text
+        .split_whitespace()
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .count();
+    words < README_MIN_WORDS
+}
+
+/// Whether a repository's README is little more than links
+struct LinkOnlyReadme;
+
+#[async_trait]
+impl RepoCheck for LinkOnlyReadme {
+    type Answer = bool;
+
+    async fn github(&self, github: &GitHub, name: &str, token: Option<&str>) -> Result<bool> {
+        Ok(is_link_only(&github.readme(name, token).await?))
+    }
+
+    async fn gitlab(
+        &self,
+        gitlab: &GitLab,
+        base_url: &str,
+        path: &str,
+        token: Option<&str>,
+    ) -> Result<bool> {
+        Ok(gitlab
+            .readme(base_url, path, token)
+            .await?
+            .is_some_and(|readme| is_link_only(&readme)))
+    }
+
+    async fn gitea(
+        &self,
+        gitea: &Gitea,
+        base_url: &str,
+        name: &str,
+        token: Option<&str>,
+    ) -> Result<bool> {
+        Ok(gitea
+            .readme(base_url, name, token)
+            .await?
+            .is_some_and(|readme| is_link_only(&readme)))
+    }
+}
+
+/// URLs of the repos whose README is little more than links (`spam.check_readmes`)
+///
+/// Only repos the README points could still push to the threshold are looked up, with
+/// one or two requests each; failed lookups and repos without a README don't count.
+pub async fn link_only_readmes(repos: &[Repo], config: &Config) -> HashSet<String> {
+    let cfg = &config.spam;
+    let Some(threshold) = cfg.threshold.filter(|_| cfg.check_readmes) else {
+        return HashSet::new();
+    };
+    let candidates: Vec<Repo> = repos
+        .iter()
+        .filter(|repo| {
+            let points = score(repo, &cfg.keywords, false);
+            points < threshold && points + LINK_ONLY_POINTS >= threshold
+        })
+        .cloned()
+        .collect();
+
+    let link_only = lookup::check_each(&candidates, config, false, &LinkOnlyReadme).await;
+    candidates
+        .into_iter()
+        .zip(link_only)
+        .filter(|(_, link_only)| link_only.unwrap_or_default())
+        .map(|(repo, _)| repo.url)
+        .collect()
+}
+
+/// Drop or demote repositories scoring at or above the configured threshold
+///
+/// `link_only_readmes` holds the URLs from [`link_only_readmes`]. Returns how many
+/// repositories were affected; does nothing without a threshold.
+pub fn apply(
+    repos: &mut Vec<Repo>,
+    cfg: &SpamConfig,
+    link_only_readmes: &HashSet<String>,
+) -> usize {
+    let Some(threshold) = cfg.threshold else {
+        return 0;
+    };
+    let is_spam = |repo: &Repo| {
+        score(repo, &cfg.keywords, link_only_readmes.contains(&repo.url)) >= threshold
+    };
+
+    match cfg.action {
+        SpamAction::Drop => {
+            let before_count = repos.len();
+            repos.retain(|repo| !is_spam(repo));
+            before_count - repos.len()
+        }
+        SpamAction::Demote => {
+            let (spam, clean): (Vec<Repo>, Vec<Repo>) = repos.drain(..).partition(is_spam);
+            let count = spam.len();
+            repos.extend(clean);
+            repos.extend(spam);
+            count
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_repo(name: &str, description: &str) -> Repo {
+        Repo {
+            description: Some(description.to_string()),
+            ..Repo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_score_signals() {
+        let clean = create_test_repo(
+            "owner/ripgrep",
+            "Recursively search directories for a regex",
+        );
+        assert_eq!(score(&clean, &[], false), 0);
+
+        let spam = create_test_repo(
+            "owner/FREE-MONEY",
+            "🚀🔥💰 Make money 💎 with this airdrop 🤑",
+        );
+        assert_eq!(score(&spam, &[], false), 30 + 20 + 20);
+
+        let link = create_test_repo("owner/landing", "Visit https://example.com");
+        assert_eq!(score(&link, &[], false), 20);
+
+        let custom = create_test_repo("owner/tool", "The best AI wrapper ever built");
+        assert_eq!(score(&custom, &["ai wrapper".to_string()], false), 10);
+        // A link-only README counts once, like a link-only description
+        assert_eq!(score(&link, &[], true), 20);
+        assert_eq!(score(&clean, &[], true), 20);
+    }
+
+    #[test]
+    fn test_is_link_only() {
+        assert!(is_link_only(
+            "# Best SaaS\n\n[![Get it](https://img.example.com/b.svg)](https://example.com)\n\n\
+             👉 [Sign up here](https://example.com/?ref=42)"
+        ));
+        assert!(!is_link_only(
+            "# tool\n\nA fast command-line tool that finds duplicate files in large \
+             directory trees by hashing them in parallel. See the [docs](https://example.com) \
+             for installation and usage instructions on every platform."
+        ));
+        assert!(!is_link_only("# tool\n\nTiny."));
+    }
+
+    #[test]
+    fn test_apply_drop_and_demote() {
+        let spam = create_test_repo("owner/SPAMMY", "🚀🔥💰 passive income");
+        let clean = create_test_repo("owner/tool", "A useful tool for developers");
+
+        let mut cfg = SpamConfig {
+            threshold: Some(50),
+            ..SpamConfig::default()
+        };
+        let mut repos = vec![spam.clone(), clean.clone()];
+        assert_eq!(apply(&mut repos, &cfg, &HashSet::new()), 1);
+        assert_eq!(repos.len(), 1);
+
+        cfg.action = SpamAction::Demote;
+        let mut repos = vec![spam, clean];
+        assert_eq!(apply(&mut repos, &cfg, &HashSet::new()), 1);
+        assert_eq!(repos[0].name, "owner/tool");
+        assert_eq!(repos[1].name, "owner/SPAMMY");
+
+        cfg.threshold = None;
+        assert_eq!(apply(&mut repos, &cfg, &HashSet::new()), 0);
+    }
+}
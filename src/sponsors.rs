@@ -0,0 +1,47 @@
+use anyhow::Result;
+use std::collections::BTreeSet;
+
+use crate::model::Repo;
+use crate::providers::GitHub;
+
+/// Mark GitHub repos whose owner has a GitHub Sponsors listing
+///
+/// Sponsors data is only available through GraphQL, which needs a token. All owners
+/// are looked up in a single batched query; other providers are left unmarked.
+pub async fn enrich(repos: &mut [Repo], github: &GitHub, token: &str) -> Result<()> {
+    let owners: Vec<String> = repos
+        .iter()
+        .filter_map(github_owner)
+        .map(str::to_string)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    let sponsorable = github.sponsorable_owners(&owners, token).await?;
+    for repo in repos.iter_mut() {
+        repo.is_sponsorable = github_owner(repo).is_some_and(|owner| sponsorable.contains(owner));
+    }
+    Ok(())
+}
+
+/// Owner login of a GitHub repo (`owner` of `owner/repo`)
+fn github_owner(repo: &Repo) -> Option<&str> {
+    if repo.provider != "github" {
+        return None;
+    }
+    repo.name.split_once('/').map(|(owner, _)| owner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_github_owner() {
+        let mut repo = Repo::test("owner/repo");
+        assert_eq!(github_owner(&repo), Some("owner"));
+
+        repo.provider = "gitlab".to_string();
+        assert_eq!(github_owner(&repo), None);
+    }
+}
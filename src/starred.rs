@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::path::PathBuf;
 
+use crate::state;
+
 /// Starred repositories cache with timestamp
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct StarredEntry {
@@ -10,21 +12,19 @@ struct StarredEntry {
     starred_repos: HashSet<String>, // Set of "owner/repo" names
 }
 
-/// Filesystem-based starred status cache
+/// Filesystem-based starred status cache, one file per provider
 pub struct StarredCache {
     cache_file: PathBuf,
     ttl_secs: u64,
 }
 
 impl StarredCache {
-    /// Create a new starred cache instance (1 hour TTL)
-    pub fn new() -> Result<Self> {
-        let cache_dir = dirs::cache_dir()
-            .context("Failed to determine cache directory")?
-            .join("trotd");
+    /// Create a new starred cache instance for a provider (1 hour TTL)
+    pub fn new(provider: &str) -> Result<Self> {
+        let cache_dir = state::cache_dir()?;
 
         Ok(Self {
-            cache_file: cache_dir.join("starred.json"),
+            cache_file: cache_dir.join(format!("starred-{provider}.json")),
             ttl_secs: 3600, // 1 hour cache
         })
     }
@@ -39,12 +39,7 @@ impl StarredCache {
 
     /// Load starred repositories from cache
     pub async fn get_starred(&self) -> Option<HashSet<String>> {
-        if !self.cache_file.exists() {
-            return None;
-        }
-
-        let content = tokio::fs::read_to_string(&self.cache_file).await.ok()?;
-        let entry: StarredEntry = serde_json::from_str(&content).ok()?;
+        let entry: StarredEntry = state::read_json(&self.cache_file).await.ok()??;
 
         // Check if cache is still valid
         let age = Self::now().saturating_sub(entry.timestamp);
@@ -57,13 +52,6 @@ impl StarredCache {
 
     /// Save starred repositories to cache
     pub async fn save_starred(&self, starred_repos: HashSet<String>) -> Result<()> {
-        // Ensure cache directory exists
-        if let Some(parent) = self.cache_file.parent() {
-            tokio::fs::create_dir_all(parent).await.with_context(|| {
-                format!("Failed to create cache directory: {}", parent.display())
-            })?;
-        }
-
         let entry = StarredEntry {
             timestamp: Self::now(),
             starred_repos,
@@ -72,16 +60,14 @@ impl StarredCache {
         let content =
             serde_json::to_string_pretty(&entry).context("Failed to serialize starred entry")?;
 
-        tokio::fs::write(&self.cache_file, content)
+        state::write_atomic(&self.cache_file, &content)
             .await
             .with_context(|| {
                 format!(
                     "Failed to write starred file: {}",
                     self.cache_file.display()
                 )
-            })?;
-
-        Ok(())
+            })
     }
 
     /// Check if a repository is starred
@@ -95,19 +81,13 @@ impl StarredCache {
     }
 
     /// Clear starred cache
-    #[allow(dead_code)]
     pub async fn clear(&self) -> Result<()> {
-        if self.cache_file.exists() {
-            tokio::fs::remove_file(&self.cache_file)
-                .await
-                .with_context(|| {
-                    format!(
-                        "Failed to remove starred file: {}",
-                        self.cache_file.display()
-                    )
-                })?;
-        }
-        Ok(())
+        state::remove(&self.cache_file).await.with_context(|| {
+            format!(
+                "Failed to remove starred file: {}",
+                self.cache_file.display()
+            )
+        })
     }
 }
 
@@ -148,6 +128,14 @@ mod tests {
         let _ = std::fs::remove_dir_all(&temp_dir);
     }
 
+    #[test]
+    fn test_cache_file_per_provider() {
+        let github = StarredCache::new("github").unwrap();
+        let gitlab = StarredCache::new("gitlab").unwrap();
+        assert!(github.cache_file.ends_with("starred-github.json"));
+        assert_ne!(github.cache_file, gitlab.cache_file);
+    }
+
     #[tokio::test]
     async fn test_starred_cache_expiry() {
         let temp_dir =
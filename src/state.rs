@@ -0,0 +1,290 @@
+use anyhow::{bail, Context, Result};
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::quiet::notice;
+
+/// Process-wide state location, from `--state-dir` / `--no-state`
+static LOCATION: OnceLock<Location> = OnceLock::new();
+
+/// Where caches and persistent data (seen, starred, bookmarks, ...) are kept
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Location {
+    /// The platform cache and data directories, e.g. `~/.cache/trotd`
+    Platform,
+    /// One directory holding `cache/` and `data/`
+    Dir(PathBuf),
+    /// Nothing is read or written
+    Disabled,
+}
+
+impl Location {
+    /// Resolve the flags, falling back to `TROTD_STATE_DIR` and `TROTD_NO_STATE`
+    pub fn resolve(state_dir: Option<PathBuf>, no_state: bool) -> Self {
+        Self::resolve_with(
+            state_dir,
+            no_state,
+            std::env::var("TROTD_NO_STATE").ok().as_deref(),
+            std::env::var_os("TROTD_STATE_DIR").map(PathBuf::from),
+        )
+    }
+
+    /// Resolve the flags against the given `TROTD_NO_STATE` and `TROTD_STATE_DIR` values
+    fn resolve_with(
+        state_dir: Option<PathBuf>,
+        no_state: bool,
+        env_no_state: Option<&str>,
+        env_dir: Option<PathBuf>,
+    ) -> Self {
+        let env_no_state = env_no_state.is_some_and(|v| !matches!(v.trim(), "" | "0" | "false"));
+        if no_state || env_no_state {
+            return Self::Disabled;
+        }
+        state_dir
+            .or(env_dir)
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .map_or(Self::Platform, Self::Dir)
+    }
+
+    fn cache_dir(&self) -> Result<PathBuf> {
+        match self {
+            Self::Platform => Ok(dirs::cache_dir()
+                .context("Failed to determine cache directory")?
+                .join("trotd")),
+            Self::Dir(dir) => Ok(dir.join("cache")),
+            Self::Disabled => bail!("State is disabled (--no-state)"),
+        }
+    }
+
+    fn data_dir(&self) -> Result<PathBuf> {
+        match self {
+            Self::Platform => Ok(dirs::data_dir()
+                .context("Failed to determine data directory")?
+                .join("trotd")),
+            Self::Dir(dir) => Ok(dir.join("data")),
+            Self::Disabled => bail!("State is disabled (--no-state)"),
+        }
+    }
+}
+
+/// Set the state location once at startup
+pub fn set_location(location: Location) {
+    let _ = LOCATION.set(location);
+}
+
+fn location() -> &'static Location {
+    LOCATION.get_or_init(|| Location::Platform)
+}
+
+/// Whether `--no-state` turned all caches and persistent data off
+pub fn disabled() -> bool {
+    *location() == Location::Disabled
+}
+
+/// Directory for caches, `~/.cache/trotd` unless `--state-dir` moves it
+pub fn cache_dir() -> Result<PathBuf> {
+    location().cache_dir()
+}
+
+/// Directory for persistent data, `~/.local/share/trotd` unless `--state-dir` moves it
+pub fn data_dir() -> Result<PathBuf> {
+    location().data_dir()
+}
+
+/// Path of the rolling backup kept next to a state file
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".bak");
+    PathBuf::from(name)
+}
+
+/// Path of the temporary file a new state file is written to before renaming
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".tmp");
+    PathBuf::from(name)
+}
+
+/// Write a state file atomically, keeping the previous valid version as a backup
+///
+/// The content goes to a temporary file that is renamed over the target, so an
+/// interrupted write never leaves a truncated file behind.
+pub async fn write_atomic(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+
+    // Only roll the backup forward from a file that is still valid JSON
+    if let Ok(current) = tokio::fs::read_to_string(path).await {
+        if serde_json::from_str::<serde_json::Value>(&current).is_ok() {
+            let _ = tokio::fs::write(backup_path(path), current).await;
+        }
+    }
+
+    let temp = temp_path(path);
+    tokio::fs::write(&temp, content)
+        .await
+        .with_context(|| format!("Failed to write file: {}", temp.display()))?;
+    tokio::fs::rename(&temp, path)
+        .await
+        .with_context(|| format!("Failed to replace file: {}", path.display()))?;
+
+    Ok(())
+}
+
+/// Read a JSON state file, restoring it from backup if it is corrupt
+///
+/// Returns `Ok(None)` when the file does not exist, and an error only when both
+/// the file and its backup are unreadable.
+pub async fn read_json<T: DeserializeOwned>(path: &Path) -> Result<Option<T>> {
+    let content = match tokio::fs::read_to_string(path).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(e).with_context(|| format!("Failed to read file: {}", path.display()))
+        }
+    };
+
+    let parse_error = match serde_json::from_str(&content) {
+        Ok(value) => return Ok(Some(value)),
+        Err(e) => e,
+    };
+
+    let backup = backup_path(path);
+    let restored = tokio::fs::read_to_string(&backup)
+        .await
+        .ok()
+        .and_then(|b| serde_json::from_str(&b).ok().map(|value| (b, value)));
+
+    match restored {
+        Some((backup_content, value)) => {
+            notice!(
+                "⚠ {} was corrupt ({parse_error}); restored from backup",
+                path.display()
+            );
+            let _ = tokio::fs::write(path, backup_content).await;
+            Ok(Some(value))
+        }
+        None => Err(parse_error).with_context(|| {
+            format!(
+                "Corrupt state file without usable backup: {}",
+                path.display()
+            )
+        }),
+    }
+}
+
+/// Remove a state file together with its backup
+pub async fn remove(path: &Path) -> Result<()> {
+    for file in [path.to_path_buf(), backup_path(path)] {
+        if file.exists() {
+            tokio::fs::remove_file(&file)
+                .await
+                .with_context(|| format!("Failed to remove file: {}", file.display()))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "trotd-state-{name}-{}",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ))
+    }
+
+    #[test]
+    fn test_location_dirs() {
+        let dir = Location::resolve_with(Some(PathBuf::from("/state")), false, None, None);
+        assert_eq!(dir.cache_dir().unwrap(), Path::new("/state/cache"));
+        assert_eq!(dir.data_dir().unwrap(), Path::new("/state/data"));
+
+        let disabled = Location::resolve_with(Some(PathBuf::from("/state")), true, None, None);
+        assert_eq!(disabled, Location::Disabled);
+        assert!(disabled.cache_dir().is_err());
+        assert!(disabled.data_dir().is_err());
+    }
+
+    #[test]
+    fn test_location_env_fallbacks() {
+        let env_dir = || Some(PathBuf::from("/env"));
+        assert_eq!(
+            Location::resolve_with(None, false, None, env_dir()),
+            Location::Dir(PathBuf::from("/env"))
+        );
+        // The flag wins over the environment
+        assert_eq!(
+            Location::resolve_with(Some(PathBuf::from("/flag")), false, None, env_dir()),
+            Location::Dir(PathBuf::from("/flag"))
+        );
+        assert_eq!(
+            Location::resolve_with(None, false, Some("1"), env_dir()),
+            Location::Disabled
+        );
+        assert_eq!(
+            Location::resolve_with(None, false, Some("false"), Some(PathBuf::new())),
+            Location::Platform
+        );
+    }
+
+    #[tokio::test]
+    async fn test_write_keeps_backup_of_previous_version() {
+        let dir = temp_dir("backup");
+        let file = dir.join("seen.json");
+
+        write_atomic(&file, r#"["a"]"#).await.unwrap();
+        write_atomic(&file, r#"["a","b"]"#).await.unwrap();
+
+        let backup = std::fs::read_to_string(backup_path(&file)).unwrap();
+        assert_eq!(backup, r#"["a"]"#);
+        assert!(!temp_path(&file).exists());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_file_restored_from_backup() {
+        let dir = temp_dir("restore");
+        let file = dir.join("seen.json");
+
+        write_atomic(&file, r#"["a"]"#).await.unwrap();
+        write_atomic(&file, r#"["a","b"]"#).await.unwrap();
+        // Simulate an interrupted write that truncated the file
+        std::fs::write(&file, r#"["a","#).unwrap();
+
+        let value: HashSet<String> = read_json(&file).await.unwrap().unwrap();
+        assert_eq!(value.len(), 1);
+        assert!(value.contains("a"));
+        // The restored content is written back
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), r#"["a"]"#);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_missing_and_unrecoverable_files() {
+        let dir = temp_dir("missing");
+        let file = dir.join("seen.json");
+
+        let missing: Option<Vec<String>> = read_json(&file).await.unwrap();
+        assert!(missing.is_none());
+
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&file, "not json").unwrap();
+        let result: Result<Option<Vec<String>>> = read_json(&file).await;
+        assert!(result.is_err());
+
+        remove(&file).await.unwrap();
+        assert!(!file.exists());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
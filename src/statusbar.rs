@@ -0,0 +1,182 @@
+use crate::config::StatusbarConfig;
+use crate::model::Repo;
+
+/// Render the single status-bar line from the configured template
+///
+/// Placeholders: `{name}` (owner/repo), `{repo}` (repo only), `{stars}` (today's
+/// stars, else total), `{language}`, `{provider}` and `{count}` (repos shown).
+/// With `on_click` the line is wrapped in a polybar left-click action tag.
+pub fn render(repos: &[Repo], cfg: &StatusbarConfig) -> String {
+    let Some(top) = repos.first() else {
+        return "\n".to_string();
+    };
+
+    let stars = top
+        .stars_today
+        .or(top.stars_total)
+        .map(|n| n.to_string())
+        .unwrap_or_default();
+    let text = cfg
+        .template
+        .replace("{name}", &top.name)
+        .replace(
+            "{repo}",
+            top.name.rsplit('/').next().unwrap_or(top.name.as_str()),
+        )
+        .replace("{stars}", &stars)
+        .replace("{language}", top.language.as_deref().unwrap_or(""))
+        .replace("{provider}", &top.provider)
+        .replace("{count}", &repos.len().to_string());
+    let text = text.replace('\n', " ");
+    let text = truncate(text.trim(), cfg.max_width);
+
+    match cfg.on_click {
+        Some(ref command) => {
+            // Colons end the command in polybar action tags
+            let command = command.replace(':', "\\:");
+            format!("%{{A1:{command}:}}{text}%{{A}}\n")
+        }
+        None => format!("{text}\n"),
+    }
+}
+
+/// Render the hottest repo of the day as a tmux status-line segment
+///
+/// Uses tmux style escapes (`#[fg=...]`); the visible text is the repo name and
+/// today's stars, with the name shortened to fit `max_width`.
+pub fn render_tmux(repos: &[Repo], cfg: &StatusbarConfig) -> String {
+    let Some(top) = hottest(repos) else {
+        return "\n".to_string();
+    };
+
+    let stars = top
+        .stars_today
+        .or(top.stars_total)
+        .map(|n| format!(" ★{n}"))
+        .unwrap_or_default();
+    let name = match cfg.max_width {
+        Some(max) if top.name.chars().count() + stars.chars().count() > max => {
+            let room = max.saturating_sub(stars.chars().count());
+            if room < 2 {
+                // Not even a shortened name fits next to the stars; drop them
+                return format!(
+                    "#[fg=cyan,bold]{}#[default]\n",
+                    tmux_escape(&truncate(&top.name, Some(max)))
+                );
+            }
+            truncate(&top.name, Some(room))
+        }
+        _ => top.name.clone(),
+    };
+
+    format!(
+        "#[fg=cyan,bold]{}#[nobold]#[fg=green]{stars}#[default]\n",
+        tmux_escape(&name)
+    )
+}
+
+/// Ultra-short prompt snippet for the hottest repo, e.g. `🔥 owner/repo +123★`
+///
+/// Empty when there are no repos, so a prompt module can hide itself.
+pub fn render_prompt(repos: &[Repo], max_width: Option<usize>) -> String {
+    let Some(top) = hottest(repos) else {
+        return String::new();
+    };
+    let stars = match (top.stars_today, top.stars_total) {
+        (Some(today), _) => format!(" +{today}★"),
+        (None, Some(total)) => format!(" ★{total}"),
+        (None, None) => String::new(),
+    };
+    let text = format!("🔥 {}{stars}", top.name);
+    truncate(&text, max_width)
+}
+
+/// Repo with the most stars today, the first of equally hot ones
+fn hottest(repos: &[Repo]) -> Option<&Repo> {
+    // Reversed because max_by_key returns the last maximum
+    repos
+        .iter()
+        .rev()
+        .max_by_key(|r| r.stars_today.unwrap_or(0))
+}
+
+/// Shorten `text` to at most `max_width` characters, ending in an ellipsis
+fn truncate(text: &str, max_width: Option<usize>) -> String {
+    match max_width {
+        Some(max) if text.chars().count() > max => {
+            let mut short: String = text.chars().take(max.saturating_sub(1)).collect();
+            if max > 0 {
+                short.push('…');
+            }
+            short
+        }
+        _ => text.to_string(),
+    }
+}
+
+/// `#` starts a format sequence in tmux status lines; `##` is a literal one
+fn tmux_escape(text: &str) -> String {
+    text.replace('#', "##")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_repo(name: &str) -> Repo {
+        Repo {
+            language: Some("Rust".to_string()),
+            stars_today: Some(42),
+            stars_total: Some(1000),
+            ..Repo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_render_template() {
+        let repos = vec![create_test_repo("owner/tool"), create_test_repo("a/b")];
+        let mut cfg = StatusbarConfig::default();
+        assert_eq!(render(&repos, &cfg), "owner/tool ★42\n");
+
+        cfg.template = "{repo} [{language}] 1/{count}".to_string();
+        assert_eq!(render(&repos, &cfg), "tool [Rust] 1/2\n");
+
+        cfg.on_click = Some("xdg-open https://x".to_string());
+        assert_eq!(
+            render(&repos, &cfg),
+            "%{A1:xdg-open https\\://x:}tool [Rust] 1/2%{A}\n"
+        );
+
+        assert_eq!(render(&[], &cfg), "\n");
+
+        cfg.on_click = None;
+        cfg.max_width = Some(6);
+        assert_eq!(render(&repos, &cfg), "tool …\n");
+    }
+
+    #[test]
+    fn test_render_tmux() {
+        let mut hot = create_test_repo("owner/c#-tool");
+        hot.stars_today = Some(100);
+        let repos = vec![create_test_repo("a/b"), hot];
+        let mut cfg = StatusbarConfig::default();
+
+        assert_eq!(
+            render_tmux(&repos, &cfg),
+            "#[fg=cyan,bold]owner/c##-tool#[nobold]#[fg=green] ★100#[default]\n"
+        );
+
+        cfg.max_width = Some(12);
+        assert_eq!(
+            render_tmux(&repos, &cfg),
+            "#[fg=cyan,bold]owner/…#[nobold]#[fg=green] ★100#[default]\n"
+        );
+
+        cfg.max_width = Some(4);
+        assert_eq!(render_tmux(&repos, &cfg), "#[fg=cyan,bold]own…#[default]\n");
+
+        assert_eq!(render_prompt(&repos, None), "🔥 owner/c#-tool +100★");
+        assert_eq!(render_prompt(&repos, Some(8)), "🔥 owner…");
+        assert_eq!(render_prompt(&[], None), "");
+    }
+}
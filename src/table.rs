@@ -0,0 +1,99 @@
+use std::fmt::Write;
+use unicode_width::UnicodeWidthStr;
+
+use crate::model::Repo;
+
+/// Column headers of the table
+const HEADER: [&str; 5] = ["Repository", "Language", "Today", "Total", "Activity"];
+
+/// Columns holding numbers, aligned to the right
+const NUMERIC: [bool; 5] = [false, false, true, true, false];
+
+/// Render an aligned table of name, language, stars today/total and last activity
+///
+/// Widths are measured in terminal columns, so wide (CJK, emoji) names line up too.
+/// Unknown values are shown as `-`.
+pub fn render(repos: &[Repo], ascii: bool) -> String {
+    if repos.is_empty() {
+        return "No trending repositories found today.\n".to_string();
+    }
+
+    let count = |n: Option<u64>| n.map_or_else(|| "-".to_string(), |n| n.to_string());
+    let rows: Vec<[String; 5]> = repos
+        .iter()
+        .map(|repo| {
+            [
+                repo.name.clone(),
+                repo.language.clone().unwrap_or_else(|| "-".to_string()),
+                count(repo.stars_today),
+                count(repo.stars_total),
+                repo.last_activity
+                    .map_or_else(|| "-".to_string(), |_| crate::render::format_recency(repo)),
+            ]
+        })
+        .collect();
+
+    let mut widths = HEADER.map(UnicodeWidthStr::width);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.width());
+        }
+    }
+
+    let mut out = String::new();
+    push_row(&mut out, &HEADER.map(str::to_string), &widths);
+    let rule = if ascii { "-" } else { "─" };
+    let rules = widths.map(|width| rule.repeat(width));
+    push_row(&mut out, &rules, &widths);
+    for row in &rows {
+        push_row(&mut out, row, &widths);
+    }
+    out
+}
+
+fn push_row(out: &mut String, cells: &[String; 5], widths: &[usize; 5]) {
+    let mut line = String::new();
+    for (idx, cell) in cells.iter().enumerate() {
+        let padding = " ".repeat(widths[idx].saturating_sub(cell.width()));
+        if idx > 0 {
+            line.push_str("  ");
+        }
+        if NUMERIC[idx] {
+            let _ = write!(line, "{padding}{cell}");
+        } else {
+            let _ = write!(line, "{cell}{padding}");
+        }
+    }
+    let _ = writeln!(out, "{}", line.trim_end());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repo(name: &str, language: Option<&str>, today: Option<u64>, total: Option<u64>) -> Repo {
+        Repo {
+            language: language.map(str::to_string),
+            description: Some("A very long description that never shows up".to_string()),
+            stars_today: today,
+            stars_total: total,
+            ..Repo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_render_aligns_wide_names() {
+        let repos = [
+            repo("a/b", Some("Rust"), Some(1234), None),
+            repo("用户/项目", None, Some(5), Some(99)),
+        ];
+        assert_eq!(
+            render(&repos, true),
+            "Repository  Language  Today  Total  Activity\n\
+             ----------  --------  -----  -----  --------\n\
+             a/b         Rust       1234      -  -\n\
+             用户/项目   -             5     99  -\n"
+        );
+        assert!(!render(&repos, false).contains("A very long"));
+    }
+}
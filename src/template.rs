@@ -0,0 +1,102 @@
+use anyhow::{Context, Result};
+use minijinja::{context, Environment};
+use std::path::Path;
+
+use crate::model::Repo;
+
+/// A user-supplied minijinja template that replaces the built-in output
+///
+/// The context has `repos` (the same fields as `--json`), `count` and `date`
+/// (`YYYY-MM-DD`). Templates named `*.html` or `*.xml` get HTML escaping.
+#[derive(Debug, Clone)]
+pub struct Template {
+    name: String,
+    source: String,
+}
+
+impl Template {
+    /// Read and compile the template, so syntax errors surface before fetching
+    pub fn load(path: &Path) -> Result<Self> {
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read template: {}", path.display()))?;
+        let name = path.file_name().map_or_else(
+            || "template".to_string(),
+            |n| n.to_string_lossy().into_owned(),
+        );
+        let template = Self { name, source };
+        template
+            .environment()
+            .with_context(|| format!("Invalid template: {}", path.display()))?;
+        Ok(template)
+    }
+
+    /// Render the repositories through the template
+    pub fn render(&self, repos: &[Repo]) -> Result<String> {
+        let env = self.environment()?;
+        let output = env
+            .get_template(&self.name)?
+            .render(context! {
+                repos => repos,
+                count => repos.len(),
+                date => chrono::Utc::now().format("%Y-%m-%d").to_string(),
+            })
+            .with_context(|| format!("Failed to render template {}", self.name))?;
+        // Like the built-in formats, end with exactly one newline
+        Ok(format!("{}\n", output.trim_end_matches('\n')))
+    }
+
+    fn environment(&self) -> Result<Environment<'_>> {
+        let mut env = Environment::new();
+        env.add_template(&self.name, &self.source)?;
+        Ok(env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repo(name: &str, stars: u64) -> Repo {
+        Repo {
+            language: Some("Rust".to_string()),
+            description: Some("<fast>".to_string()),
+            stars_today: Some(stars),
+            ..Repo::test(name)
+        }
+    }
+
+    fn template(name: &str, source: &str) -> Template {
+        Template {
+            name: name.to_string(),
+            source: source.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render_repos() {
+        let tpl = template(
+            "list.txt",
+            "{% for r in repos %}{{ loop.index }}. {{ r.name }} +{{ r.stars_today }} {{ r.description }}\n{% endfor %}{{ count }} repos\n\n",
+        );
+        let output = tpl.render(&[repo("a/one", 10), repo("b/two", 5)]).unwrap();
+        assert_eq!(output, "1. a/one +10 <fast>\n2. b/two +5 <fast>\n2 repos\n");
+
+        let html = template("list.html", "{{ repos[0].description }}");
+        assert_eq!(html.render(&[repo("a/one", 1)]).unwrap(), "&lt;fast&gt;\n");
+    }
+
+    #[test]
+    fn test_load_rejects_invalid_template() {
+        let path = std::env::temp_dir().join(format!(
+            "trotd-template-test-{}.txt",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        std::fs::write(&path, "{% for r in repos %}{{ r.name }}").unwrap();
+        assert!(Template::load(&path).is_err());
+
+        std::fs::write(&path, "{{ count }}").unwrap();
+        assert_eq!(Template::load(&path).unwrap().render(&[]).unwrap(), "0\n");
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,190 @@
+use crate::config::ColorChoice;
+
+/// Environment variables set by Windows terminals that render ANSI colors and emoji
+const MODERN_TERMINAL_VARS: &[&str] = &["WT_SESSION", "TERM_PROGRAM", "ConEmuANSI", "TERM"];
+
+/// `TERM_PROGRAM` values of terminals known to render OSC 8 hyperlinks
+const HYPERLINK_PROGRAMS: &[&str] = &["WezTerm", "iTerm.app", "vscode", "ghostty", "Hyper"];
+
+/// Environment variables only set inside terminals that render OSC 8 hyperlinks
+const HYPERLINK_VARS: &[&str] = &[
+    "KITTY_WINDOW_ID",
+    "WT_SESSION",
+    "KONSOLE_VERSION",
+    "DOMTERM",
+];
+
+/// Prepare the console for colored output
+///
+/// On Windows this turns on VT processing so ANSI colors render instead of showing
+/// as escape codes. Colors then follow `choice`, see [`set_colors`].
+pub fn init(choice: ColorChoice) {
+    #[cfg(windows)]
+    {
+        let _ = colored::control::set_virtual_terminal(true);
+    }
+    set_colors(choice);
+}
+
+/// Turn colors on or off for the rest of the run
+///
+/// `auto` colors a terminal only: `CLICOLOR_FORCE=1` forces colors, a non-empty
+/// `NO_COLOR` or `CLICOLOR=0` turns them off, and legacy Windows consoles (cmd.exe
+/// or PowerShell in conhost) get none.
+pub fn set_colors(choice: ColorChoice) {
+    use std::io::IsTerminal;
+    colored::control::set_override(use_colors(
+        choice,
+        std::io::stdout().is_terminal() && !legacy_console(),
+        |name| std::env::var(name).ok(),
+    ));
+}
+
+fn use_colors(
+    choice: ColorChoice,
+    capable_terminal: bool,
+    var: impl Fn(&str) -> Option<String>,
+) -> bool {
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            if let Some(force) = var("CLICOLOR_FORCE").filter(|v| !v.is_empty()) {
+                return force != "0";
+            }
+            if var("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+                return false;
+            }
+            capable_terminal && var("CLICOLOR").as_deref() != Some("0")
+        }
+    }
+}
+
+/// Whether the console is a legacy Windows console without emoji or reliable colors
+pub fn legacy_console() -> bool {
+    is_legacy(cfg!(windows), |name| std::env::var_os(name).is_some())
+}
+
+/// Whether stdout is a terminal that renders OSC 8 hyperlinks
+///
+/// `FORCE_HYPERLINK=1` (or `0`) overrides the detection.
+pub fn supports_hyperlinks() -> bool {
+    use std::io::IsTerminal;
+    detect_hyperlinks(std::io::stdout().is_terminal(), |name| {
+        std::env::var(name).ok()
+    })
+}
+
+/// Columns of the terminal on stdout, `None` when output is piped
+pub fn width() -> Option<usize> {
+    terminal_size::terminal_size().map(|(w, _)| usize::from(w.0))
+}
+
+fn detect_hyperlinks(is_terminal: bool, var: impl Fn(&str) -> Option<String>) -> bool {
+    if let Some(force) = var("FORCE_HYPERLINK") {
+        return force != "0";
+    }
+    if !is_terminal {
+        return false;
+    }
+    if var("TERM_PROGRAM").is_some_and(|p| HYPERLINK_PROGRAMS.contains(&p.as_str())) {
+        return true;
+    }
+    if HYPERLINK_VARS.iter().any(|name| var(name).is_some()) {
+        return true;
+    }
+    // GNOME Terminal, Tilix and other VTE terminals since 0.50
+    if var("VTE_VERSION")
+        .and_then(|v| v.parse::<u32>().ok())
+        .is_some_and(|v| v >= 5000)
+    {
+        return true;
+    }
+    var("TERM").is_some_and(|t| t.contains("kitty") || t.starts_with("foot") || t == "alacritty")
+}
+
+fn is_legacy(windows: bool, is_set: impl Fn(&str) -> bool) -> bool {
+    windows && !MODERN_TERMINAL_VARS.iter().any(|name| is_set(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_legacy() {
+        assert!(is_legacy(true, |_| false));
+        assert!(!is_legacy(true, |name| name == "WT_SESSION"));
+        assert!(!is_legacy(true, |name| name == "TERM"));
+        assert!(!is_legacy(false, |_| false));
+    }
+
+    #[test]
+    fn test_use_colors() {
+        let env = |pairs: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                pairs
+                    .iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| (*value).to_string())
+            }
+        };
+
+        assert!(use_colors(ColorChoice::Auto, true, env(&[])));
+        assert!(!use_colors(ColorChoice::Auto, false, env(&[])));
+        assert!(!use_colors(
+            ColorChoice::Auto,
+            true,
+            env(&[("NO_COLOR", "1")])
+        ));
+        assert!(use_colors(
+            ColorChoice::Auto,
+            true,
+            env(&[("NO_COLOR", "")])
+        ));
+        assert!(!use_colors(
+            ColorChoice::Auto,
+            true,
+            env(&[("CLICOLOR", "0")])
+        ));
+        assert!(use_colors(
+            ColorChoice::Auto,
+            false,
+            env(&[("CLICOLOR_FORCE", "1"), ("NO_COLOR", "1")])
+        ));
+        assert!(use_colors(
+            ColorChoice::Always,
+            false,
+            env(&[("NO_COLOR", "1")])
+        ));
+        assert!(!use_colors(
+            ColorChoice::Never,
+            true,
+            env(&[("CLICOLOR_FORCE", "1")])
+        ));
+    }
+
+    #[test]
+    fn test_detect_hyperlinks() {
+        let env = |pairs: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                pairs
+                    .iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| (*value).to_string())
+            }
+        };
+
+        assert!(detect_hyperlinks(true, env(&[("TERM", "xterm-kitty")])));
+        assert!(detect_hyperlinks(true, env(&[("TERM_PROGRAM", "WezTerm")])));
+        assert!(detect_hyperlinks(true, env(&[("VTE_VERSION", "7200")])));
+        assert!(!detect_hyperlinks(true, env(&[("VTE_VERSION", "4600")])));
+        assert!(!detect_hyperlinks(true, env(&[("TERM", "xterm-256color")])));
+        assert!(!detect_hyperlinks(false, env(&[("TERM", "xterm-kitty")])));
+        assert!(detect_hyperlinks(false, env(&[("FORCE_HYPERLINK", "1")])));
+        assert!(!detect_hyperlinks(
+            true,
+            env(&[("FORCE_HYPERLINK", "0"), ("TERM", "xterm-kitty")])
+        ));
+    }
+}
@@ -0,0 +1,183 @@
+use colored::{Color, ColoredString, Colorize};
+
+use crate::config::{ThemeConfig, ThemePreset};
+use crate::icons;
+
+/// How one field of the MOTD output is colored
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Paint {
+    /// The provider's color (GitHub purple, GitLab red, Gitea green)
+    Provider,
+    /// No color
+    Plain,
+    Color(Color),
+}
+
+impl Paint {
+    /// Parse a color name (`bright cyan`, `bright_cyan`), `#rrggbb`, `provider` or `none`
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let spec = spec.trim();
+        match spec.to_lowercase().as_str() {
+            "provider" => return Ok(Self::Provider),
+            "none" | "plain" => return Ok(Self::Plain),
+            _ => {}
+        }
+
+        if let Some(hex) = spec.strip_prefix('#') {
+            let channel = |idx: usize| {
+                hex.get(idx..idx + 2)
+                    .and_then(|c| u8::from_str_radix(c, 16).ok())
+            };
+            return match (hex.len(), channel(0), channel(2), channel(4)) {
+                (6, Some(r), Some(g), Some(b)) => Ok(Self::Color(Color::TrueColor { r, g, b })),
+                _ => Err(format!("invalid hex color `{spec}`, expected #rrggbb")),
+            };
+        }
+
+        spec.replace('_', " ")
+            .parse::<Color>()
+            .map(Self::Color)
+            .map_err(|()| {
+                format!("unknown color `{spec}`, use a name like `bright cyan`, `#rrggbb`, `provider` or `none`")
+            })
+    }
+
+    /// Color `text`, using `provider` for [`Paint::Provider`]
+    pub fn apply(self, text: &str, provider: &str) -> ColoredString {
+        match self {
+            Self::Provider => {
+                icons::look(provider).map_or_else(|| text.white(), |look| text.color(look.color))
+            }
+            Self::Plain => text.normal(),
+            Self::Color(color) => text.color(color),
+        }
+    }
+}
+
+/// Colors per field of the MOTD output, resolved once from `[theme]`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    pub icon: Paint,
+    pub name: Paint,
+    /// Stars gained today
+    pub stars: Paint,
+    /// Total stars, shown when daily stars are unavailable
+    pub stars_total: Paint,
+    pub language: Paint,
+    pub description: Paint,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::preset(ThemePreset::Dark)
+    }
+}
+
+impl Theme {
+    /// Colors of a bundled preset
+    pub fn preset(preset: ThemePreset) -> Self {
+        let rgb = |r, g, b| Paint::Color(Color::TrueColor { r, g, b });
+        match preset {
+            ThemePreset::Dark => Self {
+                icon: Paint::Provider,
+                name: Paint::Color(Color::BrightCyan),
+                stars: Paint::Color(Color::BrightGreen),
+                stars_total: Paint::Color(Color::BrightBlack),
+                language: Paint::Color(Color::BrightYellow),
+                description: Paint::Color(Color::White),
+            },
+            ThemePreset::Light => Self {
+                icon: Paint::Provider,
+                name: Paint::Color(Color::Blue),
+                stars: Paint::Color(Color::Green),
+                stars_total: Paint::Color(Color::BrightBlack),
+                language: Paint::Color(Color::Magenta),
+                description: Paint::Color(Color::Black),
+            },
+            ThemePreset::Monochrome => Self {
+                icon: Paint::Plain,
+                name: Paint::Plain,
+                stars: Paint::Plain,
+                stars_total: Paint::Plain,
+                language: Paint::Plain,
+                description: Paint::Plain,
+            },
+            // Accent and content tones of Ethan Schoonover's Solarized palette
+            ThemePreset::Solarized => Self {
+                icon: Paint::Provider,
+                name: rgb(0x26, 0x8b, 0xd2),
+                stars: rgb(0x85, 0x99, 0x00),
+                stars_total: rgb(0x58, 0x6e, 0x75),
+                language: rgb(0xb5, 0x89, 0x00),
+                description: rgb(0x83, 0x94, 0x96),
+            },
+        }
+    }
+
+    /// The preset with the per-field overrides of the config applied
+    pub fn from_config(config: &ThemeConfig) -> Self {
+        let mut theme = Self::preset(config.preset);
+        let overrides = [
+            (&config.icon, &mut theme.icon),
+            (&config.name, &mut theme.name),
+            (&config.language, &mut theme.language),
+            (&config.description, &mut theme.description),
+        ];
+        for (spec, paint) in overrides {
+            if let Some(spec) = spec {
+                *paint = spec.paint();
+            }
+        }
+        // One `stars` color covers both counts
+        if let Some(ref spec) = config.stars {
+            theme.stars = spec.paint();
+            theme.stars_total = spec.paint();
+        }
+        theme
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ThemeColor;
+
+    #[test]
+    fn test_parse_paint() {
+        assert_eq!(Paint::parse("provider"), Ok(Paint::Provider));
+        assert_eq!(Paint::parse("None"), Ok(Paint::Plain));
+        assert_eq!(
+            Paint::parse("bright_cyan"),
+            Ok(Paint::Color(Color::BrightCyan))
+        );
+        assert_eq!(
+            Paint::parse("#268bd2"),
+            Ok(Paint::Color(Color::TrueColor {
+                r: 0x26,
+                g: 0x8b,
+                b: 0xd2
+            }))
+        );
+        assert!(Paint::parse("#268bd").is_err());
+        assert!(Paint::parse("#zz8bd2").is_err());
+        assert!(Paint::parse("chartreuse").is_err());
+    }
+
+    #[test]
+    fn test_theme_overrides() {
+        let config = ThemeConfig {
+            preset: ThemePreset::Monochrome,
+            name: Some(ThemeColor::try_from("red".to_string()).unwrap()),
+            stars: Some(ThemeColor::try_from("green".to_string()).unwrap()),
+            ..ThemeConfig::default()
+        };
+        let theme = Theme::from_config(&config);
+        assert_eq!(theme.name, Paint::Color(Color::Red));
+        assert_eq!(theme.stars_total, Paint::Color(Color::Green));
+        assert_eq!(theme.language, Paint::Plain);
+        assert_eq!(
+            Theme::from_config(&ThemeConfig::default()),
+            Theme::default()
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use anyhow::{Context, Result};
+use std::process::Command;
+
+use crate::config::TodoConfig;
+use crate::hooks::shell_command;
+
+/// What the created task is about
+#[derive(Debug, Clone)]
+pub struct TodoItem {
+    /// Repository name (`owner/repo`)
+    pub name: String,
+    pub url: String,
+    pub description: Option<String>,
+}
+
+impl TodoItem {
+    /// Task title, e.g. `try out owner/repo`
+    pub fn title(&self) -> String {
+        format!("try out {}", self.name)
+    }
+}
+
+/// Create a task with the configured command, or Taskwarrior when none is set
+///
+/// Taskwarrior tasks get the `+trotd` tag and the URL and description as annotations.
+pub fn create(config: &TodoConfig, item: &TodoItem) -> Result<()> {
+    if let Some(template) = config.command.as_deref().filter(|c| !c.trim().is_empty()) {
+        let command = expand(template, item);
+        return run(&mut shell_command(&command), &command);
+    }
+
+    run(
+        Command::new("task").args(["add", &item.title(), "+trotd"]),
+        "task add",
+    )?;
+    for annotation in std::iter::once(&item.url).chain(&item.description) {
+        run(
+            Command::new("task").args(["+LATEST", "annotate", annotation]),
+            "task annotate",
+        )?;
+    }
+    Ok(())
+}
+
+fn run(cmd: &mut Command, display: &str) -> Result<()> {
+    let status = cmd
+        .status()
+        .with_context(|| format!("Failed to run `{display}`. Is it installed?"))?;
+    if !status.success() {
+        anyhow::bail!("`{display}` exited with {status}");
+    }
+    Ok(())
+}
+
+/// Fill `{title}`, `{name}`, `{url}` and `{description}`, each quoted for the shell
+fn expand(template: &str, item: &TodoItem) -> String {
+    template
+        .replace("{title}", &shell_quote(&item.title()))
+        .replace("{name}", &shell_quote(&item.name))
+        .replace("{url}", &shell_quote(&item.url))
+        .replace(
+            "{description}",
+            &shell_quote(item.description.as_deref().unwrap_or("")),
+        )
+}
+
+#[cfg(not(windows))]
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+#[cfg(windows)]
+fn shell_quote(value: &str) -> String {
+    // cmd.exe has no escape for quotes inside quotes; drop them
+    format!("\"{}\"", value.replace('"', ""))
+}
+
+#[cfg(all(test, not(windows)))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_quotes_values() {
+        let item = TodoItem {
+            name: "owner/tool".to_string(),
+            url: "https://github.com/owner/tool".to_string(),
+            description: Some("It's $(fast)".to_string()),
+        };
+        assert_eq!(
+            expand("todo.sh add {title} {url} -- {description}", &item),
+            r"todo.sh add 'try out owner/tool' 'https://github.com/owner/tool' -- 'It'\''s $(fast)'"
+        );
+    }
+
+    #[test]
+    fn test_create_runs_template() {
+        let temp_file = std::env::temp_dir().join(format!(
+            "trotd-todo-test-{}",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let config = TodoConfig {
+            command: Some(format!("echo {{title}} {{url}} > {}", temp_file.display())),
+        };
+        let item = TodoItem {
+            name: "owner/tool".to_string(),
+            url: "https://github.com/owner/tool".to_string(),
+            description: None,
+        };
+
+        create(&config, &item).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&temp_file).unwrap(),
+            "try out owner/tool https://github.com/owner/tool\n"
+        );
+
+        let _ = std::fs::remove_file(&temp_file);
+    }
+}
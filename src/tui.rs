@@ -0,0 +1,239 @@
+use anyhow::{Context, Result};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+use std::fmt::Write;
+
+use crate::interactive::Command;
+use crate::model::Repo;
+use crate::render::{render_to_string, OutputFormat, RenderOptions};
+
+/// Key hints shown in the status line while no action result is pending
+const KEYS: &str = "↑/↓ move · enter details · s star · c clone · o open · h homepage · q quit";
+
+/// What a key press asks of the browser
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+    Up,
+    Down,
+    Top,
+    Bottom,
+    ToggleDetails,
+    /// Leave the terminal to the caller for one of the prompt's commands
+    Run(Command),
+}
+
+/// Full-screen list of the shown repos for `--interactive`
+///
+/// Starring, cloning and opening run outside the TUI, so clone progress and the
+/// safety confirmation get the normal terminal; the browser returns each such
+/// command from `next_command` and resumes where it left off.
+pub struct Browser<'a> {
+    repos: &'a [Repo],
+    /// Plain-text details per repo, as `show` prints them
+    details: Vec<String>,
+    state: ListState,
+    show_details: bool,
+    status: Option<String>,
+}
+
+impl<'a> Browser<'a> {
+    pub fn new(repos: &'a [Repo], opts: &RenderOptions) -> Self {
+        let details = repos
+            .iter()
+            .map(|repo| {
+                render_to_string(std::slice::from_ref(repo), OutputFormat::Accessible, opts)
+            })
+            .collect();
+        Self {
+            repos,
+            details,
+            state: ListState::default().with_selected(Some(0)),
+            show_details: false,
+            status: None,
+        }
+    }
+
+    /// Show the outcome of the last command in the status line
+    pub fn set_status(&mut self, status: String) {
+        self.status = Some(status);
+    }
+
+    /// Browse until the user picks a command to run, `None` once they quit
+    pub fn next_command(&mut self) -> Result<Option<Command>> {
+        let mut terminal = ratatui::try_init().context("Failed to start the terminal UI")?;
+        let picked = self.event_loop(&mut terminal);
+        ratatui::try_restore().context("Failed to restore the terminal")?;
+        picked
+    }
+
+    fn event_loop(&mut self, terminal: &mut DefaultTerminal) -> Result<Option<Command>> {
+        loop {
+            terminal
+                .draw(|frame| self.draw(frame))
+                .context("Failed to draw the terminal UI")?;
+            let Event::Key(key) = event::read().context("Failed to read a key")? else {
+                continue;
+            };
+            let selected = self.state.selected().unwrap_or(0);
+            match map_key(key, selected) {
+                Some(Key::Run(Command::Quit)) => return Ok(None),
+                Some(Key::Run(command)) => return Ok(Some(command)),
+                Some(key) => self.apply(key),
+                None => {}
+            }
+        }
+    }
+
+    fn apply(&mut self, key: Key) {
+        self.status = None;
+        let last = self.repos.len().saturating_sub(1);
+        let selected = self.state.selected().unwrap_or(0);
+        match key {
+            Key::Up => self.state.select(Some(selected.saturating_sub(1))),
+            Key::Down => self.state.select(Some((selected + 1).min(last))),
+            Key::Top => self.state.select(Some(0)),
+            Key::Bottom => self.state.select(Some(last)),
+            Key::ToggleDetails => self.show_details = !self.show_details,
+            Key::Run(_) => {}
+        }
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, status] =
+            Layout::vertical([Constraint::Min(3), Constraint::Length(1)]).areas(frame.area());
+        let (list_area, details_area) = if self.show_details {
+            let [list, details] =
+                Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .areas(main);
+            (list, Some(details))
+        } else {
+            (main, None)
+        };
+
+        let items: Vec<ListItem> = self.repos.iter().map(row).map(ListItem::new).collect();
+        let list = List::new(items)
+            .block(Block::bordered().title(format!(" Trending repos ({}) ", self.repos.len())))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(list, list_area, &mut self.state);
+
+        if let Some(area) = details_area {
+            let selected = self.state.selected().unwrap_or(0);
+            let text = self.details.get(selected).map_or("", String::as_str);
+            let details = Paragraph::new(text)
+                .block(Block::bordered().title(" Details "))
+                .wrap(Wrap { trim: false });
+            frame.render_widget(details, area);
+        }
+
+        let status_text = self.status.as_deref().unwrap_or(KEYS);
+        frame.render_widget(Paragraph::new(Line::from(status_text)), status);
+    }
+}
+
+/// One list row: icon, name, language and today's stars
+fn row(repo: &Repo) -> String {
+    let mut row = format!("{} {}", repo.icon, repo.name);
+    if let Some(ref language) = repo.language {
+        let _ = write!(row, "  {language}");
+    }
+    if let Some(stars) = repo.stars_today {
+        let _ = write!(row, "  ★{stars} today");
+    }
+    row
+}
+
+/// Translate a key press on the repo at `selected`, ignoring releases and unbound keys
+fn map_key(key: KeyEvent, selected: usize) -> Option<Key> {
+    if key.kind != KeyEventKind::Press {
+        return None;
+    }
+    Some(match key.code {
+        KeyCode::Up | KeyCode::Char('k') => Key::Up,
+        KeyCode::Down | KeyCode::Char('j') => Key::Down,
+        KeyCode::Home | KeyCode::Char('g') => Key::Top,
+        KeyCode::End | KeyCode::Char('G') => Key::Bottom,
+        KeyCode::Enter | KeyCode::Char('d') => Key::ToggleDetails,
+        KeyCode::Char('s') => Key::Run(Command::Star(selected)),
+        KeyCode::Char('c') => Key::Run(Command::Clone(selected)),
+        KeyCode::Char('o') => Key::Run(Command::Open(selected)),
+        KeyCode::Char('h') => Key::Run(Command::Homepage(selected)),
+        KeyCode::Char('q') | KeyCode::Esc => Key::Run(Command::Quit),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::crossterm::event::KeyModifiers;
+    use ratatui::Terminal;
+
+    fn create_test_repo(name: &str) -> Repo {
+        Repo {
+            language: Some("Rust".to_string()),
+            description: Some(format!("About {name}")),
+            stars_today: Some(42),
+            stars_total: Some(1000),
+            ..Repo::test(name)
+        }
+    }
+
+    #[test]
+    fn test_map_key() {
+        let press = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        assert_eq!(map_key(press(KeyCode::Char('j')), 0), Some(Key::Down));
+        assert_eq!(map_key(press(KeyCode::Up), 0), Some(Key::Up));
+        assert_eq!(
+            map_key(press(KeyCode::Char('s')), 2),
+            Some(Key::Run(Command::Star(2)))
+        );
+        assert_eq!(
+            map_key(press(KeyCode::Char('c')), 1),
+            Some(Key::Run(Command::Clone(1)))
+        );
+        assert_eq!(
+            map_key(press(KeyCode::Esc), 0),
+            Some(Key::Run(Command::Quit))
+        );
+        assert_eq!(map_key(press(KeyCode::Char('x')), 0), None);
+
+        let mut release = press(KeyCode::Char('q'));
+        release.kind = KeyEventKind::Release;
+        assert_eq!(map_key(release, 0), None);
+    }
+
+    #[test]
+    fn test_browse_and_details() {
+        let repos = vec![
+            create_test_repo("owner/first"),
+            create_test_repo("owner/second"),
+        ];
+        let mut browser = Browser::new(&repos, &RenderOptions::default());
+        browser.apply(Key::Down);
+        browser.apply(Key::Down);
+        assert_eq!(browser.state.selected(), Some(1));
+        browser.apply(Key::Top);
+        assert_eq!(browser.state.selected(), Some(0));
+        browser.apply(Key::Bottom);
+        browser.apply(Key::ToggleDetails);
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 12)).unwrap();
+        terminal.draw(|frame| browser.draw(frame)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect();
+        assert!(screen.contains("> [GH] owner/second"));
+        assert!(screen.contains("About owner/second"));
+        assert!(screen.contains("s star"));
+    }
+}